@@ -0,0 +1,24 @@
+# wasm32-unknown-unknown configuration
+CC_wasm32-unknown-unknown=clang
+CXX_wasm32-unknown-unknown=clang++
+CPP_wasm32-unknown-unknown=$(CPP)
+AR_wasm32-unknown-unknown=llvm-ar
+CFG_LIB_NAME_wasm32-unknown-unknown=lib$(1).so
+CFG_STATIC_LIB_NAME_wasm32-unknown-unknown=lib$(1).a
+CFG_LIB_GLOB_wasm32-unknown-unknown=lib$(1)-*.so
+CFG_LIB_DSYM_GLOB_wasm32-unknown-unknown=lib$(1)-*.dylib.dSYM
+CFG_JEMALLOC_CFLAGS_wasm32-unknown-unknown := --target=wasm32-unknown-unknown $(CFLAGS)
+CFG_GCCISH_CFLAGS_wasm32-unknown-unknown := -Wall -Werror -g --target=wasm32-unknown-unknown $(CFLAGS)
+CFG_GCCISH_CXXFLAGS_wasm32-unknown-unknown := -fno-rtti $(CXXFLAGS)
+CFG_GCCISH_LINK_FLAGS_wasm32-unknown-unknown :=
+CFG_GCCISH_DEF_FLAG_wasm32-unknown-unknown :=
+CFG_LLC_FLAGS_wasm32-unknown-unknown :=
+CFG_INSTALL_NAME_wasm32-unknown-unknown =
+CFG_EXE_SUFFIX_wasm32-unknown-unknown = .wasm
+CFG_WINDOWSY_wasm32-unknown-unknown :=
+CFG_UNIXY_wasm32-unknown-unknown :=
+CFG_LDPATH_wasm32-unknown-unknown :=
+CFG_RUN_wasm32-unknown-unknown=$(2)
+CFG_RUN_TARG_wasm32-unknown-unknown=$(call CFG_RUN_wasm32-unknown-unknown,,$(2))
+CFG_GNU_TRIPLE_wasm32-unknown-unknown := wasm32-unknown-unknown
+CFG_DISABLE_JEMALLOC_wasm32-unknown-unknown := 1
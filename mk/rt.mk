@@ -264,10 +264,10 @@ ifeq ($$(findstring freebsd,$(1)),freebsd)
 	COMPRT_CFLAGS_$(1) += -I/usr/include/c++/v1
 endif
 
-ifeq ($$(findstring emscripten,$(1)),emscripten)
+ifneq ($$(findstring emscripten,$(1))$$(findstring wasm32,$(1)),)
 
 # FIXME: emscripten doesn't use compiler-rt and can't build it without
-# further hacks
+# further hacks, and wasm32 gets everything it needs from LLVM itself
 $$(COMPRT_LIB_$(1)):
 	touch $$@
 
@@ -286,7 +286,7 @@ $$(COMPRT_LIB_$(1)): $$(COMPRT_DEPS) $$(MKFILE_DEPS)
 		triple-builtins
 	$$(Q)cp $$(COMPRT_BUILD_DIR_$(1))/triple/builtins/libcompiler_rt.a $$@
 
-endif # if emscripten
+endif # if emscripten or wasm32
 endif
 
 ################################################################################
@@ -323,8 +323,8 @@ $$(BACKTRACE_LIB_$(1)):
 	touch $$@
 else
 
-ifeq ($$(findstring emscripten,$(1)),emscripten)
-# FIXME: libbacktrace doesn't understand the emscripten triple
+ifneq ($$(findstring emscripten,$(1))$$(findstring wasm32,$(1)),)
+# FIXME: libbacktrace doesn't understand the emscripten or wasm32 triples
 $$(BACKTRACE_LIB_$(1)):
 	touch $$@
 else
@@ -376,7 +376,7 @@ $$(BACKTRACE_LIB_$(1)): $$(BACKTRACE_BUILD_DIR_$(1))/Makefile $$(MKFILE_DEPS)
 		INCDIR=$(S)src/libbacktrace
 	$$(Q)cp $$(BACKTRACE_BUILD_DIR_$(1))/.libs/libbacktrace.a $$@
 
-endif # endif for emscripten
+endif # endif for emscripten or wasm32
 endif # endif for msvc
 endif # endif for ios
 endif # endif for darwin
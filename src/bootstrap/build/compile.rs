@@ -55,10 +55,56 @@ pub fn std<'a>(build: &'a Build, target: &str, compiler: &Compiler<'a>) {
         }
     }
 
+    // The build scripts of the sanitizer runtime crates build compiler-rt
+    // against our LLVM, they skip doing so if this isn't set.
+    if build.config.sanitizers && target == "x86_64-unknown-linux-gnu" {
+        cargo.env("LLVM_CONFIG", build.llvm_config(&build.config.build));
+    }
+
     build.run(&mut cargo);
     std_link(build, target, compiler, compiler.host);
 }
 
+/// Build libcore and liballoc.
+///
+/// This is the counterpart of `std` above for bare-metal targets, where that's
+/// as much of the standard library as can be built. The artifacts are placed
+/// where libstd's would be and are also linked into the sysroot directory.
+pub fn core<'a>(build: &'a Build, target: &str, compiler: &Compiler<'a>) {
+    println!("Building stage{} core artifacts ({} -> {})", compiler.stage,
+             compiler.host, target);
+
+    let libdir = build.sysroot_libdir(compiler, target);
+    let _ = fs::remove_dir_all(&libdir);
+    t!(fs::create_dir_all(&libdir));
+
+    let out_dir = build.cargo_out(compiler, Mode::Libstd, target);
+    build.clear_if_dirty(&out_dir, &build.compiler_path(compiler));
+    let mut cargo = build.cargo(compiler, Mode::Libstd, target, "build");
+    cargo.arg("--manifest-path")
+         .arg(build.src.join("src/liballoc/Cargo.toml"));
+    build.run(&mut cargo);
+    core_link(build, target, compiler, compiler.host);
+}
+
+/// Link the libcore and liballoc rlibs into the sysroot location.
+///
+/// Links those artifacts generated in the given `stage` for `target` produced
+/// by `compiler` into `host`'s sysroot.
+pub fn core_link(build: &Build,
+                 target: &str,
+                 compiler: &Compiler,
+                 host: &str) {
+    let target_compiler = Compiler::new(compiler.stage, host);
+    let libdir = build.sysroot_libdir(&target_compiler, target);
+    let out_dir = build.cargo_out(compiler, Mode::Libstd, target);
+    if host != compiler.host {
+        let _ = fs::remove_dir_all(&libdir);
+        t!(fs::create_dir_all(&libdir));
+    }
+    add_to_sysroot(&out_dir, &libdir);
+}
+
 /// Link all libstd rlibs/dylibs into the sysroot location.
 ///
 /// Links those artifacts generated in the given `stage` for `target` produced
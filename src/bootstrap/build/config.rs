@@ -64,6 +64,8 @@ pub struct Config {
     pub use_jemalloc: bool,
 
     // misc
+    pub sanitizers: bool,
+    pub profiler: bool,
     pub channel: String,
     pub musl_root: Option<PathBuf>,
     pub prefix: Option<String>,
@@ -102,6 +104,8 @@ struct Build {
     rustc: Option<String>,
     compiler_docs: Option<bool>,
     docs: Option<bool>,
+    sanitizers: Option<bool>,
+    profiler: Option<bool>,
 }
 
 /// TOML representation of how the LLVM build is configured.
@@ -198,6 +202,8 @@ impl Config {
         config.rustc = build.rustc;
         config.cargo = build.cargo;
         set(&mut config.compiler_docs, build.compiler_docs);
+        set(&mut config.sanitizers, build.sanitizers);
+        set(&mut config.profiler, build.profiler);
         set(&mut config.docs, build.docs);
 
         if let Some(ref llvm) = toml.llvm {
@@ -294,6 +300,8 @@ impl Config {
                 ("JEMALLOC", self.use_jemalloc),
                 ("DEBUG_JEMALLOC", self.debug_jemalloc),
                 ("RPATH", self.rust_rpath),
+                ("SANITIZERS", self.sanitizers),
+                ("PROFILER", self.profiler),
             }
 
             match key {
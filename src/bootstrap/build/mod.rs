@@ -173,6 +173,9 @@ impl Build {
                 TestHelpers { _dummy } => {
                     native::test_helpers(self, target.target);
                 }
+                Libcore { compiler } => {
+                    compile::core(self, target.target, &compiler);
+                }
                 Libstd { compiler } => {
                     compile::std(self, target.target, &compiler);
                 }
@@ -182,6 +185,9 @@ impl Build {
                 Librustc { compiler } => {
                     compile::rustc(self, target.target, &compiler);
                 }
+                LibcoreLink { compiler, host } => {
+                    compile::core_link(self, target.target, &compiler, host);
+                }
                 LibstdLink { compiler, host } => {
                     compile::std_link(self, target.target, &compiler, host);
                 }
@@ -504,6 +510,9 @@ impl Build {
         if self.config.use_jemalloc {
             features.push_str(" jemalloc");
         }
+        if self.config.profiler {
+            features.push_str(" profiler");
+        }
         return features
     }
 
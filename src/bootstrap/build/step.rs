@@ -11,6 +11,7 @@
 use std::collections::HashSet;
 
 use build::{Build, Compiler};
+use build::util::is_bare_metal;
 
 #[derive(Hash, Eq, PartialEq, Clone, Debug)]
 pub struct Step<'a> {
@@ -26,13 +27,19 @@ macro_rules! targets {
             (rustc, Rustc { stage: u32 }),
 
             // Steps for the two main cargo builds. These are parameterized over
-            // the compiler which is producing the artifact.
+            // the compiler which is producing the artifact. Bare-metal targets
+            // only get libcore and liballoc, which is what `libcore` builds.
+            (libcore, Libcore { compiler: Compiler<'a> }),
             (libstd, Libstd { compiler: Compiler<'a> }),
             (libtest, Libtest { compiler: Compiler<'a> }),
             (librustc, Librustc { compiler: Compiler<'a> }),
 
             // Links the target produced by the compiler provided into the
             // host's directory also provided.
+            (libcore_link, LibcoreLink {
+                compiler: Compiler<'a>,
+                host: &'a str
+            }),
             (libstd_link, LibstdLink {
                 compiler: Compiler<'a>,
                 host: &'a str
@@ -186,7 +193,15 @@ fn top_level(build: &Build) -> Vec<Step> {
                     continue
                 }
 
-                if host.target == build.config.build {
+                if is_bare_metal(target) {
+                    if host.target == build.config.build {
+                        targets.push(host.target(target)
+                                         .libcore(host.compiler(stage)));
+                    } else {
+                        targets.push(host.target(target)
+                                         .libcore_link(t.compiler(stage), host.target));
+                    }
+                } else if host.target == build.config.build {
                     targets.push(host.target(target)
                                      .libtest(host.compiler(stage)));
                 } else {
@@ -277,6 +292,9 @@ impl<'a> Step<'a> {
             Source::Libtest { compiler } => {
                 vec![self.libstd(compiler)]
             }
+            Source::Libcore { compiler } => {
+                vec![self.rustc(compiler.stage).target(compiler.host)]
+            }
             Source::Libstd { compiler } => {
                 vec![self.compiler_rt(()),
                      self.rustc(compiler.stage).target(compiler.host)]
@@ -288,6 +306,10 @@ impl<'a> Step<'a> {
             Source::LibtestLink { compiler, host } => {
                 vec![self.libtest(compiler), self.libstd_link(compiler, host)]
             }
+            Source::LibcoreLink { compiler, host } => {
+                vec![self.libcore(compiler),
+                     self.target(host).rustc(compiler.stage)]
+            }
             Source::LibstdLink { compiler, host } => {
                 vec![self.libstd(compiler),
                      self.target(host).rustc(compiler.stage)]
@@ -422,8 +444,11 @@ impl<'a> Step<'a> {
                 // We want to package up as many target libraries as possible
                 // for the `rust-std` package, so if this is a host target we
                 // depend on librustc and otherwise we just depend on libtest.
+                // Bare-metal targets only have libcore and liballoc to ship.
                 if build.config.host.iter().any(|t| t == self.target) {
                     vec![self.librustc(compiler)]
+                } else if is_bare_metal(self.target) {
+                    vec![self.libcore(compiler)]
                 } else {
                     vec![self.libtest(compiler)]
                 }
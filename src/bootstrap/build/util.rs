@@ -70,6 +70,14 @@ pub fn is_dylib(name: &str) -> bool {
     name.ends_with(".dylib") || name.ends_with(".so") || name.ends_with(".dll")
 }
 
+/// Returns whether `target` is a bare-metal target.
+///
+/// There's no operating system for libstd to build on top of for these, so only
+/// libcore and liballoc are built for them.
+pub fn is_bare_metal(target: &str) -> bool {
+    target.contains("-none-")
+}
+
 pub fn libdir(target: &str) -> &'static str {
     if target.contains("windows") {"bin"} else {"lib"}
 }
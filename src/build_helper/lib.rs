@@ -10,6 +10,9 @@
 
 #![deny(warnings)]
 
+extern crate cmake;
+
+use std::env;
 use std::process::{Command, Stdio};
 use std::path::{Path, PathBuf};
 
@@ -68,6 +71,29 @@ pub fn output(cmd: &mut Command) -> String {
     String::from_utf8(output.stdout).unwrap()
 }
 
+/// Builds the compiler-rt runtime of the sanitizer `name` (e.g. `asan`) and
+/// tells cargo to link it into the crate whose build script calls this.
+///
+/// The runtime is only built if the build system hands us an LLVM to build
+/// compiler-rt against, which it does when sanitizers are enabled.
+pub fn build_sanitizer_runtime(name: &str) {
+    let llvm_config = match env::var_os("LLVM_CONFIG") {
+        Some(llvm_config) => llvm_config,
+        None => return,
+    };
+    let dst = cmake::Config::new("../compiler-rt")
+        .define("COMPILER_RT_BUILD_SANITIZERS", "ON")
+        .define("COMPILER_RT_BUILD_EMUTLS", "OFF")
+        .define("LLVM_CONFIG_PATH", llvm_config)
+        .out_dir(env::var("OUT_DIR").unwrap())
+        .build_target(name)
+        .build();
+
+    println!("cargo:rustc-link-search=native={}",
+             dst.join("build/lib/linux").display());
+    println!("cargo:rustc-link-lib=static=clang_rt.{}-x86_64", name);
+}
+
 fn fail(s: &str) -> ! {
     println!("\n\n{}\n\n", s);
     std::process::exit(1);
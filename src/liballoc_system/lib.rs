@@ -20,6 +20,7 @@
 #![feature(allocator)]
 #![feature(libc)]
 #![feature(staged_api)]
+#![cfg_attr(target_arch = "wasm32", feature(link_llvm_intrinsics))]
 
 extern crate libc;
 
@@ -31,7 +32,8 @@ extern crate libc;
               target_arch = "mips",
               target_arch = "powerpc",
               target_arch = "powerpc64",
-              target_arch = "asmjs")))]
+              target_arch = "asmjs",
+              target_arch = "wasm32")))]
 const MIN_ALIGN: usize = 8;
 #[cfg(all(any(target_arch = "x86_64",
               target_arch = "aarch64")))]
@@ -217,3 +219,102 @@ mod imp {
         size
     }
 }
+
+// There's no system allocator to defer to with wasm, so this is a simple bump
+// allocator on top of the module's linear memory. The heap starts wherever the
+// memory the module was instantiated with ends and grows one 64KiB page at a
+// time. Memory is only ever reclaimed when the most recent allocation is freed
+// or resized, which is enough for the short-lived programs this target is
+// currently used for.
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    use core::cmp;
+    use core::ptr;
+
+    const PAGE_SIZE: usize = 64 * 1024;
+
+    extern {
+        #[link_name = "llvm.wasm.current.memory.i32"]
+        fn current_memory() -> i32;
+        #[link_name = "llvm.wasm.grow.memory.i32"]
+        fn grow_memory(pages: i32) -> i32;
+    }
+
+    // The next free byte of the heap and the end of linear memory.
+    static mut NEXT: usize = 0;
+    static mut END: usize = 0;
+
+    unsafe fn reserve(end: usize) -> bool {
+        if NEXT == 0 {
+            NEXT = current_memory() as usize * PAGE_SIZE;
+            END = NEXT;
+        }
+        if end <= END {
+            return true
+        }
+        let pages = (end - END + PAGE_SIZE - 1) / PAGE_SIZE;
+        if grow_memory(pages as i32) == -1 {
+            return false
+        }
+        END += pages * PAGE_SIZE;
+        true
+    }
+
+    pub unsafe fn allocate(size: usize, align: usize) -> *mut u8 {
+        if NEXT == 0 && !reserve(0) {
+            return ptr::null_mut()
+        }
+        let start = (NEXT + align - 1) & !(align - 1);
+        let end = match start.checked_add(size) {
+            Some(end) => end,
+            None => return ptr::null_mut(),
+        };
+        if !reserve(end) {
+            return ptr::null_mut()
+        }
+        NEXT = end;
+        start as *mut u8
+    }
+
+    pub unsafe fn reallocate(ptr: *mut u8, old_size: usize, size: usize, align: usize) -> *mut u8 {
+        if reallocate_inplace(ptr, old_size, size, align) == size {
+            return ptr
+        }
+        let new_ptr = allocate(size, align);
+        if !new_ptr.is_null() {
+            ptr::copy(ptr, new_ptr, cmp::min(size, old_size));
+            deallocate(ptr, old_size, align);
+        }
+        new_ptr
+    }
+
+    pub unsafe fn reallocate_inplace(ptr: *mut u8,
+                                     old_size: usize,
+                                     size: usize,
+                                     _align: usize)
+                                     -> usize {
+        // Only the most recent allocation can change size.
+        if ptr as usize + old_size != NEXT {
+            return old_size
+        }
+        let end = match (ptr as usize).checked_add(size) {
+            Some(end) => end,
+            None => return old_size,
+        };
+        if !reserve(end) {
+            return old_size
+        }
+        NEXT = end;
+        size
+    }
+
+    pub unsafe fn deallocate(ptr: *mut u8, old_size: usize, _align: usize) {
+        if ptr as usize + old_size == NEXT {
+            NEXT = ptr as usize;
+        }
+    }
+
+    pub fn usable_size(size: usize, _align: usize) -> usize {
+        size
+    }
+}
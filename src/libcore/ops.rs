@@ -72,6 +72,9 @@ use fmt;
 use convert::From;
 use marker::{Sized, Unsize};
 use num::One;
+use option::NoneError;
+use option::Option::{self, Some, None};
+use result::Result::{self, Ok, Err};
 
 /// The `Drop` trait is used to run some code when a value goes out of scope.
 /// This is sometimes called a 'destructor'.
@@ -2155,3 +2158,75 @@ pub trait BoxPlace<Data: ?Sized> : Place<Data> {
     /// Creates a globally fresh place.
     fn make_place() -> Self;
 }
+
+/// A type that the `?` operator can be applied to, and that a `do catch`
+/// block can evaluate to.
+///
+/// A value of such a type either carries a success value, which `?`
+/// unwraps, or an error value, which `?` propagates. `expr?` effectively
+/// desugars into:
+///
+/// ```rust,ignore
+/// match Carrier::into_result(expr) {
+///     Ok(val) => val,
+///     Err(err) => return Carrier::from_error(From::from(err)),
+/// }
+/// ```
+///
+/// where the `return` leaves the innermost enclosing `do catch` block
+/// instead of the function if there is one. The type returned from the
+/// function, or produced by the `do catch` block, only needs to implement
+/// `Carrier` itself, so `?` can convert between different carrier types
+/// as long as their errors are convertible with `From`.
+#[unstable(feature = "question_mark_carrier", issue = "31436")]
+pub trait Carrier {
+    /// The type of the value `?` produces on success.
+    type Success;
+    /// The type of the value `?` propagates on failure.
+    type Error;
+
+    /// Splits `self` into its success or error value.
+    fn into_result(self) -> Result<Self::Success, Self::Error>;
+
+    /// Wraps a success value, such as the final value of a `do catch` block.
+    fn from_success(v: Self::Success) -> Self;
+
+    /// Wraps an error value propagated by `?`.
+    fn from_error(v: Self::Error) -> Self;
+}
+
+#[unstable(feature = "question_mark_carrier", issue = "31436")]
+impl<T, E> Carrier for Result<T, E> {
+    type Success = T;
+    type Error = E;
+
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+
+    fn from_success(v: T) -> Self {
+        Ok(v)
+    }
+
+    fn from_error(v: E) -> Self {
+        Err(v)
+    }
+}
+
+#[unstable(feature = "question_mark_carrier", issue = "31436")]
+impl<T> Carrier for Option<T> {
+    type Success = T;
+    type Error = NoneError;
+
+    fn into_result(self) -> Result<T, NoneError> {
+        self.ok_or(NoneError)
+    }
+
+    fn from_success(v: T) -> Self {
+        Some(v)
+    }
+
+    fn from_error(_: NoneError) -> Self {
+        None
+    }
+}
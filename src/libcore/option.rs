@@ -927,3 +927,9 @@ impl<A, V: FromIterator<A>> FromIterator<Option<A>> for Option<V> {
         }
     }
 }
+
+/// The error type that results from applying the `?` operator to a `None`
+/// value.
+#[unstable(feature = "question_mark_carrier", issue = "31436")]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+pub struct NoneError;
@@ -10,28 +10,27 @@
 
 #![allow(private_no_mangle_fns)]
 
-use prelude::v1::*;
+use core::any::Any;
+use alloc::boxed::Box;
 
-use any::Any;
-use sys_common::libunwind as uw;
+use libunwind as uw;
 
 struct Exception {
     uwe: uw::_Unwind_Exception,
     cause: Option<Box<Any + Send + 'static>>,
 }
 
-pub unsafe fn panic(data: Box<Any + Send + 'static>) -> ! {
-    let exception: Box<_> = box Exception {
+pub unsafe fn panic(data: Box<Any + Send + 'static>) -> u32 {
+    let exception = Box::new(Exception {
         uwe: uw::_Unwind_Exception {
             exception_class: rust_exception_class(),
             exception_cleanup: exception_cleanup,
             private: [0; uw::unwinder_private_data_size],
         },
         cause: Some(data),
-    };
+    });
     let exception_param = Box::into_raw(exception) as *mut uw::_Unwind_Exception;
-    let error = uw::_Unwind_RaiseException(exception_param);
-    rtabort!("Could not unwind stack, error = {}", error as isize);
+    return uw::_Unwind_RaiseException(exception_param) as u32;
 
     extern fn exception_cleanup(_unwind_code: uw::_Unwind_Reason_Code,
                                 exception: *mut uw::_Unwind_Exception) {
@@ -82,7 +81,7 @@ fn rust_exception_class() -> uw::_Unwind_Exception_Class {
           not(all(windows, target_arch = "x86_64")),
           not(test)))]
 pub mod eabi {
-    use sys_common::libunwind as uw;
+    use libunwind as uw;
     use libc::c_int;
 
     extern {
@@ -138,7 +137,7 @@ pub mod eabi {
 
 #[cfg(all(target_os = "ios", target_arch = "arm", not(test)))]
 pub mod eabi {
-    use sys_common::libunwind as uw;
+    use libunwind as uw;
     use libc::c_int;
 
     extern {
@@ -193,7 +192,7 @@ pub mod eabi {
 // but otherwise works the same.
 #[cfg(all(target_arch = "arm", not(target_os = "ios"), not(test)))]
 pub mod eabi {
-    use sys_common::libunwind as uw;
+    use libunwind as uw;
     use libc::c_int;
 
     extern {
@@ -241,7 +240,7 @@ pub mod eabi {
     }
 }
 
-// See docs in the `unwind` module.
+// See docs in the crate root.
 #[cfg(all(target_os="windows", target_arch = "x86", target_env="gnu", not(test)))]
 #[lang = "eh_unwind_resume"]
 #[unwind]
@@ -253,7 +252,7 @@ unsafe extern fn rust_eh_unwind_resume(panic_ctx: *mut u8) -> ! {
 pub mod eh_frame_registry {
     // The implementation of stack unwinding is (for now) deferred to libgcc_eh, however Rust
     // crates use these Rust-specific entry points to avoid potential clashes with GCC runtime.
-    // See also: rtbegin.rs, `unwind` module.
+    // See also: rsbegin.rs, the crate root.
 
     #[link(name = "gcc_eh")]
     #[cfg(not(cargobuild))]
@@ -263,17 +262,13 @@ pub mod eh_frame_registry {
         fn __register_frame_info(eh_frame_begin: *const u8, object: *mut u8);
         fn __deregister_frame_info(eh_frame_begin: *const u8, object: *mut u8);
     }
-    #[cfg(not(test))]
     #[no_mangle]
-    #[unstable(feature = "libstd_sys_internals", issue = "0")]
     pub unsafe extern fn rust_eh_register_frames(eh_frame_begin: *const u8,
                                                  object: *mut u8) {
         __register_frame_info(eh_frame_begin, object);
     }
-    #[cfg(not(test))]
     #[no_mangle]
-    #[unstable(feature = "libstd_sys_internals", issue = "0")]
-    pub  unsafe extern fn rust_eh_unregister_frames(eh_frame_begin: *const u8,
+    pub unsafe extern fn rust_eh_unregister_frames(eh_frame_begin: *const u8,
                                                    object: *mut u8) {
         __deregister_frame_info(eh_frame_begin, object);
     }
@@ -56,7 +56,7 @@
 //! [win64]: http://msdn.microsoft.com/en-us/library/1eyas8tf.aspx
 //! [llvm]: http://llvm.org/docs/ExceptionHandling.html#background-on-windows-exceptions
 
-use sys::c;
+use windows as c;
 
 // A code which indicates panics that originate from Rust. Note that some of the
 // upper bits are used by the system so we just set them to 0 and ignore them.
@@ -66,15 +66,15 @@ const RUST_PANIC: c::DWORD = 0x00525354;
 pub use self::imp::*;
 
 mod imp {
-    use prelude::v1::*;
+    use alloc::boxed::Box;
+    use core::any::Any;
+    use core::mem;
+    use core::raw;
 
-    use any::Any;
-    use mem;
-    use raw;
+    use windows as c;
     use super::RUST_PANIC;
-    use sys::c;
 
-    pub unsafe fn panic(data: Box<Any + Send + 'static>) -> ! {
+    pub unsafe fn panic(data: Box<Any + Send + 'static>) -> u32 {
         // As mentioned above, the call stack here is preserved while the filter
         // functions are running, so it's ok to pass stack-local arrays into
         // `RaiseException`.
@@ -86,7 +86,7 @@ mod imp {
         let ptrs = mem::transmute::<_, raw::TraitObject>(data);
         let ptrs = [ptrs.data, ptrs.vtable];
         c::RaiseException(RUST_PANIC, 0, 2, ptrs.as_ptr() as *mut _);
-        rtabort!("could not unwind stack");
+        u32::max_value()
     }
 
     pub fn payload() -> [usize; 2] {
@@ -149,5 +149,5 @@ mod imp {
 #[lang = "eh_personality"]
 #[cfg(not(test))]
 fn rust_eh_personality() {
-    unsafe { ::intrinsics::abort() }
+    unsafe { ::core::intrinsics::abort() }
 }
@@ -0,0 +1,57 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Compiles the profiler part of compiler-rt, mirroring the flags of its own
+//! build system.
+
+extern crate gcc;
+
+use std::env;
+use std::path::Path;
+
+fn main() {
+    let target = env::var("TARGET").unwrap();
+    let mut cfg = gcc::Config::new();
+
+    let mut sources = vec!["GCDAProfiling.c",
+                           "InstrProfiling.c",
+                           "InstrProfilingBuffer.c",
+                           "InstrProfilingFile.c",
+                           "InstrProfilingMerge.c",
+                           "InstrProfilingMergeFile.c",
+                           "InstrProfilingPlatformDarwin.c",
+                           "InstrProfilingPlatformLinux.c",
+                           "InstrProfilingPlatformOther.c",
+                           "InstrProfilingRuntime.cc",
+                           "InstrProfilingUtil.c",
+                           "InstrProfilingValue.c",
+                           "InstrProfilingWriter.c"];
+
+    if target.contains("msvc") {
+        // Don't pull in extra libraries on MSVC.
+        cfg.flag("/Zl");
+        sources.push("WindowsMMap.c");
+        cfg.define("strdup", Some("_strdup"));
+        cfg.define("open", Some("_open"));
+        cfg.define("fdopen", Some("_fdopen"));
+    } else {
+        cfg.flag("-fno-builtin");
+        cfg.flag("-fvisibility=hidden");
+        cfg.flag("-fomit-frame-pointer");
+        cfg.flag("-ffreestanding");
+        cfg.define("VISIBILITY_HIDDEN", None);
+    }
+
+    for src in sources {
+        cfg.file(Path::new("../compiler-rt/lib/profile").join(src));
+    }
+
+    cfg.compile("libprofiler-rt.a");
+}
@@ -0,0 +1,22 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The compiler-rt profiler runtime, linked into artifacts built with
+//! `-C profile-generate` so they write out their execution profiles.
+
+#![crate_name = "profiler_builtins"]
+#![crate_type = "rlib"]
+#![cfg_attr(not(stage0), feature(profiler_runtime))]
+#![cfg_attr(not(stage0), profiler_runtime)]
+#![feature(staged_api)]
+#![no_std]
+#![unstable(feature = "profiler_runtime_lib",
+            reason = "internal implementation detail of profile-guided optimization",
+            issue = "0")]
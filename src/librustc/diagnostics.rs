@@ -1580,4 +1580,5 @@ register_diagnostics! {
     E0491, // in type `..`, reference has a longer lifetime than the data it...
     E0495, // cannot infer an appropriate lifetime due to conflicting requirements
     E0525, // expected a closure that implements `..` but this closure only implements `..`
+    E0570, // const parameters are not allowed on this type
 }
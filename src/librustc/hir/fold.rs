@@ -13,7 +13,7 @@
 
 use hir::*;
 use syntax::ast::{Name, NodeId, DUMMY_NODE_ID, Attribute, Attribute_, MetaItem};
-use syntax::ast::MetaItemKind;
+use syntax::ast::{MetaItemKind, NestedMetaItem, NestedMetaItemKind};
 use syntax::attr::ThinAttributesExt;
 use hir;
 use syntax::codemap::{respan, Span, Spanned};
@@ -38,6 +38,10 @@ pub trait Folder : Sized {
         noop_fold_meta_items(meta_items, self)
     }
 
+    fn fold_meta_list_item(&mut self, list_item: NestedMetaItem) -> NestedMetaItem {
+        noop_fold_meta_list_item(list_item, self)
+    }
+
     fn fold_meta_item(&mut self, meta_item: P<MetaItem>) -> P<MetaItem> {
         noop_fold_meta_item(meta_item, self)
     }
@@ -214,6 +218,10 @@ pub trait Folder : Sized {
         noop_fold_ty_params(tps, self)
     }
 
+    fn fold_const_param(&mut self, cp: ConstParam) -> ConstParam {
+        noop_fold_const_param(cp, self)
+    }
+
     fn fold_opt_lifetime(&mut self, o_lt: Option<Lifetime>) -> Option<Lifetime> {
         noop_fold_opt_lifetime(o_lt, self)
     }
@@ -346,7 +354,7 @@ pub fn noop_fold_ty<T: Folder>(t: P<Ty>, fld: &mut T) -> P<Ty> {
         Ty {
             id: fld.new_id(id),
             node: match node {
-                TyInfer => node,
+                TyInfer | TyNever => node,
                 TyVec(ty) => TyVec(fld.fold_ty(ty)),
                 TyPtr(mt) => TyPtr(fld.fold_mt(mt)),
                 TyRptr(region, mt) => {
@@ -384,6 +392,9 @@ pub fn noop_fold_ty<T: Folder>(t: P<Ty>, fld: &mut T) -> P<Ty> {
                 TyPolyTraitRef(bounds) => {
                     TyPolyTraitRef(bounds.move_map(|b| fld.fold_ty_param_bound(b)))
                 }
+                TyImplTrait(bounds) => {
+                    TyImplTrait(bounds.move_map(|b| fld.fold_ty_param_bound(b)))
+                }
             },
             span: fld.new_span(span),
         }
@@ -450,10 +461,11 @@ pub fn noop_fold_path_parameters<T: Folder>(path_parameters: PathParameters,
 pub fn noop_fold_angle_bracketed_parameter_data<T: Folder>(data: AngleBracketedParameterData,
                                                            fld: &mut T)
                                                            -> AngleBracketedParameterData {
-    let AngleBracketedParameterData { lifetimes, types, bindings } = data;
+    let AngleBracketedParameterData { lifetimes, types, consts, bindings } = data;
     AngleBracketedParameterData {
         lifetimes: fld.fold_lifetimes(lifetimes),
         types: types.move_map(|ty| fld.fold_ty(ty)),
+        consts: consts.move_map(|c| fld.fold_expr(c)),
         bindings: bindings.move_map(|b| fld.fold_ty_binding(b)),
     }
 }
@@ -518,13 +530,26 @@ pub fn noop_fold_explicit_self<T: Folder>(Spanned { span, node }: ExplicitSelf,
     }
 }
 
+pub fn noop_fold_meta_list_item<T: Folder>(li: NestedMetaItem, fld: &mut T)
+    -> NestedMetaItem {
+    Spanned {
+        node: match li.node {
+            NestedMetaItemKind::MetaItem(mi) => {
+                NestedMetaItemKind::MetaItem(fld.fold_meta_item(mi))
+            }
+            NestedMetaItemKind::Literal(lit) => NestedMetaItemKind::Literal(lit),
+        },
+        span: fld.new_span(li.span),
+    }
+}
+
 pub fn noop_fold_meta_item<T: Folder>(mi: P<MetaItem>, fld: &mut T) -> P<MetaItem> {
     mi.map(|Spanned { node, span }| {
         Spanned {
             node: match node {
                 MetaItemKind::Word(id) => MetaItemKind::Word(id),
                 MetaItemKind::List(id, mis) => {
-                    MetaItemKind::List(id, mis.move_map(|e| fld.fold_meta_item(e)))
+                    MetaItemKind::List(id, mis.move_map(|e| fld.fold_meta_list_item(e)))
                 }
                 MetaItemKind::NameValue(id, s) => MetaItemKind::NameValue(id, s),
             },
@@ -581,6 +606,16 @@ pub fn noop_fold_ty_params<T: Folder>(tps: HirVec<TyParam>,
     tps.move_map(|tp| fld.fold_ty_param(tp))
 }
 
+pub fn noop_fold_const_param<T: Folder>(cp: ConstParam, fld: &mut T) -> ConstParam {
+    let ConstParam {id, name, ty, span} = cp;
+    ConstParam {
+        id: fld.new_id(id),
+        name: name,
+        ty: fld.fold_ty(ty),
+        span: span,
+    }
+}
+
 pub fn noop_fold_lifetime<T: Folder>(l: Lifetime, fld: &mut T) -> Lifetime {
     Lifetime {
         id: fld.new_id(l.id),
@@ -610,11 +645,11 @@ pub fn noop_fold_opt_lifetime<T: Folder>(o_lt: Option<Lifetime>, fld: &mut T) ->
     o_lt.map(|lt| fld.fold_lifetime(lt))
 }
 
-pub fn noop_fold_generics<T: Folder>(Generics { ty_params, lifetimes, where_clause }: Generics,
-                                     fld: &mut T)
-                                     -> Generics {
+pub fn noop_fold_generics<T: Folder>(generics: Generics, fld: &mut T) -> Generics {
+    let Generics { ty_params, const_params, lifetimes, where_clause } = generics;
     Generics {
         ty_params: fld.fold_ty_params(ty_params),
+        const_params: const_params.move_map(|cp| fld.fold_const_param(cp)),
         lifetimes: fld.fold_lifetime_defs(lifetimes),
         where_clause: fld.fold_where_clause(where_clause),
     }
@@ -782,10 +817,14 @@ pub fn noop_fold_item_underscore<T: Folder>(i: Item_, folder: &mut T) -> Item_ {
             let struct_def = folder.fold_variant_data(struct_def);
             ItemStruct(struct_def, folder.fold_generics(generics))
         }
+        ItemUnion(struct_def, generics) => {
+            let struct_def = folder.fold_variant_data(struct_def);
+            ItemUnion(struct_def, folder.fold_generics(generics))
+        }
         ItemDefaultImpl(unsafety, ref trait_ref) => {
             ItemDefaultImpl(unsafety, folder.fold_trait_ref((*trait_ref).clone()))
         }
-        ItemImpl(unsafety, polarity, generics, ifce, ty, impl_items) => {
+        ItemImpl(unsafety, polarity, defaultness, generics, ifce, ty, impl_items) => {
             let new_impl_items = impl_items
                 .move_map(|item| folder.fold_impl_item(item));
             let ifce = match ifce {
@@ -796,6 +835,7 @@ pub fn noop_fold_item_underscore<T: Folder>(i: Item_, folder: &mut T) -> Item_ {
             };
             ItemImpl(unsafety,
                      polarity,
+                     defaultness,
                      folder.fold_generics(generics),
                      ifce,
                      folder.fold_ty(ty),
@@ -806,6 +846,9 @@ pub fn noop_fold_item_underscore<T: Folder>(i: Item_, folder: &mut T) -> Item_ {
             let items = items.move_map(|item| folder.fold_trait_item(item));
             ItemTrait(unsafety, folder.fold_generics(generics), bounds, items)
         }
+        ItemTraitAlias(generics, bounds) => {
+            ItemTraitAlias(folder.fold_generics(generics), folder.fold_bounds(bounds))
+        }
     }
 }
 
@@ -824,9 +867,10 @@ pub fn noop_fold_trait_item<T: Folder>(i: TraitItem,
                 MethodTraitItem(noop_fold_method_sig(sig, folder),
                                 body.map(|x| folder.fold_block(x)))
             }
-            TypeTraitItem(bounds, default) => {
+            TypeTraitItem(bounds, default, generics) => {
                 TypeTraitItem(folder.fold_bounds(bounds),
-                              default.map(|x| folder.fold_ty(x)))
+                              default.map(|x| folder.fold_ty(x)),
+                              folder.fold_generics(generics))
             }
         },
         span: folder.new_span(i.span),
@@ -847,7 +891,9 @@ pub fn noop_fold_impl_item<T: Folder>(i: ImplItem, folder: &mut T) -> ImplItem {
             ImplItemKind::Method(sig, body) => {
                 ImplItemKind::Method(noop_fold_method_sig(sig, folder), folder.fold_block(body))
             }
-            ImplItemKind::Type(ty) => ImplItemKind::Type(folder.fold_ty(ty)),
+            ImplItemKind::Type(ty, generics) => {
+                ImplItemKind::Type(folder.fold_ty(ty), folder.fold_generics(generics))
+            }
         },
         span: folder.new_span(i.span),
     }
@@ -1051,9 +1097,10 @@ pub fn noop_fold_expr<T: Folder>(Expr { id, node, span, attrs }: Expr, folder: &
                           folder.fold_block(body),
                           opt_ident.map(|i| folder.fold_ident(i)))
             }
-            ExprLoop(body, opt_ident) => {
+            ExprLoop(body, opt_ident, source) => {
                 ExprLoop(folder.fold_block(body),
-                         opt_ident.map(|i| folder.fold_ident(i)))
+                         opt_ident.map(|i| folder.fold_ident(i)),
+                         source)
             }
             ExprMatch(expr, arms, source) => {
                 ExprMatch(folder.fold_expr(expr),
@@ -1093,9 +1140,12 @@ pub fn noop_fold_expr<T: Folder>(Expr { id, node, span, attrs }: Expr, folder: &
                 });
                 ExprPath(qself, folder.fold_path(path))
             }
-            ExprBreak(opt_ident) => ExprBreak(opt_ident.map(|label| {
-                respan(folder.new_span(label.span), folder.fold_ident(label.node))
-            })),
+            ExprBreak(opt_ident, opt_expr) => {
+                ExprBreak(opt_ident.map(|label| {
+                              respan(folder.new_span(label.span), folder.fold_ident(label.node))
+                          }),
+                          opt_expr.map(|e| folder.fold_expr(e)))
+            }
             ExprAgain(opt_ident) => ExprAgain(opt_ident.map(|label| {
                 respan(folder.new_span(label.span), folder.fold_ident(label.node))
             })),
@@ -47,6 +47,7 @@ use hir::def_id::DefIndex;
 
 use std::collections::BTreeMap;
 use std::iter;
+use std::mem;
 use syntax::ast::*;
 use syntax::attr::{ThinAttributes, ThinAttributesExt};
 use syntax::errors::Handler;
@@ -70,6 +71,19 @@ pub struct LoweringContext<'a> {
     // the form of a DefIndex) so that if we create a new node which introduces
     // a definition, then we can properly create the def id.
     parent_def: Cell<Option<DefIndex>>,
+    // The loops and `do catch` blocks enclosing the expression being lowered,
+    // innermost last. `?` propagates errors to the innermost `do catch` block,
+    // and plain `break` and `continue` must not leave one. The body of a
+    // closure starts with no enclosing scopes.
+    scopes: RefCell<Vec<Scope>>,
+}
+
+#[derive(Clone, Copy)]
+enum Scope {
+    Loop,
+    // A `do catch` block, lowered to a loop with the label `label` whose
+    // result is assigned to the local `result` before breaking out of it.
+    Catch { label: hir::Ident, result: hir::Ident },
 }
 
 impl<'a, 'hir> LoweringContext<'a> {
@@ -92,6 +106,7 @@ impl<'a, 'hir> LoweringContext<'a> {
             id_assigner: id_assigner,
             definitions: Some(defs),
             parent_def: Cell::new(None),
+            scopes: RefCell::new(Vec::new()),
         }
     }
 
@@ -103,6 +118,7 @@ impl<'a, 'hir> LoweringContext<'a> {
             id_assigner: id_assigner,
             definitions: None,
             parent_def: Cell::new(None),
+            scopes: RefCell::new(Vec::new()),
         }
     }
 
@@ -133,6 +149,37 @@ impl<'a, 'hir> LoweringContext<'a> {
         result
     }
 
+    fn with_scope<T, F: FnOnce() -> T>(&self, scope: Scope, f: F) -> T {
+        self.scopes.borrow_mut().push(scope);
+        let result = f();
+        self.scopes.borrow_mut().pop();
+        result
+    }
+
+    fn with_new_scopes<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        let old_scopes = mem::replace(&mut *self.scopes.borrow_mut(), Vec::new());
+        let result = f();
+        *self.scopes.borrow_mut() = old_scopes;
+        result
+    }
+
+    // The label and result local of the innermost enclosing `do catch` block.
+    fn innermost_catch(&self) -> Option<(hir::Ident, hir::Ident)> {
+        self.scopes.borrow().iter().rev().filter_map(|scope| match *scope {
+            Scope::Catch { label, result } => Some((label, result)),
+            Scope::Loop => None,
+        }).next()
+    }
+
+    // Reports a plain `break` or `continue` that would leave a `do catch`
+    // block rather than a loop.
+    fn check_unlabeled_jump(&self, span: Span, kind: &str) {
+        if let Some(&Scope::Catch { .. }) = self.scopes.borrow().last() {
+            self.diagnostic().span_err(span,
+                &format!("`{}` inside of a `do catch` block must be labeled", kind));
+        }
+    }
+
     fn get_def(&self, id: NodeId) -> DefIndex {
         let defs = self.definitions.unwrap().borrow();
         defs.opt_def_index(id).unwrap()
@@ -224,6 +271,7 @@ pub fn lower_ty(lctx: &LoweringContext, t: &Ty) -> P<hir::Ty> {
         id: t.id,
         node: match t.node {
             Infer => hir::TyInfer,
+            Never => hir::TyNever,
             Vec(ref ty) => hir::TyVec(lower_ty(lctx, ty)),
             Ptr(ref mt) => hir::TyPtr(lower_mt(lctx, mt)),
             Rptr(ref region, ref mt) => {
@@ -262,6 +310,9 @@ pub fn lower_ty(lctx: &LoweringContext, t: &Ty) -> P<hir::Ty> {
             PolyTraitRef(ref bounds) => {
                 hir::TyPolyTraitRef(bounds.iter().map(|b| lower_ty_param_bound(lctx, b)).collect())
             }
+            ImplTrait(ref bounds) => {
+                hir::TyImplTrait(lower_bounds(lctx, bounds))
+            }
             Mac(_) => panic!("TyMac should have been expanded by now."),
         },
         span: t.span,
@@ -329,10 +380,11 @@ pub fn lower_path_parameters(lctx: &LoweringContext,
 pub fn lower_angle_bracketed_parameter_data(lctx: &LoweringContext,
                                             data: &AngleBracketedParameterData)
                                             -> hir::AngleBracketedParameterData {
-    let &AngleBracketedParameterData { ref lifetimes, ref types, ref bindings } = data;
+    let &AngleBracketedParameterData { ref lifetimes, ref types, ref consts, ref bindings } = data;
     hir::AngleBracketedParameterData {
         lifetimes: lower_lifetimes(lctx, lifetimes),
         types: types.iter().map(|ty| lower_ty(lctx, ty)).collect(),
+        consts: consts.iter().map(|c| lower_expr(lctx, c)).collect(),
         bindings: bindings.iter().map(|b| lower_ty_binding(lctx, b)).collect(),
     }
 }
@@ -438,6 +490,15 @@ pub fn lower_ty_params(lctx: &LoweringContext,
     tps.iter().map(|tp| lower_ty_param(lctx, tp)).collect()
 }
 
+pub fn lower_const_param(lctx: &LoweringContext, cp: &ConstParam) -> hir::ConstParam {
+    hir::ConstParam {
+        id: cp.id,
+        name: cp.ident.name,
+        ty: lower_ty(lctx, &cp.ty),
+        span: cp.span,
+    }
+}
+
 pub fn lower_lifetime(_lctx: &LoweringContext, l: &Lifetime) -> hir::Lifetime {
     hir::Lifetime {
         id: l.id,
@@ -472,6 +533,7 @@ pub fn lower_opt_lifetime(lctx: &LoweringContext,
 pub fn lower_generics(lctx: &LoweringContext, g: &Generics) -> hir::Generics {
     hir::Generics {
         ty_params: lower_ty_params(lctx, &g.ty_params),
+        const_params: g.const_params.iter().map(|cp| lower_const_param(lctx, cp)).collect(),
         lifetimes: lower_lifetime_defs(lctx, &g.lifetimes),
         where_clause: lower_where_clause(lctx, &g.where_clause),
     }
@@ -648,17 +710,28 @@ pub fn lower_item_kind(lctx: &LoweringContext, i: &ItemKind) -> hir::Item_ {
             let struct_def = lower_variant_data(lctx, struct_def);
             hir::ItemStruct(struct_def, lower_generics(lctx, generics))
         }
+        ItemKind::Union(ref vdata, ref generics) => {
+            let vdata = lower_variant_data(lctx, vdata);
+            hir::ItemUnion(vdata, lower_generics(lctx, generics))
+        }
         ItemKind::DefaultImpl(unsafety, ref trait_ref) => {
             hir::ItemDefaultImpl(lower_unsafety(lctx, unsafety),
                                  lower_trait_ref(lctx, trait_ref))
         }
-        ItemKind::Impl(unsafety, polarity, ref generics, ref ifce, ref ty, ref impl_items) => {
+        ItemKind::Impl(unsafety,
+                       polarity,
+                       defaultness,
+                       ref generics,
+                       ref ifce,
+                       ref ty,
+                       ref impl_items) => {
             let new_impl_items = impl_items.iter()
                                            .map(|item| lower_impl_item(lctx, item))
                                            .collect();
             let ifce = ifce.as_ref().map(|trait_ref| lower_trait_ref(lctx, trait_ref));
             hir::ItemImpl(lower_unsafety(lctx, unsafety),
                           lower_impl_polarity(lctx, polarity),
+                          lower_defaultness(lctx, defaultness),
                           lower_generics(lctx, generics),
                           ifce,
                           lower_ty(lctx, ty),
@@ -672,6 +745,9 @@ pub fn lower_item_kind(lctx: &LoweringContext, i: &ItemKind) -> hir::Item_ {
                            bounds,
                            items)
         }
+        ItemKind::TraitAlias(ref generics, ref bounds) => {
+            hir::ItemTraitAlias(lower_generics(lctx, generics), lower_bounds(lctx, bounds))
+        }
         ItemKind::Mac(_) => panic!("Shouldn't still be around"),
     }
 }
@@ -691,9 +767,10 @@ pub fn lower_trait_item(lctx: &LoweringContext, i: &TraitItem) -> hir::TraitItem
                     hir::MethodTraitItem(lower_method_sig(lctx, sig),
                                          body.as_ref().map(|x| lower_block(lctx, x)))
                 }
-                TraitItemKind::Type(ref bounds, ref default) => {
+                TraitItemKind::Type(ref bounds, ref default, ref generics) => {
                     hir::TypeTraitItem(lower_bounds(lctx, bounds),
-                                       default.as_ref().map(|x| lower_ty(lctx, x)))
+                                       default.as_ref().map(|x| lower_ty(lctx, x)),
+                                       lower_generics(lctx, generics))
                 }
             },
             span: i.span,
@@ -716,7 +793,9 @@ pub fn lower_impl_item(lctx: &LoweringContext, i: &ImplItem) -> hir::ImplItem {
                 ImplItemKind::Method(ref sig, ref body) => {
                     hir::ImplItemKind::Method(lower_method_sig(lctx, sig), lower_block(lctx, body))
                 }
-                ImplItemKind::Type(ref ty) => hir::ImplItemKind::Type(lower_ty(lctx, ty)),
+                ImplItemKind::Type(ref ty, ref generics) => {
+                    hir::ImplItemKind::Type(lower_ty(lctx, ty), lower_generics(lctx, generics))
+                }
                 ImplItemKind::Macro(..) => panic!("Shouldn't exist any more"),
             },
             span: i.span,
@@ -1139,12 +1218,14 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
                 hir::ExprIf(lower_expr(lctx, cond), lower_block(lctx, blk), else_opt)
             }
             ExprKind::While(ref cond, ref body, opt_ident) => {
-                hir::ExprWhile(lower_expr(lctx, cond), lower_block(lctx, body),
+                hir::ExprWhile(lower_expr(lctx, cond),
+                               lctx.with_scope(Scope::Loop, || lower_block(lctx, body)),
                                opt_ident.map(|ident| lower_ident(lctx, ident)))
             }
             ExprKind::Loop(ref body, opt_ident) => {
-                hir::ExprLoop(lower_block(lctx, body),
-                              opt_ident.map(|ident| lower_ident(lctx, ident)))
+                hir::ExprLoop(lctx.with_scope(Scope::Loop, || lower_block(lctx, body)),
+                              opt_ident.map(|ident| lower_ident(lctx, ident)),
+                              hir::LoopSource::Loop)
             }
             ExprKind::Match(ref expr, ref arms) => {
                 hir::ExprMatch(lower_expr(lctx, expr),
@@ -1155,7 +1236,7 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
                 lctx.with_parent_def(e.id, || {
                     hir::ExprClosure(lower_capture_clause(lctx, capture_clause),
                                      lower_fn_decl(lctx, decl),
-                                     lower_block(lctx, body),
+                                     lctx.with_new_scopes(|| lower_block(lctx, body)),
                                      fn_decl_span)
                 })
             }
@@ -1259,12 +1340,23 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
                 });
                 hir::ExprPath(hir_qself, lower_path_full(lctx, path, qself.is_none()))
             }
-            ExprKind::Break(opt_ident) => hir::ExprBreak(opt_ident.map(|sp_ident| {
-                respan(sp_ident.span, lower_ident(lctx, sp_ident.node))
-            })),
-            ExprKind::Again(opt_ident) => hir::ExprAgain(opt_ident.map(|sp_ident| {
-                respan(sp_ident.span, lower_ident(lctx, sp_ident.node))
-            })),
+            ExprKind::Break(opt_ident, ref opt_expr) => {
+                if opt_ident.is_none() {
+                    lctx.check_unlabeled_jump(e.span, "break");
+                }
+                hir::ExprBreak(opt_ident.map(|sp_ident| {
+                                   respan(sp_ident.span, lower_ident(lctx, sp_ident.node))
+                               }),
+                               opt_expr.as_ref().map(|x| lower_expr(lctx, x)))
+            }
+            ExprKind::Again(opt_ident) => {
+                if opt_ident.is_none() {
+                    lctx.check_unlabeled_jump(e.span, "continue");
+                }
+                hir::ExprAgain(opt_ident.map(|sp_ident| {
+                    respan(sp_ident.span, lower_ident(lctx, sp_ident.node))
+                }))
+            }
             ExprKind::Ret(ref e) => hir::ExprRet(e.as_ref().map(|x| lower_expr(lctx, x))),
             ExprKind::InlineAsm(InlineAsm {
                     ref inputs,
@@ -1411,7 +1503,7 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
 
                 // `<pat> => <body>`
                 let pat_arm = {
-                    let body = lower_block(lctx, body);
+                    let body = lctx.with_scope(Scope::Loop, || lower_block(lctx, body));
                     let body_expr = expr_block(lctx, body, None);
                     arm(hir_vec![lower_pat(lctx, pat)], body_expr)
                 };
@@ -1436,7 +1528,8 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
                 // `[opt_ident]: loop { ... }`
                 let loop_block = block_expr(lctx, match_expr);
                 let loop_expr = hir::ExprLoop(loop_block,
-                                              opt_ident.map(|ident| lower_ident(lctx, ident)));
+                                              opt_ident.map(|ident| lower_ident(lctx, ident)),
+                                              hir::LoopSource::WhileLet);
                 // add attributes to the outer returned expr node
                 return expr(lctx, e.span, loop_expr, e.attrs.clone());
             }
@@ -1467,7 +1560,7 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
 
                 // `::std::option::Option::Some(<pat>) => <body>`
                 let pat_arm = {
-                    let body_block = lower_block(lctx, body);
+                    let body_block = lctx.with_scope(Scope::Loop, || lower_block(lctx, body));
                     let body_span = body_block.span;
                     let body_expr = P(hir::Expr {
                         id: lctx.next_id(),
@@ -1514,7 +1607,8 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
                 // `[opt_ident]: loop { ... }`
                 let loop_block = block_expr(lctx, match_expr);
                 let loop_expr = hir::ExprLoop(loop_block,
-                                              opt_ident.map(|ident| lower_ident(lctx, ident)));
+                                              opt_ident.map(|ident| lower_ident(lctx, ident)),
+                                              hir::LoopSource::ForLoop);
                 let loop_expr = expr(lctx, e.span, loop_expr, None);
 
                 // `mut iter => { ... }`
@@ -1566,16 +1660,34 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
                 // to:
                 //
                 // {
-                //     match <expr> {
+                //     match ::std::ops::Carrier::into_result(<expr>) {
                 //         Ok(val) => val,
                 //         Err(err) => {
-                //             return Err(From::from(err))
+                //             return ::std::ops::Carrier::from_error(From::from(err))
                 //         }
                 //     }
                 // }
+                //
+                // where, inside a `do catch` block, the `return` is replaced
+                // by a jump out of the block (see `ExprKind::Catch`).
 
                 // expand <expr>
                 let sub_expr = lower_expr(lctx, sub_expr);
+                let sub_expr = signal_block_expr(lctx,
+                                                 hir_vec![],
+                                                 sub_expr,
+                                                 e.span,
+                                                 hir::PopUnstableBlock,
+                                                 None);
+
+                // ::std::ops::Carrier::into_result(<expr>)
+                let discr = {
+                    let path = std_path(lctx, &["ops", "Carrier", "into_result"]);
+                    let path = path_global(e.span, path);
+                    let into_result = expr_path(lctx, path, None);
+                    let call = expr_call(lctx, e.span, into_result, hir_vec![sub_expr], None);
+                    signal_block_expr(lctx, hir_vec![], call, e.span, hir::PushUnstableBlock, None)
+                };
 
                 // Ok(val) => val
                 let ok_arm = {
@@ -1587,7 +1699,7 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
                     arm(hir_vec![ok_pat], val_expr)
                 };
 
-                // Err(err) => return Err(From::from(err))
+                // Err(err) => return ::std::ops::Carrier::from_error(From::from(err))
                 let err_arm = {
                     let err_ident = lctx.str_to_ident("err");
                     let from_expr = {
@@ -1598,25 +1710,97 @@ pub fn lower_expr(lctx: &LoweringContext, e: &Expr) -> P<hir::Expr> {
 
                         expr_call(lctx, e.span, from, hir_vec![err_expr], None)
                     };
-                    let err_expr = {
-                        let path = std_path(lctx, &["result", "Result", "Err"]);
+                    let from_err_expr = {
+                        let path = std_path(lctx, &["ops", "Carrier", "from_error"]);
                         let path = path_global(e.span, path);
-                        let err_ctor = expr_path(lctx, path, None);
-                        expr_call(lctx, e.span, err_ctor, hir_vec![from_expr], None)
+                        let from_err = expr_path(lctx, path, None);
+                        let call = expr_call(lctx, e.span, from_err, hir_vec![from_expr], None);
+                        signal_block_expr(lctx,
+                                          hir_vec![],
+                                          call,
+                                          e.span,
+                                          hir::PushUnstableBlock,
+                                          None)
                     };
                     let err_pat = pat_err(lctx, e.span,
                                           pat_ident(lctx, e.span, err_ident));
-                    let ret_expr = expr(lctx, e.span,
-                                        hir::Expr_::ExprRet(Some(err_expr)), None);
+                    let ret_expr = match lctx.innermost_catch() {
+                        Some((label, result)) => {
+                            expr_catch_exit(lctx, e.span, label, result, from_err_expr)
+                        }
+                        None => {
+                            expr(lctx, e.span, hir::Expr_::ExprRet(Some(from_err_expr)), None)
+                        }
+                    };
 
                     arm(hir_vec![err_pat], ret_expr)
                 };
 
-                return expr_match(lctx, e.span, sub_expr, hir_vec![err_arm, ok_arm],
+                return expr_match(lctx, e.span, discr, hir_vec![err_arm, ok_arm],
                                   hir::MatchSource::TryDesugar, None);
             }
 
+            // Desugar ExprKind::Catch
+            // From: `do catch { <body> }`
+            ExprKind::Catch(ref body) => {
+                // to:
+                //
+                // {
+                //     let result;
+                //     'catch: loop {
+                //         result = ::std::ops::Carrier::from_success(<body>);
+                //         break 'catch;
+                //     }
+                //     result
+                // }
+                //
+                // where `?` inside <body> exits the loop with
+                // `{ result = <error>; break 'catch }`.
+
+                let label = lctx.str_to_ident("'catch");
+                let result = lctx.str_to_ident("result");
+                let scope = Scope::Catch { label: label, result: result };
+
+                // expand <body>
+                let body = lctx.with_scope(scope, || lower_block(lctx, body));
+                let body_expr = signal_block_expr(lctx,
+                                                  hir_vec![],
+                                                  expr_block(lctx, body, None),
+                                                  e.span,
+                                                  hir::PopUnstableBlock,
+                                                  None);
+
+                // ::std::ops::Carrier::from_success(<body>)
+                let success_expr = {
+                    let path = std_path(lctx, &["ops", "Carrier", "from_success"]);
+                    let path = path_global(e.span, path);
+                    let from_success = expr_path(lctx, path, None);
+                    let call = expr_call(lctx, e.span, from_success, hir_vec![body_expr], None);
+                    signal_block_expr(lctx, hir_vec![], call, e.span, hir::PushUnstableBlock, None)
+                };
+
+                // 'catch: loop { ... }
+                let loop_expr = {
+                    let exit = expr_catch_exit(lctx, e.span, label, result, success_expr);
+                    let loop_block = block_expr(lctx, exit);
+                    let loop_expr = hir::ExprLoop(loop_block, Some(label), hir::LoopSource::Loop);
+                    expr(lctx, e.span, loop_expr, None)
+                };
+                let loop_stmt = respan(e.span, hir::StmtSemi(loop_expr, lctx.next_id()));
+
+                // let result;
+                let result_stmt = stmt_let_uninit(lctx, e.span, result, None);
+
+                let result_expr = expr_ident(lctx, e.span, result, None);
+                let block = block_all(lctx,
+                                      e.span,
+                                      hir_vec![result_stmt, loop_stmt],
+                                      Some(result_expr));
+                return expr_block(lctx, block, e.attrs.clone());
+            }
+
             ExprKind::Mac(_) => panic!("Shouldn't exist here"),
+            ExprKind::Err => panic!("Parse error placeholder shouldn't exist here"),
         },
         span: e.span,
         attrs: e.attrs.clone(),
@@ -1732,7 +1916,7 @@ fn field(name: Name, expr: P<hir::Expr>, span: Span) -> hir::Field {
 
 fn expr_break(lctx: &LoweringContext, span: Span,
               attrs: ThinAttributes) -> P<hir::Expr> {
-    expr(lctx, span, hir::ExprBreak(None), attrs)
+    expr(lctx, span, hir::ExprBreak(None, None), attrs)
 }
 
 fn expr_call(lctx: &LoweringContext,
@@ -1822,6 +2006,37 @@ fn stmt_let(lctx: &LoweringContext,
     respan(sp, hir::StmtDecl(P(decl), lctx.next_id()))
 }
 
+fn stmt_let_uninit(lctx: &LoweringContext,
+                   sp: Span,
+                   ident: hir::Ident,
+                   attrs: ThinAttributes)
+                   -> hir::Stmt {
+    let local = P(hir::Local {
+        pat: pat_ident(lctx, sp, ident),
+        ty: None,
+        init: None,
+        id: lctx.next_id(),
+        span: sp,
+        attrs: attrs,
+    });
+    let decl = respan(sp, hir::DeclLocal(local));
+    respan(sp, hir::StmtDecl(P(decl), lctx.next_id()))
+}
+
+// `{ <result> = <value>; break <label> }`, leaving a `do catch` block.
+fn expr_catch_exit(lctx: &LoweringContext,
+                   span: Span,
+                   label: hir::Ident,
+                   result: hir::Ident,
+                   value: P<hir::Expr>)
+                   -> P<hir::Expr> {
+    let result_expr = expr_ident(lctx, span, result, None);
+    let assign = expr(lctx, span, hir::ExprAssign(result_expr, value), None);
+    let assign_stmt = respan(span, hir::StmtSemi(assign, lctx.next_id()));
+    let break_expr = expr(lctx, span, hir::ExprBreak(Some(respan(span, label)), None), None);
+    expr_block(lctx, block_all(lctx, span, hir_vec![assign_stmt], Some(break_expr)), None)
+}
+
 fn block_expr(lctx: &LoweringContext, expr: P<hir::Expr>) -> P<hir::Block> {
     block_all(lctx, expr.span, hir::HirVec::new(), Some(expr))
 }
@@ -1950,6 +2165,7 @@ fn path_all(sp: Span,
         parameters: hir::AngleBracketedParameters(hir::AngleBracketedParameterData {
             lifetimes: lifetimes,
             types: types,
+            consts: hir::HirVec::new(),
             bindings: bindings,
         }),
     });
@@ -108,7 +108,8 @@ impl<'ast> visit::Visitor<'ast> for DefCollector<'ast> {
         let def_data = match i.node {
             ItemKind::DefaultImpl(..) | ItemKind::Impl(..) =>
                 DefPathData::Impl,
-            ItemKind::Enum(..) | ItemKind::Struct(..) | ItemKind::Trait(..) |
+            ItemKind::Enum(..) | ItemKind::Struct(..) | ItemKind::Union(..) |
+            ItemKind::Trait(..) | ItemKind::TraitAlias(..) |
             ItemKind::ExternCrate(..) | ItemKind::ForeignMod(..) | ItemKind::Ty(..) =>
                 DefPathData::TypeNs(i.ident.name),
             ItemKind::Mod(..) => DefPathData::Module(i.ident.name),
@@ -149,6 +150,12 @@ impl<'ast> visit::Visitor<'ast> for DefCollector<'ast> {
                         this.create_def(field.id, DefPathData::Field(name));
                     }
                 }
+                ItemKind::Union(ref struct_def, _) => {
+                    for field in struct_def.fields() {
+                        let name = field.ident.unwrap().name;
+                        this.create_def(field.id, DefPathData::Field(name));
+                    }
+                }
                 _ => {}
             }
             visit::walk_item(this, i);
@@ -167,6 +174,9 @@ impl<'ast> visit::Visitor<'ast> for DefCollector<'ast> {
         for ty_param in generics.ty_params.iter() {
             self.create_def(ty_param.id, DefPathData::TypeParam(ty_param.ident.name));
         }
+        for const_param in generics.const_params.iter() {
+            self.create_def(const_param.id, DefPathData::ConstParam(const_param.ident.name));
+        }
 
         visit::walk_generics(self, generics);
     }
@@ -230,6 +240,13 @@ impl<'ast> visit::Visitor<'ast> for DefCollector<'ast> {
         self.parent_def = parent_def;
     }
 
+    fn visit_ty(&mut self, ty: &'ast Ty) {
+        if let TyKind::ImplTrait(..) = ty.node {
+            self.create_def(ty.id, DefPathData::ImplTrait);
+        }
+        visit::walk_ty(self, ty);
+    }
+
     fn visit_lifetime_def(&mut self, def: &'ast LifetimeDef) {
         self.create_def(def.lifetime.id, DefPathData::LifetimeDef(def.lifetime.name));
     }
@@ -258,7 +275,8 @@ impl<'ast> intravisit::Visitor<'ast> for DefCollector<'ast> {
         let def_data = match i.node {
             hir::ItemDefaultImpl(..) | hir::ItemImpl(..) =>
                 DefPathData::Impl,
-            hir::ItemEnum(..) | hir::ItemStruct(..) | hir::ItemTrait(..) |
+            hir::ItemEnum(..) | hir::ItemStruct(..) | hir::ItemUnion(..) |
+            hir::ItemTrait(..) | hir::ItemTraitAlias(..) |
             hir::ItemExternCrate(..) | hir::ItemMod(..) | hir::ItemForeignMod(..) |
             hir::ItemTy(..) =>
                 DefPathData::TypeNs(i.name),
@@ -294,6 +312,11 @@ impl<'ast> intravisit::Visitor<'ast> for DefCollector<'ast> {
                         this.create_def(field.id, DefPathData::Field(field.name));
                     }
                 }
+                hir::ItemUnion(ref struct_def, _) => {
+                    for field in struct_def.fields() {
+                        this.create_def(field.id, DefPathData::Field(field.name));
+                    }
+                }
                 _ => {}
             }
             intravisit::walk_item(this, i);
@@ -312,6 +335,9 @@ impl<'ast> intravisit::Visitor<'ast> for DefCollector<'ast> {
         for ty_param in generics.ty_params.iter() {
             self.create_def(ty_param.id, DefPathData::TypeParam(ty_param.name));
         }
+        for const_param in generics.const_params.iter() {
+            self.create_def(const_param.id, DefPathData::ConstParam(const_param.name));
+        }
 
         intravisit::walk_generics(self, generics);
     }
@@ -374,6 +400,13 @@ impl<'ast> intravisit::Visitor<'ast> for DefCollector<'ast> {
         self.parent_def = parent_def;
     }
 
+    fn visit_ty(&mut self, ty: &'ast hir::Ty) {
+        if let hir::TyImplTrait(..) = ty.node {
+            self.create_def(ty.id, DefPathData::ImplTrait);
+        }
+        intravisit::walk_ty(self, ty);
+    }
+
     fn visit_lifetime_def(&mut self, def: &'ast hir::LifetimeDef) {
         self.create_def(def.lifetime.id, DefPathData::LifetimeDef(def.lifetime.name));
     }
@@ -153,12 +153,16 @@ pub enum DefPathData {
 
     // Subportions of items
     TypeParam(ast::Name),
+    ConstParam(ast::Name),
     LifetimeDef(ast::Name),
     EnumVariant(ast::Name),
     Field(ast::Name),
     StructCtor, // implicit ctor for a tuple-like struct
     Initializer, // initializer for a const
     Binding(ast::Name), // pattern binding
+
+    // An `impl Trait` type node.
+    ImplTrait
 }
 
 impl Definitions {
@@ -292,6 +296,7 @@ impl DefPathData {
             Module(name) |
             MacroDef(name) |
             TypeParam(name) |
+            ConstParam(name) |
             LifetimeDef(name) |
             EnumVariant(name) |
             Binding(name) |
@@ -328,6 +333,10 @@ impl DefPathData {
             Initializer => {
                 InternedString::new("{{initializer}}")
             }
+
+            ImplTrait => {
+                InternedString::new("{{impl-Trait}}")
+            }
         }
     }
 
@@ -516,7 +516,8 @@ impl<'ast> Map<'ast> {
         match self.find(id) {
             Some(NodeItem(i)) => {
                 match i.node {
-                    ItemStruct(ref struct_def, _) => struct_def,
+                    ItemStruct(ref struct_def, _) |
+                    ItemUnion(ref struct_def, _) => struct_def,
                     _ => bug!("struct ID bound to non-struct")
                 }
             }
@@ -925,7 +926,9 @@ fn node_id_to_string(map: &Map, id: NodeId, include_id: bool) -> String {
                 ItemTy(..) => "ty",
                 ItemEnum(..) => "enum",
                 ItemStruct(..) => "struct",
+                ItemUnion(..) => "union",
                 ItemTrait(..) => "trait",
+                ItemTraitAlias(..) => "trait alias",
                 ItemImpl(..) => "impl",
                 ItemDefaultImpl(..) => "default impl",
             };
@@ -942,7 +945,7 @@ fn node_id_to_string(map: &Map, id: NodeId, include_id: bool) -> String {
                 ImplItemKind::Method(..) => {
                     format!("method {} in {}{}", ii.name, path_str(), id_str)
                 }
-                ImplItemKind::Type(_) => {
+                ImplItemKind::Type(..) => {
                     format!("assoc type {} in {}{}", ii.name, path_str(), id_str)
                 }
             }
@@ -233,6 +233,7 @@ impl PathParameters {
         AngleBracketedParameters(AngleBracketedParameterData {
             lifetimes: HirVec::new(),
             types: HirVec::new(),
+            consts: HirVec::new(),
             bindings: HirVec::new(),
         })
     }
@@ -288,6 +289,17 @@ impl PathParameters {
         }
     }
 
+    pub fn consts(&self) -> HirVec<&P<Expr>> {
+        match *self {
+            AngleBracketedParameters(ref data) => {
+                data.consts.iter().collect()
+            }
+            ParenthesizedParameters(_) => {
+                HirVec::new()
+            }
+        }
+    }
+
     pub fn bindings(&self) -> HirVec<&TypeBinding> {
         match *self {
             AngleBracketedParameters(ref data) => {
@@ -307,6 +319,9 @@ pub struct AngleBracketedParameterData {
     pub lifetimes: HirVec<Lifetime>,
     /// The type parameters for this path segment, if present.
     pub types: HirVec<P<Ty>>,
+    /// The const parameters for this path segment, if present.
+    /// E.g., the `3` in `Foo<T, 3>`.
+    pub consts: HirVec<P<Expr>>,
     /// Bindings (equality constraints) on associated types, if present.
     /// E.g., `Foo<A=Bar>`.
     pub bindings: HirVec<TypeBinding>,
@@ -314,7 +329,8 @@ pub struct AngleBracketedParameterData {
 
 impl AngleBracketedParameterData {
     fn is_empty(&self) -> bool {
-        self.lifetimes.is_empty() && self.types.is_empty() && self.bindings.is_empty()
+        self.lifetimes.is_empty() && self.types.is_empty() &&
+            self.consts.is_empty() && self.bindings.is_empty()
     }
 }
 
@@ -360,12 +376,22 @@ pub struct TyParam {
     pub span: Span,
 }
 
-/// Represents lifetimes and type parameters attached to a declaration
-/// of a function, enum, trait, etc.
+/// A const generic parameter, e.g. `const N: usize`.
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub struct ConstParam {
+    pub name: Name,
+    pub id: NodeId,
+    pub ty: P<Ty>,
+    pub span: Span,
+}
+
+/// Represents lifetimes, type and const parameters attached to a
+/// declaration of a function, enum, trait, etc.
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub struct Generics {
     pub lifetimes: HirVec<LifetimeDef>,
     pub ty_params: HirVec<TyParam>,
+    pub const_params: HirVec<ConstParam>,
     pub where_clause: WhereClause,
 }
 
@@ -374,6 +400,7 @@ impl Generics {
         Generics {
             lifetimes: HirVec::new(),
             ty_params: HirVec::new(),
+            const_params: HirVec::new(),
             where_clause: WhereClause {
                 id: DUMMY_NODE_ID,
                 predicates: HirVec::new(),
@@ -389,8 +416,13 @@ impl Generics {
         !self.ty_params.is_empty()
     }
 
+    pub fn is_const_parameterized(&self) -> bool {
+        !self.const_params.is_empty()
+    }
+
     pub fn is_parameterized(&self) -> bool {
-        self.is_lt_parameterized() || self.is_type_parameterized()
+        self.is_lt_parameterized() || self.is_type_parameterized() ||
+            self.is_const_parameterized()
     }
 }
 
@@ -942,10 +974,12 @@ pub enum Expr_ {
     ///
     /// `'label: while expr { block }`
     ExprWhile(P<Expr>, P<Block>, Option<Ident>),
-    /// Conditionless loop (can be exited with break, continue, or return)
+    /// Conditionless loop (can be exited with break, continue, or return),
+    /// with a source that indicates whether it was desugared from another
+    /// kind of loop
     ///
     /// `'label: loop { block }`
-    ExprLoop(P<Block>, Option<Ident>),
+    ExprLoop(P<Block>, Option<Ident>, LoopSource),
     /// A `match` block, with a source that indicates whether or not it is
     /// the result of a desugaring, and if so, which kind.
     ExprMatch(P<Expr>, HirVec<Arm>, MatchSource),
@@ -980,8 +1014,8 @@ pub enum Expr_ {
 
     /// A referencing operation (`&a` or `&mut a`)
     ExprAddrOf(Mutability, P<Expr>),
-    /// A `break`, with an optional label to break
-    ExprBreak(Option<Spanned<Ident>>),
+    /// A `break`, with an optional label to break, and an optional expression
+    ExprBreak(Option<Spanned<Ident>>, Option<P<Expr>>),
     /// A `continue`, with an optional label
     ExprAgain(Option<Spanned<Ident>>),
     /// A `return`, with an optional value to be returned
@@ -1032,6 +1066,27 @@ pub enum MatchSource {
     TryDesugar,
 }
 
+/// The loop type that yielded an ExprLoop
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug, Copy)]
+pub enum LoopSource {
+    /// A `loop { .. }` loop
+    Loop,
+    /// A `while let _ = _ { .. }` loop
+    WhileLet,
+    /// A `for _ in _ { .. }` loop
+    ForLoop,
+}
+
+impl LoopSource {
+    pub fn name(self) -> &'static str {
+        match self {
+            LoopSource::Loop => "loop",
+            LoopSource::WhileLet => "while let",
+            LoopSource::ForLoop => "for",
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug, Copy)]
 pub enum CaptureClause {
     CaptureByValue,
@@ -1075,7 +1130,7 @@ pub struct TraitItem {
 pub enum TraitItem_ {
     ConstTraitItem(P<Ty>, Option<P<Expr>>),
     MethodTraitItem(MethodSig, Option<P<Block>>),
-    TypeTraitItem(TyParamBounds, Option<P<Ty>>),
+    TypeTraitItem(TyParamBounds, Option<P<Ty>>, Generics),
 }
 
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
@@ -1093,7 +1148,7 @@ pub struct ImplItem {
 pub enum ImplItemKind {
     Const(P<Ty>, P<Expr>),
     Method(MethodSig, P<Block>),
-    Type(P<Ty>),
+    Type(P<Ty>, Generics),
 }
 
 // Bind a type to an associated type: `A=Foo`.
@@ -1150,6 +1205,8 @@ pub enum Ty_ {
     TyRptr(Option<Lifetime>, MutTy),
     /// A bare function (e.g. `fn(usize) -> bool`)
     TyBareFn(P<BareFnTy>),
+    /// The never type (`!`)
+    TyNever,
     /// A tuple (`(A, B, C, D,...)`)
     TyTup(HirVec<P<Ty>>),
     /// A path (`module::module::...::Type`), optionally
@@ -1161,6 +1218,8 @@ pub enum Ty_ {
     TyObjectSum(P<Ty>, TyParamBounds),
     /// A type like `for<'a> Foo<&'a Bar>`
     TyPolyTraitRef(TyParamBounds),
+    /// An `impl TraitA+TraitB` type.
+    TyImplTrait(TyParamBounds),
     /// Unused for now
     TyTypeof(P<Expr>),
     /// TyInfer means the type should be inferred instead of it having been
@@ -1560,8 +1619,12 @@ pub enum Item_ {
     ItemEnum(EnumDef, Generics),
     /// A struct definition, e.g. `struct Foo<A> {x: A}`
     ItemStruct(VariantData, Generics),
+    /// A union definition, e.g. `union Foo<A, B> {x: A, y: B}`
+    ItemUnion(VariantData, Generics),
     /// Represents a Trait Declaration
     ItemTrait(Unsafety, Generics, TyParamBounds, HirVec<TraitItem>),
+    /// A trait alias, e.g. `trait Foo = Bar + Quux;`
+    ItemTraitAlias(Generics, TyParamBounds),
 
     // Default trait implementations
     ///
@@ -1570,6 +1633,7 @@ pub enum Item_ {
     /// An implementation, eg `impl<A> Trait for Foo { .. }`
     ItemImpl(Unsafety,
              ImplPolarity,
+             Defaultness,
              Generics,
              Option<TraitRef>, // (optional) trait this impl implements
              P<Ty>, // self
@@ -1589,7 +1653,9 @@ impl Item_ {
             ItemTy(..) => "type alias",
             ItemEnum(..) => "enum",
             ItemStruct(..) => "struct",
+            ItemUnion(..) => "union",
             ItemTrait(..) => "trait",
+            ItemTraitAlias(..) => "trait alias",
             ItemImpl(..) |
             ItemDefaultImpl(..) => "item",
         }
@@ -60,6 +60,7 @@ pub struct State<'a> {
     cur_cmnt_and_lit: ast_pp::CurrentCommentAndLiteral,
     boxes: Vec<pp::Breaks>,
     ann: &'a (PpAnn + 'a),
+    config: ast_pp::PrintConfig,
 }
 
 impl<'a> PrintState<'a> for State<'a> {
@@ -82,6 +83,10 @@ impl<'a> PrintState<'a> for State<'a> {
     fn literals(&self) -> &Option<Vec<comments::Literal>> {
         &self.literals
     }
+
+    fn config(&self) -> &ast_pp::PrintConfig {
+        &self.config
+    }
 }
 
 pub fn rust_printer<'a>(writer: Box<Write + 'a>, krate: Option<&'a Crate>) -> State<'a> {
@@ -105,6 +110,7 @@ pub fn rust_printer_annotated<'a>(writer: Box<Write + 'a>,
         },
         boxes: Vec::new(),
         ann: ann,
+        config: ast_pp::PrintConfig::default(),
     }
 }
 
@@ -186,6 +192,7 @@ impl<'a> State<'a> {
             },
             boxes: Vec::new(),
             ann: ann,
+            config: ast_pp::PrintConfig::default(),
         }
     }
 }
@@ -509,6 +516,9 @@ impl<'a> State<'a> {
                 self.print_opt_lifetime(lifetime)?;
                 self.print_mt(mt)?;
             }
+            hir::TyNever => {
+                word(&mut self.s, "!")?;
+            }
             hir::TyTup(ref elts) => {
                 self.popen()?;
                 self.commasep(Inconsistent, &elts[..], |s, ty| s.print_type(&ty))?;
@@ -521,6 +531,7 @@ impl<'a> State<'a> {
                 let generics = hir::Generics {
                     lifetimes: f.lifetimes.clone(),
                     ty_params: hir::HirVec::new(),
+                    const_params: hir::HirVec::new(),
                     where_clause: hir::WhereClause {
                         id: ast::DUMMY_NODE_ID,
                         predicates: hir::HirVec::new(),
@@ -541,6 +552,9 @@ impl<'a> State<'a> {
             hir::TyPolyTraitRef(ref bounds) => {
                 self.print_bounds("", &bounds[..])?;
             }
+            hir::TyImplTrait(ref bounds) => {
+                self.print_bounds("impl ", &bounds[..])?;
+            }
             hir::TyFixedLengthVec(ref ty, ref v) => {
                 word(&mut self.s, "[")?;
                 self.print_type(&ty)?;
@@ -615,14 +629,17 @@ impl<'a> State<'a> {
 
     fn print_associated_type(&mut self,
                              name: ast::Name,
+                             generics: &hir::Generics,
                              bounds: Option<&hir::TyParamBounds>,
                              ty: Option<&hir::Ty>)
                              -> io::Result<()> {
         self.word_space("type")?;
         self.print_name(name)?;
+        self.print_generics(generics)?;
         if let Some(bounds) = bounds {
             self.print_bounds(":", bounds)?;
         }
+        self.print_where_clause(&generics.where_clause)?;
         if let Some(ty) = ty {
             space(&mut self.s)?;
             self.word_space("=")?;
@@ -752,6 +769,10 @@ impl<'a> State<'a> {
                 self.head(&visibility_qualified(&item.vis, "struct"))?;
                 self.print_struct(struct_def, generics, item.name, item.span, true)?;
             }
+            hir::ItemUnion(ref struct_def, ref generics) => {
+                self.head(&visibility_qualified(&item.vis, "union"))?;
+                self.print_struct(struct_def, generics, item.name, item.span, true)?;
+            }
 
             hir::ItemDefaultImpl(unsafety, ref trait_ref) => {
                 self.head("")?;
@@ -767,12 +788,16 @@ impl<'a> State<'a> {
             }
             hir::ItemImpl(unsafety,
                           polarity,
+                          defaultness,
                           ref generics,
                           ref opt_trait,
                           ref ty,
                           ref impl_items) => {
                 self.head("")?;
                 self.print_visibility(&item.vis)?;
+                if let hir::Defaultness::Default = defaultness {
+                    self.word_nbsp("default")?;
+                }
                 self.print_unsafety(unsafety)?;
                 self.word_nbsp("impl")?;
 
@@ -834,6 +859,18 @@ impl<'a> State<'a> {
                 }
                 self.bclose(item.span)?;
             }
+            hir::ItemTraitAlias(ref generics, ref bounds) => {
+                self.head("")?;
+                self.print_visibility(&item.vis)?;
+                self.word_nbsp("trait")?;
+                self.print_name(item.name)?;
+                self.print_generics(generics)?;
+                self.print_bounds(" =", &bounds[..])?;
+                self.print_where_clause(&generics.where_clause)?;
+                word(&mut self.s, ";")?;
+                self.end()?; // end inner head-block
+                self.end()?; // end outer head-block
+            }
         }
         self.ann.post(self, NodeItem(item))
     }
@@ -1004,8 +1041,9 @@ impl<'a> State<'a> {
                     word(&mut self.s, ";")?;
                 }
             }
-            hir::TypeTraitItem(ref bounds, ref default) => {
+            hir::TypeTraitItem(ref bounds, ref default, ref generics) => {
                 self.print_associated_type(ti.name,
+                                           generics,
                                            Some(bounds),
                                            default.as_ref().map(|ty| &**ty))?;
             }
@@ -1033,8 +1071,8 @@ impl<'a> State<'a> {
                 self.nbsp()?;
                 self.print_block_with_attrs(body, &ii.attrs)?;
             }
-            hir::ImplItemKind::Type(ref ty) => {
-                self.print_associated_type(ii.name, None, Some(ty))?;
+            hir::ImplItemKind::Type(ref ty, ref generics) => {
+                self.print_associated_type(ii.name, generics, None, Some(ty))?;
             }
         }
         self.ann.post(self, NodeSubItem(ii.id))
@@ -1370,7 +1408,7 @@ impl<'a> State<'a> {
                 space(&mut self.s)?;
                 self.print_block(&blk)?;
             }
-            hir::ExprLoop(ref blk, opt_ident) => {
+            hir::ExprLoop(ref blk, opt_ident, _) => {
                 if let Some(ident) = opt_ident {
                     self.print_name(ident.name)?;
                     self.word_space(":")?;
@@ -1464,13 +1502,17 @@ impl<'a> State<'a> {
             hir::ExprPath(Some(ref qself), ref path) => {
                 self.print_qpath(path, qself, true)?
             }
-            hir::ExprBreak(opt_ident) => {
+            hir::ExprBreak(opt_ident, ref opt_expr) => {
                 word(&mut self.s, "break")?;
                 space(&mut self.s)?;
                 if let Some(ident) = opt_ident {
                     self.print_name(ident.node.name)?;
                     space(&mut self.s)?;
                 }
+                if let Some(ref expr) = *opt_expr {
+                    self.print_expr(expr)?;
+                    space(&mut self.s)?;
+                }
             }
             hir::ExprAgain(opt_ident) => {
                 word(&mut self.s, "continue")?;
@@ -1685,6 +1727,14 @@ impl<'a> State<'a> {
                     comma = true;
                 }
 
+                if !data.consts.is_empty() {
+                    if comma {
+                        self.word_space(",")?
+                    }
+                    self.commasep(Inconsistent, &data.consts, |s, c| s.print_expr(&c))?;
+                    comma = true;
+                }
+
                 for binding in data.bindings.iter() {
                     if comma {
                         self.word_space(",")?
@@ -2061,7 +2111,8 @@ impl<'a> State<'a> {
     }
 
     pub fn print_generics(&mut self, generics: &hir::Generics) -> io::Result<()> {
-        let total = generics.lifetimes.len() + generics.ty_params.len();
+        let total = generics.lifetimes.len() + generics.ty_params.len() +
+                    generics.const_params.len();
         if total == 0 {
             return Ok(());
         }
@@ -2077,10 +2128,14 @@ impl<'a> State<'a> {
             if idx < generics.lifetimes.len() {
                 let lifetime = &generics.lifetimes[idx];
                 s.print_lifetime_def(lifetime)
-            } else {
+            } else if idx < generics.lifetimes.len() + generics.ty_params.len() {
                 let idx = idx - generics.lifetimes.len();
                 let param = &generics.ty_params[idx];
                 s.print_ty_param(param)
+            } else {
+                let idx = idx - generics.lifetimes.len() - generics.ty_params.len();
+                let param = &generics.const_params[idx];
+                s.print_const_param(param)
             }
         })?;
 
@@ -2101,6 +2156,13 @@ impl<'a> State<'a> {
         }
     }
 
+    pub fn print_const_param(&mut self, param: &hir::ConstParam) -> io::Result<()> {
+        self.word_nbsp("const")?;
+        self.print_name(param.name)?;
+        self.word_space(":")?;
+        self.print_type(&param.ty)
+    }
+
     pub fn print_where_clause(&mut self, where_clause: &hir::WhereClause) -> io::Result<()> {
         if where_clause.predicates.is_empty() {
             return Ok(());
@@ -2261,6 +2323,7 @@ impl<'a> State<'a> {
         let generics = hir::Generics {
             lifetimes: hir::HirVec::new(),
             ty_params: hir::HirVec::new(),
+            const_params: hir::HirVec::new(),
             where_clause: hir::WhereClause {
                 id: ast::DUMMY_NODE_ID,
                 predicates: hir::HirVec::new(),
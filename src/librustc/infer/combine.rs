@@ -119,6 +119,50 @@ pub fn super_combine_tys<'a,'tcx:'a,R>(infcx: &InferCtxt<'a, 'tcx>,
     }
 }
 
+pub fn super_combine_consts<'a,'tcx:'a,R>(infcx: &InferCtxt<'a, 'tcx>,
+                                          relation: &mut R,
+                                          a: ty::Const,
+                                          b: ty::Const)
+                                          -> RelateResult<'tcx, ty::Const>
+    where R: TypeRelation<'a,'tcx>
+{
+    let a_is_expected = relation.a_is_expected();
+    let a = infcx.shallow_resolve_const(a);
+    let b = infcx.shallow_resolve_const(b);
+
+    match (a, b) {
+        (ty::Const::Infer(a_id), ty::Const::Infer(b_id)) => {
+            infcx.const_unification_table
+                 .borrow_mut()
+                 .unify_var_var(a_id, b_id)
+                 .map_err(|e| const_unification_error(a_is_expected, e))?;
+            Ok(a)
+        }
+        (ty::Const::Infer(v_id), c) => {
+            unify_const_variable(infcx, a_is_expected, v_id, c)
+        }
+        (c, ty::Const::Infer(v_id)) => {
+            unify_const_variable(infcx, !a_is_expected, v_id, c)
+        }
+        _ => {
+            ty::relate::super_relate_consts(relation, a, b)
+        }
+    }
+}
+
+fn unify_const_variable<'a,'tcx>(infcx: &InferCtxt<'a,'tcx>,
+                                 vid_is_expected: bool,
+                                 vid: ty::ConstVid,
+                                 val: ty::Const)
+                                 -> RelateResult<'tcx, ty::Const>
+{
+    infcx.const_unification_table
+         .borrow_mut()
+         .unify_var_value(vid, val)
+         .map_err(|e| const_unification_error(vid_is_expected, e))?;
+    Ok(val)
+}
+
 fn unify_integral_variable<'a,'tcx>(infcx: &InferCtxt<'a,'tcx>,
                                     vid_is_expected: bool,
                                     vid: ty::IntVid,
@@ -396,6 +440,13 @@ fn int_unification_error<'tcx>(a_is_expected: bool, v: (ty::IntVarValue, ty::Int
     TypeError::IntMismatch(ty::relate::expected_found_bool(a_is_expected, &a, &b))
 }
 
+fn const_unification_error<'tcx>(a_is_expected: bool, v: (ty::Const, ty::Const))
+                                 -> TypeError<'tcx>
+{
+    let (a, b) = v;
+    TypeError::ConstMismatch(ty::relate::expected_found_bool(a_is_expected, &a, &b))
+}
+
 fn float_unification_error<'tcx>(a_is_expected: bool,
                                  v: (ast::FloatTy, ast::FloatTy))
                                  -> TypeError<'tcx>
@@ -90,6 +90,10 @@ impl<'a, 'tcx> TypeRelation<'a,'tcx> for Equate<'a, 'tcx> {
         Ok(a)
     }
 
+    fn consts(&mut self, a: ty::Const, b: ty::Const) -> RelateResult<'tcx, ty::Const> {
+        combine::super_combine_consts(self.fields.infcx, self, a, b)
+    }
+
     fn binders<T>(&mut self, a: &ty::Binder<T>, b: &ty::Binder<T>)
                   -> RelateResult<'tcx, ty::Binder<T>>
         where T: Relate<'a, 'tcx>
@@ -105,6 +105,7 @@ impl<'tcx> TyCtxt<'tcx> {
             match item.node {
                 hir::ItemImpl(..) => "impl",
                 hir::ItemStruct(..) => "struct",
+                hir::ItemUnion(..) => "union",
                 hir::ItemEnum(..) => "enum",
                 hir::ItemTrait(..) => "trait",
                 hir::ItemFn(..) => "function body",
@@ -1373,6 +1374,7 @@ impl<'a, 'tcx> Rebuilder<'a, 'tcx> {
         hir::Generics {
             lifetimes: lifetimes.into(),
             ty_params: ty_params,
+            const_params: generics.const_params.clone(),
             where_clause: where_clause,
         }
     }
@@ -1622,6 +1624,7 @@ impl<'a, 'tcx> Rebuilder<'a, 'tcx> {
                 hir::AngleBracketedParameters(hir::AngleBracketedParameterData {
                     lifetimes: new_lts.into(),
                     types: new_types,
+                    consts: data.consts.clone(),
                     bindings: new_bindings,
                })
             }
@@ -1962,7 +1965,7 @@ fn lifetimes_in_scope(tcx: &TyCtxt,
         match tcx.map.find(parent) {
             Some(node) => match node {
                 ast_map::NodeItem(item) => match item.node {
-                    hir::ItemImpl(_, _, ref gen, _, _, _) => {
+                    hir::ItemImpl(_, _, _, ref gen, _, _, _) => {
                         taken.extend_from_slice(&gen.lifetimes);
                     }
                     _ => ()
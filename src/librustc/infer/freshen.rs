@@ -102,6 +102,12 @@ impl<'a, 'tcx> TypeFolder<'tcx> for TypeFreshener<'a, 'tcx> {
         }
     }
 
+    fn fold_const(&mut self, c: ty::Const) -> ty::Const {
+        // Const variables are not freshened; unresolved ones keep the
+        // type flagged as needing inference.
+        self.infcx.shallow_resolve_const(c)
+    }
+
     fn fold_ty(&mut self, t: Ty<'tcx>) -> Ty<'tcx> {
         if !t.needs_infer() && !t.has_erasable_regions() {
             return t;
@@ -157,7 +163,9 @@ impl<'a, 'tcx> TypeFolder<'tcx> for TypeFreshener<'a, 'tcx> {
             ty::TyBox(..) |
             ty::TyStr |
             ty::TyError |
+            ty::TyNever |
             ty::TyArray(..) |
+            ty::TyArrayParam(..) |
             ty::TySlice(..) |
             ty::TyRawPtr(..) |
             ty::TyRef(..) |
@@ -168,6 +176,7 @@ impl<'a, 'tcx> TypeFolder<'tcx> for TypeFreshener<'a, 'tcx> {
             ty::TyClosure(..) |
             ty::TyTuple(..) |
             ty::TyProjection(..) |
+            ty::TyAnon(..) |
             ty::TyParam(..) => {
                 t.super_fold_with(self)
             }
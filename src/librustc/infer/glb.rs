@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::combine::CombineFields;
+use super::combine::{self, CombineFields};
 use super::higher_ranked::HigherRankedRelations;
 use super::InferCtxt;
 use super::lattice::{self, LatticeDir};
@@ -68,6 +68,10 @@ impl<'a, 'tcx> TypeRelation<'a, 'tcx> for Glb<'a, 'tcx> {
         Ok(self.fields.infcx.region_vars.glb_regions(origin, a, b))
     }
 
+    fn consts(&mut self, a: ty::Const, b: ty::Const) -> RelateResult<'tcx, ty::Const> {
+        combine::super_combine_consts(self.fields.infcx, self, a, b)
+    }
+
     fn binders<T>(&mut self, a: &ty::Binder<T>, b: &ty::Binder<T>)
                   -> RelateResult<'tcx, ty::Binder<T>>
         where T: Relate<'a, 'tcx>
@@ -28,7 +28,7 @@ use ty::subst;
 use ty::subst::Substs;
 use ty::subst::Subst;
 use ty::adjustment;
-use ty::{TyVid, IntVid, FloatVid};
+use ty::{TyVid, IntVid, FloatVid, ConstVid};
 use ty::{self, Ty, TyCtxt};
 use ty::error::{ExpectedFound, TypeError, UnconstrainedNumeric};
 use ty::fold::TypeFoldable;
@@ -89,6 +89,9 @@ pub struct InferCtxt<'a, 'tcx: 'a> {
     // Map from floating variable to the kind of float it represents
     float_unification_table: RefCell<UnificationTable<ty::FloatVid>>,
 
+    // Map from const variable to the value or parameter it represents
+    const_unification_table: RefCell<UnificationTable<ty::ConstVid>>,
+
     // For region variables.
     region_vars: RegionVarBindings<'a, 'tcx>,
 
@@ -363,7 +366,8 @@ pub enum RegionVariableOrigin {
 pub enum FixupError {
     UnresolvedIntTy(IntVid),
     UnresolvedFloatTy(FloatVid),
-    UnresolvedTy(TyVid)
+    UnresolvedTy(TyVid),
+    UnresolvedConst(ConstVid)
 }
 
 pub fn fixup_err_to_string(f: FixupError) -> String {
@@ -379,6 +383,7 @@ pub fn fixup_err_to_string(f: FixupError) -> String {
            the type explicitly".to_string()
       }
       UnresolvedTy(_) => "unconstrained type".to_string(),
+      UnresolvedConst(_) => "unconstrained const parameter".to_string(),
     }
 }
 
@@ -393,6 +398,7 @@ pub fn new_infer_ctxt<'a, 'tcx>(tcx: &'a TyCtxt<'tcx>,
         type_variables: RefCell::new(type_variable::TypeVariableTable::new()),
         int_unification_table: RefCell::new(UnificationTable::new()),
         float_unification_table: RefCell::new(UnificationTable::new()),
+        const_unification_table: RefCell::new(UnificationTable::new()),
         region_vars: RegionVarBindings::new(tcx),
         parameter_environment: param_env.unwrap_or(tcx.empty_parameter_environment()),
         reported_trait_errors: RefCell::new(FnvHashSet()),
@@ -517,6 +523,7 @@ pub struct CombinedSnapshot {
     type_snapshot: type_variable::Snapshot,
     int_snapshot: unify::Snapshot<ty::IntVid>,
     float_snapshot: unify::Snapshot<ty::FloatVid>,
+    const_snapshot: unify::Snapshot<ty::ConstVid>,
     region_vars_snapshot: RegionSnapshot,
 }
 
@@ -617,6 +624,9 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
     pub fn type_var_diverges(&'a self, ty: Ty) -> bool {
         match ty.sty {
             ty::TyInfer(ty::TyVar(vid)) => self.type_variables.borrow().var_diverges(vid),
+            // An expression of type `!` diverges just like one whose
+            // type is a diverging type variable.
+            ty::TyNever => true,
             _ => false
         }
     }
@@ -744,6 +754,7 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
             type_snapshot: self.type_variables.borrow_mut().snapshot(),
             int_snapshot: self.int_unification_table.borrow_mut().snapshot(),
             float_snapshot: self.float_unification_table.borrow_mut().snapshot(),
+            const_snapshot: self.const_unification_table.borrow_mut().snapshot(),
             region_vars_snapshot: self.region_vars.start_snapshot(),
         }
     }
@@ -753,6 +764,7 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         let CombinedSnapshot { type_snapshot,
                                int_snapshot,
                                float_snapshot,
+                               const_snapshot,
                                region_vars_snapshot } = snapshot;
 
         self.type_variables
@@ -764,6 +776,9 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         self.float_unification_table
             .borrow_mut()
             .rollback_to(float_snapshot);
+        self.const_unification_table
+            .borrow_mut()
+            .rollback_to(const_snapshot);
         self.region_vars
             .rollback_to(region_vars_snapshot);
     }
@@ -773,6 +788,7 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         let CombinedSnapshot { type_snapshot,
                                int_snapshot,
                                float_snapshot,
+                               const_snapshot,
                                region_vars_snapshot } = snapshot;
 
         self.type_variables
@@ -784,6 +800,9 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         self.float_unification_table
             .borrow_mut()
             .commit(float_snapshot);
+        self.const_unification_table
+            .borrow_mut()
+            .commit(const_snapshot);
         self.region_vars
             .commit(region_vars_snapshot);
     }
@@ -824,6 +843,7 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         let CombinedSnapshot { type_snapshot,
                                int_snapshot,
                                float_snapshot,
+                               const_snapshot,
                                region_vars_snapshot } = self.start_snapshot();
 
         let r = self.commit_if_ok(|_| f());
@@ -841,6 +861,9 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         self.float_unification_table
             .borrow_mut()
             .rollback_to(float_snapshot);
+        self.const_unification_table
+            .borrow_mut()
+            .rollback_to(const_snapshot);
 
         // Commit region vars that may escape through resolved types.
         self.region_vars
@@ -932,6 +955,29 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         })
     }
 
+    /// Like `sub_poly_trait_refs`, but also relates the arguments of
+    /// generic associated types.
+    pub fn sub_poly_projection_tys(&self,
+                                   a_is_expected: bool,
+                                   origin: TypeOrigin,
+                                   a: ty::Binder<ty::ProjectionTy<'tcx>>,
+                                   b: ty::Binder<ty::ProjectionTy<'tcx>>)
+        -> InferResult<'tcx, ()>
+    {
+        debug!("sub_poly_projection_tys({:?} <: {:?})",
+               a,
+               b);
+        self.commit_if_ok(|_| {
+            let a_trait_ref = ty::Binder(a.0.trait_ref.clone());
+            let b_trait_ref = ty::Binder(b.0.trait_ref.clone());
+            let trace = TypeTrace {
+                origin: origin,
+                values: PolyTraitRefs(expected_found(a_is_expected, a_trait_ref, b_trait_ref))
+            };
+            self.sub(a_is_expected, trace, &a, &b).map(|ok| ok.unit())
+        })
+    }
+
     pub fn skolemize_late_bound_regions<T>(&self,
                                            value: &ty::Binder<T>,
                                            snapshot: &CombinedSnapshot)
@@ -1035,6 +1081,18 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
             .new_key(None)
     }
 
+    pub fn next_const_var(&self) -> ty::Const {
+        ty::Const::Infer(self.const_unification_table
+                             .borrow_mut()
+                             .new_key(None))
+    }
+
+    pub fn const_vars_for_defs(&self,
+                               defs: &[ty::ConstParameterDef<'tcx>])
+                               -> Vec<ty::Const> {
+        defs.iter().map(|_| self.next_const_var()).collect()
+    }
+
     pub fn next_region_var(&self, origin: RegionVariableOrigin) -> ty::Region {
         ty::ReVar(self.region_vars.new_region_var(origin))
     }
@@ -1083,7 +1141,11 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
             generics.regions.map(
                 |d| self.next_region_var(EarlyBoundRegion(span, d.name)));
 
-        let mut substs = subst::Substs::new(type_params, region_params);
+        let const_params = generics.consts.map(|_| self.next_const_var());
+
+        let mut substs = subst::Substs::new_with_consts(type_params,
+                                                        region_params,
+                                                        const_params);
 
         for space in subst::ParamSpace::all().iter() {
             self.type_vars_for_defs(
@@ -1110,6 +1172,8 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         assert!(generics.types.len(subst::FnSpace) == 0);
         assert!(generics.regions.len(subst::SelfSpace) == 0);
         assert!(generics.regions.len(subst::FnSpace) == 0);
+        assert!(generics.consts.len(subst::SelfSpace) == 0);
+        assert!(generics.consts.len(subst::FnSpace) == 0);
 
         let type_params = Vec::new();
 
@@ -1118,6 +1182,9 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
 
         let mut substs = subst::Substs::new_trait(type_params, regions, self_ty);
 
+        let const_param_defs = generics.consts.get_slice(subst::TypeSpace);
+        substs.consts.replace(subst::TypeSpace, self.const_vars_for_defs(const_param_defs));
+
         let type_parameter_defs = generics.types.get_slice(subst::TypeSpace);
         self.type_vars_for_defs(span, subst::TypeSpace, &mut substs, type_parameter_defs);
 
@@ -1262,6 +1329,20 @@ impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
         }
     }
 
+    /// Replaces a const inference variable with its value, if it
+    /// has been unified with one.
+    pub fn shallow_resolve_const(&self, c: ty::Const) -> ty::Const {
+        match c {
+            ty::Const::Infer(v) => {
+                self.const_unification_table
+                    .borrow_mut()
+                    .probe(v)
+                    .unwrap_or(c)
+            }
+            _ => c
+        }
+    }
+
     pub fn resolve_type_vars_if_possible<T>(&self, value: &T) -> T
         where T: TypeFoldable<'tcx>
     {
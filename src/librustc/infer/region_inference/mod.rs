@@ -1366,7 +1366,9 @@ impl<'tcx> GenericKind<'tcx> {
     pub fn to_ty(&self, tcx: &TyCtxt<'tcx>) -> Ty<'tcx> {
         match *self {
             GenericKind::Param(ref p) => p.to_ty(tcx),
-            GenericKind::Projection(ref p) => tcx.mk_projection(p.trait_ref.clone(), p.item_name),
+            GenericKind::Projection(ref p) => {
+                tcx.mk_projection(p.trait_ref.clone(), p.item_name, p.item_substs)
+            }
         }
     }
 }
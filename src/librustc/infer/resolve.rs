@@ -42,6 +42,10 @@ impl<'a, 'tcx> ty::fold::TypeFolder<'tcx> for OpportunisticTypeResolver<'a, 'tcx
             t0.super_fold_with(self)
         }
     }
+
+    fn fold_const(&mut self, c: ty::Const) -> ty::Const {
+        self.infcx.shallow_resolve_const(c)
+    }
 }
 
 /// The opportunistic type and region resolver is similar to the
@@ -77,6 +81,10 @@ impl<'a, 'tcx> ty::fold::TypeFolder<'tcx> for OpportunisticTypeAndRegionResolver
           _ => r,
         }
     }
+
+    fn fold_const(&mut self, c: ty::Const) -> ty::Const {
+        self.infcx.shallow_resolve_const(c)
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -142,4 +150,14 @@ impl<'a, 'tcx> ty::fold::TypeFolder<'tcx> for FullTypeResolver<'a, 'tcx> {
           _ => r,
         }
     }
+
+    fn fold_const(&mut self, c: ty::Const) -> ty::Const {
+        match self.infcx.shallow_resolve_const(c) {
+            ty::Const::Infer(vid) => {
+                self.err = Some(FixupError::UnresolvedConst(vid));
+                c
+            }
+            c => c
+        }
+    }
 }
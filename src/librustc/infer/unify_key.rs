@@ -74,6 +74,16 @@ impl<'tcx> ToType<'tcx> for ast::FloatTy {
     }
 }
 
+// Const inference keys. A const variable is only ever bound to a
+// value or a const parameter, never to another variable.
+
+impl UnifyKey for ty::ConstVid {
+    type Value = Option<ty::Const>;
+    fn index(&self) -> u32 { self.index }
+    fn from_index(i: u32) -> ty::ConstVid { ty::ConstVid { index: i } }
+    fn tag(_: Option<ty::ConstVid>) -> &'static str { "ConstVid" }
+}
+
 impl UnifyKey for ty::TyVid {
     type Value = ();
     fn index(&self) -> u32 { self.index }
@@ -39,7 +39,7 @@ use std::cell::RefCell;
 use std::cmp;
 use std::default::Default as StdDefault;
 use std::mem;
-use syntax::attr::{self, AttrMetaMethods};
+use syntax::attr::{self, AttrMetaMethods, AttrNestedMetaItemMethods};
 use syntax::codemap::Span;
 use syntax::errors::DiagnosticBuilder;
 use syntax::parse::token::InternedString;
@@ -380,10 +380,10 @@ pub fn gather_attr(attr: &ast::Attribute)
         }
     };
 
-    for meta in metas {
-        out.push(match meta.node {
-            ast::MetaItemKind::Word(ref lint_name) => Ok((lint_name.clone(), level, meta.span)),
-            _ => Err(meta.span),
+    for li in metas {
+        out.push(match li.word() {
+            Some(word) => Ok((word.name(), level, word.span)),
+            None => Err(li.span),
         });
     }
 
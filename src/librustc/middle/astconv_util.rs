@@ -27,6 +27,11 @@ pub fn prohibit_type_params(tcx: &TyCtxt, segments: &[ast::PathSegment]) {
                       "type parameters are not allowed on this type");
             break;
         }
+        for expr in segment.parameters.consts() {
+            span_err!(tcx.sess, expr.span, E0570,
+                      "const parameters are not allowed on this type");
+            break;
+        }
         for lifetime in segment.parameters.lifetimes() {
             span_err!(tcx.sess, lifetime.span, E0110,
                       "lifetime parameters are not allowed on this type");
@@ -50,6 +50,13 @@ fn type_is_unsafe_function(ty: Ty) -> bool {
     }
 }
 
+fn type_is_union(ty: Ty) -> bool {
+    match ty.sty {
+        ty::TyStruct(def, _) => def.is_union(),
+        _ => false,
+    }
+}
+
 struct EffectCheckVisitor<'a, 'tcx: 'a> {
     tcx: &'a TyCtxt<'tcx>,
 
@@ -176,11 +183,37 @@ impl<'a, 'tcx, 'v> Visitor<'v> for EffectCheckVisitor<'a, 'tcx> {
                     self.require_unsafe(expr.span, "use of mutable static");
                 }
             }
+            hir::ExprField(ref base, _) => {
+                if type_is_union(self.tcx.expr_ty_adjusted(base)) {
+                    self.require_unsafe(expr.span, "access to union field");
+                }
+            }
+            hir::ExprAssign(ref lhs, ref rhs) => {
+                // Writing a whole field of a union is safe, only reading
+                // one requires `unsafe`.
+                if let hir::ExprField(ref base, _) = lhs.node {
+                    if type_is_union(self.tcx.expr_ty_adjusted(base)) {
+                        self.visit_expr(base);
+                        self.visit_expr(rhs);
+                        return;
+                    }
+                }
+            }
             _ => {}
         }
 
         intravisit::walk_expr(self, expr);
     }
+
+    fn visit_pat(&mut self, pat: &hir::Pat) {
+        if let hir::PatKind::Struct(..) = pat.node {
+            if type_is_union(self.tcx.pat_ty(pat)) {
+                self.require_unsafe(pat.span, "matching on union field");
+            }
+        }
+
+        intravisit::walk_pat(self, pat);
+    }
 }
 
 pub fn check_crate(tcx: &TyCtxt) {
@@ -465,11 +465,16 @@ impl<'d,'t,'a,'tcx> ExprUseVisitor<'d,'t,'a,'tcx> {
                 self.consume_exprs(inputs);
             }
 
-            hir::ExprBreak(..) |
             hir::ExprAgain(..) |
             hir::ExprLit(..) => {}
 
-            hir::ExprLoop(ref blk, _) => {
+            hir::ExprBreak(_, ref opt_expr) => {
+                if let Some(ref expr) = *opt_expr {
+                    self.consume_expr(&expr);
+                }
+            }
+
+            hir::ExprLoop(ref blk, _, _) => {
                 self.walk_block(&blk);
             }
 
@@ -648,7 +653,7 @@ impl<'d,'t,'a,'tcx> ExprUseVisitor<'d,'t,'a,'tcx> {
     }
 
     fn walk_struct_expr(&mut self,
-                        _expr: &hir::Expr,
+                        expr: &hir::Expr,
                         fields: &[hir::Field],
                         opt_with: &Option<P<hir::Expr>>) {
         // Consume the expressions supplying values for each field.
@@ -665,9 +670,27 @@ impl<'d,'t,'a,'tcx> ExprUseVisitor<'d,'t,'a,'tcx> {
 
         // Select just those fields of the `with`
         // expression that will actually be used
-        if let ty::TyStruct(def, substs) = with_cmt.ty.sty {
+        let with_variant = match with_cmt.ty.sty {
+            ty::TyStruct(def, substs) => Some((def.struct_variant(), substs, with_cmt.clone())),
+            ty::TyEnum(def, substs) => {
+                match self.tcx().def_map.borrow().get(&expr.id).map(|d| d.full_def()) {
+                    Some(Def::Variant(_, variant_did)) => {
+                        // typeck only allows this for an enum with a single
+                        // variant, so the base always holds the variant the
+                        // expression constructs
+                        let with_cmt = self.mc.cat_downcast(&*with_expr, with_cmt.clone(),
+                                                            with_cmt.ty, variant_did);
+                        Some((def.variant_with_id(variant_did), substs, with_cmt))
+                    }
+                    _ => None
+                }
+            }
+            _ => None
+        };
+
+        if let Some((variant, substs, with_cmt)) = with_variant {
             // Consume those fields of the with expression that are needed.
-            for with_field in &def.struct_variant().fields {
+            for with_field in &variant.fields {
                 if !contains_field_named(with_field, fields) {
                     let cmt_field = self.mc.cat_field(
                         &*with_expr,
@@ -680,13 +703,13 @@ impl<'d,'t,'a,'tcx> ExprUseVisitor<'d,'t,'a,'tcx> {
             }
         } else {
             // the base expression should always evaluate to a
-            // struct; however, when EUV is run during typeck, it
+            // struct or enum; however, when EUV is run during typeck, it
             // may not. This will generate an error earlier in typeck,
             // so we can just ignore it.
             if !self.tcx().sess.has_errors() {
                 span_bug!(
                     with_expr.span,
-                    "with expression doesn't evaluate to a struct");
+                    "with expression doesn't evaluate to a struct or enum");
             }
         };
 
@@ -714,7 +737,8 @@ impl<'d,'t,'a,'tcx> ExprUseVisitor<'d,'t,'a,'tcx> {
             match adjustment {
                 adjustment::AdjustReifyFnPointer |
                 adjustment::AdjustUnsafeFnPointer |
-                adjustment::AdjustMutToConstPointer => {
+                adjustment::AdjustMutToConstPointer |
+                adjustment::AdjustNeverToAny(_) => {
                     // Creating a closure/fn-pointer or unsizing consumes
                     // the input and stores it into the resulting rvalue.
                     debug!("walk_adjustment: trivial adjustment");
@@ -47,7 +47,7 @@ fn item_might_be_inlined(item: &hir::Item) -> bool {
     }
 
     match item.node {
-        hir::ItemImpl(_, _, ref generics, _, _, _) |
+        hir::ItemImpl(_, _, _, ref generics, _, _, _) |
         hir::ItemFn(_, _, _, _, ref generics, _) => {
             generics_require_inlining(generics)
         }
@@ -192,14 +192,14 @@ impl<'a, 'tcx> ReachableContext<'a, 'tcx> {
                             // does too.
                             let impl_node_id = self.tcx.map.as_local_node_id(impl_did).unwrap();
                             match self.tcx.map.expect_item(impl_node_id).node {
-                                hir::ItemImpl(_, _, ref generics, _, _, _) => {
+                                hir::ItemImpl(_, _, _, ref generics, _, _, _) => {
                                     generics_require_inlining(generics)
                                 }
                                 _ => false
                             }
                         }
                     }
-                    hir::ImplItemKind::Type(_) => false,
+                    hir::ImplItemKind::Type(..) => false,
                 }
             }
             Some(_) => false,
@@ -273,8 +273,9 @@ impl<'a, 'tcx> ReachableContext<'a, 'tcx> {
                     hir::ItemTy(..) | hir::ItemStatic(_, _, _) |
                     hir::ItemMod(..) | hir::ItemForeignMod(..) |
                     hir::ItemImpl(..) | hir::ItemTrait(..) |
+                    hir::ItemTraitAlias(..) |
                     hir::ItemStruct(..) | hir::ItemEnum(..) |
-                    hir::ItemDefaultImpl(..) => {}
+                    hir::ItemUnion(..) | hir::ItemDefaultImpl(..) => {}
                 }
             }
             ast_map::NodeTraitItem(trait_method) => {
@@ -303,7 +304,7 @@ impl<'a, 'tcx> ReachableContext<'a, 'tcx> {
                             intravisit::walk_block(self, body)
                         }
                     }
-                    hir::ImplItemKind::Type(_) => {}
+                    hir::ImplItemKind::Type(..) => {}
                 }
             }
             // Nothing to recurse on for these
@@ -334,7 +335,7 @@ struct CollectPrivateImplItemsVisitor<'a> {
 impl<'a, 'v> Visitor<'v> for CollectPrivateImplItemsVisitor<'a> {
     fn visit_item(&mut self, item: &hir::Item) {
         // We need only trait impls here, not inherent impls, and only non-exported ones
-        if let hir::ItemImpl(_, _, _, Some(_), _, ref impl_items) = item.node {
+        if let hir::ItemImpl(_, _, _, _, Some(_), _, ref impl_items) = item.node {
             if !self.access_levels.is_reachable(item.id) {
                 for impl_item in impl_items {
                     self.worklist.push(impl_item.id);
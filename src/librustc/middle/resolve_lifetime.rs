@@ -139,8 +139,10 @@ impl<'a, 'v> Visitor<'v> for LifetimeContext<'a> {
                 hir::ItemTy(_, ref generics) |
                 hir::ItemEnum(_, ref generics) |
                 hir::ItemStruct(_, ref generics) |
+                hir::ItemUnion(_, ref generics) |
                 hir::ItemTrait(_, ref generics, _, _) |
-                hir::ItemImpl(_, _, ref generics, _, _, _) => {
+                hir::ItemTraitAlias(ref generics, _) |
+                hir::ItemImpl(_, _, _, ref generics, _, _, _) => {
                     // These kinds of items have only early bound lifetime parameters.
                     let lifetimes = &generics.lifetimes;
                     let early_scope = EarlyScope(subst::TypeSpace, lifetimes, &ROOT_SCOPE);
@@ -164,8 +166,8 @@ impl<'a, 'v> Visitor<'v> for LifetimeContext<'a> {
         // Items always introduce a new root scope
         self.with(RootScope, |_, this| {
             match item.node {
-                hir::ForeignItemFn(_, ref generics) => {
-                    this.visit_early_late(subst::FnSpace, generics, |this| {
+                hir::ForeignItemFn(ref decl, ref generics) => {
+                    this.visit_early_late(subst::FnSpace, generics, Some(&**decl), |this| {
                         intravisit::walk_foreign_item(this, item);
                     })
                 }
@@ -183,12 +185,12 @@ impl<'a, 'v> Visitor<'v> for LifetimeContext<'a> {
                 b: &'v hir::Block, s: Span, fn_id: ast::NodeId) {
         match fk {
             FnKind::ItemFn(_, generics, _, _, _, _, _) => {
-                self.visit_early_late(subst::FnSpace, generics, |this| {
+                self.visit_early_late(subst::FnSpace, generics, Some(fd), |this| {
                     this.add_scope_and_walk_fn(fk, fd, b, s, fn_id)
                 })
             }
             FnKind::Method(_, sig, _, _) => {
-                self.visit_early_late(subst::FnSpace, &sig.generics, |this| {
+                self.visit_early_late(subst::FnSpace, &sig.generics, Some(fd), |this| {
                     this.add_scope_and_walk_fn(fk, fd, b, s, fn_id)
                 })
             }
@@ -233,17 +235,31 @@ impl<'a, 'v> Visitor<'v> for LifetimeContext<'a> {
         // methods in an impl can reuse label names.
         let saved = replace(&mut self.labels_in_fn, vec![]);
 
-        if let hir::MethodTraitItem(ref sig, None) = trait_item.node {
-            self.visit_early_late(
-                subst::FnSpace, &sig.generics,
-                |this| intravisit::walk_trait_item(this, trait_item))
-        } else {
-            intravisit::walk_trait_item(self, trait_item);
+        match trait_item.node {
+            hir::MethodTraitItem(ref sig, None) => {
+                self.visit_early_late(
+                    subst::FnSpace, &sig.generics, Some(&*sig.decl),
+                    |this| intravisit::walk_trait_item(this, trait_item))
+            }
+            hir::TypeTraitItem(_, _, ref generics) => {
+                self.visit_associated_type(generics,
+                                           |this| intravisit::walk_trait_item(this, trait_item))
+            }
+            _ => intravisit::walk_trait_item(self, trait_item)
         }
 
         replace(&mut self.labels_in_fn, saved);
     }
 
+    fn visit_impl_item(&mut self, impl_item: &hir::ImplItem) {
+        if let hir::ImplItemKind::Type(_, ref generics) = impl_item.node {
+            self.visit_associated_type(generics,
+                                       |this| intravisit::walk_impl_item(this, impl_item))
+        } else {
+            intravisit::walk_impl_item(self, impl_item);
+        }
+    }
+
     fn visit_lifetime(&mut self, lifetime_ref: &hir::Lifetime) {
         if lifetime_ref.name == keywords::StaticLifetime.name() {
             self.insert_lifetime(lifetime_ref, DefStaticRegion);
@@ -428,7 +444,7 @@ fn extract_labels<'v, 'a>(ctxt: &mut LifetimeContext<'a>, b: &'v hir::Block) {
     fn expression_label(ex: &hir::Expr) -> Option<ast::Name> {
         match ex.node {
             hir::ExprWhile(_, _, Some(label)) |
-            hir::ExprLoop(_, Some(label)) => Some(label.unhygienic_name),
+            hir::ExprLoop(_, Some(label), _) => Some(label.unhygienic_name),
             _ => None,
         }
     }
@@ -521,20 +537,34 @@ impl<'a> LifetimeContext<'a> {
     ///    fn foo<'a,'b,'c,T:Trait<'b>>(...)
     ///
     /// Here `'a` and `'c` are late bound but `'b` is early bound. Note that early- and late-bound
-    /// lifetimes may be interspersed together.
+    /// lifetimes may be interspersed together. Lifetimes used by an `impl Trait` in the return
+    /// type of `decl` are early bound as well, since the anonymized type is parameterized by them.
     ///
     /// If early bound lifetimes are present, we separate them into their own list (and likewise
     /// for late bound). They will be numbered sequentially, starting from the lowest index that is
     /// already in scope (for a fn item, that will be 0, but for a method it might not be). Late
     /// bound lifetimes are resolved by name and associated with a binder id (`binder_id`), so the
     /// ordering is not important there.
+    /// Associated types have only early bound lifetime parameters, which
+    /// live in `FnSpace` just like those of methods.
+    fn visit_associated_type<F>(&mut self, generics: &hir::Generics, walk: F) where
+        F: FnOnce(&mut LifetimeContext),
+    {
+        let lifetimes = &generics.lifetimes;
+        self.with(EarlyScope(subst::FnSpace, lifetimes, self.scope), move |old_scope, this| {
+            this.check_lifetime_defs(old_scope, lifetimes);
+            walk(this);
+        });
+    }
+
     fn visit_early_late<F>(&mut self,
                            early_space: subst::ParamSpace,
                            generics: &hir::Generics,
+                           decl: Option<&hir::FnDecl>,
                            walk: F) where
         F: FnOnce(&mut LifetimeContext),
     {
-        let referenced_idents = early_bound_lifetime_names(generics);
+        let referenced_idents = early_bound_lifetime_names(generics, decl);
 
         debug!("visit_early_late: referenced_idents={:?}",
                referenced_idents);
@@ -772,8 +802,10 @@ fn search_lifetimes<'a>(lifetimes: &'a [hir::LifetimeDef],
 
 ///////////////////////////////////////////////////////////////////////////
 
-pub fn early_bound_lifetimes<'a>(generics: &'a hir::Generics) -> Vec<hir::LifetimeDef> {
-    let referenced_idents = early_bound_lifetime_names(generics);
+pub fn early_bound_lifetimes<'a>(generics: &'a hir::Generics,
+                                 decl: Option<&hir::FnDecl>)
+                                 -> Vec<hir::LifetimeDef> {
+    let referenced_idents = early_bound_lifetime_names(generics, decl);
     if referenced_idents.is_empty() {
         return Vec::new();
     }
@@ -786,7 +818,9 @@ pub fn early_bound_lifetimes<'a>(generics: &'a hir::Generics) -> Vec<hir::Lifeti
 
 /// Given a set of generic declarations, returns a list of names containing all early bound
 /// lifetime names for those generics. (In fact, this list may also contain other names.)
-fn early_bound_lifetime_names(generics: &hir::Generics) -> Vec<ast::Name> {
+fn early_bound_lifetime_names(generics: &hir::Generics,
+                              decl: Option<&hir::FnDecl>)
+                              -> Vec<ast::Name> {
     // Create two lists, dividing the lifetimes into early/late bound.
     // Initially, all of them are considered late, but we will move
     // things from late into early as we go if we find references to
@@ -824,6 +858,12 @@ fn early_bound_lifetime_names(generics: &hir::Generics) -> Vec<ast::Name> {
                 &hir::WherePredicate::EqPredicate(_) => bug!("unimplemented")
             }
         }
+
+        // Any lifetime that appears in an `impl Trait` return type is
+        // early, since it parameterizes the anonymized type.
+        if let Some(&hir::FnDecl { output: hir::Return(ref ty), .. }) = decl {
+            ImplTraitLifetimeCollector { collector: &mut collector }.visit_ty(ty);
+        }
     }
 
     // Any lifetime that either has a bound or is referenced by a
@@ -852,6 +892,20 @@ fn early_bound_lifetime_names(generics: &hir::Generics) -> Vec<ast::Name> {
         }
     }
 
+    struct ImplTraitLifetimeCollector<'a, 'b: 'a> {
+        collector: &'a mut FreeLifetimeCollector<'b>,
+    }
+
+    impl<'a, 'b, 'v> Visitor<'v> for ImplTraitLifetimeCollector<'a, 'b> {
+        fn visit_ty(&mut self, ty: &hir::Ty) {
+            if let hir::TyImplTrait(..) = ty.node {
+                intravisit::walk_ty(self.collector, ty);
+            } else {
+                intravisit::walk_ty(self, ty);
+            }
+        }
+    }
+
     fn shuffle(early_bound: &mut Vec<ast::Name>,
                late_bound: &mut Vec<ast::Name>,
                name: ast::Name) {
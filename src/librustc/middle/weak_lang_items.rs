@@ -14,6 +14,8 @@ use session::config;
 use session::Session;
 use middle::lang_items;
 
+use rustc_back::PanicStrategy;
+
 use syntax::ast;
 use syntax::codemap::Span;
 use syntax::parse::token::InternedString;
@@ -84,8 +86,19 @@ fn verify(sess: &Session, items: &lang_items::LanguageItems) {
         }
     }
 
+    // If we're not compiling with unwinding then the unwinding lang items are
+    // never exercised, the panic runtime only provides them so everything
+    // links together.
+    let mut whitelisted = HashSet::new();
+    if sess.panic_strategy() != PanicStrategy::Unwind {
+        whitelisted.insert(lang_items::EhPersonalityLangItem);
+        whitelisted.insert(lang_items::EhUnwindResumeLangItem);
+    }
+
     $(
-        if missing.contains(&lang_items::$item) && items.$name().is_none() {
+        if missing.contains(&lang_items::$item) &&
+           !whitelisted.contains(&lang_items::$item) &&
+           items.$name().is_none() {
             sess.err(&format!("language item required, but not found: `{}`",
                               stringify!($name)));
 
@@ -10,7 +10,6 @@
 
 use graphviz::IntoCow;
 use middle::const_val::ConstVal;
-use rustc_const_math::{ConstUsize, ConstInt};
 use hir::def_id::DefId;
 use ty::subst::Substs;
 use ty::{self, AdtDef, ClosureSubsts, FnOutput, Region, Ty};
@@ -969,12 +968,12 @@ pub struct Constant<'tcx> {
 pub struct TypedConstVal<'tcx> {
     pub ty: Ty<'tcx>,
     pub span: Span,
-    pub value: ConstUsize,
+    pub value: ty::Const,
 }
 
 impl<'tcx> Debug for TypedConstVal<'tcx> {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-        write!(fmt, "const {}", ConstInt::Usize(self.value))
+        write!(fmt, "const {}", self.value)
     }
 }
 
@@ -987,6 +986,11 @@ pub enum Literal<'tcx> {
     Value {
         value: ConstVal,
     },
+    /// The value of a const generic parameter, known once the
+    /// function is monomorphized.
+    ConstParam {
+        param: ty::ParamConst,
+    },
 }
 
 impl<'tcx> Debug for Constant<'tcx> {
@@ -1007,6 +1011,9 @@ impl<'tcx> Debug for Literal<'tcx> {
                 write!(fmt, "const ")?;
                 fmt_const_val(fmt, value)
             }
+            ConstParam { param } => {
+                write!(fmt, "const {}", param)
+            }
         }
     }
 }
@@ -168,9 +168,7 @@ impl<'tcx> Mir<'tcx> {
             Rvalue::Use(ref operand) => Some(self.operand_ty(tcx, operand)),
             Rvalue::Repeat(ref operand, ref count) => {
                 let op_ty = self.operand_ty(tcx, operand);
-                let count = count.value.as_u64(tcx.sess.target.uint_type);
-                assert_eq!(count as usize as u64, count);
-                Some(tcx.mk_array(op_ty, count as usize))
+                Some(tcx.mk_array_const(op_ty, count.value))
             }
             Rvalue::Ref(reg, bk, ref lv) => {
                 let lv_ty = self.lvalue_ty(tcx, lv).to_ty(tcx);
@@ -11,9 +11,8 @@
 use middle::const_val::ConstVal;
 use hir::def_id::DefId;
 use ty::subst::Substs;
-use ty::{ClosureSubsts, FnOutput, Region, Ty};
+use ty::{self, ClosureSubsts, FnOutput, Region, Ty};
 use mir::repr::*;
-use rustc_const_math::ConstUsize;
 use rustc_data_structures::tuple_slice::TupleSlice;
 use syntax::codemap::Span;
 
@@ -206,9 +205,9 @@ macro_rules! make_mir_visitor {
                 self.super_const_val(const_val);
             }
 
-            fn visit_const_usize(&mut self,
-                                 const_usize: & $($mutability)* ConstUsize) {
-                self.super_const_usize(const_usize);
+            fn visit_const(&mut self,
+                           constant: & $($mutability)* ty::Const) {
+                self.super_const(constant);
             }
 
             fn visit_typed_const_val(&mut self,
@@ -639,7 +638,7 @@ macro_rules! make_mir_visitor {
 
                 self.visit_span(span);
                 self.visit_ty(ty);
-                self.visit_const_usize(value);
+                self.visit_const(value);
             }
 
             fn super_literal(&mut self,
@@ -653,6 +652,8 @@ macro_rules! make_mir_visitor {
                     Literal::Value { ref $($mutability)* value } => {
                         self.visit_const_val(value);
                     }
+                    Literal::ConstParam { .. } => {
+                    }
                 }
             }
 
@@ -685,7 +686,7 @@ macro_rules! make_mir_visitor {
             fn super_const_val(&mut self, _substs: & $($mutability)* ConstVal) {
             }
 
-            fn super_const_usize(&mut self, _substs: & $($mutability)* ConstUsize) {
+            fn super_const(&mut self, _const: & $($mutability)* ty::Const) {
             }
         }
     }
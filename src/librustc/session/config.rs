@@ -19,6 +19,7 @@ pub use self::DebugInfoLevel::*;
 use session::{early_error, early_warn, Session};
 use session::search_paths::SearchPaths;
 
+use rustc_back::{LinkerFlavor, PanicStrategy};
 use rustc_back::target::Target;
 use lint;
 use middle::cstore;
@@ -35,7 +36,7 @@ use getopts;
 use std::collections::HashMap;
 use std::env;
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct Config {
     pub target: Target,
@@ -60,6 +61,26 @@ pub enum DebugInfoLevel {
     FullDebugInfo,
 }
 
+/// Where the DWARF describing each object file ends up.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SplitDebuginfo {
+    /// All of it stays in the object files and is linked into the output.
+    Off,
+    /// Split into a `.dwo` file per object which is combined into a single
+    /// `.dwp` package next to the output after linking.
+    Packed,
+    /// Split into a `.dwo` file per object which is left next to the output.
+    Unpacked,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Sanitizer {
+    Address,
+    Leak,
+    Memory,
+    Thread,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum OutputType {
     Bitcode,
@@ -402,11 +423,23 @@ macro_rules! options {
             Some("a space-separated list of passes, or `all`");
         pub const parse_opt_uint: Option<&'static str> =
             Some("a number");
+        pub const parse_panic_strategy: Option<&'static str> =
+            Some("either `unwind` or `abort`");
+        pub const parse_sanitizer: Option<&'static str> =
+            Some("one of: `address`, `leak`, `memory` or `thread`");
+        pub const parse_linker_flavor: Option<&'static str> =
+            Some("one of: `gcc`, `gold`, `ld.lld`, `msvc`, `lld-link` or `wasm-ld`");
+        pub const parse_debuginfo: Option<&'static str> =
+            Some("one of: `0`, `1`, `2`, `none`, `line-tables-only` or `full`");
+        pub const parse_split_debuginfo: Option<&'static str> =
+            Some("one of: `off`, `packed` or `unpacked`");
     }
 
     #[allow(dead_code)]
     mod $mod_set {
-        use super::{$struct_name, Passes, SomePasses, AllPasses};
+        use super::{$struct_name, Passes, SomePasses, AllPasses, Sanitizer};
+        use super::{DebugInfoLevel, SplitDebuginfo};
+        use rustc_back::{LinkerFlavor, PanicStrategy};
 
         $(
             pub fn $opt(cg: &mut $struct_name, v: Option<&str>) -> bool {
@@ -510,6 +543,56 @@ macro_rules! options {
                 }
             }
         }
+
+        fn parse_panic_strategy(slot: &mut Option<PanicStrategy>, v: Option<&str>) -> bool {
+            match v {
+                Some("unwind") => *slot = Some(PanicStrategy::Unwind),
+                Some("abort") => *slot = Some(PanicStrategy::Abort),
+                _ => return false
+            }
+            true
+        }
+
+        fn parse_linker_flavor(slot: &mut Option<LinkerFlavor>, v: Option<&str>) -> bool {
+            match v.and_then(LinkerFlavor::from_str) {
+                Some(flavor) => *slot = Some(flavor),
+                None => return false,
+            }
+            true
+        }
+
+        fn parse_debuginfo(slot: &mut Option<DebugInfoLevel>, v: Option<&str>) -> bool {
+            match v {
+                Some("0") | Some("none") => *slot = Some(DebugInfoLevel::NoDebugInfo),
+                Some("1") | Some("line-tables-only") => {
+                    *slot = Some(DebugInfoLevel::LimitedDebugInfo)
+                }
+                Some("2") | Some("full") => *slot = Some(DebugInfoLevel::FullDebugInfo),
+                _ => return false,
+            }
+            true
+        }
+
+        fn parse_split_debuginfo(slot: &mut SplitDebuginfo, v: Option<&str>) -> bool {
+            match v {
+                Some("off") => *slot = SplitDebuginfo::Off,
+                Some("packed") => *slot = SplitDebuginfo::Packed,
+                Some("unpacked") => *slot = SplitDebuginfo::Unpacked,
+                _ => return false,
+            }
+            true
+        }
+
+        fn parse_sanitizer(slot: &mut Option<Sanitizer>, v: Option<&str>) -> bool {
+            match v {
+                Some("address") => *slot = Some(Sanitizer::Address),
+                Some("leak") => *slot = Some(Sanitizer::Leak),
+                Some("memory") => *slot = Some(Sanitizer::Memory),
+                Some("thread") => *slot = Some(Sanitizer::Thread),
+                _ => return false,
+            }
+            true
+        }
     }
 ) }
 
@@ -566,15 +649,24 @@ options! {CodegenOptions, CodegenSetter, basic_codegen_options,
         "print remarks for these optimization passes (space separated, or \"all\")"),
     no_stack_check: bool = (false, parse_bool,
         "disable checks for stack exhaustion (a memory-safety hazard!)"),
-    debuginfo: Option<usize> = (None, parse_opt_uint,
-        "debug info emission level, 0 = no debug info, 1 = line tables only, \
-         2 = full debug info with variable and type information"),
+    debuginfo: Option<DebugInfoLevel> = (None, parse_debuginfo,
+        "debug info emission level, 0 or `none` = no debug info, 1 or `line-tables-only` = \
+         line tables only, 2 or `full` = full debug info with variable and type information"),
+    split_debuginfo: SplitDebuginfo = (SplitDebuginfo::Off, parse_split_debuginfo,
+        "split debug info out of object files into .dwo files (`unpacked`) or a single \
+         .dwp package next to the output (`packed`)"),
     opt_level: Option<String> = (None, parse_opt_string,
         "optimize with possible levels 0-3, s, or z"),
     debug_assertions: Option<bool> = (None, parse_opt_bool,
         "explicitly enable the cfg(debug_assertions) directive"),
     inline_threshold: Option<usize> = (None, parse_opt_uint,
         "set the inlining threshold for"),
+    panic: Option<PanicStrategy> = (None, parse_panic_strategy,
+        "panic strategy to compile crate with"),
+    profile_generate: Option<String> = (None, parse_opt_string,
+        "instrument the crate to write execution profiles into the given directory"),
+    profile_use: Option<String> = (None, parse_opt_string,
+        "optimize the crate with the profile in the given `.profdata` file"),
 }
 
 
@@ -644,6 +736,15 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
           "treat all errors that occur as bugs"),
     continue_parse_after_error: bool = (false, parse_bool,
           "attempt to recover from parse errors (experimental)"),
+    parse_recovery: bool = (false, parse_bool,
+          "parse in error-recovery mode, synthesising placeholders for malformed code \
+           (experimental)"),
+    pretty_style: Option<String> = (None, parse_opt_string,
+          "layout of --pretty output, as a comma-separated list of `width=N`, `indent=N`, \
+           `brace-style=same-line|next-line` and `trailing-comments`"),
+    pretty_check: bool = (false, parse_bool,
+          "with --pretty normal, check that the source is laid out as it would be printed \
+           instead of printing it"),
     incremental: Option<String> = (None, parse_opt_string,
           "enable incremental compilation (experimental)"),
     dump_dep_graph: bool = (false, parse_bool,
@@ -680,6 +781,19 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
           "dump MIR state at various points in translation"),
     orbit: bool = (false, parse_bool,
           "get MIR where it belongs - everywhere; most importantly, in orbit"),
+    sanitizer: Option<Sanitizer> = (None, parse_sanitizer,
+          "instrument the crate with an LLVM sanitizer"),
+    instrument_coverage: bool = (false, parse_bool,
+          "instrument the generated code for source-based coverage (implies -Z orbit)"),
+    print_code_size: bool = (false, parse_bool,
+          "print the size of the code in linked outputs per crate, module and function"),
+    linker_flavor: Option<LinkerFlavor> = (None, parse_linker_flavor,
+          "the kind of linker to invoke (gcc, gold, ld.lld, msvc, lld-link or wasm-ld)"),
+    compress_debug_sections: bool = (false, parse_bool,
+          "compress the debug info sections of object files and linked outputs with zlib"),
+    cross_lang_lto: bool = (false, parse_bool,
+          "emit LLVM bitcode in object files, for a linker that performs LTO across languages \
+           (only for `--emit=obj` and static libraries)"),
 }
 
 pub fn default_lib_output() -> CrateType {
@@ -1115,6 +1229,17 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         early_error(error_format, "Value for codegen units must be a positive nonzero integer");
     }
 
+    if cg.profile_generate.is_some() && cg.profile_use.is_some() {
+        early_error(error_format, "options `-C profile-generate` and `-C profile-use` \
+                                   are exclusive");
+    }
+    if let Some(ref path) = cg.profile_use {
+        if !Path::new(path).exists() {
+            early_error(error_format, &format!("file `{}` passed to `-C profile-use` \
+                                                does not exist", path));
+        }
+    }
+
     let cg = cg;
 
     let sysroot_opt = matches.opt_str("sysroot").map(|m| PathBuf::from(&m));
@@ -1156,16 +1281,7 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         }
         FullDebugInfo
     } else {
-        match cg.debuginfo {
-            None | Some(0) => NoDebugInfo,
-            Some(1) => LimitedDebugInfo,
-            Some(2) => FullDebugInfo,
-            Some(arg) => {
-                early_error(error_format, &format!("debug info level needs to be between \
-                                                  0-2 (instead was `{}`)",
-                                                 arg));
-            }
-        }
+        cg.debuginfo.unwrap_or(NoDebugInfo)
     };
 
     let mut search_paths = SearchPaths::new();
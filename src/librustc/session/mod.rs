@@ -29,6 +29,7 @@ use syntax::parse::token;
 use syntax::{ast, codemap};
 use syntax::feature_gate::AttributeType;
 
+use rustc_back::{LinkerFlavor, PanicStrategy};
 use rustc_back::target::Target;
 use llvm;
 
@@ -86,6 +87,10 @@ pub struct Session {
     /// didn't already find one, and this tracks what was injected.
     pub injected_allocator: Cell<Option<ast::CrateNum>>,
 
+    /// The metadata::creader module may also inject a panic runtime, this
+    /// tracks which crate was chosen to implement `panic!`.
+    pub injected_panic_runtime: Cell<Option<ast::CrateNum>>,
+
     /// Names of all bang-style macros and syntax extensions
     /// available in this crate
     pub available_macros: RefCell<HashSet<Name>>,
@@ -295,7 +300,29 @@ impl Session {
         self.opts.cg.lto
     }
     pub fn no_landing_pads(&self) -> bool {
-        self.opts.debugging_opts.no_landing_pads
+        self.opts.debugging_opts.no_landing_pads ||
+            self.panic_strategy() == PanicStrategy::Abort
+    }
+    /// The panic strategy for this compilation, falling back to the target's
+    /// default if `-C panic` wasn't passed.
+    pub fn panic_strategy(&self) -> PanicStrategy {
+        self.opts.cg.panic.unwrap_or(self.target.target.options.panic_strategy)
+    }
+    /// The kind of linker to drive, falling back to the target's default if
+    /// `-Z linker-flavor` wasn't passed.
+    pub fn linker_flavor(&self) -> LinkerFlavor {
+        self.opts.debugging_opts.linker_flavor
+            .unwrap_or(self.target.target.options.linker_flavor)
+    }
+    /// Where the debug info of this crate's objects goes. LLVM only knows how
+    /// to split DWARF in ELF objects, so everything else keeps it inline.
+    pub fn split_debuginfo(&self) -> config::SplitDebuginfo {
+        let options = &self.target.target.options;
+        if self.opts.debuginfo == config::NoDebugInfo || options.is_like_osx ||
+           options.is_like_windows || options.is_like_wasm {
+            return config::SplitDebuginfo::Off
+        }
+        self.opts.cg.split_debuginfo
     }
     pub fn unstable_options(&self) -> bool {
         self.opts.debugging_opts.unstable_options
@@ -502,6 +529,7 @@ pub fn build_session_(sopts: config::Options,
         recursion_limit: Cell::new(64),
         next_node_id: Cell::new(1),
         injected_allocator: Cell::new(None),
+        injected_panic_runtime: Cell::new(None),
         available_macros: RefCell::new(HashSet::new()),
         imported_macro_spans: RefCell::new(HashMap::new()),
     };
@@ -546,6 +574,9 @@ unsafe fn configure_llvm(sess: &Session) {
         add("rustc"); // fake program name
         if sess.time_llvm_passes() { add("-time-passes"); }
         if sess.print_llvm_passes() { add("-debug-pass=Structure"); }
+        if sess.split_debuginfo() != config::SplitDebuginfo::Off {
+            add("-split-dwarf=Enable");
+        }
 
         for arg in &sess.opts.cg.llvm_args {
             add(&(*arg));
@@ -256,15 +256,18 @@ fn ty_is_local_constructor<'tcx>(tcx: &TyCtxt<'tcx>,
         ty::TyUint(..) |
         ty::TyFloat(..) |
         ty::TyStr |
+        ty::TyNever |
         ty::TyFnDef(..) |
         ty::TyFnPtr(_) |
         ty::TyArray(..) |
+        ty::TyArrayParam(..) |
         ty::TySlice(..) |
         ty::TyRawPtr(..) |
         ty::TyRef(..) |
         ty::TyTuple(..) |
         ty::TyParam(..) |
-        ty::TyProjection(..) => {
+        ty::TyProjection(..) |
+        ty::TyAnon(..) => {
             false
         }
 
@@ -589,6 +589,11 @@ pub fn report_object_safety_error<'tcx>(tcx: &TyCtxt<'tcx>,
                 err.note(&format!("method `{}` has generic type parameters",
                                   method.name));
             }
+
+            ObjectSafetyViolation::GenericAssociatedType(name) => {
+                err.note(&format!("associated type `{}` has generic parameters",
+                                  name));
+            }
         }
     }
     err
@@ -13,6 +13,7 @@ use infer::{InferCtxt, InferOk};
 use ty::{self, Ty, TyCtxt, TypeFoldable, ToPolyTraitRef};
 use rustc_data_structures::obligation_forest::{Backtrace, ObligationForest, Error};
 use std::iter;
+use std::mem;
 use syntax::ast;
 use util::common::ErrorReported;
 use util::nodemap::{FnvHashMap, FnvHashSet, NodeMap};
@@ -23,6 +24,7 @@ use super::CodeSelectionError;
 use super::is_object_safe;
 use super::FulfillmentError;
 use super::FulfillmentErrorCode;
+use super::Obligation;
 use super::ObligationCause;
 use super::PredicateObligation;
 use super::project;
@@ -95,6 +97,12 @@ pub struct FulfillmentContext<'tcx> {
     // obligations (otherwise, it's easy to fail to walk to a
     // particular node-id).
     region_obligations: NodeMap<Vec<RegionObligation<'tcx>>>,
+
+    // A list of auto trait obligations on anonymized types whose
+    // concrete type is not known yet, because the function defining
+    // them has not been type-checked. These are handed back to the
+    // caller and checked once all function bodies have been.
+    deferred_obligations: Vec<DeferredObligation<'tcx>>,
 }
 
 #[derive(Clone)]
@@ -110,6 +118,45 @@ pub struct PendingPredicateObligation<'tcx> {
     pub stalled_on: Vec<Ty<'tcx>>,
 }
 
+/// An auto trait obligation like `impl Trait: Send`, which can only be
+/// decided once the concrete type behind `impl Trait` is known.
+#[derive(Clone, Debug)]
+pub struct DeferredObligation<'tcx> {
+    pub predicate: ty::PolyTraitPredicate<'tcx>,
+    pub cause: ObligationCause<'tcx>,
+}
+
+impl<'tcx> DeferredObligation<'tcx> {
+    /// Whether `predicate` has to wait for the concrete type of a
+    /// local anonymized type that has not been inferred yet.
+    pub fn must_defer(tcx: &TyCtxt<'tcx>, predicate: &ty::PolyTraitPredicate<'tcx>) -> bool {
+        match predicate.skip_binder().self_ty().sty {
+            ty::TyAnon(def_id, _) => {
+                tcx.trait_has_default_impl(predicate.def_id()) &&
+                    def_id.is_local() &&
+                    !tcx.tcache.borrow().contains_key(&def_id)
+            }
+            _ => false
+        }
+    }
+
+    /// Detaches `predicate` from the inference context it came from;
+    /// auto traits do not care about regions, so they are erased.
+    fn new(tcx: &TyCtxt<'tcx>,
+           predicate: &ty::PolyTraitPredicate<'tcx>,
+           cause: &ObligationCause<'tcx>)
+           -> DeferredObligation<'tcx> {
+        DeferredObligation {
+            predicate: tcx.erase_regions(predicate),
+            cause: cause.clone()
+        }
+    }
+
+    pub fn to_obligation(&self) -> PredicateObligation<'tcx> {
+        Obligation::new(self.cause.clone(), ty::Predicate::Trait(self.predicate.clone()))
+    }
+}
+
 impl<'tcx> FulfillmentContext<'tcx> {
     /// Creates a new fulfillment context.
     pub fn new() -> FulfillmentContext<'tcx> {
@@ -117,6 +164,7 @@ impl<'tcx> FulfillmentContext<'tcx> {
             duplicate_set: LocalFulfilledPredicates::new(),
             predicates: ObligationForest::new(),
             region_obligations: NodeMap(),
+            deferred_obligations: vec![],
         }
     }
 
@@ -236,6 +284,10 @@ impl<'tcx> FulfillmentContext<'tcx> {
         self.predicates.pending_obligations()
     }
 
+    pub fn take_deferred_obligations(&mut self) -> Vec<DeferredObligation<'tcx>> {
+        mem::replace(&mut self.deferred_obligations, vec![])
+    }
+
     fn is_duplicate_or_add(&mut self,
                            tcx: &TyCtxt<'tcx>,
                            predicate: &ty::Predicate<'tcx>)
@@ -279,12 +331,14 @@ impl<'tcx> FulfillmentContext<'tcx> {
             // Process pending obligations.
             let outcome = {
                 let region_obligations = &mut self.region_obligations;
+                let deferred_obligations = &mut self.deferred_obligations;
                 self.predicates.process_obligations(
                     |obligation, tree, backtrace| process_predicate(selcx,
                                                                      tree,
                                                                      obligation,
                                                                      backtrace,
-                                                                     region_obligations))
+                                                                     region_obligations,
+                                                                     deferred_obligations))
             };
 
             debug!("select: outcome={:?}", outcome);
@@ -292,6 +346,12 @@ impl<'tcx> FulfillmentContext<'tcx> {
             // these are obligations that were proven to be true.
             for pending_obligation in outcome.completed {
                 let predicate = &pending_obligation.obligation.predicate;
+                // deferred obligations have not actually been proven yet
+                if let ty::Predicate::Trait(ref data) = *predicate {
+                    if DeferredObligation::must_defer(selcx.tcx(), data) {
+                        continue;
+                    }
+                }
                 selcx.tcx().fulfilled_predicates.borrow_mut().add_if_global(predicate);
             }
 
@@ -321,11 +381,13 @@ fn process_predicate<'a,'tcx>(selcx: &mut SelectionContext<'a,'tcx>,
                               tree_cache: &mut LocalFulfilledPredicates<'tcx>,
                               pending_obligation: &mut PendingPredicateObligation<'tcx>,
                               backtrace: Backtrace<PendingPredicateObligation<'tcx>>,
-                              region_obligations: &mut NodeMap<Vec<RegionObligation<'tcx>>>)
+                              region_obligations: &mut NodeMap<Vec<RegionObligation<'tcx>>>,
+                              deferred_obligations: &mut Vec<DeferredObligation<'tcx>>)
                               -> Result<Option<Vec<PendingPredicateObligation<'tcx>>>,
                                         FulfillmentErrorCode<'tcx>>
 {
-    match process_predicate1(selcx, pending_obligation, region_obligations) {
+    match process_predicate1(selcx, pending_obligation, region_obligations,
+                             deferred_obligations) {
         Ok(Some(v)) => process_child_obligations(selcx,
                                                  tree_cache,
                                                  &pending_obligation.obligation,
@@ -507,7 +569,8 @@ fn trait_ref_type_vars<'a, 'tcx>(selcx: &mut SelectionContext<'a, 'tcx>,
 /// - `Err` if the predicate does not hold
 fn process_predicate1<'a,'tcx>(selcx: &mut SelectionContext<'a,'tcx>,
                                pending_obligation: &mut PendingPredicateObligation<'tcx>,
-                               region_obligations: &mut NodeMap<Vec<RegionObligation<'tcx>>>)
+                               region_obligations: &mut NodeMap<Vec<RegionObligation<'tcx>>>,
+                               deferred_obligations: &mut Vec<DeferredObligation<'tcx>>)
                                -> Result<Option<Vec<PredicateObligation<'tcx>>>,
                                          FulfillmentErrorCode<'tcx>>
 {
@@ -539,6 +602,12 @@ fn process_predicate1<'a,'tcx>(selcx: &mut SelectionContext<'a,'tcx>,
                 return Ok(Some(vec![]));
             }
 
+            if !data.has_infer_types() && DeferredObligation::must_defer(selcx.tcx(), data) {
+                deferred_obligations.push(DeferredObligation::new(selcx.tcx(), data,
+                                                                  &obligation.cause));
+                return Ok(Some(vec![]));
+            }
+
             let trait_obligation = obligation.with(data.clone());
             match selcx.select(&trait_obligation) {
                 Ok(Some(vtable)) => {
@@ -35,7 +35,8 @@ pub use self::error_reporting::report_object_safety_error;
 pub use self::coherence::orphan_check;
 pub use self::coherence::overlapping_impls;
 pub use self::coherence::OrphanCheckErr;
-pub use self::fulfill::{FulfillmentContext, GlobalFulfilledPredicates, RegionObligation};
+pub use self::fulfill::{DeferredObligation, FulfillmentContext, GlobalFulfilledPredicates};
+pub use self::fulfill::RegionObligation;
 pub use self::project::{MismatchedProjectionTypes, ProjectionMode};
 pub use self::project::{normalize, Normalized};
 pub use self::object_safety::is_object_safe;
@@ -49,6 +50,7 @@ pub use self::select::{MethodMatchResult, MethodMatched, MethodAmbiguous, Method
 pub use self::select::{MethodMatchedData}; // intentionally don't export variants
 pub use self::specialize::{Overlap, specialization_graph, specializes, translate_substs};
 pub use self::util::elaborate_predicates;
+pub use self::util::elaborate_trait_ref;
 pub use self::util::get_vtable_index_of_object_method;
 pub use self::util::trait_ref_for_builtin_bound;
 pub use self::util::predicate_for_trait_def;
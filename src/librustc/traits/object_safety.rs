@@ -38,6 +38,10 @@ pub enum ObjectSafetyViolation<'tcx> {
 
     /// Method has something illegal
     Method(Rc<ty::Method<'tcx>>, MethodViolationCode),
+
+    /// Associated type has its own generic parameters
+    /// (e.g. `type Item<'a>;`)
+    GenericAssociatedType(ast::Name),
 }
 
 /// Reasons a method might not be object-safe.
@@ -119,6 +123,15 @@ fn object_safety_violations_for_trait<'tcx>(tcx: &TyCtxt<'tcx>,
                     object_safety_violation_for_method(tcx, trait_def_id, &m)
                         .map(|code| ObjectSafetyViolation::Method(m.clone(), code))
                 }
+                ty::TypeTraitItem(ref assoc_ty) => {
+                    let generics = &assoc_ty.generics;
+                    if generics.types.is_empty_in(subst::FnSpace) &&
+                       generics.regions.is_empty_in(subst::FnSpace) {
+                        None
+                    } else {
+                        Some(ObjectSafetyViolation::GenericAssociatedType(assoc_ty.name))
+                    }
+                }
                 _ => None,
             }
         })
@@ -281,7 +294,8 @@ fn virtual_call_violation_for_method<'tcx>(tcx: &TyCtxt<'tcx>,
     }
 
     // We can't monomorphize things like `fn foo<A>(...)`.
-    if !method.generics.types.is_empty_in(subst::FnSpace) {
+    if !method.generics.types.is_empty_in(subst::FnSpace) ||
+       !method.generics.consts.is_empty_in(subst::FnSpace) {
         return Some(MethodViolationCode::Generic);
     }
 
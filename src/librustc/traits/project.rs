@@ -25,7 +25,7 @@ use super::util;
 
 use hir::def_id::DefId;
 use infer::{self, InferOk, TypeOrigin};
-use ty::subst::Subst;
+use ty::subst::{Subst, Substs};
 use ty::{self, ToPredicate, ToPolyTraitRef, Ty, TyCtxt};
 use ty::fold::{TypeFoldable, TypeFolder};
 use syntax::parse::token;
@@ -397,6 +397,15 @@ impl<'a,'b,'tcx> TypeFolder<'tcx> for AssociatedTypeNormalizer<'a,'b,'tcx> {
                 ty
             }
 
+            ty::TyAnon(def_id, substs) if self.selcx.projection_mode().is_any() => {
+                // Only trans gets to look through an anonymized type;
+                // everywhere else it stays opaque and is used through
+                // its bounds alone.
+                let concrete_ty = self.tcx().lookup_item_type(def_id).ty;
+                let concrete_ty = concrete_ty.subst(self.tcx(), substs);
+                self.fold_ty(concrete_ty)
+            }
+
             _ => {
                 ty
             }
@@ -742,7 +751,8 @@ fn project_type<'cx,'tcx>(
         None => {
             Ok(ProjectedTy::NoProgress(selcx.tcx().mk_projection(
                 obligation.predicate.trait_ref.clone(),
-                obligation.predicate.item_name)))
+                obligation.predicate.item_name,
+                obligation.predicate.item_substs)))
         }
     }
 }
@@ -784,9 +794,11 @@ fn assemble_candidates_from_trait_def<'cx,'tcx>(
 {
     debug!("assemble_candidates_from_trait_def(..)");
 
-    // Check whether the self-type is itself a projection.
-    let trait_ref = match obligation_trait_ref.self_ty().sty {
-        ty::TyProjection(ref data) => data.trait_ref.clone(),
+    // Check whether the self-type is itself a projection or an
+    // anonymized type, both of which carry their own bounds.
+    let (def_id, substs) = match obligation_trait_ref.self_ty().sty {
+        ty::TyProjection(ref data) => selcx.tcx().projection_bounds_source(data),
+        ty::TyAnon(def_id, substs) => (def_id, substs),
         ty::TyInfer(ty::TyVar(_)) => {
             // If the self-type is an inference variable, then it MAY wind up
             // being a projected type, so induce an ambiguity.
@@ -797,8 +809,8 @@ fn assemble_candidates_from_trait_def<'cx,'tcx>(
     };
 
     // If so, extract what we know from the trait and try to come up with a good answer.
-    let trait_predicates = selcx.tcx().lookup_predicates(trait_ref.def_id);
-    let bounds = trait_predicates.instantiate(selcx.tcx(), trait_ref.substs);
+    let predicates = selcx.tcx().lookup_predicates(def_id);
+    let bounds = predicates.instantiate(selcx.tcx(), substs);
     let bounds = elaborate_predicates(selcx.tcx(), bounds.predicates.into_vec());
     assemble_candidates_from_predicates(selcx,
                                         obligation,
@@ -829,14 +841,18 @@ fn assemble_candidates_from_predicates<'cx,'tcx,I>(
 
                 let is_match = same_name && infcx.probe(|_| {
                     let origin = TypeOrigin::Misc(obligation.cause.span);
-                    let data_poly_trait_ref =
-                        data.to_poly_trait_ref();
-                    let obligation_poly_trait_ref =
-                        obligation_trait_ref.to_poly_trait_ref();
-                    infcx.sub_poly_trait_refs(false,
-                                              origin,
-                                              data_poly_trait_ref,
-                                              obligation_poly_trait_ref)
+                    let data_poly_projection_ty =
+                        ty::Binder(data.0.projection_ty.clone());
+                    let obligation_poly_projection_ty =
+                        ty::Binder(ty::ProjectionTy {
+                            trait_ref: obligation_trait_ref.clone(),
+                            item_name: obligation.predicate.item_name,
+                            item_substs: obligation.predicate.item_substs,
+                        });
+                    infcx.sub_poly_projection_tys(false,
+                                                  origin,
+                                                  data_poly_projection_ty,
+                                                  obligation_poly_projection_ty)
                         // FIXME(#32730) propagate obligations
                         .map(|InferOk { obligations, .. }| assert!(obligations.is_empty()))
                         .is_ok()
@@ -1063,6 +1079,7 @@ fn confirm_callable_candidate<'cx,'tcx>(
         projection_ty: ty::ProjectionTy {
             trait_ref: trait_ref,
             item_name: token::intern(FN_OUTPUT_NAME),
+            item_substs: tcx.mk_substs(Substs::empty()),
         },
         ty: ret_type
     });
@@ -1106,6 +1123,32 @@ fn confirm_param_env_candidate<'cx,'tcx>(
         }
     }
 
+    // Generic associated types must also agree on their own arguments.
+    if !obligation.predicate.item_substs.is_noop() {
+        let tcx = infcx.tcx;
+        let obligation_ty = tcx.mk_projection(obligation.predicate.trait_ref.clone(),
+                                              obligation.predicate.item_name,
+                                              obligation.predicate.item_substs);
+        let projection_ty = tcx.mk_projection(projection.projection_ty.trait_ref.clone(),
+                                              projection.projection_ty.item_name,
+                                              projection.projection_ty.item_substs);
+        let origin = TypeOrigin::RelateOutputImplTypes(obligation.cause.span);
+        match infcx.eq_types(false, origin, obligation_ty, projection_ty) {
+            Ok(InferOk { obligations, .. }) => {
+                // FIXME(#32730) propagate obligations
+                assert!(obligations.is_empty());
+            }
+            Err(e) => {
+                span_bug!(
+                    obligation.cause.span,
+                    "Failed to unify `{:?}` and `{:?}` in projection: {}",
+                    obligation,
+                    projection,
+                    e);
+            }
+        }
+    }
+
     (projection.ty, vec!())
 }
 
@@ -1134,7 +1177,10 @@ fn confirm_impl_candidate<'cx,'tcx>(
                 tcx.types.err
             });
             let substs = translate_substs(selcx.infcx(), impl_def_id, substs, node_item.node);
-            (ty.subst(tcx, substs), nested)
+            // A generic associated type is instantiated with its own
+            // arguments, which the impl leaves in `FnSpace`.
+            let substs = substs.clone().with_method_from_subst(obligation.predicate.item_substs);
+            (ty.subst(tcx, &substs), nested)
         }
         None => {
             span_bug!(obligation.cause.span,
@@ -212,6 +212,10 @@ enum SelectionCandidate<'tcx> {
     BuiltinObjectCandidate,
 
     BuiltinUnsizeCandidate,
+
+    /// The trait is a trait alias: the obligation holds if the bounds
+    /// the alias stands for hold.
+    TraitAliasCandidate(DefId),
 }
 
 struct SelectionCandidateSet<'tcx> {
@@ -1017,6 +1021,7 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                 self.assemble_fn_pointer_candidates(obligation, &mut candidates)?;
                 self.assemble_candidates_from_impls(obligation, &mut candidates)?;
                 self.assemble_candidates_from_object_ty(obligation, &mut candidates);
+                self.assemble_candidates_for_trait_alias(obligation, &mut candidates);
             }
         }
 
@@ -1041,8 +1046,9 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
 
         // before we go into the whole skolemization thing, just
         // quickly check if the self-type is a projection at all.
-        let trait_def_id = match obligation.predicate.0.trait_ref.self_ty().sty {
+        let def_id = match obligation.predicate.0.trait_ref.self_ty().sty {
             ty::TyProjection(ref data) => data.trait_ref.def_id,
+            ty::TyAnon(def_id, _) => def_id,
             ty::TyInfer(ty::TyVar(_)) => {
                 span_bug!(obligation.cause.span,
                     "Self=_ should have been handled by assemble_candidates");
@@ -1050,8 +1056,8 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             _ => { return; }
         };
 
-        debug!("assemble_candidates_for_projected_tys: trait_def_id={:?}",
-               trait_def_id);
+        debug!("assemble_candidates_for_projected_tys: def_id={:?}",
+               def_id);
 
         let result = self.infcx.probe(|snapshot| {
             self.match_projection_obligation_against_bounds_from_trait(obligation,
@@ -1078,8 +1084,9 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                skol_trait_predicate,
                skol_map);
 
-        let projection_trait_ref = match skol_trait_predicate.trait_ref.self_ty().sty {
-            ty::TyProjection(ref data) => &data.trait_ref,
+        let (def_id, substs) = match skol_trait_predicate.trait_ref.self_ty().sty {
+            ty::TyProjection(ref data) => self.tcx().projection_bounds_source(data),
+            ty::TyAnon(def_id, substs) => (def_id, substs),
             _ => {
                 span_bug!(
                     obligation.cause.span,
@@ -1089,11 +1096,11 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             }
         };
         debug!("match_projection_obligation_against_bounds_from_trait: \
-                projection_trait_ref={:?}",
-               projection_trait_ref);
+                def_id={:?}, substs={:?}",
+               def_id, substs);
 
-        let trait_predicates = self.tcx().lookup_predicates(projection_trait_ref.def_id);
-        let bounds = trait_predicates.instantiate(self.tcx(), projection_trait_ref.substs);
+        let predicates = self.tcx().lookup_predicates(def_id);
+        let bounds = predicates.instantiate(self.tcx(), substs);
         debug!("match_projection_obligation_against_bounds_from_trait: \
                 bounds={:?}",
                bounds);
@@ -1307,6 +1314,12 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             self.tcx(),
             obligation.predicate.0.trait_ref.self_ty(),
             |impl_def_id| {
+                // A `default impl` only supplies items to the impls that
+                // specialize it; it never implements the trait on its own.
+                if self.tcx().impl_is_default(impl_def_id) {
+                    return;
+                }
+
                 self.infcx.probe(|snapshot| {
                     if let Ok(_) = self.match_impl(impl_def_id, obligation, snapshot) {
                         candidates.vec.push(ImplCandidate(impl_def_id));
@@ -1318,6 +1331,21 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
         Ok(())
     }
 
+    fn assemble_candidates_for_trait_alias(&mut self,
+                                           obligation: &TraitObligation<'tcx>,
+                                           candidates: &mut SelectionCandidateSet<'tcx>)
+    {
+        let def_id = obligation.predicate.def_id();
+
+        debug!("assemble_candidates_for_trait_alias(self_ty={:?}, def_id={:?})",
+               obligation.self_ty().skip_binder(),
+               def_id);
+
+        if self.tcx().lookup_trait_def(def_id).is_alias {
+            candidates.vec.push(TraitAliasCandidate(def_id));
+        }
+    }
+
     fn assemble_candidates_from_default_impls(&mut self,
                                               obligation: &TraitObligation<'tcx>,
                                               candidates: &mut SelectionCandidateSet<'tcx>)
@@ -1365,6 +1393,12 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                     // the defaulted impl might apply, we don't know
                     candidates.ambiguous = true;
                 }
+                ty::TyAnon(anon_def_id, _) if anon_def_id.is_local() &&
+                    !self.tcx().tcache.borrow().contains_key(&anon_def_id) => {
+                    // Auto traits look through anonymized types, but
+                    // the concrete type has not been inferred yet.
+                    candidates.ambiguous = true;
+                }
                 _ => {
                     candidates.vec.push(DefaultImplCandidate(def_id.clone()))
                 }
@@ -1514,7 +1548,8 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             }
 
             // [T; n] -> [T].
-            (&ty::TyArray(_, _), &ty::TySlice(_)) => true,
+            (&ty::TyArray(_, _), &ty::TySlice(_)) |
+            (&ty::TyArrayParam(_, _), &ty::TySlice(_)) => true,
 
             // Struct<T> -> Struct<U>.
             (&ty::TyStruct(def_id_a, _), &ty::TyStruct(def_id_b, _)) => {
@@ -1569,6 +1604,7 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                 FnPointerCandidate |
                 BuiltinObjectCandidate |
                 BuiltinUnsizeCandidate |
+                TraitAliasCandidate(..) |
                 DefaultImplObjectCandidate(..) |
                 BuiltinCandidate(..) => {
                     // We have a where-clause so don't go around looking
@@ -1650,6 +1686,7 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             ty::TyFloat(_) |
             ty::TyFnDef(..) |
             ty::TyFnPtr(_) |
+            ty::TyNever |
             ty::TyChar => {
                 // safe for everything
                 ok_if(Vec::new())
@@ -1726,7 +1763,7 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                 }
             }
 
-            ty::TyArray(element_ty, _) => {
+            ty::TyArray(element_ty, _) | ty::TyArrayParam(element_ty, _) => {
                 // [T; n]
                 match bound {
                     ty::BoundCopy => ok_if(vec![element_ty]),
@@ -1793,6 +1830,16 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                 Ok(ParameterBuiltin)
             }
 
+            ty::TyAnon(..) => {
+                // `impl Trait` is always sized; any other builtin
+                // bound has to come from its declared bounds.
+                if bound == ty::BoundSized {
+                    ok_if(Vec::new())
+                } else {
+                    Ok(ParameterBuiltin)
+                }
+            }
+
             ty::TyInfer(ty::TyVar(_)) => {
                 // Unbound type variable. Might or might not have
                 // applicable impls and so forth, depending on what
@@ -1855,6 +1902,7 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             ty::TyFnPtr(_) |
             ty::TyStr |
             ty::TyError |
+            ty::TyNever |
             ty::TyInfer(ty::IntVar(_)) |
             ty::TyInfer(ty::FloatVar(_)) |
             ty::TyChar => {
@@ -1881,7 +1929,9 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                 vec![element_ty]
             },
 
-            ty::TyArray(element_ty, _) | ty::TySlice(element_ty) => {
+            ty::TyArray(element_ty, _) |
+            ty::TyArrayParam(element_ty, _) |
+            ty::TySlice(element_ty) => {
                 vec![element_ty]
             }
 
@@ -1912,6 +1962,13 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                     .map(|f| f.ty(self.tcx(), substs))
                     .collect()
             }
+
+            ty::TyAnon(def_id, substs) => {
+                // auto traits leak through `impl Trait` to the
+                // concrete type it stands for
+                let concrete_ty = self.tcx().lookup_item_type(def_id).ty;
+                vec![concrete_ty.subst(self.tcx(), substs)]
+            }
         }
     }
 
@@ -2058,6 +2115,11 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
                 let data = self.confirm_builtin_unsize_candidate(obligation)?;
                 Ok(VtableBuiltin(data))
             }
+
+            TraitAliasCandidate(alias_def_id) => {
+                let data = self.confirm_trait_alias_candidate(obligation, alias_def_id);
+                Ok(VtableBuiltin(data))
+            }
         }
     }
 
@@ -2141,6 +2203,40 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
         VtableBuiltinData { nested: obligations }
     }
 
+    /// `X : Alias` holds if `X` satisfies each of the bounds that `Alias`
+    /// stands for. Those bounds are the super predicates of the alias.
+    fn confirm_trait_alias_candidate(&mut self,
+                                     obligation: &TraitObligation<'tcx>,
+                                     alias_def_id: DefId)
+                                     -> VtableBuiltinData<PredicateObligation<'tcx>>
+    {
+        debug!("confirm_trait_alias_candidate({:?}, {:?})",
+               obligation,
+               alias_def_id);
+
+        let tcx = self.tcx();
+        let cause = obligation.cause.clone();
+        let recursion_depth = obligation.recursion_depth + 1;
+        let poly_trait_ref = obligation.predicate.to_poly_trait_ref();
+        let predicates = tcx.lookup_super_predicates(alias_def_id)
+                            .instantiate_supertrait(tcx, &poly_trait_ref);
+
+        let obligations: Vec<_> = predicates.predicates.into_iter().flat_map(|predicate| {
+            let predicate =
+                normalize_with_depth(self, cause.clone(), recursion_depth, &predicate);
+            predicate.obligations.into_iter().chain(
+                Some(Obligation {
+                    cause: cause.clone(),
+                    recursion_depth: recursion_depth,
+                    predicate: predicate.value
+                }))
+        }).collect();
+
+        debug!("confirm_trait_alias_candidate: obligations={:?}", obligations);
+
+        VtableBuiltinData { nested: obligations }
+    }
+
     /// This handles the case where a `impl Foo for ..` impl is being used.
     /// The idea is that the impl applies to `X : Foo` if the following conditions are met:
     ///
@@ -2553,7 +2649,8 @@ impl<'cx, 'tcx> SelectionContext<'cx, 'tcx> {
             }
 
             // [T; n] -> [T].
-            (&ty::TyArray(a, _), &ty::TySlice(b)) => {
+            (&ty::TyArray(a, _), &ty::TySlice(b)) |
+            (&ty::TyArrayParam(a, _), &ty::TySlice(b)) => {
                 let origin = TypeOrigin::Misc(obligation.cause.span);
                 let InferOk { obligations, .. } =
                     self.infcx.sub_types(false, origin, a, b)
@@ -11,8 +11,9 @@
 // Logic and data structures related to impl specialization, explained in
 // greater detail below.
 //
-// At the moment, this implementation support only the simple "chain" rule:
-// If any two impls overlap, one must be a strict subset of the other.
+// Two impls may overlap if one is a strict subset of the other (the simple
+// "chain" rule), or if a third impl specializes both of them and applies to
+// the whole of their intersection (the "lattice" rule).
 //
 // See traits/README.md for a bit more detail on how specialization
 // fits together with the rest of the trait machinery.
@@ -23,10 +24,13 @@ use super::util::{fresh_type_vars_for_impl, impl_trait_ref_and_oblig};
 use hir::def_id::DefId;
 use infer::{self, InferCtxt, TypeOrigin};
 use middle::region;
-use ty::subst::{Subst, Substs};
+use ty::subst::{self, Subst, Substs};
 use traits::{self, ProjectionMode, ObligationCause, Normalized};
-use ty::{self, TyCtxt};
+use ty::{self, TyCtxt, TypeFoldable};
+use ty::fold::BottomUpFolder;
+use util::nodemap::FnvHashMap;
 use syntax::codemap::DUMMY_SP;
+use syntax::parse::token;
 
 pub mod specialization_graph;
 
@@ -160,6 +164,77 @@ pub fn specializes(tcx: &TyCtxt, impl1_def_id: DefId, impl2_def_id: DefId) -> bo
     fulfill_implication(&infcx, impl1_trait_ref, impl2_def_id).is_ok()
 }
 
+/// Does `impl3` specialize both `impl1` and `impl2`, and apply to every type
+/// to which both of them apply?
+///
+/// Such an impl resolves the overlap between `impl1` and `impl2`: anything
+/// that would be ambiguous between the two is instead handled by `impl3`.
+pub fn covers_intersection(tcx: &TyCtxt,
+                           impl1_def_id: DefId,
+                           impl2_def_id: DefId,
+                           impl3_def_id: DefId)
+                           -> bool {
+    if !specializes(tcx, impl3_def_id, impl1_def_id) ||
+       !specializes(tcx, impl3_def_id, impl2_def_id) {
+        return false;
+    }
+
+    // We determine whether impl3 covers the intersection by:
+    //
+    // - instantiating impl1 and impl2 with fresh inference variables,
+    // - unifying them, yielding the header of their intersection,
+    // - replacing the remaining variables with fresh type parameters,
+    // - assuming the where clauses of both impl1 and impl2,
+    // - attempting to prove that impl3 applies to the intersection.
+    let infcx = infer::normalizing_infer_ctxt(tcx, &tcx.tables, ProjectionMode::Topmost);
+    let (trait_ref, predicates) = {
+        let selcx = &mut SelectionContext::new(&infcx);
+        let header1 = ty::ImplHeader::with_fresh_ty_vars(selcx, impl1_def_id);
+        let header2 = ty::ImplHeader::with_fresh_ty_vars(selcx, impl2_def_id);
+
+        if let Err(_) = infer::mk_eq_impl_headers(&infcx,
+                                                  true,
+                                                  TypeOrigin::Misc(DUMMY_SP),
+                                                  &header1,
+                                                  &header2) {
+            return false;
+        }
+
+        let trait_ref = infcx.resolve_type_vars_if_possible(&header1.trait_ref.unwrap());
+        let predicates: Vec<_> = header1.predicates.iter()
+            .chain(&header2.predicates)
+            .map(|p| infcx.resolve_type_vars_if_possible(p))
+            .collect();
+        (trait_ref, predicates)
+    };
+
+    let mut params = FnvHashMap();
+    let (trait_ref, predicates) = {
+        let mut skolemizer = BottomUpFolder {
+            tcx: tcx,
+            fldop: |ty| match ty.sty {
+                ty::TyInfer(ty::TyVar(vid)) => {
+                    let idx = params.len() as u32;
+                    *params.entry(vid).or_insert_with(|| {
+                        let name = token::intern(&format!("I{}", idx));
+                        tcx.mk_param(subst::TypeSpace, idx, name)
+                    })
+                }
+                _ => ty,
+            },
+        };
+        (tcx.erase_regions(&trait_ref.fold_with(&mut skolemizer)),
+         tcx.erase_regions(&predicates.fold_with(&mut skolemizer)))
+    };
+
+    let mut infcx = infer::normalizing_infer_ctxt(tcx, &tcx.tables, ProjectionMode::Topmost);
+    let mut penv = tcx.empty_parameter_environment();
+    penv.caller_bounds = predicates;
+    infcx.parameter_environment = penv;
+
+    fulfill_implication(&infcx, trait_ref, impl3_def_id).is_ok()
+}
+
 /// Attempt to fulfill all obligations of `target_impl` after unification with
 /// `source_trait_ref`. If successful, returns a substitution for *all* the
 /// generics of `target_impl`, including both those needed to unify with
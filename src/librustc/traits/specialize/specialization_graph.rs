@@ -11,7 +11,7 @@
 use std::cell;
 use std::rc::Rc;
 
-use super::{Overlap, specializes};
+use super::{Overlap, covers_intersection, specializes};
 
 use hir::def_id::DefId;
 use infer;
@@ -29,13 +29,15 @@ use util::nodemap::{DefIdMap, FnvHashMap};
 /// The graph provides two key services:
 ///
 /// - Construction, which implicitly checks for overlapping impls (i.e., impls
-///   that overlap but where neither specializes the other -- an artifact of the
-///   simple "chain" rule.
+///   that overlap but where neither specializes the other, and no third impl
+///   specializes both while covering their intersection).
 ///
 /// - Parent extraction. In particular, the graph can give you the *immediate*
 ///   parents of a given specializing impl, which is needed for extracting
-///   default items amongst other thigns. In the simple "chain" rule, every impl
-///   has at most one parent.
+///   default items amongst other thigns. Every impl has at most one parent in
+///   the graph: an impl covering the intersection of two others is placed
+///   beneath the first of them it is compared against, and only that impl's
+///   chain of parents is consulted for default items.
 pub struct Graph {
     // all impls have a parent; the "root" impls have as their parent the def_id
     // of the trait
@@ -133,6 +135,12 @@ impl Children {
                     // possible_sibling specializes the impl
                     *slot = impl_def_id;
                     return InsertResult::Replaced(possible_sibling);
+                } else if has_intersection_impl(tcx, possible_sibling, impl_def_id) {
+                    debug!("overlap with TraitRef {:?} is covered by an intersection impl",
+                           tcx.impl_trait_ref(possible_sibling).unwrap());
+
+                    // the overlap is resolved by a third impl specializing both
+                    continue;
                 } else {
                     // overlap, but no specialization; error out
                     return InsertResult::Overlapped(Overlap {
@@ -162,6 +170,19 @@ impl Children {
     }
 }
 
+/// Is there an impl of the trait that specializes both `impl1` and `impl2` and
+/// applies to their whole intersection?
+fn has_intersection_impl(tcx: &TyCtxt, impl1_def_id: DefId, impl2_def_id: DefId) -> bool {
+    let trait_def_id = tcx.impl_trait_ref(impl1_def_id).unwrap().def_id;
+    let mut found = false;
+    tcx.lookup_trait_def(trait_def_id).for_each_impl(tcx, |impl3_def_id| {
+        if !found && impl3_def_id != impl1_def_id && impl3_def_id != impl2_def_id {
+            found = covers_intersection(tcx, impl1_def_id, impl2_def_id, impl3_def_id);
+        }
+    });
+    found
+}
+
 impl Graph {
     pub fn new() -> Graph {
         Graph {
@@ -24,6 +24,7 @@ pub enum AutoAdjustment<'tcx> {
     AdjustReifyFnPointer,    // go from a fn-item type to a fn-pointer type
     AdjustUnsafeFnPointer,   // go from a safe fn pointer to an unsafe fn pointer
     AdjustMutToConstPointer, // go from a mut raw pointer to a const raw pointer
+    AdjustNeverToAny(Ty<'tcx>), // go from `!` to any type
     AdjustDerefRef(AutoDerefRef<'tcx>),
 }
 
@@ -108,7 +109,8 @@ impl<'tcx> AutoAdjustment<'tcx> {
         match *self {
             AdjustReifyFnPointer |
             AdjustUnsafeFnPointer |
-            AdjustMutToConstPointer => false,
+            AdjustMutToConstPointer |
+            AdjustNeverToAny(_) => false,
             AdjustDerefRef(ref r) => r.is_identity(),
         }
     }
@@ -175,6 +177,8 @@ impl<'tcx> ty::TyS<'tcx> {
                         }
                     }
 
+                    AdjustNeverToAny(target) => target,
+
                     AdjustMutToConstPointer => {
                         match self.sty {
                             ty::TyRawPtr(mt) => cx.mk_ptr(ty::TypeAndMut {
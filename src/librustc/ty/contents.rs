@@ -207,14 +207,14 @@ impl<'tcx> ty::TyS<'tcx> {
                     TC::None
                 }
 
-                ty::TyArray(ty, _) => {
+                ty::TyArray(ty, _) | ty::TyArrayParam(ty, _) => {
                     tc_ty(cx, ty, cache)
                 }
 
                 ty::TySlice(ty) => {
                     tc_ty(cx, ty, cache)
                 }
-                ty::TyStr => TC::None,
+                ty::TyStr | ty::TyNever => TC::None,
 
                 ty::TyClosure(_, ref substs) => {
                     TypeContents::union(&substs.upvar_tys, |ty| tc_ty(cx, &ty, cache))
@@ -233,6 +233,12 @@ impl<'tcx> ty::TyS<'tcx> {
                             })
                         });
 
+                    // The fields of a union are never dropped, so
+                    // whatever they own does not make it need drop glue.
+                    if def.is_union() {
+                        res = res - TC::OwnsAll;
+                    }
+
                     if def.has_dtor() {
                         res = res | TC::OwnsDtor;
                     }
@@ -241,7 +247,8 @@ impl<'tcx> ty::TyS<'tcx> {
                 }
 
                 ty::TyProjection(..) |
-                ty::TyParam(_) => {
+                ty::TyParam(_) |
+                ty::TyAnon(..) => {
                     TC::All
                 }
 
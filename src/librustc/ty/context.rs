@@ -82,6 +82,7 @@ impl<'tcx> CtxtArenas<'tcx> {
 pub struct CommonTypes<'tcx> {
     pub bool: Ty<'tcx>,
     pub char: Ty<'tcx>,
+    pub never: Ty<'tcx>,
     pub isize: Ty<'tcx>,
     pub i8: Ty<'tcx>,
     pub i16: Ty<'tcx>,
@@ -197,6 +198,7 @@ impl<'tcx> CommonTypes<'tcx> {
         CommonTypes {
             bool: mk(TyBool),
             char: mk(TyChar),
+            never: mk(TyNever),
             err: mk(TyError),
             isize: mk(TyInt(ast::IntTy::Is)),
             i8: mk(TyInt(ast::IntTy::I8)),
@@ -737,7 +739,7 @@ macro_rules! sty_debug_print {
                 for (_, t) in tcx.interner.borrow().iter() {
                     let variant = match t.sty {
                         ty::TyBool | ty::TyChar | ty::TyInt(..) | ty::TyUint(..) |
-                            ty::TyFloat(..) | ty::TyStr => continue,
+                            ty::TyFloat(..) | ty::TyStr | ty::TyNever => continue,
                         ty::TyError => /* unimportant */ continue,
                         $(ty::$variant(..) => &mut $variant,)*
                     };
@@ -778,7 +780,7 @@ impl<'tcx> TyCtxt<'tcx> {
         sty_debug_print!(
             self,
             TyEnum, TyBox, TyArray, TySlice, TyRawPtr, TyRef, TyFnDef, TyFnPtr,
-            TyTrait, TyStruct, TyClosure, TyTuple, TyParam, TyInfer, TyProjection);
+            TyTrait, TyStruct, TyClosure, TyTuple, TyParam, TyInfer, TyProjection, TyAnon);
 
         println!("Substs interner: #{}", self.substs_interner.borrow().len());
         println!("BareFnTy interner: #{}", self.bare_fn_interner.borrow().len());
@@ -973,6 +975,15 @@ impl<'tcx> TyCtxt<'tcx> {
         self.mk_ty(TyArray(ty, n))
     }
 
+    /// Creates an array type whose length may not be known yet; arrays
+    /// with a known length are always created as `TyArray`.
+    pub fn mk_array_const(&self, ty: Ty<'tcx>, n: ty::Const) -> Ty<'tcx> {
+        match n.to_array_len() {
+            Some(n) => self.mk_array(ty, n),
+            None => self.mk_ty(TyArrayParam(ty, n)),
+        }
+    }
+
     pub fn mk_slice(&self, ty: Ty<'tcx>) -> Ty<'tcx> {
         self.mk_ty(TySlice(ty))
     }
@@ -1015,13 +1026,22 @@ impl<'tcx> TyCtxt<'tcx> {
 
     pub fn mk_projection(&self,
                          trait_ref: TraitRef<'tcx>,
-                         item_name: Name)
+                         item_name: Name,
+                         item_substs: &'tcx Substs<'tcx>)
                          -> Ty<'tcx> {
         // take a copy of substs so that we own the vectors inside
-        let inner = ProjectionTy { trait_ref: trait_ref, item_name: item_name };
+        let inner = ProjectionTy {
+            trait_ref: trait_ref,
+            item_name: item_name,
+            item_substs: item_substs
+        };
         self.mk_ty(TyProjection(inner))
     }
 
+    pub fn mk_anon(&self, def_id: DefId, substs: &'tcx Substs<'tcx>) -> Ty<'tcx> {
+        self.mk_ty(TyAnon(def_id, substs))
+    }
+
     pub fn mk_struct(&self, def: AdtDef<'tcx>, substs: &'tcx Substs<'tcx>) -> Ty<'tcx> {
         // take a copy of substs so that we own the vectors inside
         self.mk_ty(TyStruct(def, substs))
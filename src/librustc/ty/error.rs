@@ -41,6 +41,8 @@ pub enum TypeError<'tcx> {
     TupleSize(ExpectedFound<usize>),
     FixedArraySize(ExpectedFound<usize>),
     TyParamSize(ExpectedFound<usize>),
+    ConstParamSize(ExpectedFound<usize>),
+    ConstMismatch(ExpectedFound<ty::Const>),
     ArgCount,
     RegionsDoesNotOutlive(Region, Region),
     RegionsNotSame(Region, Region),
@@ -112,12 +114,23 @@ impl<'tcx> fmt::Display for TypeError<'tcx> {
                        values.expected,
                        values.found)
             }
+            ConstParamSize(values) => {
+                write!(f, "expected a type with {} const params, \
+                           found one with {} const params",
+                       values.expected,
+                       values.found)
+            }
             FixedArraySize(values) => {
                 write!(f, "expected an array with a fixed size of {} elements, \
                            found one with {} elements",
                        values.expected,
                        values.found)
             }
+            ConstMismatch(values) => {
+                write!(f, "expected `{}`, found `{}`",
+                       values.expected,
+                       values.found)
+            }
             TupleSize(values) => {
                 write!(f, "expected a tuple with {} elements, \
                            found one with {} elements",
@@ -214,12 +227,13 @@ impl<'tcx> ty::TyS<'tcx> {
     fn sort_string(&self, cx: &TyCtxt) -> String {
         match self.sty {
             ty::TyBool | ty::TyChar | ty::TyInt(_) |
-            ty::TyUint(_) | ty::TyFloat(_) | ty::TyStr => self.to_string(),
+            ty::TyUint(_) | ty::TyFloat(_) | ty::TyStr | ty::TyNever => self.to_string(),
             ty::TyTuple(ref tys) if tys.is_empty() => self.to_string(),
 
             ty::TyEnum(def, _) => format!("enum `{}`", cx.item_path_str(def.did)),
             ty::TyBox(_) => "box".to_string(),
             ty::TyArray(_, n) => format!("array of {} elements", n),
+            ty::TyArrayParam(_, n) => format!("array of `{}` elements", n),
             ty::TySlice(_) => "slice".to_string(),
             ty::TyRawPtr(_) => "*-ptr".to_string(),
             ty::TyRef(_, _) => "&-ptr".to_string(),
@@ -240,6 +254,7 @@ impl<'tcx> ty::TyS<'tcx> {
             ty::TyInfer(ty::FreshIntTy(_)) => "skolemized integral type".to_string(),
             ty::TyInfer(ty::FreshFloatTy(_)) => "skolemized floating-point type".to_string(),
             ty::TyProjection(_) => "associated type".to_string(),
+            ty::TyAnon(..) => "anonymized type".to_string(),
             ty::TyParam(ref p) => {
                 if p.space == subst::SelfSpace {
                     "Self".to_string()
@@ -24,6 +24,7 @@ pub enum SimplifiedType {
     FloatSimplifiedType(ast::FloatTy),
     EnumSimplifiedType(DefId),
     StrSimplifiedType,
+    NeverSimplifiedType,
     VecSimplifiedType,
     PtrSimplifiedType,
     TupleSimplifiedType(usize),
@@ -56,7 +57,8 @@ pub fn simplify_type(tcx: &TyCtxt,
         ty::TyFloat(float_type) => Some(FloatSimplifiedType(float_type)),
         ty::TyEnum(def, _) => Some(EnumSimplifiedType(def.did)),
         ty::TyStr => Some(StrSimplifiedType),
-        ty::TyArray(..) | ty::TySlice(_) => Some(VecSimplifiedType),
+        ty::TyNever => Some(NeverSimplifiedType),
+        ty::TyArray(..) | ty::TyArrayParam(..) | ty::TySlice(_) => Some(VecSimplifiedType),
         ty::TyRawPtr(_) => Some(PtrSimplifiedType),
         ty::TyTrait(ref trait_info) => {
             Some(TraitSimplifiedType(trait_info.principal_def_id()))
@@ -86,7 +88,7 @@ pub fn simplify_type(tcx: &TyCtxt,
         ty::TyFnDef(_, _, ref f) | ty::TyFnPtr(ref f) => {
             Some(FunctionSimplifiedType(f.sig.0.inputs.len()))
         }
-        ty::TyProjection(_) | ty::TyParam(_) => {
+        ty::TyProjection(_) | ty::TyParam(_) | ty::TyAnon(..) => {
             if can_simplify_params {
                 // In normalized types, projections don't unify with
                 // anything. when lazy normalization happens, this
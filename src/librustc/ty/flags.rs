@@ -60,6 +60,7 @@ impl FlagComputation {
             &ty::TyInt(_) |
             &ty::TyFloat(_) |
             &ty::TyUint(_) |
+            &ty::TyNever |
             &ty::TyStr => {
             }
 
@@ -104,6 +105,13 @@ impl FlagComputation {
                 self.add_projection_ty(data);
             }
 
+            &ty::TyAnon(_, substs) => {
+                // Anonymized types are revealed by normalization in trans,
+                // like projections.
+                self.add_flags(TypeFlags::HAS_PROJECTION);
+                self.add_substs(substs);
+            }
+
             &ty::TyTrait(box ty::TraitTy { ref principal, ref bounds }) => {
                 let mut computation = FlagComputation::new();
                 computation.add_substs(principal.0.substs);
@@ -121,6 +129,11 @@ impl FlagComputation {
                 self.add_ty(tt)
             }
 
+            &ty::TyArrayParam(tt, c) => {
+                self.add_ty(tt);
+                self.add_const(c);
+            }
+
             &ty::TyRawPtr(ref m) => {
                 self.add_ty(m.ty);
             }
@@ -183,6 +196,20 @@ impl FlagComputation {
         }
     }
 
+    fn add_const(&mut self, c: ty::Const) {
+        match c {
+            ty::Const::Value(_) => {}
+            ty::Const::Param(_) => {
+                self.add_flags(TypeFlags::HAS_LOCAL_NAMES);
+                self.add_flags(TypeFlags::HAS_PARAMS);
+            }
+            ty::Const::Infer(_) => {
+                self.add_flags(TypeFlags::HAS_LOCAL_NAMES);
+                self.add_flags(TypeFlags::HAS_TY_INFER);
+            }
+        }
+    }
+
     fn add_projection_predicate(&mut self, projection_predicate: &ty::ProjectionPredicate) {
         self.add_projection_ty(&projection_predicate.projection_ty);
         self.add_ty(projection_predicate.ty);
@@ -190,6 +217,7 @@ impl FlagComputation {
 
     fn add_projection_ty(&mut self, projection_ty: &ty::ProjectionTy) {
         self.add_substs(projection_ty.trait_ref.substs);
+        self.add_substs(projection_ty.item_substs);
     }
 
     fn add_substs(&mut self, substs: &subst::Substs) {
@@ -197,6 +225,9 @@ impl FlagComputation {
         for &r in &substs.regions {
             self.add_region(r);
         }
+        for &c in &substs.consts {
+            self.add_const(c);
+        }
     }
 
     fn add_bounds(&mut self, bounds: &ty::ExistentialBounds) {
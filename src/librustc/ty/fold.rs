@@ -173,6 +173,10 @@ pub trait TypeFolder<'tcx> : Sized {
         r.super_fold_with(self)
     }
 
+    fn fold_const(&mut self, c: ty::Const) -> ty::Const {
+        c.super_fold_with(self)
+    }
+
     fn fold_existential_bounds(&mut self, s: &ty::ExistentialBounds<'tcx>)
                                -> ty::ExistentialBounds<'tcx> {
         s.super_fold_with(self)
@@ -196,6 +200,10 @@ pub trait TypeVisitor<'tcx> : Sized {
     fn visit_region(&mut self, r: ty::Region) -> bool {
         r.super_visit_with(self)
     }
+
+    fn visit_const(&mut self, c: ty::Const) -> bool {
+        c.super_visit_with(self)
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -520,7 +528,8 @@ impl<'tcx> TyCtxt<'tcx> {
                            substs: &subst::Substs<'tcx>)
                            -> subst::Substs<'tcx> {
                 subst::Substs { regions: substs.regions.fold_with(self),
-                                types: substs.types.fold_with(self) }
+                                types: substs.types.fold_with(self),
+                                consts: substs.consts.fold_with(self) }
             }
         }
     }
@@ -149,6 +149,7 @@ impl<'tcx> TyCtxt<'tcx> {
             data @ DefPathData::ValueNs(..) |
             data @ DefPathData::Module(..) |
             data @ DefPathData::TypeParam(..) |
+            data @ DefPathData::ConstParam(..) |
             data @ DefPathData::LifetimeDef(..) |
             data @ DefPathData::EnumVariant(..) |
             data @ DefPathData::Field(..) |
@@ -156,7 +157,8 @@ impl<'tcx> TyCtxt<'tcx> {
             data @ DefPathData::Initializer |
             data @ DefPathData::MacroDef(..) |
             data @ DefPathData::ClosureExpr |
-            data @ DefPathData::Binding(..) => {
+            data @ DefPathData::Binding(..) |
+            data @ DefPathData::ImplTrait => {
                 let parent_def_id = self.parent_def_id(def_id).unwrap();
                 self.push_item_path(buffer, parent_def_id);
                 buffer.push(&data.as_interned_str());
@@ -292,6 +294,7 @@ pub fn characteristic_def_id_of_type<'tcx>(ty: Ty<'tcx>) -> Option<DefId> {
         ty::TyTrait(ref data) => Some(data.principal_def_id()),
 
         ty::TyArray(subty, _) |
+        ty::TyArrayParam(subty, _) |
         ty::TySlice(subty) |
         ty::TyBox(subty) => characteristic_def_id_of_type(subty),
 
@@ -303,13 +306,15 @@ pub fn characteristic_def_id_of_type<'tcx>(ty: Ty<'tcx>) -> Option<DefId> {
                                    .next(),
 
         ty::TyFnDef(def_id, _, _) |
-        ty::TyClosure(def_id, _) => Some(def_id),
+        ty::TyClosure(def_id, _) |
+        ty::TyAnon(def_id, _) => Some(def_id),
 
         ty::TyBool |
         ty::TyChar |
         ty::TyInt(_) |
         ty::TyUint(_) |
         ty::TyStr |
+        ty::TyNever |
         ty::TyFnPtr(_) |
         ty::TyProjection(_) |
         ty::TyParam(_) |
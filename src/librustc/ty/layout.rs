@@ -570,6 +570,10 @@ impl Struct {
                 }
             }
 
+            // The fields of a union overlap, so none of them is
+            // known to be non-zero.
+            (&UntaggedUnion { .. }, _) => Ok(None),
+
             // Perhaps one of the fields of this struct is non-zero
             // let's recurse and find out
             (_, &ty::TyStruct(def, substs)) => {
@@ -614,6 +618,54 @@ impl Struct {
     }
 }
 
+/// An untagged union.
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct Union {
+    pub align: Align,
+
+    pub min_size: Size,
+
+    /// If true, no alignment padding is used.
+    pub packed: bool,
+}
+
+impl Union {
+    pub fn new(dl: &TargetDataLayout, packed: bool) -> Union {
+        Union {
+            align: if packed { dl.i8_align } else { dl.aggregate_align },
+            min_size: Size::from_bytes(0),
+            packed: packed,
+        }
+    }
+
+    /// Extend the Union with more fields, all of which start at offset 0.
+    pub fn extend<'a, 'tcx, I>(&mut self, dl: &TargetDataLayout,
+                               fields: I,
+                               scapegoat: Ty<'tcx>)
+                               -> Result<(), LayoutError<'tcx>>
+    where I: Iterator<Item=Result<&'a Layout, LayoutError<'tcx>>> {
+        for (index, field) in fields.enumerate() {
+            let field = field?;
+            if field.is_unsized() {
+                bug!("Union::extend: field #{} of `{}` is unsized",
+                     index, scapegoat);
+            }
+
+            if !self.packed {
+                self.align = self.align.max(field.align(dl));
+            }
+            self.min_size = cmp::max(self.min_size, field.size(dl));
+        }
+
+        Ok(())
+    }
+
+    /// Get the size with trailing aligment padding.
+    pub fn stride(&self) -> Size {
+        self.min_size.abi_align(self.align)
+    }
+}
+
 /// The first half of a fat pointer.
 /// - For a trait object, this is the address of the box.
 /// - For a slice, this is the base address.
@@ -673,6 +725,11 @@ pub enum Layout {
         max: u64
     },
 
+    /// Untagged unions.
+    UntaggedUnion {
+        variants: Union,
+    },
+
     /// Single-case enums, and structs/tuples.
     Univariant {
         variant: Struct,
@@ -831,7 +888,7 @@ impl Layout {
             }
 
             // Odd unit types.
-            ty::TyFnDef(..) => {
+            ty::TyFnDef(..) | ty::TyNever => {
                 Univariant {
                     variant: Struct::new(dl, false),
                     non_zero: false
@@ -875,6 +932,13 @@ impl Layout {
                         .layout(infcx)
                 });
                 let packed = tcx.lookup_packed(def.did);
+                if def.is_union() {
+                    // All the fields of a union start at offset 0, and
+                    // unions never get a drop flag.
+                    let mut un = Union::new(dl, packed);
+                    un.extend(dl, fields, ty)?;
+                    return Ok(UntaggedUnion { variants: un });
+                }
                 let mut st = Struct::new(dl, packed);
                 st.extend(dl, fields, ty)?;
 
@@ -1103,7 +1167,8 @@ impl Layout {
             }
 
             // Types with no meaningful known layout.
-            ty::TyProjection(_) | ty::TyParam(_) => {
+            ty::TyProjection(_) | ty::TyAnon(..) | ty::TyParam(_) |
+            ty::TyArrayParam(..) => {
                 return Err(LayoutError::Unknown(ty));
             }
             ty::TyInfer(_) | ty::TyError => {
@@ -1118,7 +1183,7 @@ impl Layout {
     pub fn is_unsized(&self) -> bool {
         match *self {
             Scalar {..} | Vector {..} | FatPointer {..} |
-            CEnum {..} | General {..} |
+            CEnum {..} | UntaggedUnion {..} | General {..} |
             RawNullablePointer {..} |
             StructWrappedNullablePointer {..} => false,
 
@@ -1152,6 +1217,7 @@ impl Layout {
 
             CEnum { discr, .. } => Int(discr).size(dl),
             Array { size, .. } | General { size, .. } => size,
+            UntaggedUnion { ref variants } => variants.stride(),
 
             Univariant { ref variant, .. } |
             StructWrappedNullablePointer { nonnull: ref variant, .. } => {
@@ -1191,6 +1257,7 @@ impl Layout {
 
             CEnum { discr, .. } => Int(discr).align(dl),
             Array { align, .. } | General { align, .. } => align,
+            UntaggedUnion { ref variants } => variants.align,
 
             Univariant { ref variant, .. } |
             StructWrappedNullablePointer { nonnull: ref variant, .. } => {
@@ -32,7 +32,7 @@ use ty::subst::{Subst, Substs, VecPerParamSpace};
 use ty::walk::TypeWalker;
 use util::common::MemoizationMap;
 use util::nodemap::NodeSet;
-use util::nodemap::FnvHashMap;
+use util::nodemap::{FnvHashMap, FnvHashSet};
 
 use serialize::{Encodable, Encoder, Decodable, Decoder};
 use std::borrow::Cow;
@@ -50,17 +50,18 @@ use syntax::parse::token::InternedString;
 use rustc_const_math::ConstInt;
 
 use hir;
-use hir::{ItemImpl, ItemTrait, PatKind};
+use hir::{ItemImpl, ItemTrait, ItemTraitAlias, PatKind};
 use hir::intravisit::Visitor;
 
 pub use self::sty::{Binder, DebruijnIndex};
 pub use self::sty::{BuiltinBound, BuiltinBounds, ExistentialBounds};
 pub use self::sty::{BareFnTy, FnSig, PolyFnSig, FnOutput, PolyFnOutput};
 pub use self::sty::{ClosureTy, InferTy, ParamTy, ProjectionTy, TraitTy};
+pub use self::sty::{Const, ParamConst};
 pub use self::sty::{ClosureSubsts, TypeAndMut};
 pub use self::sty::{TraitRef, TypeVariants, PolyTraitRef};
 pub use self::sty::{BoundRegion, EarlyBoundRegion, FreeRegion, Region};
-pub use self::sty::{TyVid, IntVid, FloatVid, RegionVid, SkolemizedRegionVid};
+pub use self::sty::{TyVid, IntVid, FloatVid, ConstVid, RegionVid, SkolemizedRegionVid};
 pub use self::sty::BoundRegion::*;
 pub use self::sty::FnOutput::*;
 pub use self::sty::InferTy::*;
@@ -414,9 +415,11 @@ pub struct AssociatedConst<'tcx> {
     pub has_value: bool
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct AssociatedType<'tcx> {
     pub name: Name,
+    pub generics: Generics<'tcx>,
+    pub predicates: GenericPredicates<'tcx>,
     pub ty: Option<Ty<'tcx>>,
     pub vis: Visibility,
     pub defaultness: hir::Defaultness,
@@ -724,6 +727,21 @@ pub struct RegionParameterDef {
     pub bounds: Vec<ty::Region>,
 }
 
+#[derive(Clone)]
+pub struct ConstParameterDef<'tcx> {
+    pub name: Name,
+    pub def_id: DefId,
+    pub space: subst::ParamSpace,
+    pub index: u32,
+    pub ty: Ty<'tcx>,
+}
+
+impl<'tcx> ConstParameterDef<'tcx> {
+    pub fn to_const(&self) -> Const {
+        Const::Param(ParamConst::for_def(self))
+    }
+}
+
 impl RegionParameterDef {
     pub fn to_early_bound_region(&self) -> ty::Region {
         ty::ReEarlyBound(ty::EarlyBoundRegion {
@@ -743,6 +761,7 @@ impl RegionParameterDef {
 pub struct Generics<'tcx> {
     pub types: VecPerParamSpace<TypeParameterDef<'tcx>>,
     pub regions: VecPerParamSpace<RegionParameterDef>,
+    pub consts: VecPerParamSpace<ConstParameterDef<'tcx>>,
 }
 
 impl<'tcx> Generics<'tcx> {
@@ -750,11 +769,12 @@ impl<'tcx> Generics<'tcx> {
         Generics {
             types: VecPerParamSpace::empty(),
             regions: VecPerParamSpace::empty(),
+            consts: VecPerParamSpace::empty(),
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.types.is_empty() && self.regions.is_empty()
+        self.types.is_empty() && self.regions.is_empty() && self.consts.is_empty()
     }
 
     pub fn has_type_params(&self, space: subst::ParamSpace) -> bool {
@@ -764,6 +784,10 @@ impl<'tcx> Generics<'tcx> {
     pub fn has_region_params(&self, space: subst::ParamSpace) -> bool {
         !self.regions.is_empty_in(space)
     }
+
+    pub fn has_const_params(&self, space: subst::ParamSpace) -> bool {
+        !self.consts.is_empty_in(space)
+    }
 }
 
 /// Bounds on generics.
@@ -1256,17 +1280,22 @@ impl<'a, 'tcx> ParameterEnvironment<'a, 'tcx> {
         match cx.map.find(id) {
             Some(ast_map::NodeImplItem(ref impl_item)) => {
                 match impl_item.node {
-                    hir::ImplItemKind::Type(_) => {
+                    hir::ImplItemKind::Type(..) => {
                         // associated types don't have their own entry (for some reason),
-                        // so for now just grab environment for the impl
-                        let impl_id = cx.map.get_parent(id);
-                        let impl_def_id = cx.map.local_def_id(impl_id);
-                        let scheme = cx.lookup_item_type(impl_def_id);
-                        let predicates = cx.lookup_predicates(impl_def_id);
-                        cx.construct_parameter_environment(impl_item.span,
-                                                           &scheme.generics,
-                                                           &predicates,
-                                                           cx.region_maps.item_extent(id))
+                        // but they carry the generics of the impl along with their own
+                        let def_id = cx.map.local_def_id(id);
+                        match cx.impl_or_trait_item(def_id) {
+                            TypeTraitItem(ref assoc_ty) => {
+                                cx.construct_parameter_environment(impl_item.span,
+                                                                   &assoc_ty.generics,
+                                                                   &assoc_ty.predicates,
+                                                                   cx.region_maps.item_extent(id))
+                            }
+                            _ => {
+                                bug!("ParameterEnvironment::for_item(): \
+                                      got non-type item from impl type?!")
+                            }
+                        }
                     }
                     hir::ImplItemKind::Const(_, _) => {
                         let def_id = cx.map.local_def_id(id);
@@ -1301,15 +1330,20 @@ impl<'a, 'tcx> ParameterEnvironment<'a, 'tcx> {
                 match trait_item.node {
                     hir::TypeTraitItem(..) => {
                         // associated types don't have their own entry (for some reason),
-                        // so for now just grab environment for the trait
-                        let trait_id = cx.map.get_parent(id);
-                        let trait_def_id = cx.map.local_def_id(trait_id);
-                        let trait_def = cx.lookup_trait_def(trait_def_id);
-                        let predicates = cx.lookup_predicates(trait_def_id);
-                        cx.construct_parameter_environment(trait_item.span,
-                                                           &trait_def.generics,
-                                                           &predicates,
-                                                           cx.region_maps.item_extent(id))
+                        // but they carry the generics of the trait along with their own
+                        let def_id = cx.map.local_def_id(id);
+                        match cx.impl_or_trait_item(def_id) {
+                            TypeTraitItem(ref assoc_ty) => {
+                                cx.construct_parameter_environment(trait_item.span,
+                                                                   &assoc_ty.generics,
+                                                                   &assoc_ty.predicates,
+                                                                   cx.region_maps.item_extent(id))
+                            }
+                            _ => {
+                                bug!("ParameterEnvironment::for_item(): \
+                                      got non-type item from trait type?!")
+                            }
+                        }
                     }
                     hir::ConstTraitItem(..) => {
                         let def_id = cx.map.local_def_id(id);
@@ -1367,6 +1401,7 @@ impl<'a, 'tcx> ParameterEnvironment<'a, 'tcx> {
                     }
                     hir::ItemEnum(..) |
                     hir::ItemStruct(..) |
+                    hir::ItemUnion(..) |
                     hir::ItemImpl(..) |
                     hir::ItemConst(..) |
                     hir::ItemStatic(..) => {
@@ -1378,7 +1413,7 @@ impl<'a, 'tcx> ParameterEnvironment<'a, 'tcx> {
                                                            &predicates,
                                                            cx.region_maps.item_extent(id))
                     }
-                    hir::ItemTrait(..) => {
+                    hir::ItemTrait(..) | hir::ItemTraitAlias(..) => {
                         let def_id = cx.map.local_def_id(id);
                         let trait_def = cx.lookup_trait_def(def_id);
                         let predicates = cx.lookup_predicates(def_id);
@@ -1443,6 +1478,7 @@ bitflags! {
         const IS_SIMD             = 1 << 4,
         const IS_FUNDAMENTAL      = 1 << 5,
         const IS_NO_DROP_FLAG     = 1 << 6,
+        const IS_UNION            = 1 << 7,
     }
 }
 
@@ -1534,7 +1570,7 @@ impl<'tcx> Decodable for AdtDef<'tcx> {
 
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum AdtKind { Struct, Enum }
+pub enum AdtKind { Struct, Union, Enum }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, RustcEncodable, RustcDecodable)]
 pub enum VariantKind { Struct, Tuple, Unit }
@@ -1568,8 +1604,10 @@ impl<'tcx, 'container> AdtDefData<'tcx, 'container> {
         if Some(did) == tcx.lang_items.phantom_data() {
             flags = flags | AdtFlags::IS_PHANTOM_DATA;
         }
-        if let AdtKind::Enum = kind {
-            flags = flags | AdtFlags::IS_ENUM;
+        match kind {
+            AdtKind::Enum => flags = flags | AdtFlags::IS_ENUM,
+            AdtKind::Union => flags = flags | AdtFlags::IS_UNION,
+            AdtKind::Struct => {}
         }
         AdtDefData {
             did: did,
@@ -1586,16 +1624,25 @@ impl<'tcx, 'container> AdtDefData<'tcx, 'container> {
         self.flags.set(self.flags.get() | AdtFlags::IS_DTORCK_VALID)
     }
 
-    /// Returns the kind of the ADT - Struct or Enum.
+    /// Returns the kind of the ADT - Struct, Union or Enum.
     #[inline]
     pub fn adt_kind(&self) -> AdtKind {
         if self.flags.get().intersects(AdtFlags::IS_ENUM) {
             AdtKind::Enum
+        } else if self.flags.get().intersects(AdtFlags::IS_UNION) {
+            AdtKind::Union
         } else {
             AdtKind::Struct
         }
     }
 
+    /// Returns whether this is a union, whose fields all share the
+    /// same storage. Unions are represented as `TyStruct` types.
+    #[inline]
+    pub fn is_union(&self) -> bool {
+        self.flags.get().intersects(AdtFlags::IS_UNION)
+    }
+
     /// Returns whether this is a dtorck type. If this returns
     /// true, this type being safe for destruction requires it to be
     /// alive; Otherwise, only the contents are required to be.
@@ -1633,10 +1680,10 @@ impl<'tcx, 'container> AdtDefData<'tcx, 'container> {
         }
     }
 
-    /// Asserts this is a struct and returns the struct's unique
+    /// Asserts this is a struct or union and returns its unique
     /// variant.
     pub fn struct_variant(&self) -> &VariantDefData<'tcx, 'container> {
-        assert_eq!(self.adt_kind(), AdtKind::Struct);
+        assert!(self.adt_kind() != AdtKind::Enum);
         &self.variants[0]
     }
 
@@ -1668,6 +1715,30 @@ impl<'tcx, 'container> AdtDefData<'tcx, 'container> {
         self.variants.is_empty()
     }
 
+    /// Returns whether no value of this ADT can be constructed with
+    /// the given substitutions: an enum all of whose variants are
+    /// uninhabited, or a struct with an uninhabited field. `visited`
+    /// holds the ADTs currently being checked, so that recursive types
+    /// are assumed to be inhabited instead of looping forever.
+    pub fn is_uninhabited_recurse(&self,
+                                  visited: &mut FnvHashSet<(DefId, &'tcx Substs<'tcx>)>,
+                                  cx: &TyCtxt<'tcx>,
+                                  substs: &'tcx Substs<'tcx>) -> bool {
+        if !visited.insert((self.did, substs)) {
+            return false;
+        }
+        let uninhabited = match self.adt_kind() {
+            AdtKind::Enum => self.variants.iter().all(|v| {
+                v.is_uninhabited_recurse(visited, cx, substs)
+            }),
+            AdtKind::Struct => self.struct_variant().is_uninhabited_recurse(visited, cx, substs),
+            // A union can always be constructed through any one of its fields.
+            AdtKind::Union => false,
+        };
+        visited.remove(&(self.did, substs));
+        uninhabited
+    }
+
     #[inline]
     pub fn is_univariant(&self) -> bool {
         self.variants.len() == 1
@@ -1750,6 +1821,15 @@ impl<'tcx, 'container> VariantDefData<'tcx, 'container> {
     pub fn field_named(&self, name: ast::Name) -> &FieldDefData<'tcx, 'container> {
         self.find_field_named(name).unwrap()
     }
+
+    /// Returns whether any field of this variant is uninhabited, in
+    /// which case the variant itself can never be constructed.
+    pub fn is_uninhabited_recurse(&self,
+                                  visited: &mut FnvHashSet<(DefId, &'tcx Substs<'tcx>)>,
+                                  cx: &TyCtxt<'tcx>,
+                                  substs: &'tcx Substs<'tcx>) -> bool {
+        self.fields.iter().any(|f| f.ty(cx, substs).is_uninhabited_recurse(visited, cx))
+    }
 }
 
 impl<'tcx, 'container> FieldDefData<'tcx, 'container> {
@@ -2136,6 +2216,8 @@ impl<'tcx> TyCtxt<'tcx> {
                         None
                     }
                 }).collect()
+            } else if let ItemTraitAlias(..) = self.map.expect_item(id).node {
+                Vec::new()
             } else {
                 bug!("provided_trait_methods: `{:?}` is not a trait", id)
             }
@@ -2163,7 +2245,7 @@ impl<'tcx> TyCtxt<'tcx> {
                         }
                     }).collect()
                 }
-                ItemImpl(_, _, _, _, _, ref iis) => {
+                ItemImpl(_, _, _, _, _, _, ref iis) => {
                     iis.iter().filter_map(|ii| {
                         if let hir::ImplItemKind::Const(_, _) = ii.node {
                             match self.impl_or_trait_item(self.map.local_def_id(ii.id)) {
@@ -2179,6 +2261,7 @@ impl<'tcx> TyCtxt<'tcx> {
                         }
                     }).collect()
                 }
+                ItemTraitAlias(..) => Vec::new(),
                 _ => {
                     bug!("associated_consts: `{:?}` is not a trait or impl", id)
                 }
@@ -2193,7 +2276,7 @@ impl<'tcx> TyCtxt<'tcx> {
             match self.map.find(id) {
                 Some(ast_map::NodeItem(item)) => {
                     match item.node {
-                        hir::ItemImpl(_, polarity, _, _, _, _) => Some(polarity),
+                        hir::ItemImpl(_, polarity, _, _, _, _, _) => Some(polarity),
                         _ => None
                     }
                 }
@@ -2204,6 +2287,25 @@ impl<'tcx> TyCtxt<'tcx> {
         }
     }
 
+    /// Whether `id` is a `default impl`, which only supplies default items
+    /// to the impls specializing it.
+    pub fn impl_is_default(&self, id: DefId) -> bool {
+        let defaultness = if let Some(id) = self.map.as_local_node_id(id) {
+            match self.map.find(id) {
+                Some(ast_map::NodeItem(item)) => {
+                    match item.node {
+                        hir::ItemImpl(_, _, defaultness, _, _, _, _) => defaultness,
+                        _ => hir::Defaultness::Final
+                    }
+                }
+                _ => hir::Defaultness::Final
+            }
+        } else {
+            self.sess.cstore.impl_defaultness(id)
+        };
+        defaultness.is_default()
+    }
+
     pub fn custom_coerce_unsized_kind(&self, did: DefId) -> adjustment::CustomCoerceUnsized {
         self.custom_coerce_unsized_kinds.memoize(did, || {
             let (kind, src) = if did.krate != LOCAL_CRATE {
@@ -2236,6 +2338,25 @@ impl<'tcx> TyCtxt<'tcx> {
             || Rc::new(self.sess.cstore.trait_item_def_ids(id)))
     }
 
+    /// Returns the item whose predicates bound the projection `data`, along
+    /// with the substitutions for them. This is the trait, except for generic
+    /// associated types, which carry their own bounds.
+    pub fn projection_bounds_source(&self, data: &ProjectionTy<'tcx>)
+                                    -> (DefId, &'tcx Substs<'tcx>) {
+        if !data.item_substs.is_noop() {
+            for item_id in self.trait_item_def_ids(data.trait_ref.def_id).iter() {
+                if let TypeTraitItemId(def_id) = *item_id {
+                    if self.impl_or_trait_item(def_id).name() == data.item_name {
+                        let substs = data.trait_ref.substs.clone()
+                                         .with_method_from_subst(data.item_substs);
+                        return (def_id, self.mk_substs(substs));
+                    }
+                }
+            }
+        }
+        (data.trait_ref.def_id, data.trait_ref.substs)
+    }
+
     /// Returns the trait-ref corresponding to a given impl, or None if it is
     /// an inherent impl.
     pub fn impl_trait_ref(&self, id: DefId) -> Option<TraitRef<'tcx>> {
@@ -2644,9 +2765,13 @@ impl<'tcx> TyCtxt<'tcx> {
             regions.push(def.space, region);
         }
 
+        // map N => N
+        let consts = generics.consts.map(|def| def.to_const());
+
         Substs {
             types: types,
             regions: regions,
+            consts: consts,
         }
     }
 
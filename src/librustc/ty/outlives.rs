@@ -177,7 +177,9 @@ fn compute_components<'a,'tcx>(infcx: &InferCtxt<'a,'tcx>,
         ty::TyStruct(..) |      // OutlivesNominalType
         ty::TyBox(..) |         // OutlivesNominalType (ish)
         ty::TyStr |             // OutlivesScalar (ish)
+        ty::TyNever |           // ...
         ty::TyArray(..) |       // ...
+        ty::TyArrayParam(..) |  // ...
         ty::TySlice(..) |       // ...
         ty::TyRawPtr(..) |      // ...
         ty::TyRef(..) |         // OutlivesReference
@@ -185,6 +187,7 @@ fn compute_components<'a,'tcx>(infcx: &InferCtxt<'a,'tcx>,
         ty::TyFnDef(..) |       // OutlivesFunction (*)
         ty::TyFnPtr(_) |        // OutlivesFunction (*)
         ty::TyTrait(..) |       // OutlivesObject, OutlivesFragment (*)
+        ty::TyAnon(..) |        // OutlivesNominalType (ish)
         ty::TyError => {
             // (*) Bare functions and traits are both binders. In the
             // RFC, this means we would add the bound regions to the
@@ -74,6 +74,11 @@ pub trait TypeRelation<'a,'tcx> : Sized {
     fn regions(&mut self, a: ty::Region, b: ty::Region)
                -> RelateResult<'tcx, ty::Region>;
 
+    fn consts(&mut self, a: ty::Const, b: ty::Const)
+              -> RelateResult<'tcx, ty::Const> {
+        super_relate_consts(self, a, b)
+    }
+
     fn binders<T>(&mut self, a: &ty::Binder<T>, b: &ty::Binder<T>)
                   -> RelateResult<'tcx, ty::Binder<T>>
         where T: Relate<'a,'tcx>;
@@ -167,6 +172,18 @@ pub fn relate_substs<'a,'tcx:'a,R>(relation: &mut R,
         substs.regions.replace(space, regions);
     }
 
+    for &space in &ParamSpace::all() {
+        let a_consts = a_subst.consts.get_slice(space);
+        let b_consts = b_subst.consts.get_slice(space);
+        if a_consts.len() != b_consts.len() {
+            return Err(TypeError::ConstParamSize(expected_found(relation,
+                                                                &a_consts.len(),
+                                                                &b_consts.len())));
+        }
+        let consts = relation.relate_zip(a_consts, b_consts)?;
+        substs.consts.replace(space, consts);
+    }
+
     Ok(substs)
 }
 
@@ -329,7 +346,12 @@ impl<'a,'tcx:'a> Relate<'a,'tcx> for ty::ProjectionTy<'tcx> {
                 expected_found(relation, &a.item_name, &b.item_name)))
         } else {
             let trait_ref = relation.relate(&a.trait_ref, &b.trait_ref)?;
-            Ok(ty::ProjectionTy { trait_ref: trait_ref, item_name: a.item_name })
+            let item_substs = relate_substs(relation, None, a.item_substs, b.item_substs)?;
+            Ok(ty::ProjectionTy {
+                trait_ref: trait_ref,
+                item_name: a.item_name,
+                item_substs: relation.tcx().mk_substs(item_substs)
+            })
         }
     }
 }
@@ -466,7 +488,8 @@ pub fn super_relate_tys<'a,'tcx:'a,R>(relation: &mut R,
         (&ty::TyInt(_), _) |
         (&ty::TyUint(_), _) |
         (&ty::TyFloat(_), _) |
-        (&ty::TyStr, _)
+        (&ty::TyStr, _) |
+        (&ty::TyNever, _)
             if a == b =>
         {
             Ok(a)
@@ -539,6 +562,17 @@ pub fn super_relate_tys<'a,'tcx:'a,R>(relation: &mut R,
             }
         }
 
+        (&ty::TyArray(..), &ty::TyArrayParam(..)) |
+        (&ty::TyArrayParam(..), &ty::TyArray(..)) |
+        (&ty::TyArrayParam(..), &ty::TyArrayParam(..)) =>
+        {
+            let (a_t, sz_a) = a.array_const(tcx).unwrap();
+            let (b_t, sz_b) = b.array_const(tcx).unwrap();
+            let t = relation.relate(&a_t, &b_t)?;
+            let sz = relation.relate(&sz_a, &sz_b)?;
+            Ok(tcx.mk_array_const(t, sz))
+        }
+
         (&ty::TySlice(a_t), &ty::TySlice(b_t)) =>
         {
             let t = relation.relate(&a_t, &b_t)?;
@@ -578,7 +612,16 @@ pub fn super_relate_tys<'a,'tcx:'a,R>(relation: &mut R,
         (&ty::TyProjection(ref a_data), &ty::TyProjection(ref b_data)) =>
         {
             let projection_ty = relation.relate(a_data, b_data)?;
-            Ok(tcx.mk_projection(projection_ty.trait_ref, projection_ty.item_name))
+            Ok(tcx.mk_projection(projection_ty.trait_ref,
+                                 projection_ty.item_name,
+                                 projection_ty.item_substs))
+        }
+
+        (&ty::TyAnon(a_def_id, a_substs), &ty::TyAnon(b_def_id, b_substs))
+            if a_def_id == b_def_id =>
+        {
+            let substs = relate_substs(relation, None, a_substs, b_substs)?;
+            Ok(tcx.mk_anon(a_def_id, tcx.mk_substs(substs)))
         }
 
         _ =>
@@ -588,6 +631,32 @@ pub fn super_relate_tys<'a,'tcx:'a,R>(relation: &mut R,
     }
 }
 
+/// The default relation for consts, used when neither side is an
+/// inference variable: they must be the same.
+pub fn super_relate_consts<'a,'tcx:'a,R>(relation: &mut R,
+                                         a: ty::Const,
+                                         b: ty::Const)
+                                         -> RelateResult<'tcx, ty::Const>
+    where R: TypeRelation<'a,'tcx>
+{
+    if a == b {
+        Ok(a)
+    } else {
+        Err(TypeError::ConstMismatch(expected_found(relation, &a, &b)))
+    }
+}
+
+impl<'a,'tcx:'a> Relate<'a,'tcx> for ty::Const {
+    fn relate<R>(relation: &mut R,
+                 a: &ty::Const,
+                 b: &ty::Const)
+                 -> RelateResult<'tcx, ty::Const>
+        where R: TypeRelation<'a,'tcx>
+    {
+        relation.consts(*a, *b)
+    }
+}
+
 impl<'a,'tcx:'a> Relate<'a,'tcx> for ty::ClosureSubsts<'tcx> {
     fn relate<R>(relation: &mut R,
                  a: &ty::ClosureSubsts<'tcx>,
@@ -89,11 +89,14 @@ impl<'tcx, A: Copy+Lift<'tcx>, B: Copy+Lift<'tcx>> Lift<'tcx> for ty::OutlivesPr
 impl<'a, 'tcx> Lift<'tcx> for ty::ProjectionPredicate<'a> {
     type Lifted = ty::ProjectionPredicate<'tcx>;
     fn lift_to_tcx(&self, tcx: &TyCtxt<'tcx>) -> Option<ty::ProjectionPredicate<'tcx>> {
-        tcx.lift(&(self.projection_ty.trait_ref, self.ty)).map(|(trait_ref, ty)| {
+        let projection_ty = &self.projection_ty;
+        tcx.lift(&((projection_ty.trait_ref, projection_ty.item_substs), self.ty))
+           .map(|((trait_ref, item_substs), ty)| {
             ty::ProjectionPredicate {
                 projection_ty: ty::ProjectionTy {
                     trait_ref: trait_ref,
-                    item_name: self.projection_ty.item_name
+                    item_name: projection_ty.item_name,
+                    item_substs: item_substs
                 },
                 ty: ty
             }
@@ -245,6 +248,15 @@ impl<'tcx> TypeFoldable<'tcx> for Ty<'tcx> {
             ty::TyBox(typ) => ty::TyBox(typ.fold_with(folder)),
             ty::TyRawPtr(ref tm) => ty::TyRawPtr(tm.fold_with(folder)),
             ty::TyArray(typ, sz) => ty::TyArray(typ.fold_with(folder), sz),
+            ty::TyArrayParam(typ, sz) => {
+                // Once the length is known, the array is an ordinary `TyArray`.
+                let typ = typ.fold_with(folder);
+                let sz = sz.fold_with(folder);
+                match sz.to_array_len() {
+                    Some(n) => ty::TyArray(typ, n),
+                    None => ty::TyArrayParam(typ, sz),
+                }
+            }
             ty::TySlice(typ) => ty::TySlice(typ.fold_with(folder)),
             ty::TyEnum(tid, ref substs) => {
                 let substs = substs.fold_with(folder);
@@ -275,9 +287,13 @@ impl<'tcx> TypeFoldable<'tcx> for Ty<'tcx> {
                 ty::TyClosure(did, substs.fold_with(folder))
             }
             ty::TyProjection(ref data) => ty::TyProjection(data.fold_with(folder)),
+            ty::TyAnon(did, substs) => {
+                let substs = substs.fold_with(folder);
+                ty::TyAnon(did, folder.tcx().mk_substs(substs))
+            }
             ty::TyBool | ty::TyChar | ty::TyStr | ty::TyInt(_) |
             ty::TyUint(_) | ty::TyFloat(_) | ty::TyError | ty::TyInfer(_) |
-            ty::TyParam(..) => self.sty.clone(),
+            ty::TyParam(..) | ty::TyNever => self.sty.clone(),
         };
         folder.tcx().mk_ty(sty)
     }
@@ -291,6 +307,7 @@ impl<'tcx> TypeFoldable<'tcx> for Ty<'tcx> {
             ty::TyBox(typ) => typ.visit_with(visitor),
             ty::TyRawPtr(ref tm) => tm.visit_with(visitor),
             ty::TyArray(typ, _sz) => typ.visit_with(visitor),
+            ty::TyArrayParam(typ, sz) => typ.visit_with(visitor) || sz.visit_with(visitor),
             ty::TySlice(typ) => typ.visit_with(visitor),
             ty::TyEnum(_tid, ref substs) => substs.visit_with(visitor),
             ty::TyTrait(ref trait_ty) => trait_ty.visit_with(visitor),
@@ -303,9 +320,10 @@ impl<'tcx> TypeFoldable<'tcx> for Ty<'tcx> {
             ty::TyStruct(_did, ref substs) => substs.visit_with(visitor),
             ty::TyClosure(_did, ref substs) => substs.visit_with(visitor),
             ty::TyProjection(ref data) => data.visit_with(visitor),
+            ty::TyAnon(_, ref substs) => substs.visit_with(visitor),
             ty::TyBool | ty::TyChar | ty::TyStr | ty::TyInt(_) |
             ty::TyUint(_) | ty::TyFloat(_) | ty::TyError | ty::TyInfer(_) |
-            ty::TyParam(..) => false,
+            ty::TyParam(..) | ty::TyNever => false,
         }
     }
 
@@ -455,10 +473,29 @@ impl<'tcx> TypeFoldable<'tcx> for ty::Region {
     }
 }
 
+impl<'tcx> TypeFoldable<'tcx> for ty::Const {
+    fn super_fold_with<F: TypeFolder<'tcx>>(&self, _folder: &mut F) -> Self {
+        *self
+    }
+
+    fn fold_with<F: TypeFolder<'tcx>>(&self, folder: &mut F) -> Self {
+        folder.fold_const(*self)
+    }
+
+    fn super_visit_with<V: TypeVisitor<'tcx>>(&self, _visitor: &mut V) -> bool {
+        false
+    }
+
+    fn visit_with<V: TypeVisitor<'tcx>>(&self, visitor: &mut V) -> bool {
+        visitor.visit_const(*self)
+    }
+}
+
 impl<'tcx> TypeFoldable<'tcx> for subst::Substs<'tcx> {
     fn super_fold_with<F: TypeFolder<'tcx>>(&self, folder: &mut F) -> Self {
         subst::Substs { regions: self.regions.fold_with(folder),
-                        types: self.types.fold_with(folder) }
+                        types: self.types.fold_with(folder),
+                        consts: self.consts.fold_with(folder) }
     }
 
     fn fold_with<F: TypeFolder<'tcx>>(&self, folder: &mut F) -> Self {
@@ -466,7 +503,9 @@ impl<'tcx> TypeFoldable<'tcx> for subst::Substs<'tcx> {
     }
 
     fn super_visit_with<V: TypeVisitor<'tcx>>(&self, visitor: &mut V) -> bool {
-        self.types.visit_with(visitor) || self.regions.visit_with(visitor)
+        self.types.visit_with(visitor) ||
+            self.regions.visit_with(visitor) ||
+            self.consts.visit_with(visitor)
     }
 }
 
@@ -604,16 +643,35 @@ impl<'tcx> TypeFoldable<'tcx> for ty::RegionParameterDef {
     }
 }
 
+impl<'tcx> TypeFoldable<'tcx> for ty::ConstParameterDef<'tcx> {
+    fn super_fold_with<F: TypeFolder<'tcx>>(&self, folder: &mut F) -> Self {
+        ty::ConstParameterDef {
+            name: self.name,
+            def_id: self.def_id,
+            space: self.space,
+            index: self.index,
+            ty: self.ty.fold_with(folder),
+        }
+    }
+
+    fn super_visit_with<V: TypeVisitor<'tcx>>(&self, visitor: &mut V) -> bool {
+        self.ty.visit_with(visitor)
+    }
+}
+
 impl<'tcx> TypeFoldable<'tcx> for ty::Generics<'tcx> {
     fn super_fold_with<F: TypeFolder<'tcx>>(&self, folder: &mut F) -> Self {
         ty::Generics {
             types: self.types.fold_with(folder),
             regions: self.regions.fold_with(folder),
+            consts: self.consts.fold_with(folder),
         }
     }
 
     fn super_visit_with<V: TypeVisitor<'tcx>>(&self, visitor: &mut V) -> bool {
-        self.types.visit_with(visitor) || self.regions.visit_with(visitor)
+        self.types.visit_with(visitor) ||
+            self.regions.visit_with(visitor) ||
+            self.consts.visit_with(visitor)
     }
 }
 
@@ -683,11 +741,12 @@ impl<'tcx> TypeFoldable<'tcx> for ty::ProjectionTy<'tcx> {
         ty::ProjectionTy {
             trait_ref: self.trait_ref.fold_with(folder),
             item_name: self.item_name,
+            item_substs: self.item_substs.fold_with(folder),
         }
     }
 
     fn super_visit_with<V: TypeVisitor<'tcx>>(&self, visitor: &mut V) -> bool {
-        self.trait_ref.visit_with(visitor)
+        self.trait_ref.visit_with(visitor) || self.item_substs.visit_with(visitor)
     }
 }
 
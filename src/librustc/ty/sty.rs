@@ -17,6 +17,9 @@ use ty::subst::{self, Substs};
 use traits;
 use ty::{self, AdtDef, ToPredicate, TypeFlags, Ty, TyCtxt, TyS, TypeFoldable};
 use util::common::ErrorReported;
+use util::nodemap::FnvHashSet;
+
+use rustc_const_math::{ConstInt, ConstUsize};
 
 use collections::enum_set::{self, EnumSet, CLike};
 use std::fmt;
@@ -117,6 +120,11 @@ pub enum TypeVariants<'tcx> {
     /// An array with the given length. Written as `[T; n]`.
     TyArray(Ty<'tcx>, usize),
 
+    /// An array whose length is a const generic parameter or a const
+    /// inference variable. Written as `[T; N]`. Once the length is known
+    /// to be an integer, the type is always a `TyArray` instead.
+    TyArrayParam(Ty<'tcx>, Const),
+
     /// The pointee of an array slice.  Written as `[T]`.
     TySlice(Ty<'tcx>),
 
@@ -143,6 +151,9 @@ pub enum TypeVariants<'tcx> {
     /// `|a| a`.
     TyClosure(DefId, Box<ClosureSubsts<'tcx>>),
 
+    /// The never type `!`
+    TyNever,
+
     /// A tuple type.  For example, `(i32, bool)`.
     TyTuple(Vec<Ty<'tcx>>),
 
@@ -150,6 +161,14 @@ pub enum TypeVariants<'tcx> {
     /// `<T as Trait<..>>::N`.
     TyProjection(ProjectionTy<'tcx>),
 
+    /// The anonymized type of an `impl Trait` in a function's return
+    /// type. The `DefId` is that of the `impl Trait` type node, and the
+    /// substitutions are those of the function it appears in. Outside of
+    /// that function only the bounds of the type are known (except for
+    /// auto traits, which see through it), and trans replaces it with the
+    /// type inferred from the function's body.
+    TyAnon(DefId, &'tcx Substs<'tcx>),
+
     /// A type parameter; for example, `T` in `fn f<T>(x: T) {}
     TyParam(ParamTy),
 
@@ -310,7 +329,8 @@ impl<'tcx> TraitTy<'tcx> {
                                               substs);
                 let projection_ty = ty::ProjectionTy {
                     trait_ref: trait_ref,
-                    item_name: in_projection_ty.item_name
+                    item_name: in_projection_ty.item_name,
+                    item_substs: in_projection_ty.item_substs
                 };
                 ty::Binder(ty::ProjectionPredicate {
                     projection_ty: projection_ty,
@@ -431,6 +451,11 @@ pub struct ProjectionTy<'tcx> {
 
     /// The name `N` of the associated type.
     pub item_name: Name,
+
+    /// The arguments for the associated type's own parameters, as in
+    /// `<T as Trait>::N<'a, U>`. These are always in `FnSpace`, like the
+    /// arguments of a method, and are empty for non-generic associated types.
+    pub item_substs: &'tcx Substs<'tcx>,
 }
 
 impl<'tcx> ProjectionTy<'tcx> {
@@ -549,6 +574,58 @@ impl ParamTy {
     }
 }
 
+/// A const generic parameter, e.g. `N` in `struct Foo<const N: usize>`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub struct ParamConst {
+    pub space: subst::ParamSpace,
+    pub idx: u32,
+    pub name: Name,
+}
+
+impl ParamConst {
+    pub fn new(space: subst::ParamSpace,
+               index: u32,
+               name: Name)
+               -> ParamConst {
+        ParamConst { space: space, idx: index, name: name }
+    }
+
+    pub fn for_def(def: &ty::ConstParameterDef) -> ParamConst {
+        ParamConst::new(def.space, def.index, def.name)
+    }
+}
+
+/// The value of a const generic argument. Only integers are supported as
+/// values for now, and no arithmetic is performed on parameters.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub enum Const {
+    /// A fully evaluated integer.
+    Value(ConstInt),
+
+    /// A const generic parameter.
+    Param(ParamConst),
+
+    /// A const inference variable.
+    Infer(ConstVid),
+}
+
+impl Const {
+    pub fn is_value(&self) -> bool {
+        match *self {
+            Const::Value(_) => true,
+            _ => false,
+        }
+    }
+
+    /// The length of an array whose length is this constant, if known.
+    pub fn to_array_len(&self) -> Option<usize> {
+        match *self {
+            Const::Value(value) => value.to_u64().map(|len| len as usize),
+            _ => None,
+        }
+    }
+}
+
 /// A [De Bruijn index][dbi] is a standard means of representing
 /// regions (and perhaps later types) in a higher-ranked setting. In
 /// particular, imagine a type like this:
@@ -714,6 +791,11 @@ pub struct FloatVid {
     pub index: u32
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub struct ConstVid {
+    pub index: u32
+}
+
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Copy)]
 pub struct RegionVid {
     pub index: u32
@@ -902,6 +984,35 @@ impl<'tcx> TyS<'tcx> {
         }
     }
 
+    pub fn is_never(&self) -> bool {
+        match self.sty {
+            TyNever => true,
+            _ => false,
+        }
+    }
+
+    /// Checks whether a type is uninhabited, i.e. no value of it can
+    /// ever exist: `!`, an enum without constructible variants, or an
+    /// aggregate containing such a type.
+    pub fn is_uninhabited(&self, cx: &TyCtxt<'tcx>) -> bool {
+        let mut visited = FnvHashSet();
+        self.is_uninhabited_recurse(&mut visited, cx)
+    }
+
+    pub fn is_uninhabited_recurse(&self,
+                                  visited: &mut FnvHashSet<(DefId, &'tcx Substs<'tcx>)>,
+                                  cx: &TyCtxt<'tcx>) -> bool {
+        match self.sty {
+            TyEnum(def, substs) | TyStruct(def, substs) => {
+                def.is_uninhabited_recurse(visited, cx, substs)
+            }
+            TyNever => true,
+            TyTuple(ref tys) => tys.iter().any(|ty| ty.is_uninhabited_recurse(visited, cx)),
+            TyArray(ty, len) => len > 0 && ty.is_uninhabited_recurse(visited, cx),
+            _ => false,
+        }
+    }
+
     pub fn is_empty(&self, _cx: &TyCtxt) -> bool {
         // FIXME(#24885): be smarter here
         match self.sty {
@@ -961,7 +1072,7 @@ impl<'tcx> TyS<'tcx> {
     pub fn is_structural(&self) -> bool {
         match self.sty {
             TyStruct(..) | TyTuple(_) | TyEnum(..) |
-            TyArray(..) | TyClosure(..) => true,
+            TyArray(..) | TyArrayParam(..) | TyClosure(..) => true,
             _ => self.is_slice() | self.is_trait()
         }
     }
@@ -974,9 +1085,23 @@ impl<'tcx> TyS<'tcx> {
         }
     }
 
+    /// The element type and length of an array, with the length
+    /// given as a `Const` whether or not it is known.
+    pub fn array_const(&self, cx: &TyCtxt<'tcx>) -> Option<(Ty<'tcx>, Const)> {
+        match self.sty {
+            TyArray(ty, len) => {
+                let len = ConstUsize::new(len as u64, cx.sess.target.uint_type)
+                    .expect("array length out of range");
+                Some((ty, Const::Value(ConstInt::Usize(len))))
+            }
+            TyArrayParam(ty, len) => Some((ty, len)),
+            _ => None,
+        }
+    }
+
     pub fn sequence_element_type(&self, cx: &TyCtxt<'tcx>) -> Ty<'tcx> {
         match self.sty {
-            TyArray(ty, _) | TySlice(ty) => ty,
+            TyArray(ty, _) | TyArrayParam(ty, _) | TySlice(ty) => ty,
             TyStr => cx.mk_mach_uint(ast::UintTy::U8),
             _ => bug!("sequence_element_type called on non-sequence value: {}", self),
         }
@@ -1139,7 +1264,7 @@ impl<'tcx> TyS<'tcx> {
     // Returns the type of ty[i]
     pub fn builtin_index(&self) -> Option<Ty<'tcx>> {
         match self.sty {
-            TyArray(ty, _) | TySlice(ty) => Some(ty),
+            TyArray(ty, _) | TyArrayParam(ty, _) | TySlice(ty) => Some(ty),
             _ => None
         }
     }
@@ -1207,25 +1332,30 @@ impl<'tcx> TyS<'tcx> {
                 v
             }
             TyEnum(_, substs) |
-            TyStruct(_, substs) => {
+            TyStruct(_, substs) |
+            TyAnon(_, substs) => {
                 substs.regions.as_slice().to_vec()
             }
             TyClosure(_, ref substs) => {
                 substs.func_substs.regions.as_slice().to_vec()
             }
             TyProjection(ref data) => {
-                data.trait_ref.substs.regions.as_slice().to_vec()
+                let mut v = data.trait_ref.substs.regions.as_slice().to_vec();
+                v.extend_from_slice(data.item_substs.regions.as_slice());
+                v
             }
             TyFnDef(..) |
             TyFnPtr(_) |
             TyBool |
             TyChar |
+            TyNever |
             TyInt(_) |
             TyUint(_) |
             TyFloat(_) |
             TyBox(_) |
             TyStr |
             TyArray(_, _) |
+            TyArrayParam(_, _) |
             TySlice(_) |
             TyRawPtr(_) |
             TyTuple(_) |
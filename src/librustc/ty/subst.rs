@@ -26,7 +26,7 @@ use syntax::codemap::{Span, DUMMY_SP};
 
 ///////////////////////////////////////////////////////////////////////////
 
-/// A substitution mapping type/region parameters to new values. We
+/// A substitution mapping type/region/const parameters to new values. We
 /// identify each in-scope parameter by an *index* and a *parameter
 /// space* (which indices where the parameter is defined; see
 /// `ParamSpace`).
@@ -34,6 +34,7 @@ use syntax::codemap::{Span, DUMMY_SP};
 pub struct Substs<'tcx> {
     pub types: VecPerParamSpace<Ty<'tcx>>,
     pub regions: VecPerParamSpace<ty::Region>,
+    pub consts: VecPerParamSpace<ty::Const>,
 }
 
 impl<'tcx> Substs<'tcx> {
@@ -41,7 +42,15 @@ impl<'tcx> Substs<'tcx> {
                r: VecPerParamSpace<ty::Region>)
                -> Substs<'tcx>
     {
-        Substs { types: t, regions: r }
+        Substs::new_with_consts(t, r, VecPerParamSpace::empty())
+    }
+
+    pub fn new_with_consts(t: VecPerParamSpace<Ty<'tcx>>,
+                           r: VecPerParamSpace<ty::Region>,
+                           c: VecPerParamSpace<ty::Const>)
+                           -> Substs<'tcx>
+    {
+        Substs { types: t, regions: r, consts: c }
     }
 
     pub fn new_type(t: Vec<Ty<'tcx>>,
@@ -65,17 +74,22 @@ impl<'tcx> Substs<'tcx> {
         Substs {
             types: VecPerParamSpace::empty(),
             regions: VecPerParamSpace::empty(),
+            consts: VecPerParamSpace::empty(),
         }
     }
 
     pub fn is_noop(&self) -> bool {
-        self.regions.is_empty() && self.types.is_empty()
+        self.regions.is_empty() && self.types.is_empty() && self.consts.is_empty()
     }
 
     pub fn type_for_def(&self, ty_param_def: &ty::TypeParameterDef) -> Ty<'tcx> {
         *self.types.get(ty_param_def.space, ty_param_def.index as usize)
     }
 
+    pub fn const_for_def(&self, const_param_def: &ty::ConstParameterDef) -> ty::Const {
+        *self.consts.get(const_param_def.space, const_param_def.index as usize)
+    }
+
     pub fn self_ty(&self) -> Option<Ty<'tcx>> {
         self.types.get_self().cloned()
     }
@@ -88,9 +102,9 @@ impl<'tcx> Substs<'tcx> {
     }
 
     pub fn erase_regions(self) -> Substs<'tcx> {
-        let Substs { types, regions } = self;
+        let Substs { types, regions, consts } = self;
         let regions = regions.map(|_| ty::ReStatic);
-        Substs { types: types, regions: regions }
+        Substs { types: types, regions: regions, consts: consts }
     }
 
     pub fn with_method(self,
@@ -98,39 +112,42 @@ impl<'tcx> Substs<'tcx> {
                        m_regions: Vec<ty::Region>)
                        -> Substs<'tcx>
     {
-        let Substs { types, regions } = self;
+        let Substs { types, regions, consts } = self;
         let types = types.with_slice(FnSpace, &m_types);
         let regions = regions.with_slice(FnSpace, &m_regions);
-        Substs { types: types, regions: regions }
+        Substs { types: types, regions: regions, consts: consts }
     }
 
     pub fn with_method_from(&self,
                             meth_substs: &Substs<'tcx>)
                             -> Substs<'tcx>
     {
-        let Substs { types, regions } = self.clone();
+        let Substs { types, regions, consts } = self.clone();
         let types = types.with_slice(FnSpace, meth_substs.types.get_slice(FnSpace));
         let regions = regions.with_slice(FnSpace, meth_substs.regions.get_slice(FnSpace));
-        Substs { types: types, regions: regions }
+        let consts = consts.with_slice(FnSpace, meth_substs.consts.get_slice(FnSpace));
+        Substs { types: types, regions: regions, consts: consts }
     }
 
     pub fn with_method_from_subst(self, other: &Substs<'tcx>) -> Substs<'tcx> {
-        let Substs { types, regions } = self;
+        let Substs { types, regions, consts } = self;
         let types = types.with_slice(FnSpace, other.types.get_slice(FnSpace));
         let regions = regions.with_slice(FnSpace, other.regions.get_slice(FnSpace));
-        Substs { types: types, regions: regions }
+        let consts = consts.with_slice(FnSpace, other.consts.get_slice(FnSpace));
+        Substs { types: types, regions: regions, consts: consts }
     }
 
     /// Creates a trait-ref out of this substs, ignoring the FnSpace substs
     pub fn to_trait_ref(&self, tcx: &TyCtxt<'tcx>, trait_id: DefId)
                         -> ty::TraitRef<'tcx> {
-        let Substs { mut types, mut regions } = self.clone();
+        let Substs { mut types, mut regions, mut consts } = self.clone();
         types.truncate(FnSpace, 0);
         regions.truncate(FnSpace, 0);
+        consts.truncate(FnSpace, 0);
 
         ty::TraitRef {
             def_id: trait_id,
-            substs: tcx.mk_substs(Substs { types: types, regions: regions })
+            substs: tcx.mk_substs(Substs { types: types, regions: regions, consts: consts })
         }
     }
 }
@@ -648,6 +665,29 @@ impl<'a, 'tcx> TypeFolder<'tcx> for SubstFolder<'a, 'tcx> {
 
         return t1;
     }
+
+    fn fold_const(&mut self, c: ty::Const) -> ty::Const {
+        match c {
+            ty::Const::Param(p) => {
+                match self.substs.consts.opt_get(p.space, p.idx as usize) {
+                    Some(&c) => c,
+                    None => {
+                        let span = self.span.unwrap_or(DUMMY_SP);
+                        span_bug!(
+                            span,
+                            "Const parameter `{}` ({:?}/{}) out of range \
+                             when substituting (root type={:?}) substs={:?}",
+                            p.name,
+                            p.space,
+                            p.idx,
+                            self.root_ty,
+                            self.substs);
+                    }
+                }
+            }
+            _ => c
+        }
+    }
 }
 
 impl<'a,'tcx> SubstFolder<'a,'tcx> {
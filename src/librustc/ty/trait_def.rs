@@ -29,6 +29,11 @@ pub struct TraitDef<'tcx> {
     /// be usable with the sugar (or without it).
     pub paren_sugar: bool,
 
+    /// If `true`, then this is a trait alias (`trait Foo = Bar + Baz;`).
+    /// Its supertraits are the bounds it stands for, it has no items
+    /// of its own and it cannot be implemented.
+    pub is_alias: bool,
+
     /// Generic type definitions. Note that `Self` is listed in here
     /// as having a single bound, the trait itself (e.g., in the trait
     /// `Eq`, there is a single bound `Self : Eq`). This is so that
@@ -76,12 +81,14 @@ pub struct TraitDef<'tcx> {
 impl<'tcx> TraitDef<'tcx> {
     pub fn new(unsafety: hir::Unsafety,
                paren_sugar: bool,
+               is_alias: bool,
                generics: ty::Generics<'tcx>,
                trait_ref: ty::TraitRef<'tcx>,
                associated_type_names: Vec<Name>)
                -> TraitDef<'tcx> {
         TraitDef {
             paren_sugar: paren_sugar,
+            is_alias: is_alias,
             unsafety: unsafety,
             generics: generics,
             trait_ref: trait_ref,
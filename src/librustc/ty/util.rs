@@ -395,9 +395,10 @@ impl<'tcx> TyCtxt<'tcx> {
                     TyStr => {
                         byte!(7);
                     }
-                    TyEnum(d, _) => {
+                    TyEnum(d, substs) => {
                         byte!(8);
                         did(state, d.did);
+                        hash!(substs.consts);
                     }
                     TyBox(_) => {
                         byte!(9);
@@ -406,6 +407,10 @@ impl<'tcx> TyCtxt<'tcx> {
                         byte!(10);
                         n.hash(state);
                     }
+                    TyArrayParam(_, n) => {
+                        byte!(26);
+                        n.hash(state);
+                    }
                     TySlice(_) => {
                         byte!(11);
                     }
@@ -441,9 +446,10 @@ impl<'tcx> TyCtxt<'tcx> {
 
                         return false;
                     }
-                    TyStruct(d, _) => {
+                    TyStruct(d, substs) => {
                         byte!(18);
                         did(state, d.did);
+                        hash!(substs.consts);
                     }
                     TyTuple(ref inner) => {
                         byte!(19);
@@ -466,6 +472,11 @@ impl<'tcx> TyCtxt<'tcx> {
                         did(state, data.trait_ref.def_id);
                         hash!(data.item_name.as_str());
                     }
+                    TyAnon(d, _) => {
+                        byte!(24);
+                        did(state, d);
+                    }
+                    TyNever => byte!(25),
                 }
                 true
             });
@@ -535,7 +546,7 @@ impl<'tcx> ty::TyS<'tcx> {
 
         // Fast-path for primitive types
         let result = match self.sty {
-            TyBool | TyChar | TyInt(..) | TyUint(..) | TyFloat(..) |
+            TyBool | TyChar | TyInt(..) | TyUint(..) | TyFloat(..) | TyNever |
             TyRawPtr(..) | TyFnDef(..) | TyFnPtr(_) | TyRef(_, TypeAndMut {
                 mutbl: hir::MutImmutable, ..
             }) => Some(false),
@@ -544,9 +555,9 @@ impl<'tcx> ty::TyS<'tcx> {
                 mutbl: hir::MutMutable, ..
             }) => Some(true),
 
-            TyArray(..) | TySlice(_) | TyTrait(..) | TyTuple(..) |
+            TyArray(..) | TyArrayParam(..) | TySlice(_) | TyTrait(..) | TyTuple(..) |
             TyClosure(..) | TyEnum(..) | TyStruct(..) |
-            TyProjection(..) | TyParam(..) | TyInfer(..) | TyError => None
+            TyProjection(..) | TyParam(..) | TyAnon(..) | TyInfer(..) | TyError => None
         }.unwrap_or_else(|| !self.impls_bound(param_env, ty::BoundCopy, span));
 
         if !self.has_param_types() && !self.has_self_ty() {
@@ -579,12 +590,13 @@ impl<'tcx> ty::TyS<'tcx> {
         let result = match self.sty {
             TyBool | TyChar | TyInt(..) | TyUint(..) | TyFloat(..) |
             TyBox(..) | TyRawPtr(..) | TyRef(..) | TyFnDef(..) | TyFnPtr(_) |
-            TyArray(..) | TyTuple(..) | TyClosure(..) => Some(true),
+            TyArray(..) | TyArrayParam(..) | TyTuple(..) | TyClosure(..) |
+            TyNever => Some(true),
 
             TyStr | TyTrait(..) | TySlice(_) => Some(false),
 
             TyEnum(..) | TyStruct(..) | TyProjection(..) | TyParam(..) |
-            TyInfer(..) | TyError => None
+            TyAnon(..) | TyInfer(..) | TyError => None
         }.unwrap_or_else(|| self.impls_bound(param_env, ty::BoundSized, span));
 
         if !self.has_param_types() && !self.has_self_ty() {
@@ -640,7 +652,7 @@ impl<'tcx> ty::TyS<'tcx> {
                 }
                 // Fixed-length vectors.
                 // FIXME(#11924) Behavior undecided for zero-length vectors.
-                TyArray(ty, _) => {
+                TyArray(ty, _) | TyArrayParam(ty, _) => {
                     is_type_structurally_recursive(cx, sp, seen, ty)
                 }
                 TyStruct(def, substs) | TyEnum(def, substs) => {
@@ -70,15 +70,16 @@ pub fn walk_shallow<'tcx>(ty: Ty<'tcx>) -> IntoIter<Ty<'tcx>> {
 fn push_subtypes<'tcx>(stack: &mut Vec<Ty<'tcx>>, parent_ty: Ty<'tcx>) {
     match parent_ty.sty {
         ty::TyBool | ty::TyChar | ty::TyInt(_) | ty::TyUint(_) | ty::TyFloat(_) |
-        ty::TyStr | ty::TyInfer(_) | ty::TyParam(_) | ty::TyError => {
+        ty::TyStr | ty::TyInfer(_) | ty::TyParam(_) | ty::TyNever | ty::TyError => {
         }
-        ty::TyBox(ty) | ty::TyArray(ty, _) | ty::TySlice(ty) => {
+        ty::TyBox(ty) | ty::TyArray(ty, _) | ty::TyArrayParam(ty, _) | ty::TySlice(ty) => {
             stack.push(ty);
         }
         ty::TyRawPtr(ref mt) | ty::TyRef(_, ref mt) => {
             stack.push(mt.ty);
         }
         ty::TyProjection(ref data) => {
+            push_reversed(stack, data.item_substs.types.as_slice());
             push_reversed(stack, data.trait_ref.substs.types.as_slice());
         }
         ty::TyTrait(box ty::TraitTy { ref principal, ref bounds }) => {
@@ -95,6 +96,9 @@ fn push_subtypes<'tcx>(stack: &mut Vec<Ty<'tcx>>, parent_ty: Ty<'tcx>) {
             push_reversed(stack, substs.func_substs.types.as_slice());
             push_reversed(stack, &substs.upvar_tys);
         }
+        ty::TyAnon(_, ref substs) => {
+            push_reversed(stack, substs.types.as_slice());
+        }
         ty::TyTuple(ref ts) => {
             push_reversed(stack, ts);
         }
@@ -273,6 +273,23 @@ impl<'a,'tcx> WfPredicates<'a,'tcx> {
 
         self.compute_trait_ref(&data.trait_ref);
 
+        // The arguments of a generic associated type must be WF and
+        // satisfy its where clauses as well.
+        if !data.item_substs.is_noop() {
+            let (def_id, substs) = self.infcx.tcx.projection_bounds_source(&data);
+            let obligations = self.nominal_obligations(def_id, substs);
+            self.out.extend(obligations);
+
+            let cause = self.cause(traits::MiscObligation);
+            self.out.extend(
+                data.item_substs.types
+                                .as_slice()
+                                .iter()
+                                .filter(|ty| !ty.has_escaping_regions())
+                                .map(|ty| traits::Obligation::new(cause.clone(),
+                                                                  ty::Predicate::WellFormed(ty))));
+        }
+
         if !data.has_escaping_regions() {
             let predicate = data.trait_ref.to_predicate();
             let cause = self.cause(traits::ProjectionWf(data));
@@ -295,12 +312,14 @@ impl<'a,'tcx> WfPredicates<'a,'tcx> {
                 ty::TyFloat(..) |
                 ty::TyError |
                 ty::TyStr |
+                ty::TyNever |
                 ty::TyParam(_) => {
                     // WfScalar, WfParameter, etc
                 }
 
                 ty::TySlice(subty) |
-                ty::TyArray(subty, _) => {
+                ty::TyArray(subty, _) |
+                ty::TyArrayParam(subty, _) => {
                     if !subty.has_escaping_regions() {
                         let cause = self.cause(traits::SliceOrArrayElem);
                         match traits::trait_ref_for_builtin_bound(self.infcx.tcx,
@@ -318,7 +337,8 @@ impl<'a,'tcx> WfPredicates<'a,'tcx> {
 
                 ty::TyBox(_) |
                 ty::TyTuple(_) |
-                ty::TyRawPtr(_) => {
+                ty::TyRawPtr(_) |
+                ty::TyAnon(..) => {
                     // simple cases that are WF if their type args are WF
                 }
 
@@ -13,8 +13,8 @@ use hir::def_id::DefId;
 use ty::subst::{self, Subst};
 use ty::{BrAnon, BrEnv, BrFresh, BrNamed};
 use ty::{TyBool, TyChar, TyStruct, TyEnum};
-use ty::{TyError, TyStr, TyArray, TySlice, TyFloat, TyFnDef, TyFnPtr};
-use ty::{TyParam, TyRawPtr, TyRef, TyTuple};
+use ty::{TyError, TyStr, TyArray, TyArrayParam, TySlice, TyFloat, TyFnDef, TyFnPtr};
+use ty::{TyParam, TyRawPtr, TyRef, TyNever, TyTuple};
 use ty::TyClosure;
 use ty::{TyBox, TyTrait, TyInt, TyUint, TyInfer};
 use ty::{self, Ty, TyCtxt, TypeFoldable};
@@ -192,6 +192,11 @@ pub fn parameterized<GG>(f: &mut fmt::Formatter,
         write!(f, "{}", ty)?;
     }
 
+    for c in substs.consts.get_slice(subst::TypeSpace) {
+        start_or_continue(f, "<", ", ")?;
+        write!(f, "{}", c)?;
+    }
+
     for projection in projections {
         start_or_continue(f, "<", ", ")?;
         write!(f, "{}={}",
@@ -357,6 +362,16 @@ impl<'tcx> fmt::Debug for ty::TypeParameterDef<'tcx> {
     }
 }
 
+impl<'tcx> fmt::Debug for ty::ConstParameterDef<'tcx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ConstParameterDef({}, {:?}, {:?}/{}, {:?})",
+               self.name,
+               self.def_id,
+               self.space, self.index,
+               self.ty)
+    }
+}
+
 impl fmt::Debug for ty::RegionParameterDef {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "RegionParameterDef({}, {:?}, {:?}/{}, {:?})",
@@ -383,8 +398,8 @@ impl<'tcx> fmt::Display for ty::TypeAndMut<'tcx> {
 
 impl<'tcx> fmt::Debug for subst::Substs<'tcx> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Substs[types={:?}, regions={:?}]",
-               self.types, self.regions)
+        write!(f, "Substs[types={:?}, regions={:?}, consts={:?}]",
+               self.types, self.regions, self.consts)
     }
 }
 
@@ -433,6 +448,9 @@ impl<'tcx> fmt::Debug for ty::adjustment::AutoAdjustment<'tcx> {
             ty::adjustment::AdjustMutToConstPointer => {
                 write!(f, "AdjustMutToConstPointer")
             }
+            ty::adjustment::AdjustNeverToAny(ref target) => {
+                write!(f, "AdjustNeverToAny({:?})", target)
+            }
             ty::adjustment::AdjustDerefRef(ref data) => {
                 write!(f, "{:?}", data)
             }
@@ -723,6 +741,12 @@ impl fmt::Debug for ty::FloatVid {
     }
 }
 
+impl fmt::Debug for ty::ConstVid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "_#{}c", self.index)
+    }
+}
+
 impl fmt::Debug for ty::RegionVid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "'_#{}r", self.index)
@@ -890,7 +914,38 @@ impl<'tcx> fmt::Display for ty::TypeVariants<'tcx> {
             }
             TyTrait(ref data) => write!(f, "{}", data),
             ty::TyProjection(ref data) => write!(f, "{}", data),
+            ty::TyAnon(def_id, _) => ty::tls::with(|tcx| {
+                if def_id.is_local() && !tcx.predicates.borrow().contains_key(&def_id) {
+                    return write!(f, "impl Trait");
+                }
+
+                // Print `impl Bound1 + Bound2` from the recorded bounds of
+                // the anonymized type, leaving out the implicit `Sized`.
+                let predicates = tcx.lookup_predicates(def_id).predicates;
+                write!(f, "impl")?;
+                let mut sep = " ";
+                for predicate in predicates.iter() {
+                    let trait_ref = match *predicate {
+                        ty::Predicate::Trait(ref data) => data.0.trait_ref,
+                        _ => continue
+                    };
+                    if Some(trait_ref.def_id) == tcx.lang_items.sized_trait() {
+                        continue;
+                    }
+                    let projections: Vec<_> = predicates.iter().filter_map(|p| match *p {
+                        ty::Predicate::Projection(ref data)
+                            if data.0.projection_ty.trait_ref.def_id == trait_ref.def_id => {
+                            Some(data.0.clone())
+                        }
+                        _ => None
+                    }).collect();
+                    write!(f, "{}{}", sep, TraitAndProjections(trait_ref, projections))?;
+                    sep = " + ";
+                }
+                Ok(())
+            }),
             TyStr => write!(f, "str"),
+            TyNever => write!(f, "!"),
             TyClosure(did, ref substs) => ty::tls::with(|tcx| {
                 write!(f, "[closure")?;
 
@@ -923,6 +978,7 @@ impl<'tcx> fmt::Display for ty::TypeVariants<'tcx> {
                 write!(f, "]")
             }),
             TyArray(ty, sz) => write!(f, "[{}; {}]",  ty, sz),
+            TyArrayParam(ty, sz) => write!(f, "[{}; {}]",  ty, sz),
             TySlice(ty) => write!(f, "[{}]",  ty)
         }
     }
@@ -991,6 +1047,39 @@ impl fmt::Debug for ty::ParamTy {
     }
 }
 
+impl fmt::Display for ty::ParamConst {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl fmt::Debug for ty::ParamConst {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{:?}.{}", self, self.space, self.idx)
+    }
+}
+
+impl fmt::Display for ty::Const {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ty::Const::Value(v) => write!(f, "{}", v.erase_type()),
+            ty::Const::Param(ref p) => write!(f, "{}", p),
+            ty::Const::Infer(ref vid) if verbose() => write!(f, "{:?}", vid),
+            ty::Const::Infer(_) => write!(f, "_"),
+        }
+    }
+}
+
+impl fmt::Debug for ty::Const {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ty::Const::Value(v) => write!(f, "{}", v),
+            ty::Const::Param(ref p) => write!(f, "{:?}", p),
+            ty::Const::Infer(ref vid) => write!(f, "{:?}", vid),
+        }
+    }
+}
+
 impl<'tcx, T, U> fmt::Display for ty::OutlivesPredicate<T,U>
     where T: fmt::Display, U: fmt::Display
 {
@@ -1038,7 +1127,17 @@ impl<'tcx> fmt::Display for ty::ProjectionTy<'tcx> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}::{}",
                self.trait_ref,
-               self.item_name)
+               self.item_name)?;
+
+        let regions = self.item_substs.regions.get_slice(subst::FnSpace);
+        let types = self.item_substs.types.get_slice(subst::FnSpace);
+        if regions.is_empty() && types.is_empty() {
+            return Ok(());
+        }
+
+        let mut strings: Vec<String> = regions.iter().map(|r| r.to_string()).collect();
+        strings.extend(types.iter().map(|t| t.to_string()));
+        write!(f, "<{}>", strings.join(", "))
     }
 }
 
@@ -52,3 +52,63 @@ pub mod sha2;
 pub mod target;
 pub mod slice;
 pub mod dynamic_lib;
+
+/// How a crate handles `panic!`: either by unwinding the stack or by aborting
+/// the process outright.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PanicStrategy {
+    Unwind,
+    Abort,
+}
+
+impl PanicStrategy {
+    pub fn desc(&self) -> &str {
+        match *self {
+            PanicStrategy::Unwind => "unwind",
+            PanicStrategy::Abort => "abort",
+        }
+    }
+}
+
+/// The kind of linker being driven, which determines how arguments are passed
+/// to it.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LinkerFlavor {
+    /// A C compiler, e.g. `cc`, driving the system linker.
+    Gcc,
+    /// A C compiler driving the gold linker with `-fuse-ld=gold`.
+    Gold,
+    /// LLD's ELF flavor, `ld.lld`, invoked directly.
+    LdLld,
+    /// Microsoft's `link.exe`.
+    Msvc,
+    /// LLD's COFF flavor, `lld-link`, invoked directly.
+    LldLink,
+    /// LLD's WebAssembly flavor, `wasm-ld`, invoked directly.
+    WasmLd,
+}
+
+impl LinkerFlavor {
+    pub fn from_str(s: &str) -> Option<LinkerFlavor> {
+        Some(match s {
+            "gcc" => LinkerFlavor::Gcc,
+            "gold" => LinkerFlavor::Gold,
+            "ld.lld" => LinkerFlavor::LdLld,
+            "msvc" => LinkerFlavor::Msvc,
+            "lld-link" => LinkerFlavor::LldLink,
+            "wasm-ld" => LinkerFlavor::WasmLd,
+            _ => return None,
+        })
+    }
+
+    pub fn desc(&self) -> &str {
+        match *self {
+            LinkerFlavor::Gcc => "gcc",
+            LinkerFlavor::Gold => "gold",
+            LinkerFlavor::LdLld => "ld.lld",
+            LinkerFlavor::Msvc => "msvc",
+            LinkerFlavor::LldLink => "lld-link",
+            LinkerFlavor::WasmLd => "wasm-ld",
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Targets bare-metal RV32 cores with the multiply, atomic and compressed
+// extensions, using the soft-float ilp32 ABI.
+
+use PanicStrategy;
+use target::{Target, TargetOptions};
+
+pub fn target() -> Target {
+    Target {
+        llvm_target: "riscv32".to_string(),
+        target_endian: "little".to_string(),
+        target_pointer_width: "32".to_string(),
+        target_os: "none".to_string(),
+        target_env: "".to_string(),
+        target_vendor: "unknown".to_string(),
+        arch: "riscv32".to_string(),
+        data_layout: "e-m:e-p:32:32-i64:64-n32-S128".to_string(),
+
+        options: TargetOptions {
+            linker: "riscv32-unknown-elf-gcc".to_string(),
+            cpu: "generic-rv32".to_string(),
+            features: "+m,+a,+c".to_string(),
+            executables: true,
+            dynamic_linking: false,
+            relocation_model: "static".to_string(),
+            no_default_libraries: true,
+            no_compiler_rt: true,
+            panic_strategy: PanicStrategy::Abort,
+            .. Default::default()
+        },
+    }
+}
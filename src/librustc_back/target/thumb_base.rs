@@ -0,0 +1,32 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// These are the targets for ARM Cortex-M microcontrollers, which only execute
+// the Thumb instruction set and have no operating system underneath them. The
+// resulting binaries are statically linked firmware images, so there's no
+// dynamic linking, no position independent code and no unwinding.
+
+use PanicStrategy;
+use target::TargetOptions;
+use std::default::Default;
+
+pub fn opts() -> TargetOptions {
+    TargetOptions {
+        linker: "arm-none-eabi-gcc".to_string(),
+        executables: true,
+        dynamic_linking: false,
+        relocation_model: "static".to_string(),
+        // There's no libc or libgcc_s to fall back on
+        no_default_libraries: true,
+        no_compiler_rt: true,
+        panic_strategy: PanicStrategy::Abort,
+        .. Default::default()
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use {LinkerFlavor, PanicStrategy};
+use super::{Target, TargetOptions};
+
+pub fn target() -> Target {
+    let opts = TargetOptions {
+        linker: "wasm-ld".to_string(),
+        linker_flavor: LinkerFlavor::WasmLd,
+        // There's no loader to call `main` for us, so it's exported and left
+        // to the embedder, which also provides any undefined imports.
+        pre_link_args: vec!["--no-entry".to_string(),
+                            "--export=main".to_string(),
+                            "--allow-undefined".to_string()],
+        dynamic_linking: false,
+        executables: true,
+        exe_suffix: ".wasm".to_string(),
+        relocation_model: "static".to_string(),
+        is_like_wasm: true,
+        no_compiler_rt: true,
+        allow_asm: false,
+        panic_strategy: PanicStrategy::Abort,
+        lib_allocation_crate: "alloc_system".to_string(),
+        exe_allocation_crate: "alloc_system".to_string(),
+        .. Default::default()
+    };
+    Target {
+        llvm_target: "wasm32-unknown-unknown".to_string(),
+        target_endian: "little".to_string(),
+        target_pointer_width: "32".to_string(),
+        target_os: "unknown".to_string(),
+        target_env: "".to_string(),
+        target_vendor: "unknown".to_string(),
+        arch: "wasm32".to_string(),
+        data_layout: "e-m:e-p:32:32-i64:64-n32:64-S128".to_string(),
+        options: opts,
+    }
+}
@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use syntax::attr::AttrMetaMethods;
+use syntax::attr::{AttrMetaMethods, AttrNestedMetaItemMethods};
 
 use rustc::ty::TyCtxt;
 use rustc::mir::repr::{self, Mir};
@@ -215,7 +215,7 @@ impl<'b, 'a: 'b, 'tcx: 'a> MirBorrowckCtxt<'b, 'a, 'tcx> {
                         } else {
                             self.bcx.tcx.sess.span_err(
                                 item.span,
-                                &format!("{} attribute requires a path", item.name()));
+                                &format!("{} attribute requires a path", name));
                         }
                     }
                 }
@@ -42,7 +42,7 @@ use syntax::codemap::{Span, Spanned, DUMMY_SP};
 use rustc::hir::fold::{Folder, noop_fold_pat};
 use rustc::hir::print::pat_to_string;
 use syntax::ptr::P;
-use rustc::util::nodemap::FnvHashMap;
+use rustc::util::nodemap::{FnvHashMap, FnvHashSet};
 
 pub const DUMMY_WILD_PAT: &'static Pat = &Pat {
     id: DUMMY_NODE_ID,
@@ -210,9 +210,16 @@ fn check_expr(cx: &mut MatchCheckCtxt, ex: &hir::Expr) {
 
             // Finally, check if the whole match expression is exhaustive.
             // Check for empty enum, because is_useful only works on inhabited types.
+            // With `feature(never_type)`, any uninhabited type may be matched
+            // with no arms.
             let pat_ty = cx.tcx.node_id_to_type(scrut.id);
             if inlined_arms.is_empty() {
-                if !pat_ty.is_empty(cx.tcx) {
+                let is_empty = if cx.tcx.sess.features.borrow().never_type {
+                    pat_ty.is_uninhabited(cx.tcx)
+                } else {
+                    pat_ty.is_empty(cx.tcx)
+                };
+                if !is_empty {
                     // We know the type is inhabited, so this must be wrong
                     let mut err = struct_span_err!(cx.tcx.sess, ex.span, E0002,
                                                    "non-exhaustive patterns: type {} is non-empty",
@@ -645,8 +652,9 @@ impl Constructor {
     }
 }
 
-fn missing_constructors(cx: &MatchCheckCtxt, &Matrix(ref rows): &Matrix,
-                       left_ty: Ty, max_slice_length: usize) -> Vec<Constructor> {
+fn missing_constructors<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>, &Matrix(ref rows): &Matrix,
+                                  left_ty: Ty<'tcx>, max_slice_length: usize)
+                                  -> Vec<Constructor> {
     let used_constructors: Vec<Constructor> = rows.iter()
         .flat_map(|row| pat_constructors(cx, row[0], left_ty, max_slice_length))
         .collect();
@@ -659,9 +667,11 @@ fn missing_constructors(cx: &MatchCheckCtxt, &Matrix(ref rows): &Matrix,
 /// This determines the set of all possible constructors of a pattern matching
 /// values of type `left_ty`. For vectors, this would normally be an infinite set
 /// but is instead bounded by the maximum fixed length of slice patterns in
-/// the column of patterns being analyzed.
-fn all_constructors(_cx: &MatchCheckCtxt, left_ty: Ty,
-                    max_slice_length: usize) -> Vec<Constructor> {
+/// the column of patterns being analyzed. With `feature(never_type)`, enum
+/// variants that can never be constructed are left out, so matches need not
+/// cover them.
+fn all_constructors<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>, left_ty: Ty<'tcx>,
+                              max_slice_length: usize) -> Vec<Constructor> {
     match left_ty.sty {
         ty::TyBool =>
             [true, false].iter().map(|b| ConstantValue(ConstVal::Bool(*b))).collect(),
@@ -672,7 +682,15 @@ fn all_constructors(_cx: &MatchCheckCtxt, left_ty: Ty,
             _ => vec![Single]
         },
 
-        ty::TyEnum(def, _) => def.variants.iter().map(|v| Variant(v.did)).collect(),
+        ty::TyEnum(def, substs) => {
+            let never_type = cx.tcx.sess.features.borrow().never_type;
+            def.variants.iter()
+                .filter(|v| {
+                    !never_type || !v.is_uninhabited_recurse(&mut FnvHashSet(), cx.tcx, substs)
+                })
+                .map(|v| Variant(v.did))
+                .collect()
+        }
         _ => vec![Single]
     }
 }
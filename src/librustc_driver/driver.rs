@@ -231,7 +231,8 @@ pub fn compile_input(sess: &Session,
             }
             let trans = phase_4_translate_to_llvm(tcx,
                                                   mir_map.unwrap(),
-                                                  analysis);
+                                                  analysis,
+                                                  &outputs);
 
             if log_enabled!(::log::INFO) {
                 println!("Post-trans");
@@ -490,6 +491,9 @@ pub fn phase_1_parse_input<'a>(sess: &'a Session,
     sess.diagnostic().set_continue_after_error(continue_after_error);
 
     let krate = time(sess.time_passes(), "parsing", || {
+        if sess.opts.debugging_opts.parse_recovery {
+            return Ok(parse_input_with_recovery(sess, cfg.clone(), input));
+        }
         match *input {
             Input::File(ref file) => {
                 parse::parse_crate_from_file(file, cfg.clone(), &sess.parse_sess)
@@ -521,6 +525,31 @@ pub fn phase_1_parse_input<'a>(sess: &'a Session,
     Ok(krate)
 }
 
+/// Parse the input in error-recovery mode, reporting every error recovered
+/// from. The placeholders in the resulting crate must not make it any further
+/// than parsing, so this aborts if there were any errors.
+fn parse_input_with_recovery(sess: &Session,
+                             cfg: ast::CrateConfig,
+                             input: &Input)
+                             -> ast::Crate {
+    let (krate, errors) = match *input {
+        Input::File(ref file) => {
+            parse::parse_crate_from_file_with_recovery(file, cfg, &sess.parse_sess)
+        }
+        Input::Str { ref input, ref name } => {
+            parse::parse_crate_from_source_str_with_recovery(name.clone(),
+                                                             input.clone(),
+                                                             cfg,
+                                                             &sess.parse_sess)
+        }
+    };
+    for mut err in errors {
+        err.emit();
+    }
+    sess.abort_if_errors();
+    krate
+}
+
 fn count_nodes(krate: &ast::Crate) -> usize {
     let mut counter = NodeCounter::new();
     visit::walk_crate(&mut counter, krate);
@@ -717,8 +746,7 @@ pub fn phase_2_configure_and_expand(sess: &Session,
     // Needs to go *after* expansion to be able to check the results of macro expansion.
     time(time_passes, "complete gated feature checking", || {
         sess.track_errors(|| {
-            let features = syntax::feature_gate::check_crate(sess.codemap(),
-                                                             &sess.parse_sess.span_diagnostic,
+            let features = syntax::feature_gate::check_crate(&sess.parse_sess,
                                                              &krate,
                                                              &attributes,
                                                              sess.opts.unstable_features);
@@ -830,7 +858,7 @@ pub fn phase_3_run_analysis_passes<'tcx, F, R>(sess: &'tcx Session,
 
     time(time_passes,
          "loop checking",
-         || loops::check_crate(sess, &hir_map));
+         || loops::check_crate(sess, &hir_map, &def_map.borrow()));
 
     time(time_passes,
               "static item recursion checking",
@@ -959,7 +987,9 @@ pub fn phase_3_run_analysis_passes<'tcx, F, R>(sess: &'tcx Session,
 /// Run the translation phase to LLVM, after which the AST and analysis can
 pub fn phase_4_translate_to_llvm<'tcx>(tcx: &TyCtxt<'tcx>,
                                        mut mir_map: MirMap<'tcx>,
-                                       analysis: ty::CrateAnalysis) -> trans::CrateTranslation {
+                                       analysis: ty::CrateAnalysis,
+                                       outputs: &OutputFilenames)
+                                       -> trans::CrateTranslation {
     let time_passes = tcx.sess.time_passes();
 
     time(time_passes,
@@ -980,7 +1010,7 @@ pub fn phase_4_translate_to_llvm<'tcx>(tcx: &TyCtxt<'tcx>,
     let translation =
         time(time_passes,
              "translation",
-             move || trans::trans_crate(tcx, &mir_map, analysis));
+             move || trans::trans_crate(tcx, &mir_map, analysis, outputs));
 
     time(time_passes,
          "assert dep graph",
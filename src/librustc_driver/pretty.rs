@@ -40,6 +40,7 @@ use syntax::util::small_vector::SmallVector;
 
 use graphviz as dot;
 
+use std::cmp;
 use std::fs::File;
 use std::io::{self, Write};
 use std::iter;
@@ -148,6 +149,51 @@ pub fn parse_pretty(sess: &Session,
     (first, opt_second)
 }
 
+/// The layout of pretty-printed source, as configured with `-Z pretty-style`.
+fn print_config(sess: &Session) -> pprust::PrintConfig {
+    let mut config = pprust::PrintConfig::default();
+    let style = match sess.opts.debugging_opts.pretty_style {
+        Some(ref style) => style,
+        None => return config,
+    };
+    for option in style.split(',') {
+        let mut split = option.splitn(2, '=');
+        let name = split.next().unwrap().trim();
+        match (name, split.next().map(|v| v.trim())) {
+            ("width", Some(value)) | ("indent", Some(value)) => {
+                let n = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => sess.fatal(&format!("`-Z pretty-style` expects a positive number \
+                                              for `{}`; got `{}`",
+                                             name, value)),
+                };
+                if name == "width" {
+                    config.max_width = n;
+                } else {
+                    config.indent = n;
+                }
+            }
+            ("brace-style", Some("same-line")) => {
+                config.brace_style = pprust::BraceStyle::SameLine;
+            }
+            ("brace-style", Some("next-line")) => {
+                config.brace_style = pprust::BraceStyle::NextLine;
+            }
+            ("trailing-comments", None) => {
+                config.trailing_comments = true;
+            }
+            _ => {
+                sess.fatal(&format!("options to `-Z pretty-style` must be one of `width=N`, \
+                                     `indent=N`, `brace-style=same-line`, \
+                                     `brace-style=next-line` or `trailing-comments`; \
+                                     got `{}`",
+                                    option));
+            }
+        }
+    }
+    config
+}
+
 
 
 // This slightly awkward construction is to allow for each PpMode to
@@ -762,6 +808,32 @@ fn get_source(input: &Input, sess: &Session) -> (Vec<u8>, String) {
     (src, src_name)
 }
 
+/// For `-Z pretty-check`: report an error at the first line of the source
+/// that differs from its pretty-printed form `out`.
+fn check_formatted(sess: &Session, src: &[u8], src_name: &str, out: &[u8]) {
+    if src == out {
+        return;
+    }
+
+    let src = String::from_utf8_lossy(src);
+    let out = String::from_utf8_lossy(out);
+    let diff = src.char_indices()
+                  .zip(out.chars())
+                  .find(|&((_, a), b)| a != b)
+                  .map_or(cmp::min(src.len(), out.len()), |((i, _), _)| i);
+    let line_start = src[..diff].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = src[line_start..].find('\n').map_or(src.len(), |i| line_start + i);
+    let expected = out[line_start..].lines().next().unwrap_or("");
+
+    let filemap = sess.codemap().get_filemap(src_name);
+    let sp = codemap::mk_sp(filemap.start_pos + codemap::BytePos(line_start as u32),
+                            filemap.start_pos + codemap::BytePos(line_end as u32));
+    sess.struct_span_err(sp, "source is not laid out in the configured pretty-printing style")
+        .note(&format!("the pretty printer prints this line as `{}`", expected))
+        .emit();
+    sess.abort_if_errors();
+}
+
 fn write_output(out: Vec<u8>, ofile: Option<&Path>) {
     match ofile {
         None => print!("{}", String::from_utf8(out).unwrap()),
@@ -783,6 +855,11 @@ pub fn print_after_parsing(sess: &Session,
     let _ignore = dep_graph.in_ignore();
 
     let (src, src_name) = get_source(input, sess);
+    let config = print_config(sess);
+    let check = sess.opts.debugging_opts.pretty_check;
+    if check && ppm != PpmSource(PpmNormal) {
+        sess.fatal("`-Z pretty-check` can only be used with `--pretty normal`");
+    }
 
     let mut rdr = &*src;
     let mut out = Vec::new();
@@ -800,13 +877,18 @@ pub fn print_after_parsing(sess: &Session,
                                 &mut rdr,
                                 out,
                                 annotation.pp_ann(),
-                                false)
+                                false,
+                                config)
         }).unwrap()
     } else {
         unreachable!();
     };
 
-    write_output(out, ofile);
+    if check {
+        check_formatted(sess, &src, &src_name, &out);
+    } else {
+        write_output(out, ofile);
+    }
 }
 
 pub fn print_after_write_deps<'tcx, 'a: 'tcx>(sess: &'a Session,
@@ -821,12 +903,17 @@ pub fn print_after_write_deps<'tcx, 'a: 'tcx>(sess: &'a Session,
     let dep_graph = DepGraph::new(false);
     let _ignore = dep_graph.in_ignore();
 
+    if sess.opts.debugging_opts.pretty_check {
+        sess.fatal("`-Z pretty-check` can only be used with `--pretty normal`");
+    }
+
     if ppm.needs_analysis() {
         print_with_analysis(sess, ast_map, crate_name, arenas, ppm, opt_uii, ofile);
         return;
     }
 
     let (src, src_name) = get_source(input, sess);
+    let config = print_config(sess);
 
     let mut rdr = &src[..];
     let mut out = Vec::new();
@@ -845,7 +932,8 @@ pub fn print_after_write_deps<'tcx, 'a: 'tcx>(sess: &'a Session,
                                     &mut rdr,
                                     out,
                                     annotation.pp_ann(),
-                                    true)
+                                    true,
+                                    config)
             })
         }
 
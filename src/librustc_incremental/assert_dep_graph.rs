@@ -55,7 +55,7 @@ use std::env;
 use std::fs::File;
 use std::io::Write;
 use syntax::ast;
-use syntax::attr::AttrMetaMethods;
+use syntax::attr::{AttrMetaMethods, AttrNestedMetaItemMethods};
 use syntax::codemap::Span;
 use syntax::parse::token::InternedString;
 
@@ -108,13 +108,13 @@ impl<'a, 'tcx> IfThisChanged<'a, 'tcx> {
         for attr in self.tcx.get_attrs(def_id).iter() {
             if attr.check_name(IF_THIS_CHANGED) {
                 let mut id = None;
-                for meta_item in attr.meta_item_list().unwrap_or_default() {
-                    match meta_item.node {
-                        ast::MetaItemKind::Word(ref s) if id.is_none() => id = Some(s.clone()),
+                for list_item in attr.meta_item_list().unwrap_or_default() {
+                    match list_item.word() {
+                        Some(word) if id.is_none() => id = Some(word.name()),
                         _ => {
                             self.tcx.sess.span_err(
-                                meta_item.span,
-                                &format!("unexpected meta-item {:?}", meta_item.node));
+                                list_item.span,
+                                &format!("unexpected meta-item {:?}", list_item.node));
                         }
                     }
                 }
@@ -125,16 +125,15 @@ impl<'a, 'tcx> IfThisChanged<'a, 'tcx> {
             } else if attr.check_name(THEN_THIS_WOULD_NEED) {
                 let mut dep_node_interned = None;
                 let mut id = None;
-                for meta_item in attr.meta_item_list().unwrap_or_default() {
-                    match meta_item.node {
-                        ast::MetaItemKind::Word(ref s) if dep_node_interned.is_none() =>
-                            dep_node_interned = Some(s.clone()),
-                        ast::MetaItemKind::Word(ref s) if id.is_none() =>
-                            id = Some(s.clone()),
+                for list_item in attr.meta_item_list().unwrap_or_default() {
+                    match list_item.word() {
+                        Some(word) if dep_node_interned.is_none() =>
+                            dep_node_interned = Some(word.name()),
+                        Some(word) if id.is_none() => id = Some(word.name()),
                         _ => {
                             self.tcx.sess.span_err(
-                                meta_item.span,
-                                &format!("unexpected meta-item {:?}", meta_item.node));
+                                list_item.span,
+                                &format!("unexpected meta-item {:?}", list_item.node));
                         }
                     }
                 }
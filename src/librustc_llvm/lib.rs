@@ -113,6 +113,19 @@ pub enum Linkage {
     CommonLinkage = 14,
 }
 
+/// A source region whose execution count is given by a single coverage
+/// counter, mirrors `LLVMRustCounterMappingRegion` in rustllvm.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct CounterMappingRegion {
+    pub counter: u32,
+    pub file_id: u32,
+    pub line_start: u32,
+    pub column_start: u32,
+    pub line_end: u32,
+    pub column_end: u32,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 pub enum DiagnosticSeverity {
@@ -482,6 +495,9 @@ pub type ObjectFileRef = *mut ObjectFile_opaque;
 pub enum SectionIterator_opaque {}
 pub type SectionIteratorRef = *mut SectionIterator_opaque;
 #[allow(missing_copy_implementations)]
+pub enum SymbolIterator_opaque {}
+pub type SymbolIteratorRef = *mut SymbolIterator_opaque;
+#[allow(missing_copy_implementations)]
 pub enum Pass_opaque {}
 pub type PassRef = *mut Pass_opaque;
 #[allow(missing_copy_implementations)]
@@ -1732,6 +1748,27 @@ extern {
     pub fn LLVMGetSectionSize(SI: SectionIteratorRef) -> c_ulonglong;
     /// Returns the current section contents as a string buffer.
     pub fn LLVMGetSectionContents(SI: SectionIteratorRef) -> *const c_char;
+    /// Returns the address of the current section.
+    pub fn LLVMGetSectionAddress(SI: SectionIteratorRef) -> c_ulonglong;
+    /// Moves the section iterator to the section containing the symbol the
+    /// symbol iterator points to.
+    pub fn LLVMMoveToContainingSection(Sect: SectionIteratorRef,
+                                       Sym: SymbolIteratorRef);
+
+    /// Enumerates the symbols in an object file.
+    pub fn LLVMGetSymbols(ObjFile: ObjectFileRef) -> SymbolIteratorRef;
+    /// Destroys a symbol iterator.
+    pub fn LLVMDisposeSymbolIterator(SI: SymbolIteratorRef);
+    /// Returns true if the symbol iterator is at the end of the symbol list.
+    pub fn LLVMIsSymbolIteratorAtEnd(ObjFile: ObjectFileRef,
+                                     SI: SymbolIteratorRef)
+                                     -> Bool;
+    /// Moves the symbol iterator to point to the next symbol.
+    pub fn LLVMMoveToNextSymbol(SI: SymbolIteratorRef);
+    /// Returns the name of the current symbol.
+    pub fn LLVMGetSymbolName(SI: SymbolIteratorRef) -> *const c_char;
+    /// Returns the address of the current symbol.
+    pub fn LLVMGetSymbolAddress(SI: SymbolIteratorRef) -> c_ulonglong;
 
     /// Reads the given file and returns it as a memory buffer. Use
     /// LLVMDisposeMemoryBuffer() to get rid of it.
@@ -1803,7 +1840,8 @@ extern {
                                           isOptimized: bool,
                                           Flags: *const c_char,
                                           RuntimeVer: c_uint,
-                                          SplitName: *const c_char)
+                                          SplitName: *const c_char,
+                                          LineTablesOnly: bool)
                                           -> DIDescriptor;
 
     pub fn LLVMDIBuilderCreateFile(Builder: DIBuilderRef,
@@ -2034,7 +2072,9 @@ extern {
                                        UseSoftFP: bool,
                                        PositionIndependentExecutable: bool,
                                        FunctionSections: bool,
-                                       DataSections: bool) -> TargetMachineRef;
+                                       DataSections: bool,
+                                       CompressDebugSections: bool)
+                                       -> TargetMachineRef;
     pub fn LLVMRustDisposeTargetMachine(T: TargetMachineRef);
     pub fn LLVMRustAddAnalysisPasses(T: TargetMachineRef,
                                      PM: PassManagerRef,
@@ -2046,7 +2086,9 @@ extern {
                                                OptLevel: CodeGenOptLevel,
                                                MergeFunctions: bool,
                                                SLPVectorize: bool,
-                                               LoopVectorize: bool);
+                                               LoopVectorize: bool,
+                                               PGOGenPath: *const c_char,
+                                               PGOUsePath: *const c_char);
     pub fn LLVMRustAddLibraryInfo(PM: PassManagerRef, M: ModuleRef,
                                   DisableSimplifyLibCalls: bool);
     pub fn LLVMRustRunFunctionPassManager(PM: PassManagerRef, M: ModuleRef);
@@ -2066,6 +2108,9 @@ extern {
     pub fn LLVMRustLinkInExternalBitcode(M: ModuleRef,
                                          bc: *const c_char,
                                          len: size_t) -> bool;
+    pub fn LLVMRustGetBitcodeProducer(bc: *const c_char,
+                                      len: size_t,
+                                      s: RustStringRef) -> bool;
     pub fn LLVMRustRunRestrictionPass(M: ModuleRef,
                                       syms: *const *const c_char,
                                       len: size_t);
@@ -2140,6 +2185,21 @@ extern {
 
     pub fn LLVMRustSetComdat(M: ModuleRef, V: ValueRef, Name: *const c_char);
     pub fn LLVMRustUnsetComdat(V: ValueRef);
+
+    pub fn LLVMRustCoverageWriteFilenamesSectionToBuffer(Filenames: *const *const c_char,
+                                                         FilenamesLen: size_t,
+                                                         BufferOut: RustStringRef);
+    pub fn LLVMRustCoverageWriteMappingToBuffer(VirtualFileMappingIDs: *const c_uint,
+                                                NumVirtualFileMappingIDs: c_uint,
+                                                Regions: *const CounterMappingRegion,
+                                                NumRegions: c_uint,
+                                                BufferOut: RustStringRef);
+    pub fn LLVMRustCoverageCreatePGOFuncNameVar(F: ValueRef,
+                                                FuncName: *const c_char)
+                                                -> ValueRef;
+    pub fn LLVMRustCoverageComputeHash(Name: *const c_char) -> u64;
+    pub fn LLVMRustCoverageWriteSectionNameToString(M: ModuleRef, Str: RustStringRef);
+    pub fn LLVMRustCoverageMappingVersion() -> u32;
 }
 
 // LLVM requires symbols from this library, but apparently they're not printed
@@ -2299,6 +2359,28 @@ pub fn mk_section_iter(llof: ObjectFileRef) -> SectionIter {
     }
 }
 
+/* Memory-managed interface to symbol iterators. */
+
+pub struct SymbolIter {
+    pub llsi: SymbolIteratorRef
+}
+
+impl Drop for SymbolIter {
+    fn drop(&mut self) {
+        unsafe {
+            LLVMDisposeSymbolIterator(self.llsi);
+        }
+    }
+}
+
+pub fn mk_symbol_iter(llof: ObjectFileRef) -> SymbolIter {
+    unsafe {
+        SymbolIter {
+            llsi: LLVMGetSymbols(llof)
+        }
+    }
+}
+
 /// Safe wrapper around `LLVMGetParam`, because segfaults are no fun.
 pub fn get_param(llfn: ValueRef, index: c_uint) -> ValueRef {
     unsafe {
@@ -2341,6 +2423,23 @@ pub fn build_string<F>(f: F) -> Option<String> where F: FnOnce(RustStringRef){
     String::from_utf8(buf.into_inner()).ok()
 }
 
+/// Returns the producer recorded in a bitcode file, e.g. `LLVM3.9.0`, if any.
+pub fn bitcode_producer(bc: &[u8]) -> Option<String> {
+    let mut found = false;
+    let producer = build_string(|s| unsafe {
+        found = LLVMRustGetBitcodeProducer(bc.as_ptr() as *const c_char,
+                                           bc.len() as size_t,
+                                           s);
+    });
+    if found { producer } else { None }
+}
+
+pub fn build_byte_buffer<F>(f: F) -> Vec<u8> where F: FnOnce(RustStringRef) {
+    let mut buf = RefCell::new(Vec::new());
+    f(&mut buf as RustStringRepr as RustStringRef);
+    buf.into_inner()
+}
+
 pub unsafe fn twine_to_string(tr: TwineRef) -> String {
     build_string(|s| LLVMWriteTwineToString(tr, s))
         .expect("got a non-UTF8 Twine from LLVM")
@@ -2402,6 +2501,16 @@ pub fn initialize_available_targets() {
                  LLVMInitializePNaClTargetInfo,
                  LLVMInitializePNaClTarget,
                  LLVMInitializePNaClTargetMC);
+    init_target!(llvm_component = "webassembly",
+                 LLVMInitializeWebAssemblyTargetInfo,
+                 LLVMInitializeWebAssemblyTarget,
+                 LLVMInitializeWebAssemblyTargetMC,
+                 LLVMInitializeWebAssemblyAsmPrinter);
+    init_target!(llvm_component = "riscv",
+                 LLVMInitializeRISCVTargetInfo,
+                 LLVMInitializeRISCVTarget,
+                 LLVMInitializeRISCVTargetMC,
+                 LLVMInitializeRISCVAsmPrinter);
 }
 
 pub fn last_error() -> Option<String> {
@@ -0,0 +1,30 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The runtime of LeakSanitizer, linked into executables built with
+//! `-Z sanitizer=leak`.
+//!
+//! This crate only bundles the compiler-rt library built by `build.rs`, the
+//! compiler injects it and links it in whole when it's needed.
+
+#![crate_name = "rustc_lsan"]
+#![crate_type = "rlib"]
+#![cfg_attr(not(stage0), feature(sanitizer_runtime))]
+#![cfg_attr(not(stage0), sanitizer_runtime)]
+#![feature(alloc_system)]
+#![feature(staged_api)]
+#![no_std]
+#![unstable(feature = "sanitizer_runtime_lib",
+            reason = "internal implementation detail of sanitizers",
+            issue = "0")]
+
+// The sanitizer intercepts the system allocator, so make sure that's the one
+// used by the executable.
+extern crate alloc_system;
@@ -45,7 +45,9 @@ pub const tag_items_closure_kind: usize = 0x2a;
 pub const tag_items_closure_ty: usize = 0x2b;
 pub const tag_def_key: usize = 0x2c;
 
-// GAP 0x2d 0x2e
+pub const tag_meta_item_literal: usize = 0x2d;
+
+// GAP 0x2e
 
 pub const tag_index: usize = 0x110; // top-level only
 pub const tag_xref_index: usize = 0x111; // top-level only
@@ -74,6 +76,7 @@ pub const tag_crate_dep: usize = 0x35;
 pub const tag_crate_hash: usize = 0x103; // top-level only
 pub const tag_crate_crate_name: usize = 0x104; // top-level only
 pub const tag_crate_disambiguator: usize = 0x113; // top-level only
+pub const tag_panic_strategy: usize = 0x114; // top-level only
 
 pub const tag_crate_dep_crate_name: usize = 0x36;
 pub const tag_crate_dep_hash: usize = 0x37;
@@ -193,7 +196,7 @@ pub const tag_struct_field: usize = 0x8a;
 
 pub const tag_items_data_item_struct_ctor: usize = 0x8b;
 pub const tag_attribute_is_sugared_doc: usize = 0x8c;
-// GAP 0x8d
+pub const tag_const_param_def: usize = 0x8d;
 pub const tag_items_data_region: usize = 0x8e;
 
 pub const tag_region_param_def: usize = 0x8f;
@@ -243,6 +246,8 @@ pub const tag_items_data_item_defaultness: usize = 0xa8;
 
 pub const tag_items_data_parent_impl: usize = 0xa9;
 
+pub const tag_trait_alias: usize = 0xaa;
+
 pub const tag_rustc_version: usize = 0x10f;
 pub fn rustc_version() -> String {
     format!(
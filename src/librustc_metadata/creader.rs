@@ -20,10 +20,12 @@ use loader::{self, CratePaths};
 use rustc::hir::svh::Svh;
 use rustc::dep_graph::{DepGraph, DepNode};
 use rustc::session::{config, Session};
+use rustc::session::config::Sanitizer;
 use rustc::session::search_paths::PathKind;
 use rustc::middle::cstore::{CrateStore, validate_crate_name, ExternCrate};
 use rustc::util::nodemap::FnvHashMap;
 use rustc::hir::map as hir_map;
+use rustc_back::PanicStrategy;
 
 use std::cell::{RefCell, Cell};
 use std::path::PathBuf;
@@ -35,7 +37,7 @@ use syntax::abi::Abi;
 use syntax::codemap::{self, Span, mk_sp, Pos};
 use syntax::parse;
 use syntax::attr;
-use syntax::attr::AttrMetaMethods;
+use syntax::attr::{AttrMetaMethods, AttrNestedMetaItemMethods};
 use syntax::parse::token::InternedString;
 use syntax::visit;
 use log;
@@ -630,6 +632,67 @@ impl<'a> CrateReader<'a> {
         }
     }
 
+    fn inject_sanitizer_runtime(&mut self) {
+        let sanitizer = match self.sess.opts.debugging_opts.sanitizer {
+            Some(sanitizer) => sanitizer,
+            None => return,
+        };
+
+        // The compiler-rt runtimes are only built for this target so far.
+        const SUPPORTED_TARGET: &'static str = "x86_64-unknown-linux-gnu";
+        if self.sess.target.target.llvm_target != SUPPORTED_TARGET {
+            self.sess.err(&format!("{:?}Sanitizer only works with the `{}` target",
+                                   sanitizer, SUPPORTED_TARGET));
+            return
+        }
+
+        // Only executables get a sanitizer runtime linked in, libraries are
+        // merely instrumented.
+        let any_exe = self.sess.crate_types.borrow().iter().any(|ct| {
+            *ct == config::CrateTypeExecutable
+        });
+        if !any_exe {
+            info!("sanitizer runtime injection skipped, not generating an executable");
+            return
+        }
+
+        let name = match sanitizer {
+            Sanitizer::Address => "rustc_asan",
+            Sanitizer::Leak => "rustc_lsan",
+            Sanitizer::Memory => "rustc_msan",
+            Sanitizer::Thread => "rustc_tsan",
+        };
+        info!("loading sanitizer runtime: {}", name);
+
+        // The runtime is linked as if it were named with `extern crate`, this
+        // also pulls in its `alloc_system` dependency as the allocator since
+        // the sanitizers need to intercept the system allocator.
+        let (_, data, _) = self.resolve_crate(&None, name, name, None,
+                                              codemap::DUMMY_SP,
+                                              PathKind::Crate, true);
+        if !data.is_sanitizer_runtime() {
+            self.sess.err(&format!("the crate `{}` is not a sanitizer runtime",
+                                   name));
+        }
+    }
+
+    fn inject_profiler_runtime(&mut self) {
+        if self.sess.opts.cg.profile_generate.is_none() &&
+           !self.sess.opts.debugging_opts.instrument_coverage {
+            return
+        }
+
+        info!("loading profiler runtime");
+        let name = "profiler_builtins";
+        let (_, data, _) = self.resolve_crate(&None, name, name, None,
+                                              codemap::DUMMY_SP,
+                                              PathKind::Crate, true);
+        if !data.is_profiler_runtime() {
+            self.sess.err(&format!("the crate `{}` is not a profiler runtime",
+                                   name));
+        }
+    }
+
     fn inject_allocator_crate(&mut self) {
         // Make sure that we actually need an allocator, if none of our
         // dependencies need one then we definitely don't!
@@ -642,7 +705,8 @@ impl<'a> CrateReader<'a> {
             needs_allocator = needs_allocator || data.needs_allocator();
             if data.is_allocator() {
                 debug!("{} required by rlib and is an allocator", data.name());
-                self.inject_allocator_dependency(cnum);
+                self.inject_dependency_if(cnum, "an allocator",
+                                          &|data| data.needs_allocator());
                 found_required_allocator = found_required_allocator ||
                     data.explicitly_linked.get();
             }
@@ -701,49 +765,115 @@ impl<'a> CrateReader<'a> {
         }
 
         self.sess.injected_allocator.set(Some(cnum));
-        self.inject_allocator_dependency(cnum);
+        self.inject_dependency_if(cnum, "an allocator",
+                                  &|data| data.needs_allocator());
+    }
+
+    fn inject_panic_runtime(&mut self, krate: &ast::Crate) {
+        // If we're only compiling rlibs then there's no need to select a panic
+        // runtime, that's left to whatever final artifact links them.
+        let any_non_rlib = self.sess.crate_types.borrow().iter().any(|ct| {
+            *ct != config::CrateTypeRlib
+        });
+        if !any_non_rlib {
+            info!("panic runtime injection skipped, only generating rlib");
+            return
+        }
+
+        // This mirrors the allocator logic above: look for a panic runtime
+        // which was already loaded, and only inject a default one if nothing
+        // explicitly linked one in. The compatibility of the panic strategies
+        // of all crates in the DAG is verified later on, in the
+        // `dependency_format` module.
+        let desired_strategy = self.sess.panic_strategy();
+        let mut runtime_found = false;
+        let mut needs_panic_runtime = attr::contains_name(&krate.attrs,
+                                                          "needs_panic_runtime");
+        self.cstore.iter_crate_data(|cnum, data| {
+            needs_panic_runtime = needs_panic_runtime || data.needs_panic_runtime();
+            if data.is_panic_runtime() {
+                debug!("{} required by rlib and is a panic runtime", data.name());
+                self.inject_dependency_if(cnum, "a panic runtime",
+                                          &|data| data.needs_panic_runtime());
+                runtime_found = runtime_found || data.explicitly_linked.get();
+            }
+        });
+        if !needs_panic_runtime || runtime_found { return }
+
+        // At this point we need a panic runtime and none was explicitly linked,
+        // so load the default one for our panic strategy. This may resolve to
+        // a crate which is already loaded, which is fine.
+        let name = match desired_strategy {
+            PanicStrategy::Unwind => "panic_unwind",
+            PanicStrategy::Abort => "panic_abort",
+        };
+        info!("panic runtime not found -- loading {}", name);
+
+        let (cnum, data, _) = self.resolve_crate(&None, name, name, None,
+                                                 codemap::DUMMY_SP,
+                                                 PathKind::Crate, false);
+
+        // Sanity check that the crate we just loaded is a panic runtime, and
+        // that it was compiled with the strategy we asked for.
+        if !data.is_panic_runtime() {
+            self.sess.err(&format!("the crate `{}` is not a panic runtime",
+                                   name));
+        }
+        if data.panic_strategy() != desired_strategy {
+            self.sess.err(&format!("the crate `{}` does not have the panic \
+                                    strategy `{}`",
+                                   name, desired_strategy.desc()));
+        }
+
+        self.sess.injected_panic_runtime.set(Some(cnum));
+        self.inject_dependency_if(cnum, "a panic runtime",
+                                  &|data| data.needs_panic_runtime());
     }
 
-    fn inject_allocator_dependency(&self, allocator: ast::CrateNum) {
+    fn inject_dependency_if(&self,
+                            krate: ast::CrateNum,
+                            what: &str,
+                            needs_dep: &Fn(&cstore::crate_metadata) -> bool) {
         // Before we inject any dependencies, make sure we don't inject a
-        // circular dependency by validating that this allocator crate doesn't
-        // transitively depend on any `#![needs_allocator]` crates.
-        validate(self, allocator, allocator);
-
-        // All crates tagged with `needs_allocator` do not explicitly depend on
-        // the allocator selected for this compile, but in order for this
-        // compilation to be successfully linked we need to inject a dependency
-        // (to order the crates on the command line correctly).
-        //
-        // Here we inject a dependency from all crates with #![needs_allocator]
-        // to the crate tagged with #![allocator] for this compilation unit.
+        // circular dependency by validating that this crate doesn't
+        // transitively depend on any crates satisfying `needs_dep`.
+        validate(self, krate, krate, what, needs_dep);
+
+        // All crates satisfying `needs_dep` do not explicitly depend on the
+        // crate provided for this compile (the allocator or panic runtime),
+        // but in order for this compilation to be successfully linked we need
+        // to inject a dependency (to order the crates on the command line
+        // correctly).
         self.cstore.iter_crate_data(|cnum, data| {
-            if !data.needs_allocator() {
+            if !needs_dep(data) {
                 return
             }
 
-            info!("injecting a dep from {} to {}", cnum, allocator);
+            info!("injecting a dep from {} to {}", cnum, krate);
             let mut cnum_map = data.cnum_map.borrow_mut();
             let remote_cnum = cnum_map.len() + 1;
-            let prev = cnum_map.insert(remote_cnum as ast::CrateNum, allocator);
+            let prev = cnum_map.insert(remote_cnum as ast::CrateNum, krate);
             assert!(prev.is_none());
         });
 
-        fn validate(me: &CrateReader, krate: ast::CrateNum,
-                    allocator: ast::CrateNum) {
+        fn validate(me: &CrateReader,
+                    krate: ast::CrateNum,
+                    root: ast::CrateNum,
+                    what: &str,
+                    needs_dep: &Fn(&cstore::crate_metadata) -> bool) {
             let data = me.cstore.get_crate_data(krate);
-            if data.needs_allocator() {
+            if needs_dep(&data) {
                 let krate_name = data.name();
-                let data = me.cstore.get_crate_data(allocator);
-                let alloc_name = data.name();
-                me.sess.err(&format!("the allocator crate `{}` cannot depend \
-                                      on a crate that needs an allocator, but \
-                                      it depends on `{}`", alloc_name,
+                let data = me.cstore.get_crate_data(root);
+                let root_name = data.name();
+                me.sess.err(&format!("the crate `{}` cannot depend \
+                                      on a crate that needs {}, but \
+                                      it depends on `{}`", root_name, what,
                                       krate_name));
             }
 
             for (_, &dep) in data.cnum_map.borrow().iter() {
-                validate(me, dep, allocator);
+                validate(me, dep, root, what, needs_dep);
             }
         }
     }
@@ -773,7 +903,10 @@ impl<'a> LocalCrateReader<'a> {
 
         self.process_crate(self.krate);
         visit::walk_crate(self, self.krate);
+        self.creader.inject_sanitizer_runtime();
+        self.creader.inject_profiler_runtime();
         self.creader.inject_allocator_crate();
+        self.creader.inject_panic_runtime(self.krate);
 
         if log_enabled!(log::INFO) {
             dump_crates(&self.cstore);
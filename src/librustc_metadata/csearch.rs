@@ -32,6 +32,7 @@ use syntax::ast;
 use syntax::attr;
 use syntax::parse::token;
 use rustc::hir::svh::Svh;
+use rustc_back::PanicStrategy;
 use rustc_back::target::Target;
 use rustc::hir;
 
@@ -180,6 +181,12 @@ impl<'tcx> CrateStore<'tcx> for cstore::CStore {
         decoder::get_impl_polarity(&cdata, def.index)
     }
 
+    fn impl_defaultness(&self, def: DefId) -> hir::Defaultness
+    {
+        let cdata = self.get_crate_data(def.krate);
+        decoder::get_impl_defaultness(&cdata, def.index)
+    }
+
     fn impl_trait_ref(&self, tcx: &TyCtxt<'tcx>, def: DefId)
                       -> Option<ty::TraitRef<'tcx>>
     {
@@ -306,6 +313,21 @@ impl<'tcx> CrateStore<'tcx> for cstore::CStore {
         self.get_crate_data(cnum).is_allocator()
     }
 
+    fn is_panic_runtime(&self, cnum: ast::CrateNum) -> bool
+    {
+        self.get_crate_data(cnum).is_panic_runtime()
+    }
+
+    fn panic_strategy(&self, cnum: ast::CrateNum) -> PanicStrategy
+    {
+        self.get_crate_data(cnum).panic_strategy()
+    }
+
+    fn is_sanitizer_runtime(&self, cnum: ast::CrateNum) -> bool
+    {
+        self.get_crate_data(cnum).is_sanitizer_runtime()
+    }
+
     fn crate_attrs(&self, cnum: ast::CrateNum) -> Vec<ast::Attribute>
     {
         decoder::get_crate_attributes(self.get_crate_data(cnum).data())
@@ -38,6 +38,7 @@ use rustc::ty::{ImplContainer, TraitContainer};
 use rustc::ty::{self, Ty, TyCtxt, TypeFoldable, VariantKind};
 
 use rustc_const_math::ConstInt;
+use rustc_back::PanicStrategy;
 
 use rustc::mir;
 use rustc::mir::visit::MutVisitor;
@@ -108,6 +109,7 @@ enum Family {
     DefaultImpl,           // d
     Trait,                 // I
     Struct(VariantKind),   // S, s, u
+    Union,                 // U
     PublicField,           // g
     InheritedField,        // N
     Constant,              // C
@@ -135,6 +137,7 @@ fn item_family(item: rbml::Doc) -> Family {
       'S' => Struct(VariantKind::Struct),
       's' => Struct(VariantKind::Tuple),
       'u' => Struct(VariantKind::Unit),
+      'U' => Union,
       'g' => PublicField,
       'N' => InheritedField,
        c => bug!("unexpected family char: {}", c)
@@ -267,7 +270,7 @@ fn item_name(intr: &IdentInterner, item: rbml::Doc) -> ast::Name {
 
 fn family_to_variant_kind<'tcx>(family: Family) -> Option<ty::VariantKind> {
     match family {
-        Struct(VariantKind::Struct) | Variant(VariantKind::Struct) =>
+        Struct(VariantKind::Struct) | Variant(VariantKind::Struct) | Union =>
             Some(ty::VariantKind::Struct),
         Struct(VariantKind::Tuple) | Variant(VariantKind::Tuple) =>
             Some(ty::VariantKind::Tuple),
@@ -294,7 +297,7 @@ fn item_to_def_like(cdata: Cmd, item: rbml::Doc, did: DefId) -> DefLike {
         }
         ImmStatic => DlDef(Def::Static(did, false)),
         MutStatic => DlDef(Def::Static(did, true)),
-        Struct(..) => DlDef(Def::Struct(did)),
+        Struct(..) | Union => DlDef(Def::Struct(did)),
         Fn        => DlDef(Def::Fn(did)),
         Method | StaticMethod => {
             DlDef(Def::Method(did))
@@ -334,6 +337,11 @@ fn parse_paren_sugar(item_doc: rbml::Doc) -> bool {
     reader::doc_as_u8(paren_sugar_doc) != 0
 }
 
+fn parse_trait_alias(item_doc: rbml::Doc) -> bool {
+    let trait_alias_doc = reader::get_doc(item_doc, tag_trait_alias);
+    reader::doc_as_u8(trait_alias_doc) != 0
+}
+
 fn parse_polarity(item_doc: rbml::Doc) -> hir::ImplPolarity {
     let polarity_doc = reader::get_doc(item_doc, tag_polarity);
     if reader::doc_as_u8(polarity_doc) != 0 {
@@ -359,9 +367,11 @@ pub fn get_trait_def<'tcx>(cdata: Cmd,
     let unsafety = parse_unsafety(item_doc);
     let associated_type_names = parse_associated_type_names(item_doc);
     let paren_sugar = parse_paren_sugar(item_doc);
+    let is_alias = parse_trait_alias(item_doc);
 
     ty::TraitDef::new(unsafety,
                       paren_sugar,
+                      is_alias,
                       generics,
                       item_trait_ref(item_doc, tcx, cdata),
                       associated_type_names)
@@ -449,6 +459,10 @@ pub fn get_adt_def<'tcx>(intr: &IdentInterner,
             (ty::AdtKind::Struct,
              vec![get_struct_variant(intr, cdata, doc, ctor_did)])
         }
+        Union => {
+            (ty::AdtKind::Union,
+             vec![get_struct_variant(intr, cdata, doc, did)])
+        }
         _ => bug!("get_adt_def called on a non-ADT {:?} - {:?}",
                   item_family(doc), did)
     };
@@ -564,6 +578,10 @@ pub fn get_repr_attrs(cdata: Cmd, id: DefIndex) -> Vec<attr::ReprAttr> {
     }
 }
 
+pub fn get_impl_defaultness(cdata: Cmd, id: DefIndex) -> hir::Defaultness {
+    item_defaultness(cdata.lookup_item(id))
+}
+
 pub fn get_impl_polarity<'tcx>(cdata: Cmd,
                                id: DefIndex)
                                -> Option<hir::ImplPolarity>
@@ -1000,9 +1018,13 @@ pub fn get_impl_or_trait_item<'tcx>(intr: Rc<IdentInterner>,
                                                         container)))
         }
         Some('t') => {
+            let generics = doc_generics(item_doc, tcx, cdata, tag_item_generics);
+            let predicates = doc_predicates(item_doc, tcx, cdata, tag_item_generics);
             let ty = maybe_doc_type(item_doc, tcx, cdata);
             ty::TypeTraitItem(Rc::new(ty::AssociatedType {
                 name: name,
+                generics: generics,
+                predicates: predicates,
                 ty: ty,
                 vis: vis,
                 defaultness: defaultness,
@@ -1160,25 +1182,53 @@ pub fn get_struct_field_names(intr: &IdentInterner, cdata: Cmd, id: DefIndex)
     })).collect()
 }
 
-fn get_meta_items(md: rbml::Doc) -> Vec<P<ast::MetaItem>> {
-    reader::tagged_docs(md, tag_meta_item_word).map(|meta_item_doc| {
-        let nd = reader::get_doc(meta_item_doc, tag_meta_item_name);
-        let n = token::intern_and_get_ident(nd.as_str_slice());
+fn get_meta_item_literal(doc: rbml::Doc) -> ast::Lit {
+    let mut decoder = reader::Decoder::new(doc);
+    codemap::dummy_spanned(Decodable::decode(&mut decoder).unwrap())
+}
+
+/// Decodes the meta item in `doc` if `tag` is one of the meta item tags.
+fn get_meta_item(tag: usize, doc: rbml::Doc) -> Option<P<ast::MetaItem>> {
+    if tag != tag_meta_item_word && tag != tag_meta_item_name_value &&
+       tag != tag_meta_item_list {
+        return None;
+    }
+
+    let nd = reader::get_doc(doc, tag_meta_item_name);
+    let n = token::intern_and_get_ident(nd.as_str_slice());
+    Some(if tag == tag_meta_item_word {
         attr::mk_word_item(n)
-    }).chain(reader::tagged_docs(md, tag_meta_item_name_value).map(|meta_item_doc| {
-        let nd = reader::get_doc(meta_item_doc, tag_meta_item_name);
-        let vd = reader::get_doc(meta_item_doc, tag_meta_item_value);
-        let n = token::intern_and_get_ident(nd.as_str_slice());
-        let v = token::intern_and_get_ident(vd.as_str_slice());
-        // FIXME (#623): Should be able to decode MetaItemKind::NameValue variants,
-        // but currently the encoder just drops them
-        attr::mk_name_value_item_str(n, v)
-    })).chain(reader::tagged_docs(md, tag_meta_item_list).map(|meta_item_doc| {
-        let nd = reader::get_doc(meta_item_doc, tag_meta_item_name);
-        let n = token::intern_and_get_ident(nd.as_str_slice());
-        let subitems = get_meta_items(meta_item_doc);
+    } else if tag == tag_meta_item_name_value {
+        match reader::maybe_get_doc(doc, tag_meta_item_value) {
+            Some(vd) => {
+                let v = token::intern_and_get_ident(vd.as_str_slice());
+                attr::mk_name_value_item_str(n, v)
+            }
+            None => {
+                let lit = get_meta_item_literal(reader::get_doc(doc, tag_meta_item_literal));
+                attr::mk_name_value_item(n, lit)
+            }
+        }
+    } else {
+        // Walk the items in order, so that words, name-value pairs and
+        // literals keep their relative positions in the list.
+        let subitems = reader::docs(doc).filter_map(|(tag, doc)| {
+            let node = if tag == tag_meta_item_literal {
+                ast::NestedMetaItemKind::Literal(get_meta_item_literal(doc))
+            } else {
+                match get_meta_item(tag, doc) {
+                    Some(mi) => ast::NestedMetaItemKind::MetaItem(mi),
+                    None => return None,
+                }
+            };
+            Some(codemap::dummy_spanned(node))
+        }).collect();
         attr::mk_list_item(n, subitems)
-    })).collect()
+    })
+}
+
+fn get_meta_items(md: rbml::Doc) -> Vec<P<ast::MetaItem>> {
+    reader::docs(md).filter_map(|(tag, doc)| get_meta_item(tag, doc)).collect()
 }
 
 fn get_attributes(md: rbml::Doc) -> Vec<ast::Attribute> {
@@ -1291,6 +1341,16 @@ pub fn get_crate_disambiguator<'a>(data: &'a [u8]) -> &'a str {
     slice
 }
 
+pub fn get_panic_strategy(data: &[u8]) -> PanicStrategy {
+    let crate_doc = rbml::Doc::new(data);
+    let strategy_doc = reader::get_doc(crate_doc, tag_panic_strategy);
+    match reader::doc_as_u8(strategy_doc) {
+        b'U' => PanicStrategy::Unwind,
+        b'A' => PanicStrategy::Abort,
+        b => bug!("unknown panic strategy in metadata: {}", b),
+    }
+}
+
 pub fn get_crate_triple(data: &[u8]) -> Option<String> {
     let cratedoc = rbml::Doc::new(data);
     let triple_doc = reader::maybe_get_doc(cratedoc, tag_crate_triple);
@@ -1654,7 +1714,16 @@ fn doc_generics<'tcx>(base_doc: rbml::Doc,
                                                      bounds: bounds });
     }
 
-    ty::Generics { types: types, regions: regions }
+    let mut consts = subst::VecPerParamSpace::empty();
+    for p in reader::tagged_docs(doc, tag_const_param_def) {
+        let bd =
+            TyDecoder::with_doc(tcx, cdata.cnum, p,
+                                &mut |did| translate_def_id(cdata, did))
+            .parse_const_param_def();
+        consts.push(bd.space, bd);
+    }
+
+    ty::Generics { types: types, regions: regions, consts: consts }
 }
 
 fn doc_predicate<'tcx>(cdata: Cmd,
@@ -35,12 +35,14 @@ pub enum DefPathData {
     MacroDef,
     ClosureExpr,
     TypeParam,
+    ConstParam,
     LifetimeDef,
     EnumVariant,
     Field,
     StructCtor,
     Initializer,
     Binding,
+    ImplTrait,
 }
 
 pub fn simplify_def_key(key: hir_map::DefKey) -> DefKey {
@@ -66,10 +68,12 @@ fn simplify_def_path_data(data: hir_map::DefPathData) -> DefPathData {
         hir_map::DefPathData::MacroDef(_) => DefPathData::MacroDef,
         hir_map::DefPathData::ClosureExpr => DefPathData::ClosureExpr,
         hir_map::DefPathData::TypeParam(_) => DefPathData::TypeParam,
+        hir_map::DefPathData::ConstParam(_) => DefPathData::ConstParam,
         hir_map::DefPathData::LifetimeDef(_) => DefPathData::LifetimeDef,
         hir_map::DefPathData::EnumVariant(_) => DefPathData::EnumVariant,
         hir_map::DefPathData::Field(_) => DefPathData::Field,
         hir_map::DefPathData::StructCtor => DefPathData::StructCtor,
+        hir_map::DefPathData::ImplTrait => DefPathData::ImplTrait,
         hir_map::DefPathData::Initializer => DefPathData::Initializer,
         hir_map::DefPathData::Binding(_) => DefPathData::Binding,
     }
@@ -97,10 +101,12 @@ fn recover_def_path_data(data: DefPathData, name: Option<Name>) -> hir_map::DefP
         DefPathData::MacroDef => hir_map::DefPathData::MacroDef(name.unwrap()),
         DefPathData::ClosureExpr => hir_map::DefPathData::ClosureExpr,
         DefPathData::TypeParam => hir_map::DefPathData::TypeParam(name.unwrap()),
+        DefPathData::ConstParam => hir_map::DefPathData::ConstParam(name.unwrap()),
         DefPathData::LifetimeDef => hir_map::DefPathData::LifetimeDef(name.unwrap()),
         DefPathData::EnumVariant => hir_map::DefPathData::EnumVariant(name.unwrap()),
         DefPathData::Field => hir_map::DefPathData::Field(name.unwrap()),
         DefPathData::StructCtor => hir_map::DefPathData::StructCtor,
+        DefPathData::ImplTrait => hir_map::DefPathData::ImplTrait,
         DefPathData::Initializer => hir_map::DefPathData::Initializer,
         DefPathData::Binding => hir_map::DefPathData::Binding(name.unwrap()),
     }
@@ -35,6 +35,7 @@ use rustc::hir::svh::Svh;
 use rustc::mir::mir_map::MirMap;
 use rustc::session::config;
 use rustc::util::nodemap::{FnvHashMap, NodeMap, NodeSet};
+use rustc_back::PanicStrategy;
 
 use rustc_serialize::Encodable;
 use std::cell::RefCell;
@@ -547,6 +548,13 @@ fn encode_generics<'a, 'tcx>(rbml_w: &mut Encoder,
         rbml_w.end_tag();
     }
 
+    for param in &generics.consts {
+        rbml_w.start_tag(tag_const_param_def);
+        tyencode::enc_const_param_def(rbml_w.writer, &ecx.ty_str_ctxt(), param);
+        rbml_w.mark_stable_position();
+        rbml_w.end_tag();
+    }
+
     // Region parameters
     for param in &generics.regions {
         rbml_w.start_tag(tag_region_param_def);
@@ -654,7 +662,7 @@ fn encode_info_for_associated_const<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
 
     if let Some(ii) = impl_item_opt {
         encode_attributes(rbml_w, &ii.attrs);
-        encode_defaultness(rbml_w, ii.defaultness);
+        encode_defaultness(rbml_w, associated_const.defaultness);
         encode_inlined_item(ecx,
                             rbml_w,
                             InlinedItemRef::ImplItem(ecx.tcx.map.local_def_id(parent_id),
@@ -694,7 +702,8 @@ fn encode_info_for_method<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
         if let hir::ImplItemKind::Method(ref sig, _) = impl_item.node {
             encode_attributes(rbml_w, &impl_item.attrs);
             let scheme = ecx.tcx.lookup_item_type(m.def_id);
-            let any_types = !scheme.generics.types.is_empty();
+            let any_types = !scheme.generics.types.is_empty() ||
+                            !scheme.generics.consts.is_empty();
             let needs_inline = any_types || is_default_impl ||
                                attr::requests_inline(&impl_item.attrs);
             if needs_inline || sig.constness == hir::Constness::Const {
@@ -705,7 +714,7 @@ fn encode_info_for_method<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
                 encode_mir(ecx, rbml_w, impl_item.id);
             }
             encode_constness(rbml_w, sig.constness);
-            encode_defaultness(rbml_w, impl_item.defaultness);
+            encode_defaultness(rbml_w, m.defaultness);
             if !any_types {
                 let m_id = ecx.local_id(m.def_id);
                 encode_symbol(ecx, rbml_w, m_id);
@@ -744,13 +753,13 @@ fn encode_info_for_associated_type<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
 
     if let Some(ii) = impl_item_opt {
         encode_attributes(rbml_w, &ii.attrs);
-        encode_defaultness(rbml_w, ii.defaultness);
-    } else {
-        encode_predicates(rbml_w, ecx, index,
-                          &ecx.tcx.lookup_predicates(associated_type.def_id),
-                          tag_item_generics);
+        encode_defaultness(rbml_w, associated_type.defaultness);
     }
 
+    encode_generics(rbml_w, ecx, index,
+                    &associated_type.generics, &associated_type.predicates,
+                    tag_item_generics);
+
     if let Some(ty) = associated_type.ty {
         encode_type(ecx, rbml_w, ty);
     }
@@ -915,7 +924,7 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
         rbml_w.start_tag(tag_items_data_item);
         encode_def_id_and_key(ecx, rbml_w, def_id);
         encode_family(rbml_w, FN_FAMILY);
-        let tps_len = generics.ty_params.len();
+        let tps_len = generics.ty_params.len() + generics.const_params.len();
         encode_bounds_and_type_for_item(rbml_w, ecx, index, item.id);
         encode_name(rbml_w, item.name);
         encode_attributes(rbml_w, &item.attrs);
@@ -1057,6 +1066,38 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
             encode_info_for_struct_ctor(ecx, rbml_w, item.name, struct_def, index, item.id);
         }
       }
+      hir::ItemUnion(..) => {
+        let def = ecx.tcx.lookup_adt_def(def_id);
+        let variant = def.struct_variant();
+
+        index.record(def_id, rbml_w);
+        rbml_w.start_tag(tag_items_data_item);
+        encode_def_id_and_key(ecx, rbml_w, def_id);
+        encode_family(rbml_w, 'U');
+        encode_bounds_and_type_for_item(rbml_w, ecx, index, item.id);
+
+        encode_item_variances(rbml_w, ecx, item.id);
+        encode_name(rbml_w, item.name);
+        encode_attributes(rbml_w, &item.attrs);
+        encode_stability(rbml_w, stab);
+        encode_deprecation(rbml_w, depr);
+        encode_visibility(rbml_w, vis);
+        encode_repr_attrs(rbml_w, ecx, &item.attrs);
+
+        encode_struct_fields(rbml_w, variant);
+
+        encode_inlined_item(ecx, rbml_w, InlinedItemRef::Item(item));
+        encode_mir(ecx, rbml_w, item.id);
+
+        // Encode inherent implementations for this union.
+        encode_inherent_implementations(ecx, rbml_w, def_id);
+
+        rbml_w.end_tag();
+
+        for field in &variant.fields {
+            encode_field(ecx, rbml_w, field, index);
+        }
+      }
       hir::ItemDefaultImpl(unsafety, _) => {
           index.record(def_id, rbml_w);
           rbml_w.start_tag(tag_items_data_item);
@@ -1069,7 +1110,7 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
           encode_trait_ref(rbml_w, ecx, trait_ref, tag_item_trait_ref);
           rbml_w.end_tag();
       }
-      hir::ItemImpl(unsafety, polarity, _, _, _, ref ast_items) => {
+      hir::ItemImpl(unsafety, polarity, defaultness, _, _, _, ref ast_items) => {
         // We need to encode information about the default methods we
         // have inherited, so we drive this based on the impl structure.
         let impl_items = tcx.impl_items.borrow();
@@ -1084,6 +1125,7 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
         encode_attributes(rbml_w, &item.attrs);
         encode_unsafety(rbml_w, unsafety);
         encode_polarity(rbml_w, polarity);
+        encode_defaultness(rbml_w, defaultness);
 
         match tcx.custom_coerce_unsized_kinds.borrow().get(&ecx.tcx.map.local_def_id(item.id)) {
             Some(&kind) => {
@@ -1171,7 +1213,7 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
             }
         }
       }
-      hir::ItemTrait(_, _, _, ref ms) => {
+      hir::ItemTrait(..) | hir::ItemTraitAlias(..) => {
         index.record(def_id, rbml_w);
         rbml_w.start_tag(tag_items_data_item);
         encode_def_id_and_key(ecx, rbml_w, def_id);
@@ -1181,6 +1223,7 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
         let trait_predicates = tcx.lookup_predicates(def_id);
         encode_unsafety(rbml_w, trait_def.unsafety);
         encode_paren_sugar(rbml_w, trait_def.paren_sugar);
+        encode_trait_alias(rbml_w, trait_def.is_alias);
         encode_defaulted(rbml_w, tcx.trait_has_default_impl(def_id));
         encode_associated_type_names(rbml_w, &trait_def.associated_type_names);
         encode_generics(rbml_w, ecx, index,
@@ -1223,6 +1266,10 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
         rbml_w.end_tag();
 
         // Now output the trait item info for each trait item.
+        let ms: &[hir::TraitItem] = match item.node {
+            hir::ItemTrait(_, _, _, ref ms) => ms,
+            _ => &[],
+        };
         let r = tcx.trait_item_def_ids(def_id);
         for (i, &item_def_id) in r.iter().enumerate() {
             assert_eq!(item_def_id.def_id().krate, LOCAL_CRATE);
@@ -1279,6 +1326,9 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
                     encode_def_id_and_key(ecx, rbml_w, associated_type.def_id);
                     encode_item_sort(rbml_w, 't');
                     encode_family(rbml_w, 'y');
+                    encode_generics(rbml_w, ecx, index,
+                                    &associated_type.generics, &associated_type.predicates,
+                                    tag_item_generics);
 
                     if let Some(ty) = associated_type.ty {
                         encode_type(ecx, rbml_w, ty);
@@ -1414,6 +1464,23 @@ fn my_visit_expr(expr: &hir::Expr,
     }
 }
 
+fn encode_info_for_anon_ty<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
+                                     rbml_w: &mut Encoder,
+                                     index: &mut CrateIndex<'tcx>,
+                                     ty: &hir::Ty) {
+    if let hir::TyImplTrait(_) = ty.node {
+        let def_id = ecx.tcx.map.local_def_id(ty.id);
+
+        index.record(def_id, rbml_w);
+
+        rbml_w.start_tag(tag_items_data_item);
+        encode_def_id_and_key(ecx, rbml_w, def_id);
+        encode_family(rbml_w, 'y');
+        encode_bounds_and_type_for_item(rbml_w, ecx, index, ty.id);
+        rbml_w.end_tag();
+    }
+}
+
 struct EncodeVisitor<'a, 'b:'a, 'c:'a, 'tcx:'c> {
     rbml_w_for_visit_item: &'a mut Encoder<'b>,
     ecx: &'a EncodeContext<'c,'tcx>,
@@ -1425,6 +1492,10 @@ impl<'a, 'b, 'c, 'tcx> Visitor<'tcx> for EncodeVisitor<'a, 'b, 'c, 'tcx> {
         intravisit::walk_expr(self, ex);
         my_visit_expr(ex, self.rbml_w_for_visit_item, self.ecx, self.index);
     }
+    fn visit_ty(&mut self, ty: &'tcx hir::Ty) {
+        intravisit::walk_ty(self, ty);
+        encode_info_for_anon_ty(self.ecx, self.rbml_w_for_visit_item, self.index, ty);
+    }
     fn visit_item(&mut self, i: &'tcx hir::Item) {
         intravisit::walk_item(self, i);
         encode_info_for_item(self.ecx, self.rbml_w_for_visit_item, i, self.index);
@@ -1486,20 +1557,34 @@ fn encode_meta_item(rbml_w: &mut Encoder, mi: &ast::MetaItem) {
             rbml_w.wr_tagged_str(tag_meta_item_value, value);
             rbml_w.end_tag();
           }
-          _ => {/* FIXME (#623): encode other variants */ }
+          _ => {
+            rbml_w.start_tag(tag_meta_item_name_value);
+            rbml_w.wr_tagged_str(tag_meta_item_name, name);
+            encode_meta_item_literal(rbml_w, value);
+            rbml_w.end_tag();
+          }
         }
       }
       ast::MetaItemKind::List(ref name, ref items) => {
         rbml_w.start_tag(tag_meta_item_list);
         rbml_w.wr_tagged_str(tag_meta_item_name, name);
         for inner_item in items {
-            encode_meta_item(rbml_w, &inner_item);
+            match inner_item.node {
+                ast::NestedMetaItemKind::MetaItem(ref mi) => encode_meta_item(rbml_w, &mi),
+                ast::NestedMetaItemKind::Literal(ref lit) => encode_meta_item_literal(rbml_w, lit),
+            }
         }
         rbml_w.end_tag();
       }
     }
 }
 
+fn encode_meta_item_literal(rbml_w: &mut Encoder, lit: &ast::Lit) {
+    rbml_w.start_tag(tag_meta_item_literal);
+    lit.node.encode(rbml_w).unwrap();
+    rbml_w.end_tag();
+}
+
 fn encode_attributes(rbml_w: &mut Encoder, attrs: &[ast::Attribute]) {
     rbml_w.start_tag(tag_attributes);
     for attr in attrs {
@@ -1524,6 +1609,11 @@ fn encode_paren_sugar(rbml_w: &mut Encoder, paren_sugar: bool) {
     rbml_w.wr_tagged_u8(tag_paren_sugar, byte);
 }
 
+fn encode_trait_alias(rbml_w: &mut Encoder, is_alias: bool) {
+    let byte: u8 = if is_alias {1} else {0};
+    rbml_w.wr_tagged_u8(tag_trait_alias, byte);
+}
+
 fn encode_defaulted(rbml_w: &mut Encoder, is_defaulted: bool) {
     let byte: u8 = if is_defaulted {1} else {0};
     rbml_w.wr_tagged_u8(tag_defaulted_trait, byte);
@@ -1804,6 +1894,14 @@ fn encode_crate_disambiguator(rbml_w: &mut Encoder, crate_disambiguator: &str) {
     rbml_w.wr_tagged_str(tag_crate_disambiguator, crate_disambiguator);
 }
 
+fn encode_panic_strategy(rbml_w: &mut Encoder, strategy: PanicStrategy) {
+    let byte = match strategy {
+        PanicStrategy::Unwind => b'U',
+        PanicStrategy::Abort => b'A',
+    };
+    rbml_w.wr_tagged_u8(tag_panic_strategy, byte);
+}
+
 fn encode_crate_triple(rbml_w: &mut Encoder, triple: &str) {
     rbml_w.wr_tagged_str(tag_crate_triple, triple);
 }
@@ -1916,6 +2014,7 @@ fn encode_metadata_inner(rbml_w: &mut Encoder,
     encode_hash(rbml_w, &ecx.link_meta.crate_hash);
     encode_crate_disambiguator(rbml_w, &ecx.tcx.sess.crate_disambiguator.get().as_str());
     encode_dylib_dependency_formats(rbml_w, &ecx);
+    encode_panic_strategy(rbml_w, ecx.tcx.sess.panic_strategy());
 
     let mut i = rbml_w.writer.seek(SeekFrom::Current(0)).unwrap();
     encode_attributes(rbml_w, &krate.attrs);
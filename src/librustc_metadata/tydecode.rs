@@ -26,6 +26,7 @@ use rustc::ty::{self, ToPredicate, Ty, TyCtxt, TypeFoldable};
 
 use rbml;
 use rbml::leb128;
+use rustc_const_math::{ConstInt, ConstIsize, ConstUsize};
 use std::str;
 use syntax::abi;
 use syntax::ast;
@@ -146,7 +147,47 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
     pub fn parse_substs(&mut self) -> subst::Substs<'tcx> {
         let regions = self.parse_vec_per_param_space(|this| this.parse_region());
         let types = self.parse_vec_per_param_space(|this| this.parse_ty());
-        subst::Substs { types: types, regions: regions }
+        let consts = self.parse_vec_per_param_space(|this| this.parse_const());
+        subst::Substs { types: types, regions: regions, consts: consts }
+    }
+
+    pub fn parse_const(&mut self) -> ty::Const {
+        match self.next() {
+            'v' => {
+                let ty = self.parse_ty();
+                let bits = self.parse_u64();
+                assert_eq!(self.next(), '|');
+                let target = &self.tcx.sess.target;
+                let value = match ty.sty {
+                    ty::TyInt(ast::IntTy::I8) => ConstInt::I8(bits as i8),
+                    ty::TyInt(ast::IntTy::I16) => ConstInt::I16(bits as i16),
+                    ty::TyInt(ast::IntTy::I32) => ConstInt::I32(bits as i32),
+                    ty::TyInt(ast::IntTy::I64) => ConstInt::I64(bits as i64),
+                    ty::TyInt(ast::IntTy::Is) => {
+                        ConstInt::Isize(ConstIsize::new(bits as i64, target.int_type).unwrap())
+                    }
+                    ty::TyUint(ast::UintTy::U8) => ConstInt::U8(bits as u8),
+                    ty::TyUint(ast::UintTy::U16) => ConstInt::U16(bits as u16),
+                    ty::TyUint(ast::UintTy::U32) => ConstInt::U32(bits as u32),
+                    ty::TyUint(ast::UintTy::U64) => ConstInt::U64(bits),
+                    ty::TyUint(ast::UintTy::Us) => {
+                        ConstInt::Usize(ConstUsize::new(bits, target.uint_type).unwrap())
+                    }
+                    _ => bug!("parse_const: bad const type {:?}", ty),
+                };
+                ty::Const::Value(value)
+            }
+            'p' => {
+                assert_eq!(self.next(), '[');
+                let index = self.parse_u32();
+                assert_eq!(self.next(), '|');
+                let space = self.parse_param_space();
+                assert_eq!(self.next(), '|');
+                let name = token::intern(&self.parse_str(']'));
+                ty::Const::Param(ty::ParamConst::new(space, index, name))
+            }
+            c => bug!("parse_const: bad input {}", c)
+        }
     }
 
     fn parse_bound_region(&mut self) -> ty::BoundRegion {
@@ -319,6 +360,7 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
                 }
             }
             'c' => return tcx.types.char,
+            '!' => return tcx.types.never,
             't' => {
                 assert_eq!(self.next(), '[');
                 let did = self.parse_def();
@@ -357,6 +399,10 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
                     None => tcx.mk_slice(t)
                 };
             }
+            'W' => {
+                let t = self.parse_ty();
+                return tcx.mk_array_const(t, self.parse_const());
+            }
             'v' => {
                 return tcx.mk_str();
             }
@@ -435,8 +481,17 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
             'P' => {
                 assert_eq!(self.next(), '[');
                 let trait_ref = self.parse_trait_ref();
-                let name = token::intern(&self.parse_str(']'));
-                return tcx.mk_projection(trait_ref, name);
+                let name = token::intern(&self.parse_str('|'));
+                let item_substs = self.parse_substs();
+                assert_eq!(self.next(), ']');
+                return tcx.mk_projection(trait_ref, name, tcx.mk_substs(item_substs));
+            }
+            'A' => {
+                assert_eq!(self.next(), '[');
+                let def_id = self.parse_def();
+                let substs = self.parse_substs();
+                assert_eq!(self.next(), ']');
+                return self.tcx.mk_anon(def_id, self.tcx.mk_substs(substs));
             }
             'e' => {
                 return tcx.types.err;
@@ -473,6 +528,17 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
         };
     }
 
+    fn parse_u64(&mut self) -> u64 {
+        let mut n = 0;
+        loop {
+            let cur = self.peek();
+            if cur < '0' || cur > '9' { return n; }
+            self.pos = self.pos + 1;
+            n *= 10;
+            n += (cur as u64) - ('0' as u64);
+        };
+    }
+
     fn parse_u32(&mut self) -> u32 {
         let n = self.parse_uint();
         let m = n as u32;
@@ -574,6 +640,10 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
             projection_ty: ty::ProjectionTy {
                 trait_ref: self.parse_trait_ref(),
                 item_name: token::intern(&self.parse_str('|')),
+                item_substs: {
+                    let item_substs = self.parse_substs();
+                    self.tcx.mk_substs(item_substs)
+                },
             },
             ty: self.parse_ty(),
         }
@@ -601,6 +671,24 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
         }
     }
 
+    pub fn parse_const_param_def(&mut self) -> ty::ConstParameterDef<'tcx> {
+        let name = self.parse_name(':');
+        let def_id = self.parse_def();
+        let space = self.parse_param_space();
+        assert_eq!(self.next(), '|');
+        let index = self.parse_u32();
+        assert_eq!(self.next(), '|');
+        let ty = self.parse_ty();
+
+        ty::ConstParameterDef {
+            name: name,
+            def_id: def_id,
+            space: space,
+            index: index,
+            ty: ty,
+        }
+    }
+
     pub fn parse_region_param_def(&mut self) -> ty::RegionParameterDef {
         let name = self.parse_name(':');
         let def_id = self.parse_def();
@@ -29,6 +29,7 @@ use rustc::hir;
 
 use syntax::abi::Abi;
 use syntax::ast;
+use syntax::attr;
 use syntax::errors::Handler;
 
 use rbml::leb128;
@@ -127,6 +128,11 @@ pub fn enc_ty<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>, t: Ty<'tcx
             enc_ty(w, cx, t);
             write!(w, "/{}|", sz);
         }
+        ty::TyArrayParam(t, c) => {
+            write!(w, "W");
+            enc_ty(w, cx, t);
+            enc_const(w, cx, c);
+        }
         ty::TySlice(t) => {
             write!(w, "V");
             enc_ty(w, cx, t);
@@ -135,6 +141,9 @@ pub fn enc_ty<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>, t: Ty<'tcx
         ty::TyStr => {
             write!(w, "v");
         }
+        ty::TyNever => {
+            write!(w, "!");
+        }
         ty::TyFnDef(def_id, substs, f) => {
             write!(w, "F");
             write!(w, "{}|", (cx.ds)(cx.tcx, def_id));
@@ -168,7 +177,14 @@ pub fn enc_ty<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>, t: Ty<'tcx
         ty::TyProjection(ref data) => {
             write!(w, "P[");
             enc_trait_ref(w, cx, data.trait_ref);
-            write!(w, "{}]", data.item_name);
+            write!(w, "{}|", data.item_name);
+            enc_substs(w, cx, data.item_substs);
+            write!(w, "]");
+        }
+        ty::TyAnon(def_id, substs) => {
+            write!(w, "A[{}|", (cx.ds)(cx.tcx, def_id));
+            enc_substs(w, cx, substs);
+            write!(w, "]");
         }
         ty::TyError => {
             write!(w, "e");
@@ -250,6 +266,29 @@ pub fn enc_substs<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>,
                             |w, cx, &r| enc_region(w, cx, r));
     enc_vec_per_param_space(w, cx, &substs.types,
                             |w, cx, &ty| enc_ty(w, cx, ty));
+    enc_vec_per_param_space(w, cx, &substs.consts,
+                            |w, cx, &c| enc_const(w, cx, c));
+}
+
+pub fn enc_const(w: &mut Cursor<Vec<u8>>, cx: &ctxt, c: ty::Const) {
+    match c {
+        ty::Const::Value(value) => {
+            let ty = match value.int_type() {
+                Some(attr::SignedInt(t)) => cx.tcx.mk_mach_int(t),
+                Some(attr::UnsignedInt(t)) => cx.tcx.mk_mach_uint(t),
+                None => bug!("cannot encode untyped const {}", value),
+            };
+            write!(w, "v");
+            enc_ty(w, cx, ty);
+            write!(w, "{}|", value.to_u64_unchecked());
+        }
+        ty::Const::Param(ty::ParamConst {space, idx, name}) => {
+            write!(w, "p[{}|{}|{}]", idx, space.to_uint(), name);
+        }
+        ty::Const::Infer(_) => {
+            bug!("cannot encode inference variable consts");
+        }
+    }
 }
 
 pub fn enc_region(w: &mut Cursor<Vec<u8>>, cx: &ctxt, r: ty::Region) {
@@ -414,6 +453,14 @@ pub fn enc_type_param_def<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>
     enc_object_lifetime_default(w, cx, v.object_lifetime_default);
 }
 
+pub fn enc_const_param_def<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>,
+                                     v: &ty::ConstParameterDef<'tcx>) {
+    write!(w, "{}:{}|{}|{}|",
+             v.name, (cx.ds)(cx.tcx, v.def_id),
+             v.space.to_uint(), v.index);
+    enc_ty(w, cx, v.ty);
+}
+
 pub fn enc_region_param_def(w: &mut Cursor<Vec<u8>>, cx: &ctxt,
                             v: &ty::RegionParameterDef) {
     write!(w, "{}:{}|{}|{}|",
@@ -495,5 +542,6 @@ fn enc_projection_predicate<'a, 'tcx>(w: &mut Cursor<Vec<u8>>,
                                       data: &ty::ProjectionPredicate<'tcx>) {
     enc_trait_ref(w, cx, data.projection_ty.trait_ref);
     write!(w, "{}|", data.projection_ty.item_name);
+    enc_substs(w, cx, data.projection_ty.item_substs);
     enc_ty(w, cx, data.ty);
 }
@@ -16,6 +16,7 @@ use build::{BlockAnd, BlockAndExtension, Builder};
 use build::expr::category::{Category, RvalueFunc};
 use hair::*;
 use rustc::mir::repr::*;
+use rustc::ty;
 
 impl<'a,'tcx> Builder<'a,'tcx> {
     /// Compile `expr`, yielding an rvalue.
@@ -157,6 +158,22 @@ impl<'a,'tcx> Builder<'a,'tcx> {
             ExprKind::Adt {
                 adt_def, variant_index, substs, fields, base
             } => { // see (*) above
+                if adt_def.is_union() {
+                    // A union literal initializes exactly one of its
+                    // overlapping fields, so assign that field of a temporary.
+                    assert!(base.is_none());
+                    let temp = this.temp(expr.ty);
+                    for f in fields {
+                        let field_expr = this.hir.mirror(f.expr);
+                        let field_ty = field_expr.ty;
+                        let operand = unpack!(block = this.as_operand(block, field_expr));
+                        this.cfg.push_assign(block, scope_id, expr_span,
+                                             &temp.clone().field(f.name, field_ty),
+                                             Rvalue::Use(operand));
+                    }
+                    return block.and(Rvalue::Use(Operand::Consume(temp)));
+                }
+
                 // first process the set of fields that were provided
                 // (evaluating them in order given by user)
                 let fields_map: FnvHashMap<_, _> =
@@ -167,7 +184,13 @@ impl<'a,'tcx> Builder<'a,'tcx> {
                 let field_names = this.hir.all_fields(adt_def, variant_index);
 
                 let fields = if let Some(FruInfo { base, field_types }) = base {
-                    let base = unpack!(block = this.as_lvalue(block, base));
+                    let mut base = unpack!(block = this.as_lvalue(block, base));
+
+                    // Typeck only allows an enum base if the enum has a
+                    // single variant, so the base always holds it.
+                    if adt_def.adt_kind() == ty::AdtKind::Enum {
+                        base = base.elem(ProjectionElem::Downcast(adt_def, variant_index));
+                    }
 
                     // MIR does not natively support FRU, so for each
                     // base-supplied field, generate an operand that
@@ -198,6 +221,7 @@ impl<'a,'tcx> Builder<'a,'tcx> {
             ExprKind::Loop { .. } |
             ExprKind::LogicalOp { .. } |
             ExprKind::Call { .. } |
+            ExprKind::NeverToAny { .. } |
             ExprKind::Field { .. } |
             ExprKind::Deref { .. } |
             ExprKind::Index { .. } |
@@ -163,23 +163,29 @@ impl<'a,'tcx> Builder<'a,'tcx> {
                                    expr_span,
                                    TerminatorKind::Goto { target: loop_block });
 
-                let might_break = this.in_loop_scope(loop_block, exit_block, move |this| {
+                let destination = destination.clone();
+                this.in_loop_scope(loop_block, exit_block, destination.clone(), move |this| {
                     // conduct the test, if necessary
                     let body_block;
                     if let Some(cond_expr) = opt_cond_expr {
-                        // This loop has a condition, ergo its exit_block is reachable.
-                        this.find_loop_scope(expr_span, None).might_break = true;
-
                         let loop_block_end;
                         let cond = unpack!(loop_block_end = this.as_operand(loop_block, cond_expr));
                         body_block = this.cfg.start_new_block();
+                        // A loop that ends because its condition became false has
+                        // the value `()`.
+                        let false_block = this.cfg.start_new_block();
                         this.cfg.terminate(loop_block_end,
                                            scope_id,
                                            expr_span,
                                            TerminatorKind::If {
                                                cond: cond,
-                                               targets: (body_block, exit_block)
+                                               targets: (body_block, false_block)
                                            });
+                        this.cfg.push_assign_unit(false_block, scope_id, expr_span, &destination);
+                        this.cfg.terminate(false_block,
+                                           scope_id,
+                                           expr_span,
+                                           TerminatorKind::Goto { target: exit_block });
                     } else {
                         body_block = loop_block;
                     }
@@ -197,13 +203,18 @@ impl<'a,'tcx> Builder<'a,'tcx> {
                                        expr_span,
                                        TerminatorKind::Goto { target: loop_block });
                 });
-                // If the loop may reach its exit_block, we assign an empty tuple to the
-                // destination to keep the MIR well-formed.
-                if might_break {
-                    this.cfg.push_assign_unit(exit_block, scope_id, expr_span, destination);
-                }
+                // Every edge into exit_block (a `break` or a failed condition) has
+                // already stored the loop's value into the destination.
                 exit_block.unit()
             }
+            ExprKind::NeverToAny { source } => {
+                // The source never produces a value, so whatever follows
+                // it is unreachable and `destination` is never written.
+                let source = this.hir.mirror(source);
+                let temp = this.temp(source.ty);
+                unpack!(block = this.into(&temp, block, source));
+                block.unit()
+            }
             ExprKind::Call { ty, fun, args } => {
                 let diverges = match ty.sty {
                     ty::TyFnDef(_, _, ref f) | ty::TyFnPtr(ref f) => {
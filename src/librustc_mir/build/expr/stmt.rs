@@ -85,11 +85,17 @@ impl<'a,'tcx> Builder<'a,'tcx> {
                 this.break_or_continue(expr_span, label, block,
                                        |loop_scope| loop_scope.continue_block)
             }
-            ExprKind::Break { label } => {
-                this.break_or_continue(expr_span, label, block, |loop_scope| {
-                    loop_scope.might_break = true;
-                    loop_scope.break_block
-                })
+            ExprKind::Break { label, value } => {
+                let destination = this.find_loop_scope(expr_span, label).break_destination.clone();
+                block = match value {
+                    Some(value) => unpack!(this.into(&destination, block, value)),
+                    None => {
+                        this.cfg.push_assign_unit(block, scope_id, expr_span, &destination);
+                        block
+                    }
+                };
+                this.break_or_continue(expr_span, label, block,
+                                       |loop_scope| loop_scope.break_block)
             }
             ExprKind::Return { value } => {
                 block = match value {
@@ -70,6 +70,14 @@ impl<'tcx> Mirror<'tcx> for &'tcx hir::Expr {
                     kind: ExprKind::Cast { source: expr.to_ref() },
                 };
             }
+            Some(&ty::adjustment::AdjustNeverToAny(adjusted_ty)) => {
+                expr = Expr {
+                    temp_lifetime: temp_lifetime,
+                    ty: adjusted_ty,
+                    span: self.span,
+                    kind: ExprKind::NeverToAny { source: expr.to_ref() },
+                };
+            }
             Some(&ty::adjustment::AdjustDerefRef(ref adj)) => {
                 for i in 0..adj.autoderefs {
                     let i = i as u32;
@@ -454,7 +462,6 @@ fn make_mirror_unadjusted<'a, 'tcx>(cx: &mut Cx<'a, 'tcx>, expr: &'tcx hir::Expr
                     match cx.tcx.def_map.borrow()[&expr.id].full_def() {
                         Def::Variant(enum_id, variant_id) => {
                             debug_assert!(adt.did == enum_id);
-                            assert!(base.is_none());
 
                             let index = adt.variant_index_with_id(variant_id);
                             let field_refs = field_refs(&adt.variants[index], fields);
@@ -463,7 +470,15 @@ fn make_mirror_unadjusted<'a, 'tcx>(cx: &mut Cx<'a, 'tcx>, expr: &'tcx hir::Expr
                                 variant_index: index,
                                 substs: substs,
                                 fields: field_refs,
-                                base: None
+                                base: base.as_ref().map(|base| {
+                                    FruInfo {
+                                        base: base.to_ref(),
+                                        field_types: cx.tcx.tables
+                                            .borrow()
+                                            .fru_field_types[&expr.id]
+                                            .clone()
+                                    }
+                                })
                             }
                         }
                         ref def => {
@@ -525,16 +540,21 @@ fn make_mirror_unadjusted<'a, 'tcx>(cx: &mut Cx<'a, 'tcx>, expr: &'tcx hir::Expr
             count: TypedConstVal {
                 ty: cx.tcx.expr_ty(c),
                 span: c.span,
-                value: match const_eval::eval_const_expr(cx.tcx, c) {
-                    ConstVal::Integral(ConstInt::Usize(u)) => u,
-                    other => bug!("constant evaluation of repeat count yielded {:?}", other),
+                value: match cx.tcx.expr_ty(expr).sty {
+                    // The count is a const parameter, resolved at monomorphization.
+                    ty::TyArrayParam(_, count) => count,
+                    _ => match const_eval::eval_const_expr(cx.tcx, c) {
+                        ConstVal::Integral(u @ ConstInt::Usize(_)) => ty::Const::Value(u),
+                        other => bug!("constant evaluation of repeat count yielded {:?}", other),
+                    },
                 },
             }
         },
         hir::ExprRet(ref v) =>
             ExprKind::Return { value: v.to_ref() },
-        hir::ExprBreak(label) =>
-            ExprKind::Break { label: label.map(|_| loop_label(cx, expr)) },
+        hir::ExprBreak(label, ref value) =>
+            ExprKind::Break { label: label.map(|_| loop_label(cx, expr)),
+                              value: value.to_ref() },
         hir::ExprAgain(label) =>
             ExprKind::Continue { label: label.map(|_| loop_label(cx, expr)) },
         hir::ExprMatch(ref discr, ref arms, _) =>
@@ -547,7 +567,7 @@ fn make_mirror_unadjusted<'a, 'tcx>(cx: &mut Cx<'a, 'tcx>, expr: &'tcx hir::Expr
         hir::ExprWhile(ref cond, ref body, _) =>
             ExprKind::Loop { condition: Some(cond.to_ref()),
                              body: block::to_expr_ref(cx, body) },
-        hir::ExprLoop(ref body, _) =>
+        hir::ExprLoop(ref body, _, _) =>
             ExprKind::Loop { condition: None,
                              body: block::to_expr_ref(cx, body) },
         hir::ExprField(ref source, name) => {
@@ -706,6 +726,10 @@ fn convert_path_expr<'a, 'tcx: 'a>(cx: &mut Cx<'a, 'tcx>, expr: &'tcx hir::Expr)
             id: node_id,
         },
 
+        Def::ConstParam(space, index, _, name) => return ExprKind::Literal {
+            literal: Literal::ConstParam { param: ty::ParamConst::new(space, index, name) },
+        },
+
         def @ Def::Local(..) |
         def @ Def::Upvar(..) => return convert_var(cx, expr, def),
 
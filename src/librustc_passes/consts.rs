@@ -412,11 +412,16 @@ impl<'a, 'tcx, 'v> Visitor<'v> for CheckCrateVisitor<'a, 'tcx> {
                 self.visit_expr(&element);
                 // The count is checked elsewhere (typeck).
                 let count = match node_ty.sty {
-                    ty::TyArray(_, n) => n,
+                    ty::TyArray(_, n) => Some(n),
+                    // The length is only known once monomorphized.
+                    ty::TyArrayParam(..) => {
+                        self.add_qualif(ConstQualif::NOT_CONST);
+                        None
+                    }
                     _ => bug!()
                 };
                 // [element; 0] is always zero-sized.
-                if count == 0 {
+                if count == Some(0) {
                     self.qualif.remove(ConstQualif::NON_ZERO_SIZED | ConstQualif::PREFER_IN_PLACE);
                 }
             }
@@ -730,6 +735,20 @@ fn check_expr<'a, 'tcx>(v: &mut CheckCrateVisitor<'a, 'tcx>,
             v.add_qualif(ConstQualif::NON_ZERO_SIZED);
         }
 
+        hir::ExprField(ref base, _) => {
+            // Reading a union field reinterprets the bytes of another
+            // field, which constant evaluation can't do.
+            if let ty::TyStruct(def, _) = v.tcx.expr_ty_adjusted(base).sty {
+                if def.is_union() {
+                    v.add_qualif(ConstQualif::NOT_CONST);
+                    if v.mode != Mode::Var {
+                        span_err!(v.tcx.sess, e.span, E0019,
+                                  "{} contains unimplemented expression type", v.msg());
+                    }
+                }
+            }
+        }
+
         hir::ExprRepeat(..) => {
             v.add_qualif(ConstQualif::PREFER_IN_PLACE);
         }
@@ -746,7 +765,6 @@ fn check_expr<'a, 'tcx>(v: &mut CheckCrateVisitor<'a, 'tcx>,
 
         hir::ExprBlock(_) |
         hir::ExprIndex(..) |
-        hir::ExprField(..) |
         hir::ExprTupField(..) |
         hir::ExprVec(_) |
         hir::ExprType(..) |
@@ -761,7 +779,7 @@ fn check_expr<'a, 'tcx>(v: &mut CheckCrateVisitor<'a, 'tcx>,
         hir::ExprLoop(..) |
 
         // More control flow (also not very meaningful).
-        hir::ExprBreak(_) |
+        hir::ExprBreak(..) |
         hir::ExprAgain(_) |
         hir::ExprRet(_) |
 
@@ -784,7 +802,8 @@ fn check_adjustments<'a, 'tcx>(v: &mut CheckCrateVisitor<'a, 'tcx>, e: &hir::Exp
         None |
         Some(&ty::adjustment::AdjustReifyFnPointer) |
         Some(&ty::adjustment::AdjustUnsafeFnPointer) |
-        Some(&ty::adjustment::AdjustMutToConstPointer) => {}
+        Some(&ty::adjustment::AdjustMutToConstPointer) |
+        Some(&ty::adjustment::AdjustNeverToAny(..)) => {}
 
         Some(&ty::adjustment::AdjustDerefRef(
             ty::adjustment::AutoDerefRef { autoderefs, .. }
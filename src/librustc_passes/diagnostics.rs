@@ -600,6 +600,42 @@ static A : &'static u32 = &S.a; // ok!
 ```
 "##,
 
+E0566: r##"
+A `break` with a value was used inside a `while` or `for` loop. Only `loop`
+can be given a value by `break`, since the other kinds of loop may finish
+without ever reaching a `break`. Erroneous code example:
+
+```compile_fail
+#![feature(loop_break_value)]
+
+let mut i = 1;
+let x = while i < 10 {
+    i *= 2;
+    if i == 4 {
+        break i; // error: `break` with value from a `while` loop
+    }
+};
+```
+
+Use `loop` instead and break out of it explicitly when the condition no
+longer holds:
+
+```
+#![feature(loop_break_value)]
+
+let mut i = 1;
+let x = loop {
+    if i >= 10 {
+        break 0;
+    }
+    i *= 2;
+    if i == 4 {
+        break i; // ok!
+    }
+};
+```
+"##,
+
 }
 
 register_diagnostics! {
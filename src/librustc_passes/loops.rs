@@ -12,26 +12,49 @@ use self::Context::*;
 use rustc::session::Session;
 
 use rustc::dep_graph::DepNode;
+use rustc::hir::def::{Def, DefMap};
 use rustc::hir::map::Map;
 use rustc::hir::intravisit::{self, Visitor};
 use rustc::hir;
 use syntax::codemap::Span;
 
+#[derive(Clone, Copy, PartialEq)]
+enum LoopKind {
+    Loop(hir::LoopSource),
+    WhileLoop,
+}
+
+impl LoopKind {
+    fn name(self) -> &'static str {
+        match self {
+            LoopKind::Loop(source) => source.name(),
+            LoopKind::WhileLoop => "while",
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum Context {
-    Normal, Loop, Closure
+    Normal, Loop(LoopKind), Closure
 }
 
 #[derive(Copy, Clone)]
 struct CheckLoopVisitor<'a> {
     sess: &'a Session,
+    map: &'a Map<'a>,
+    def_map: &'a DefMap,
     cx: Context
 }
 
-pub fn check_crate(sess: &Session, map: &Map) {
+pub fn check_crate(sess: &Session, map: &Map, def_map: &DefMap) {
     let _task = map.dep_graph.in_task(DepNode::CheckLoops);
     let krate = map.krate();
-    krate.visit_all_items(&mut CheckLoopVisitor { sess: sess, cx: Normal });
+    krate.visit_all_items(&mut CheckLoopVisitor {
+        sess: sess,
+        map: map,
+        def_map: def_map,
+        cx: Normal
+    });
 }
 
 impl<'a, 'v> Visitor<'v> for CheckLoopVisitor<'a> {
@@ -43,16 +66,39 @@ impl<'a, 'v> Visitor<'v> for CheckLoopVisitor<'a> {
         match e.node {
             hir::ExprWhile(ref e, ref b, _) => {
                 self.visit_expr(&e);
-                self.with_context(Loop, |v| v.visit_block(&b));
+                self.with_context(Loop(LoopKind::WhileLoop), |v| v.visit_block(&b));
             }
-            hir::ExprLoop(ref b, _) => {
-                self.with_context(Loop, |v| v.visit_block(&b));
+            hir::ExprLoop(ref b, _, source) => {
+                self.with_context(Loop(LoopKind::Loop(source)), |v| v.visit_block(&b));
             }
             hir::ExprClosure(_, _, ref b, _) => {
                 self.with_context(Closure, |v| v.visit_block(&b));
             }
-            hir::ExprBreak(_) => self.require_loop("break", e.span),
-            hir::ExprAgain(_) => self.require_loop("continue", e.span),
+            hir::ExprBreak(ref opt_label, ref opt_expr) => {
+                if self.require_loop("break", e.span) && opt_expr.is_some() {
+                    let loop_kind = if opt_label.is_some() {
+                        self.labeled_loop_kind(e)
+                    } else if let Loop(kind) = self.cx {
+                        Some(kind)
+                    } else {
+                        None
+                    };
+                    match loop_kind {
+                        Some(LoopKind::Loop(hir::LoopSource::Loop)) | None => {}
+                        Some(kind) => {
+                            struct_span_err!(self.sess, e.span, E0566,
+                                             "`break` with value from a `{}` loop",
+                                             kind.name())
+                                .span_label(e.span, &"can only break with a value inside `loop`")
+                                .emit();
+                        }
+                    }
+                }
+                intravisit::walk_expr(self, e);
+            }
+            hir::ExprAgain(_) => {
+                self.require_loop("continue", e.span);
+            }
             _ => intravisit::walk_expr(self, e)
         }
     }
@@ -68,17 +114,33 @@ impl<'a> CheckLoopVisitor<'a> {
         self.cx = old_cx;
     }
 
-    fn require_loop(&self, name: &str, span: Span) {
+    fn require_loop(&self, name: &str, span: Span) -> bool {
         match self.cx {
-            Loop => {}
+            Loop(_) => true,
             Closure => {
                 span_err!(self.sess, span, E0267,
                                    "`{}` inside of a closure", name);
+                false
             }
             Normal => {
                 span_err!(self.sess, span, E0268,
                                    "`{}` outside of loop", name);
+                false
             }
         }
     }
+
+    /// Finds the kind of loop a labeled `break` refers to, using the
+    /// label definition recorded during name resolution.
+    fn labeled_loop_kind(&self, e: &hir::Expr) -> Option<LoopKind> {
+        let loop_id = match self.def_map.get(&e.id).map(|d| d.base_def) {
+            Some(Def::Label(loop_id)) => loop_id,
+            _ => return None
+        };
+        match self.map.expect_expr(loop_id).node {
+            hir::ExprLoop(_, _, source) => Some(LoopKind::Loop(source)),
+            hir::ExprWhile(..) => Some(LoopKind::WhileLoop),
+            _ => None
+        }
+    }
 }
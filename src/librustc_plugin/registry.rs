@@ -41,7 +41,7 @@ pub struct Registry<'a> {
     pub sess: &'a Session,
 
     #[doc(hidden)]
-    pub args_hidden: Option<Vec<P<ast::MetaItem>>>,
+    pub args_hidden: Option<Vec<ast::NestedMetaItem>>,
 
     #[doc(hidden)]
     pub krate_span: Span,
@@ -92,7 +92,7 @@ impl<'a> Registry<'a> {
     /// ```no_run
     /// #![plugin(my_plugin_name(... args ...))]
     /// ```
-    pub fn args<'b>(&'b self) -> &'b Vec<P<ast::MetaItem>> {
+    pub fn args<'b>(&'b self) -> &'b Vec<ast::NestedMetaItem> {
         self.args_hidden.as_ref().expect("args not set")
     }
 
@@ -37,7 +37,7 @@ use rustc::hir::{Block, DeclItem};
 use rustc::hir::{ForeignItem, ForeignItemFn, ForeignItemStatic};
 use rustc::hir::{Item, ItemConst, ItemEnum, ItemExternCrate, ItemFn};
 use rustc::hir::{ItemForeignMod, ItemImpl, ItemMod, ItemStatic, ItemDefaultImpl};
-use rustc::hir::{ItemStruct, ItemTrait, ItemTy, ItemUse};
+use rustc::hir::{ItemStruct, ItemTrait, ItemTy, ItemUnion, ItemUse};
 use rustc::hir::{PathListIdent, PathListMod, StmtDecl};
 use rustc::hir::{Variant, ViewPathGlob, ViewPathList, ViewPathSimple};
 use rustc::hir::intravisit::{self, Visitor};
@@ -320,8 +320,29 @@ impl<'b, 'tcx:'b> Resolver<'b, 'tcx> {
                 self.structs.insert(item_def_id, field_names);
             }
 
+            // Unions only live in the type namespace, they have no constructor.
+            ItemUnion(ref struct_def, _) => {
+                let def = Def::Struct(self.ast_map.local_def_id(item.id));
+                self.define(parent, name, TypeNS, (def, sp, vis));
+
+                // Record the def ID and fields of this union.
+                let field_names = struct_def.fields().iter().map(|field| {
+                    self.resolve_visibility(&field.vis);
+                    field.name
+                }).collect();
+                let item_def_id = self.ast_map.local_def_id(item.id);
+                self.structs.insert(item_def_id, field_names);
+            }
+
             ItemDefaultImpl(_, _) | ItemImpl(..) => {}
 
+            ItemTraitAlias(..) => {
+                // A trait alias has no items, so unlike a trait it needs no module; it is
+                // otherwise resolved just like the trait it stands for.
+                let def = Def::Trait(self.ast_map.local_def_id(item.id));
+                self.define(parent, name, TypeNS, (def, sp, vis));
+            }
+
             ItemTrait(_, _, _, ref items) => {
                 let def_id = self.ast_map.local_def_id(item.id);
 
@@ -497,6 +518,7 @@ impl<'b, 'tcx:'b> Resolver<'b, 'tcx> {
             Def::Local(..) |
             Def::PrimTy(..) |
             Def::TyParam(..) |
+            Def::ConstParam(..) |
             Def::Upvar(..) |
             Def::Label(..) |
             Def::SelfTy(..) |
@@ -74,7 +74,7 @@ use rustc::hir::{ExprPath, ExprStruct, FnDecl};
 use rustc::hir::{ForeignItemFn, ForeignItemStatic, Generics};
 use rustc::hir::{ImplItem, Item, ItemConst, ItemEnum, ItemExternCrate};
 use rustc::hir::{ItemFn, ItemForeignMod, ItemImpl, ItemMod, ItemStatic, ItemDefaultImpl};
-use rustc::hir::{ItemStruct, ItemTrait, ItemTy, ItemUse};
+use rustc::hir::{ItemStruct, ItemTrait, ItemTy, ItemUnion, ItemUse};
 use rustc::hir::Local;
 use rustc::hir::{Pat, PatKind, Path, PrimTy};
 use rustc::hir::{PathSegment, PathParameters};
@@ -1573,7 +1573,8 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
         match item.node {
             ItemEnum(_, ref generics) |
             ItemTy(_, ref generics) |
-            ItemStruct(_, ref generics) => {
+            ItemStruct(_, ref generics) |
+            ItemUnion(_, ref generics) => {
                 self.with_type_parameter_rib(HasTypeParameters(generics, TypeSpace, ItemRibKind),
                                              |this| intravisit::walk_item(this, item));
             }
@@ -1585,7 +1586,7 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
             ItemDefaultImpl(_, ref trait_ref) => {
                 self.with_optional_trait_ref(Some(trait_ref), |_, _| {});
             }
-            ItemImpl(_, _, ref generics, ref opt_trait_ref, ref self_type, ref impl_items) => {
+            ItemImpl(_, _, _, ref generics, ref opt_trait_ref, ref self_type, ref impl_items) => {
                 self.resolve_implementation(generics,
                                             opt_trait_ref,
                                             &self_type,
@@ -1627,8 +1628,12 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
                                         intravisit::walk_trait_item(this, trait_item)
                                     });
                                 }
-                                hir::TypeTraitItem(..) => {
-                                    this.with_type_parameter_rib(NoTypeParameters, |this| {
+                                hir::TypeTraitItem(_, _, ref generics) => {
+                                    let type_parameters =
+                                        HasTypeParameters(generics,
+                                                          FnSpace,
+                                                          MethodRibKind);
+                                    this.with_type_parameter_rib(type_parameters, |this| {
                                         intravisit::walk_trait_item(this, trait_item)
                                     });
                                 }
@@ -1638,6 +1643,20 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
                 });
             }
 
+            ItemTraitAlias(ref generics, ref bounds) => {
+                // Create a new rib for the alias-wide type parameters.
+                self.with_type_parameter_rib(HasTypeParameters(generics,
+                                                               TypeSpace,
+                                                               ItemRibKind),
+                                             |this| {
+                    let local_def_id = this.ast_map.local_def_id(item.id);
+                    this.with_self_rib(Def::SelfTy(Some(local_def_id), None), |this| {
+                        this.visit_generics(generics);
+                        walk_list!(this, visit_ty_param_bound, bounds);
+                    });
+                });
+            }
+
             ItemMod(_) | ItemForeignMod(_) => {
                 self.with_scope(item.id, |this| {
                     intravisit::walk_item(this, item);
@@ -1707,6 +1726,25 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
                     function_type_rib.bindings.insert(name, def);
                 }
                 self.type_ribs.push(function_type_rib);
+
+                // Const parameters live in the value namespace.
+                let mut function_value_rib = Rib::new(rib_kind);
+                for (index, const_parameter) in generics.const_params.iter().enumerate() {
+                    let name = const_parameter.name;
+                    debug!("with_type_parameter_rib: const {}", const_parameter.id);
+
+                    if seen_bindings.contains(&name) {
+                        resolve_error(self,
+                                      const_parameter.span,
+                                      ResolutionError::NameAlreadyUsedInTypeParameterList(name));
+                    }
+                    seen_bindings.insert(name);
+
+                    let def_id = self.ast_map.local_def_id(const_parameter.id);
+                    let def = Def::ConstParam(space, index as u32, def_id, name);
+                    function_value_rib.bindings.insert(name, def);
+                }
+                self.value_ribs.push(function_value_rib);
             }
 
             NoTypeParameters => {
@@ -1717,6 +1755,7 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
         f(self);
 
         if let HasTypeParameters(..) = type_parameters {
+            self.value_ribs.pop();
             self.type_ribs.pop();
         }
     }
@@ -1950,14 +1989,22 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
                                         intravisit::walk_impl_item(this, impl_item);
                                     });
                                 }
-                                hir::ImplItemKind::Type(ref ty) => {
+                                hir::ImplItemKind::Type(_, ref generics) => {
                                     // If this is a trait impl, ensure the type
                                     // exists in trait
                                     this.check_trait_item(impl_item.name,
                                                           impl_item.span,
                                         |n, s| ResolutionError::TypeNotMemberOfTrait(n, s));
 
-                                    this.visit_ty(ty);
+                                    // We also need a new scope for the type-
+                                    // specific type parameters.
+                                    let type_parameters =
+                                        HasTypeParameters(generics,
+                                                          FnSpace,
+                                                          MethodRibKind);
+                                    this.with_type_parameter_rib(type_parameters, |this| {
+                                        intravisit::walk_impl_item(this, impl_item);
+                                    });
                                 }
                             }
                         }
@@ -2641,7 +2688,7 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
                     }
                 }
             }
-            Def::TyParam(..) | Def::SelfTy(..) => {
+            Def::TyParam(..) | Def::ConstParam(..) | Def::SelfTy(..) => {
                 for rib in ribs {
                     match rib.kind {
                         NormalRibKind | MethodRibKind | ClosureRibKind(..) |
@@ -3020,7 +3067,7 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
                 intravisit::walk_expr(self, expr);
             }
 
-            ExprLoop(_, Some(label)) | ExprWhile(_, _, Some(label)) => {
+            ExprLoop(_, Some(label), _) | ExprWhile(_, _, Some(label)) => {
                 self.with_label_rib(|this| {
                     let def = Def::Label(expr.id);
 
@@ -3033,7 +3080,7 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
                 })
             }
 
-            ExprBreak(Some(label)) | ExprAgain(Some(label)) => {
+            ExprBreak(Some(label), _) | ExprAgain(Some(label)) => {
                 match self.search_label(label.node.name) {
                     None => {
                         self.record_def(expr.id, err_path_resolution());
@@ -3053,6 +3100,8 @@ impl<'a, 'tcx> Resolver<'a, 'tcx> {
                         span_bug!(expr.span, "label wasn't mapped to a label def!")
                     }
                 }
+
+                intravisit::walk_expr(self, expr);
             }
             ExprField(ref subexpression, _) => {
                 self.resolve_expr(subexpression, Some(expr));
@@ -321,6 +321,7 @@ impl<'l, 'tcx: 'l, 'll, D: Dump + 'll> DumpVisitor<'l, 'tcx, 'll, D> {
             Def::Static(_, _) |
             Def::Const(_) |
             Def::AssociatedConst(..) |
+            Def::ConstParam(..) |
             Def::Local(..) |
             Def::Variant(..) |
             Def::Upvar(..) => {
@@ -1092,7 +1093,7 @@ impl<'v, 'l, 'tcx: 'l, 'll, D: Dump +'ll> Visitor<'v> for DumpVisitor<'l, 'tcx,
                 self.process_static_or_const_item(item, &typ, &expr),
             Struct(ref def, ref ty_params) => self.process_struct(item, def, ty_params),
             Enum(ref def, ref ty_params) => self.process_enum(item, def, ty_params),
-            Impl(_, _,
+            Impl(_, _, _,
                           ref ty_params,
                           ref trait_ref,
                           ref typ,
@@ -1178,7 +1179,7 @@ impl<'v, 'l, 'tcx: 'l, 'll, D: Dump +'ll> Visitor<'v> for DumpVisitor<'l, 'tcx,
                                     impl_item.ident.name,
                                     impl_item.span);
             }
-            ast::ImplItemKind::Type(_) |
+            ast::ImplItemKind::Type(..) |
             ast::ImplItemKind::Macro(_) => {}
         }
     }
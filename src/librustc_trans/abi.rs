@@ -22,6 +22,7 @@ use cabi_powerpc;
 use cabi_powerpc64;
 use cabi_mips;
 use cabi_asmjs;
+use cabi_riscv;
 use machine::{llalign_of_min, llsize_of, llsize_of_real};
 use type_::Type;
 use type_of;
@@ -464,7 +465,9 @@ impl FnType {
             "mips" => cabi_mips::compute_abi_info(ccx, self),
             "powerpc" => cabi_powerpc::compute_abi_info(ccx, self),
             "powerpc64" => cabi_powerpc64::compute_abi_info(ccx, self),
-            "asmjs" => cabi_asmjs::compute_abi_info(ccx, self),
+            "riscv32" => cabi_riscv::compute_abi_info(ccx, self),
+            // The WebAssembly C ABI currently matches Emscripten's asm.js one.
+            "asmjs" | "wasm32" => cabi_asmjs::compute_abi_info(ccx, self),
             a => ccx.sess().fatal(&format!("unrecognized arch \"{}\" in target specification", a))
         }
 
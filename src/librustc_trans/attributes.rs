@@ -11,7 +11,7 @@
 
 use libc::c_uint;
 use llvm::{self, ValueRef};
-use session::config::NoDebugInfo;
+use session::config::{NoDebugInfo, Sanitizer};
 pub use syntax::attr::InlineAttr;
 use syntax::ast;
 use context::CrateContext;
@@ -74,6 +74,24 @@ pub fn naked(val: ValueRef, is_naked: bool) {
     }
 }
 
+/// Tell LLVM to instrument this function for the sanitizer chosen with `-Z sanitizer`, if any.
+#[inline]
+pub fn sanitize(ccx: &CrateContext, val: ValueRef) {
+    match ccx.sess().opts.debugging_opts.sanitizer {
+        Some(Sanitizer::Address) => {
+            llvm::SetFunctionAttribute(val, llvm::Attribute::SanitizeAddress)
+        }
+        Some(Sanitizer::Memory) => {
+            llvm::SetFunctionAttribute(val, llvm::Attribute::SanitizeMemory)
+        }
+        Some(Sanitizer::Thread) => {
+            llvm::SetFunctionAttribute(val, llvm::Attribute::SanitizeThread)
+        }
+        // LeakSanitizer only needs its runtime, there's no instrumentation.
+        Some(Sanitizer::Leak) | None => {}
+    }
+}
+
 /// Composite function which sets LLVM attributes for function depending on its AST (#[attribute])
 /// attributes.
 pub fn from_fn_attrs(ccx: &CrateContext, attrs: &[ast::Attribute], llfn: ValueRef) {
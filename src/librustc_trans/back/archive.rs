@@ -23,6 +23,7 @@ use std::str;
 
 use middle::cstore::CrateStore;
 
+use back::lto;
 use libc;
 use llvm::archive_ro::{ArchiveRO, Child};
 use llvm::{self, ArchiveKind};
@@ -147,10 +148,20 @@ impl<'a> ArchiveBuilder<'a> {
 
     /// Adds all of the contents of a native library to this archive. This will
     /// search in the relevant locations for a library named `name`.
+    ///
+    /// If LTO is enabled then any LLVM bitcode in the library has already been
+    /// merged into the crate's module and isn't added.
     pub fn add_native_library(&mut self, name: &str) {
         let location = find_library(name, &self.config.lib_search_paths,
                                     self.config.sess);
-        self.add_archive(&location, name, |_| false).unwrap_or_else(|e| {
+        let bitcode = if self.config.sess.lto() {
+            lto::bitcode_members(&location)
+        } else {
+            Vec::new()
+        };
+        self.add_archive(&location, name, move |fname: &str| {
+            bitcode.iter().any(|b| b == fname)
+        }).unwrap_or_else(|e| {
             self.config.sess.fatal(&format!("failed to add native library {}: {}",
                                             location.to_string_lossy(), e));
         });
@@ -160,24 +171,22 @@ impl<'a> ArchiveBuilder<'a> {
     /// archive.
     ///
     /// This ignores adding the bytecode from the rlib, and if LTO is enabled
-    /// then the object file also isn't added.
+    /// then the object file and any LLVM bitcode from native libraries also
+    /// aren't added.
     pub fn add_rlib(&mut self, rlib: &Path, name: &str, lto: bool)
                     -> io::Result<()> {
-        // Ignoring obj file starting with the crate name
-        // as simple comparison is not enough - there
-        // might be also an extra name suffix
-        let obj_start = format!("{}", name);
-
         // Ignoring all bytecode files, no matter of
         // name
         let bc_ext = ".bytecode.deflate";
         let metadata_filename =
             self.config.sess.cstore.metadata_filename().to_owned();
+        let bitcode = if lto { lto::bitcode_members(rlib) } else { Vec::new() };
+        let rlib_path = rlib.to_path_buf();
 
         self.add_archive(rlib, &name[..], move |fname: &str| {
-            let skip_obj = lto && fname.starts_with(&obj_start)
-                && fname.ends_with(".o");
-            skip_obj || fname.ends_with(bc_ext) || fname == metadata_filename
+            let skip_obj = lto && lto::is_rust_object(&rlib_path, fname);
+            skip_obj || fname.ends_with(bc_ext) || fname == metadata_filename ||
+                bitcode.iter().any(|b| b == fname)
         })
     }
 
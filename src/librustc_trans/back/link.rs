@@ -8,13 +8,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::archive::{ArchiveBuilder, ArchiveConfig};
-use super::linker::{Linker, GnuLinker, MsvcLinker};
+use super::archive::{self, ArchiveBuilder, ArchiveConfig};
+use super::linker::{self, Linker, GnuLinker, MsvcLinker, WasmLd};
+use super::lto;
 use super::rpath::RPathConfig;
 use super::rpath;
+use super::size_report;
 use super::msvc;
 use session::config;
-use session::config::NoDebugInfo;
+use session::config::{NoDebugInfo, SplitDebuginfo};
 use session::config::{OutputFilenames, Input, OutputType};
 use session::filesearch;
 use session::search_paths::PathKind;
@@ -26,6 +28,7 @@ use CrateTranslation;
 use util::common::time;
 use util::fs::fix_windows_verbatim_for_gcc;
 use rustc::ty::TyCtxt;
+use rustc_back::LinkerFlavor;
 use rustc_back::tempdir::TempDir;
 
 use rustc_incremental::SvhCalculate;
@@ -136,14 +139,29 @@ pub fn build_link_meta(tcx: &TyCtxt,
 }
 
 pub fn get_linker(sess: &Session) -> (String, Command) {
-    if let Some(ref linker) = sess.opts.cg.linker {
-        (linker.clone(), Command::new(linker))
-    } else if sess.target.target.options.is_like_msvc {
-        ("link.exe".to_string(), msvc::link_exe_cmd(sess))
-    } else {
+    let target_linker = || {
         (sess.target.target.options.linker.clone(),
          Command::new(&sess.target.target.options.linker))
+    };
+    if let Some(ref linker) = sess.opts.cg.linker {
+        return (linker.clone(), Command::new(linker))
     }
+    let flavor = sess.linker_flavor();
+    // The target's own linker already speaks its default flavor, otherwise
+    // fall back to the usual name of the requested LLD driver.
+    let lld = match flavor {
+        LinkerFlavor::Msvc => {
+            return ("link.exe".to_string(), msvc::link_exe_cmd(sess))
+        }
+        LinkerFlavor::Gcc | LinkerFlavor::Gold => return target_linker(),
+        _ if flavor == sess.target.target.options.linker_flavor => {
+            return target_linker()
+        }
+        LinkerFlavor::LdLld => "ld.lld",
+        LinkerFlavor::LldLink => "lld-link",
+        LinkerFlavor::WasmLd => "wasm-ld",
+    };
+    (lld.to_string(), Command::new(lld))
 }
 
 pub fn get_ar_prog(sess: &Session) -> String {
@@ -194,12 +212,35 @@ pub fn link_binary(sess: &Session,
         out_filenames.push(out_file);
     }
 
+    // The code size report is read out of what the linker produced, archives
+    // don't have the final code in them yet.
+    if sess.opts.debugging_opts.print_code_size {
+        let linked = sess.crate_types.borrow().iter().zip(&out_filenames).filter(|&(ty, _)| {
+            *ty == config::CrateTypeExecutable || *ty == config::CrateTypeDylib
+        }).map(|(_, out)| out.clone()).collect::<Vec<_>>();
+        if linked.is_empty() {
+            sess.warn("-Z print-code-size only reports on executables and dynamic libraries");
+        }
+        size_report::report(sess, trans, &linked);
+    }
+
     // Remove the temporary object file and metadata if we aren't saving temps
     if !sess.opts.cg.save_temps {
         for obj in object_filenames(sess, outputs) {
             remove(sess, &obj);
         }
         remove(sess, &outputs.with_extension("metadata.o"));
+
+        // Once packaged the `.dwo` files are only needed by rlibs and static
+        // libraries, whose objects still point at them.
+        let keep_dwo = sess.crate_types.borrow().iter().any(|ty| {
+            *ty == config::CrateTypeRlib || *ty == config::CrateTypeStaticlib
+        });
+        if sess.split_debuginfo() == SplitDebuginfo::Packed && !keep_dwo {
+            for i in 0..sess.opts.cg.codegen_units {
+                remove(sess, &outputs.with_extension(&format!("{}.dwo", i)));
+            }
+        }
     }
 
     out_filenames
@@ -355,7 +396,7 @@ fn object_filenames(sess: &Session, outputs: &OutputFilenames) -> Vec<PathBuf> {
     }).collect()
 }
 
-fn archive_search_paths(sess: &Session) -> Vec<PathBuf> {
+pub fn archive_search_paths(sess: &Session) -> Vec<PathBuf> {
     let mut search = Vec::new();
     sess.target_filesearch(PathKind::Native).for_each_lib_search_path(|path, _| {
         search.push(path.to_path_buf());
@@ -605,6 +646,31 @@ fn link_staticlib(sess: &Session, objects: &[PathBuf], out_filename: &Path,
     }
 }
 
+// Makes sure the requested linker flavor can produce output for the target,
+// since e.g. an ELF linker has no hope of linking COFF objects. LLD's ELF
+// flavor is also limited to freestanding targets: invoked directly it doesn't
+// know where the C runtime's startup objects and libraries live, which the C
+// compiler would otherwise have passed along.
+fn check_linker_flavor(sess: &Session, flavor: LinkerFlavor) {
+    let opts = &sess.target.target.options;
+    let ok = match flavor {
+        LinkerFlavor::Msvc | LinkerFlavor::LldLink => opts.is_like_msvc,
+        LinkerFlavor::WasmLd => opts.is_like_wasm,
+        LinkerFlavor::Gcc | LinkerFlavor::Gold => {
+            !opts.is_like_msvc && !opts.is_like_wasm
+        }
+        LinkerFlavor::LdLld => {
+            !opts.is_like_msvc && !opts.is_like_wasm &&
+            !opts.is_like_osx && !opts.is_like_windows &&
+            sess.target.target.target_os == "none"
+        }
+    };
+    if !ok {
+        sess.fatal(&format!("linker flavor `{}` cannot link for target `{}`",
+                            flavor.desc(), sess.opts.target_triple));
+    }
+}
+
 // Create a dynamic library or executable
 //
 // This will invoke the system linker/cc to create the resulting file. This
@@ -617,39 +683,56 @@ fn link_natively(sess: &Session, dylib: bool,
     info!("preparing dylib? ({}) from {:?} to {:?}", dylib, objects,
           out_filename);
 
+    let flavor = sess.linker_flavor();
+    check_linker_flavor(sess, flavor);
+
     // The invocations of cc share some flags across platforms
     let (pname, mut cmd) = get_linker(sess);
     cmd.env("PATH", command_path(sess));
+    if flavor == LinkerFlavor::Gold {
+        cmd.arg("-fuse-ld=gold");
+    }
 
     let root = sess.target_filesearch(PathKind::Native).get_lib_path();
-    cmd.args(&sess.target.target.options.pre_link_args);
-
     let pre_link_objects = if dylib {
         &sess.target.target.options.pre_link_objects_dll
     } else {
         &sess.target.target.options.pre_link_objects_exe
     };
-    for obj in pre_link_objects {
-        cmd.arg(root.join(obj));
-    }
 
     {
-        let mut linker = if sess.target.target.options.is_like_msvc {
-            Box::new(MsvcLinker { cmd: &mut cmd, sess: &sess }) as Box<Linker>
-        } else {
-            Box::new(GnuLinker { cmd: &mut cmd, sess: &sess }) as Box<Linker>
+        // Everything goes through the `Linker` so that arguments written for a
+        // C compiler are translated when the linker is invoked directly.
+        let mut linker = match flavor {
+            LinkerFlavor::Msvc | LinkerFlavor::LldLink => {
+                Box::new(MsvcLinker { cmd: &mut cmd, sess: &sess }) as Box<Linker>
+            }
+            LinkerFlavor::WasmLd => {
+                Box::new(WasmLd { cmd: &mut cmd, sess: &sess }) as Box<Linker>
+            }
+            LinkerFlavor::Gcc | LinkerFlavor::Gold | LinkerFlavor::LdLld => {
+                Box::new(GnuLinker {
+                    cmd: &mut cmd,
+                    sess: &sess,
+                    is_ld: flavor == LinkerFlavor::LdLld,
+                }) as Box<Linker>
+            }
         };
+        linker.args(&sess.target.target.options.pre_link_args);
+        for obj in pre_link_objects {
+            linker.add_object(&root.join(obj));
+        }
         link_args(&mut *linker, sess, dylib, tmpdir,
                   objects, out_filename, trans, outputs);
         if !sess.target.target.options.no_compiler_rt {
             linker.link_staticlib("compiler-rt");
         }
+        linker.args(&sess.target.target.options.late_link_args);
+        for obj in &sess.target.target.options.post_link_objects {
+            linker.add_object(&root.join(obj));
+        }
+        linker.args(&sess.target.target.options.post_link_args);
     }
-    cmd.args(&sess.target.target.options.late_link_args);
-    for obj in &sess.target.target.options.post_link_objects {
-        cmd.arg(root.join(obj));
-    }
-    cmd.args(&sess.target.target.options.post_link_args);
 
     if sess.opts.debugging_opts.print_link_args {
         println!("{:?}", &cmd);
@@ -701,6 +784,29 @@ fn link_natively(sess: &Session, dylib: bool,
             Err(e) => sess.fatal(&format!("failed to run dsymutil: {}", e)),
         }
     }
+
+    // Similarly the split debuginfo of this crate and everything linked into
+    // it gets packaged up next to the output, where debuggers look for it.
+    if sess.split_debuginfo() == SplitDebuginfo::Packed {
+        let mut dwp = out_filename.as_os_str().to_owned();
+        dwp.push(".dwp");
+        let mut cmd = Command::new("dwp");
+        cmd.arg("-e").arg(out_filename).arg("-o").arg(&dwp);
+        match cmd.output() {
+            Ok(ref prog) if !prog.status.success() => {
+                let mut output = prog.stderr.clone();
+                output.extend_from_slice(&prog.stdout);
+                sess.struct_err(&format!("packaging split debuginfo with `dwp` failed: {}",
+                                         prog.status))
+                    .note(&format!("{:?}", &cmd))
+                    .note(&String::from_utf8_lossy(&output))
+                    .emit();
+                sess.abort_if_errors();
+            }
+            Ok(..) => {}
+            Err(e) => sess.fatal(&format!("failed to run dwp: {}", e)),
+        }
+    }
 }
 
 fn link_args(cmd: &mut Linker,
@@ -740,7 +846,7 @@ fn link_args(cmd: &mut Linker,
 
     // Try to strip as much out of the generated object by removing unused
     // sections if possible. See more comments in linker.rs
-    if !sess.opts.cg.link_dead_code {
+    if linker::gc_sections(sess) {
         cmd.gc_sections(dylib);
     }
 
@@ -762,6 +868,12 @@ fn link_args(cmd: &mut Linker,
     // Pass optimization flags down to the linker.
     cmd.optimize();
 
+    // Make sure the profiler runtime is pulled in for instrumented code.
+    if sess.opts.cg.profile_generate.is_some() ||
+       sess.opts.debugging_opts.instrument_coverage {
+        cmd.pgo_gen();
+    }
+
     // Pass debuginfo flags down to the linker.
     cmd.debuginfo();
 
@@ -800,7 +912,7 @@ fn link_args(cmd: &mut Linker,
     // link line. And finally upstream native libraries can't depend on anything
     // in this DAG so far because they're only dylibs and dylibs can only depend
     // on other dylibs (e.g. other native deps).
-    add_local_native_libraries(cmd, sess);
+    add_local_native_libraries(cmd, sess, tmpdir);
     add_upstream_rust_crates(cmd, sess, dylib, tmpdir);
     add_upstream_native_libraries(cmd, sess);
 
@@ -854,7 +966,7 @@ fn link_args(cmd: &mut Linker,
 // Also note that the native libraries linked here are only the ones located
 // in the current crate. Upstream crates with native library dependencies
 // may have their native library pulled in above.
-fn add_local_native_libraries(cmd: &mut Linker, sess: &Session) {
+fn add_local_native_libraries(cmd: &mut Linker, sess: &Session, tmpdir: &Path) {
     sess.target_filesearch(PathKind::All).for_each_lib_search_path(|path, k| {
         match k {
             PathKind::Framework => { cmd.framework_path(path); }
@@ -885,6 +997,21 @@ fn add_local_native_libraries(cmd: &mut Linker, sess: &Session) {
         // don't otherwise explicitly reference them. This can occur for
         // libraries which are just providing bindings, libraries with generic
         // functions, etc.
+        //
+        // Under LTO any LLVM bitcode in the library is already part of the
+        // crate's module, so only the rest of it gets linked.
+        if sess.lto() {
+            let path = archive::find_library(l, &search_path, sess);
+            if !lto::bitcode_members(&path).is_empty() {
+                let dst = tmpdir.join(path.file_name().unwrap());
+                let mut ab = ArchiveBuilder::new(archive_config(sess, &dst, None));
+                ab.add_native_library(l);
+                ab.update_symbols();
+                ab.build();
+                cmd.link_whole_rlib(&fix_windows_verbatim_for_gcc(&dst));
+                continue
+            }
+        }
         cmd.link_whole_staticlib(l, &search_path);
     }
 
@@ -933,6 +1060,14 @@ fn add_upstream_rust_crates(cmd: &mut Linker, sess: &Session,
         match data[cnum as usize - 1] {
             Linkage::NotLinked |
             Linkage::IncludedFromDylib => {}
+            // Sanitizer runtimes are only linked into executables, and they're
+            // linked in whole so their interceptors take effect even though
+            // nothing refers to them.
+            Linkage::Static if sess.cstore.is_sanitizer_runtime(cnum) => {
+                if !dylib {
+                    add_static_crate(cmd, sess, tmpdir, true, &src.rlib.unwrap().0)
+                }
+            }
             Linkage::Static => {
                 add_static_crate(cmd, sess, tmpdir, dylib, &src.rlib.unwrap().0)
             }
@@ -956,7 +1091,8 @@ fn add_upstream_rust_crates(cmd: &mut Linker, sess: &Session,
     // dynamic libraries. Specifically:
     //
     // * For LTO, we remove upstream object files.
-    // * For dylibs we remove metadata and bytecode from upstream rlibs
+    // * For dylibs and sanitizer runtimes we remove metadata and bytecode from
+    //   upstream rlibs
     //
     // When performing LTO, all of the bytecode from the upstream libraries has
     // already been included in our object file output. As a result we need to
@@ -980,8 +1116,8 @@ fn add_upstream_rust_crates(cmd: &mut Linker, sess: &Session,
     // the linker (fast) because it's fine if it's not actually included as
     // we're at the end of the dependency chain.
     fn add_static_crate(cmd: &mut Linker, sess: &Session, tmpdir: &Path,
-                        dylib: bool, cratepath: &Path) {
-        if !sess.lto() && !dylib {
+                        whole_archive: bool, cratepath: &Path) {
+        if !sess.lto() && !whole_archive {
             cmd.link_rlib(&fix_windows_verbatim_for_gcc(cratepath));
             return
         }
@@ -996,28 +1132,30 @@ fn add_upstream_rust_crates(cmd: &mut Linker, sess: &Session,
             archive.remove_file(sess.cstore.metadata_filename());
             archive.update_symbols();
 
+            // Under LTO any LLVM bitcode from native libraries is already
+            // part of the crate's module.
+            let bitcode = if sess.lto() {
+                lto::bitcode_members(cratepath)
+            } else {
+                Vec::new()
+            };
+
             let mut any_objects = false;
             for f in archive.src_files() {
-                if f.ends_with("bytecode.deflate") {
+                if f.ends_with("bytecode.deflate") || bitcode.contains(&f) {
                     archive.remove_file(&f);
                     continue
                 }
-                let canonical = f.replace("-", "_");
-                let canonical_name = name.replace("-", "_");
-                if sess.lto() && canonical.starts_with(&canonical_name) &&
-                   canonical.ends_with(".o") {
-                    let num = &f[name.len()..f.len() - 2];
-                    if num.len() > 0 && num[1..].parse::<u32>().is_ok() {
-                        archive.remove_file(&f);
-                        continue
-                    }
+                if sess.lto() && lto::is_rust_object(cratepath, &f) {
+                    archive.remove_file(&f);
+                    continue
                 }
                 any_objects = true;
             }
 
             if any_objects {
                 archive.build();
-                if dylib {
+                if whole_archive {
                     cmd.link_whole_rlib(&fix_windows_verbatim_for_gcc(&dst));
                 } else {
                     cmd.link_rlib(&fix_windows_verbatim_for_gcc(&dst));
@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fs::{self, File};
 use std::io::{self, BufWriter};
 use std::io::prelude::*;
@@ -29,8 +29,10 @@ use CrateTranslation;
 ///
 /// This trait is the total list of requirements needed by `back::link` and
 /// represents the meaning of each option being passed down. This trait is then
-/// used to dispatch on whether a GNU-like linker (generally `ld.exe`) or an
-/// MSVC linker (e.g. `link.exe`) is being used.
+/// used to dispatch on whether a GNU-like linker (generally `ld.exe`, either
+/// through a C compiler or invoked directly like `ld.lld`), an MSVC linker
+/// (e.g. `link.exe` or `lld-link`) or a WebAssembly linker (`wasm-ld`) is being
+/// used. Which one is picked by the session's linker flavor.
 pub trait Linker {
     fn link_dylib(&mut self, lib: &str);
     fn link_rust_dylib(&mut self, lib: &str, path: &Path);
@@ -46,6 +48,7 @@ pub trait Linker {
     fn gc_sections(&mut self, is_dylib: bool);
     fn position_independent_executable(&mut self);
     fn optimize(&mut self);
+    fn pgo_gen(&mut self);
     fn debuginfo(&mut self);
     fn no_default_libraries(&mut self);
     fn build_dylib(&mut self, out_filename: &Path);
@@ -58,15 +61,63 @@ pub trait Linker {
                       tmpdir: &Path);
 }
 
+/// Returns whether the linker should garbage collect unused sections.
+///
+/// This is done by default for all targets, and `-C link-dead-code` turns it
+/// off.
+pub fn gc_sections(sess: &Session) -> bool {
+    !sess.opts.cg.link_dead_code
+}
+
+/// Returns whether each function and piece of data should be emitted into a
+/// section of its own.
+///
+/// This is what lets the linker's section garbage collection drop individual
+/// unused functions rather than whole object files, so it's only done when
+/// that's going to happen. Targets whose linkers can strip dead code without
+/// it (like OSX's `-dead_strip`) turn it off in their target options.
+pub fn function_sections(sess: &Session) -> bool {
+    gc_sections(sess) && sess.target.target.options.function_sections
+}
+
 pub struct GnuLinker<'a> {
     pub cmd: &'a mut Command,
     pub sess: &'a Session,
+    /// Whether the linker is invoked directly rather than through a C compiler.
+    pub is_ld: bool,
 }
 
 impl<'a> GnuLinker<'a> {
     fn takes_hints(&self) -> bool {
         !self.sess.target.target.options.is_like_osx
     }
+
+    /// Passes an argument to the linker itself, wrapping it in `-Wl,` if it has
+    /// to go through a C compiler first.
+    fn linker_arg<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut Self {
+        if self.is_ld {
+            self.cmd.arg(arg);
+        } else {
+            let mut wrapped = OsString::from("-Wl,");
+            wrapped.push(arg);
+            self.cmd.arg(wrapped);
+        }
+        self
+    }
+}
+
+/// Returns whether `arg` only means something to a C compiler, and should be
+/// dropped when the linker is invoked directly.
+fn is_compiler_only_arg(arg: &str) -> bool {
+    match arg {
+        "-m32" | "-m64" | "-nodefaultlibs" | "-nostartfiles" | "-pthread" |
+        "-static-libgcc" | "-shared-libgcc" => true,
+        _ => {
+            arg.starts_with("-fuse-ld=") || arg.starts_with("-march=") ||
+            arg.starts_with("-mcpu=") || arg.starts_with("-mfpu=") ||
+            arg.starts_with("-mfloat-abi=")
+        }
+    }
 }
 
 impl<'a> Linker for GnuLinker<'a> {
@@ -78,7 +129,29 @@ impl<'a> Linker for GnuLinker<'a> {
     fn output_filename(&mut self, path: &Path) { self.cmd.arg("-o").arg(path); }
     fn add_object(&mut self, path: &Path) { self.cmd.arg(path); }
     fn position_independent_executable(&mut self) { self.cmd.arg("-pie"); }
-    fn args(&mut self, args: &[String]) { self.cmd.args(args); }
+
+    fn args(&mut self, args: &[String]) {
+        if !self.is_ld {
+            self.cmd.args(args);
+            return
+        }
+
+        // Arguments from target specs and the command line are written for a C
+        // compiler, so anything meant for the linker needs unwrapping and
+        // anything meant for the compiler needs dropping.
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg.starts_with("-Wl,") {
+                self.cmd.args(&arg[4..].split(',').collect::<Vec<_>>());
+            } else if arg == "-Xlinker" {
+                if let Some(arg) = args.next() {
+                    self.cmd.arg(arg);
+                }
+            } else if !is_compiler_only_arg(arg) {
+                self.cmd.arg(arg);
+            }
+        }
+    }
 
     fn link_rust_dylib(&mut self, lib: &str, _path: &Path) {
         self.cmd.arg("-l").arg(lib);
@@ -91,9 +164,8 @@ impl<'a> Linker for GnuLinker<'a> {
     fn link_whole_staticlib(&mut self, lib: &str, search_path: &[PathBuf]) {
         let target = &self.sess.target.target;
         if !target.options.is_like_osx {
-            self.cmd.arg("-Wl,--whole-archive")
-                    .arg("-l").arg(lib)
-                    .arg("-Wl,--no-whole-archive");
+            self.linker_arg("--whole-archive").cmd.arg("-l").arg(lib);
+            self.linker_arg("--no-whole-archive");
         } else {
             // -force_load is the OSX equivalent of --whole-archive, but it
             // involves passing the full path to the library to link.
@@ -109,8 +181,8 @@ impl<'a> Linker for GnuLinker<'a> {
             v.push(lib);
             self.cmd.arg(&v);
         } else {
-            self.cmd.arg("-Wl,--whole-archive").arg(lib)
-                    .arg("-Wl,--no-whole-archive");
+            self.linker_arg("--whole-archive").cmd.arg(lib);
+            self.linker_arg("--no-whole-archive");
         }
     }
 
@@ -132,8 +204,8 @@ impl<'a> Linker for GnuLinker<'a> {
         if self.sess.target.target.options.is_like_osx {
             self.cmd.arg("-Wl,-dead_strip");
         } else if self.sess.target.target.options.is_like_solaris {
-            self.cmd.arg("-Wl,-z");
-            self.cmd.arg("-Wl,ignore");
+            self.linker_arg("-z");
+            self.linker_arg("ignore");
 
         // If we're building a dylib, we don't use --gc-sections because LLVM
         // has already done the best it can do, and we also don't want to
@@ -141,7 +213,7 @@ impl<'a> Linker for GnuLinker<'a> {
         // --gc-sections drops the size of hello world from 1.8MB to 597K, a 67%
         // reduction.
         } else if !is_dylib {
-            self.cmd.arg("-Wl,--gc-sections");
+            self.linker_arg("--gc-sections");
         }
     }
 
@@ -152,16 +224,34 @@ impl<'a> Linker for GnuLinker<'a> {
         // need a numeric argument, but other linkers do.
         if self.sess.opts.optimize == config::OptLevel::Default ||
            self.sess.opts.optimize == config::OptLevel::Aggressive {
-            self.cmd.arg("-Wl,-O1");
+            self.linker_arg("-O1");
         }
     }
 
+    fn pgo_gen(&mut self) {
+        if !self.sess.target.target.options.linker_is_gnu { return }
+
+        // LLVM doesn't reference the profiler runtime's initialization from
+        // instrumented code on Linux, it expects the linker to be told to pull
+        // it in instead (this is what clang does too).
+        self.cmd.arg("-u").arg("__llvm_profile_runtime");
+    }
+
     fn debuginfo(&mut self) {
-        // Don't do anything special here for GNU-style linkers.
+        // The debuginfo is already in the object files, but ELF linkers also
+        // need to know to compress it in the output.
+        let target = &self.sess.target.target;
+        if self.sess.opts.debugging_opts.compress_debug_sections &&
+           !target.options.is_like_osx && !target.options.is_like_windows {
+            self.linker_arg("--compress-debug-sections=zlib");
+        }
     }
 
     fn no_default_libraries(&mut self) {
-        self.cmd.arg("-nodefaultlibs");
+        // A linker invoked directly doesn't link any libraries by default.
+        if !self.is_ld {
+            self.cmd.arg("-nodefaultlibs");
+        }
     }
 
     fn build_dylib(&mut self, out_filename: &Path) {
@@ -181,22 +271,22 @@ impl<'a> Linker for GnuLinker<'a> {
 
     fn whole_archives(&mut self) {
         if !self.takes_hints() { return }
-        self.cmd.arg("-Wl,--whole-archive");
+        self.linker_arg("--whole-archive");
     }
 
     fn no_whole_archives(&mut self) {
         if !self.takes_hints() { return }
-        self.cmd.arg("-Wl,--no-whole-archive");
+        self.linker_arg("--no-whole-archive");
     }
 
     fn hint_static(&mut self) {
         if !self.takes_hints() { return }
-        self.cmd.arg("-Wl,-Bstatic");
+        self.linker_arg("-Bstatic");
     }
 
     fn hint_dynamic(&mut self) {
         if !self.takes_hints() { return }
-        self.cmd.arg("-Wl,-Bdynamic");
+        self.linker_arg("-Bdynamic");
     }
 
     fn export_symbols(&mut self, _: &Session, _: &CrateTranslation, _: &Path) {
@@ -289,6 +379,11 @@ impl<'a> Linker for MsvcLinker<'a> {
         // Needs more investigation of `/OPT` arguments
     }
 
+    fn pgo_gen(&mut self) {
+        // Nothing needed here, the instrumented code references the profiler
+        // runtime itself.
+    }
+
     fn debuginfo(&mut self) {
         // This will cause the Microsoft linker to generate a PDB file
         // from the CodeView line tables in the object files.
@@ -370,3 +465,107 @@ impl<'a> Linker for MsvcLinker<'a> {
         self.cmd.arg(&arg);
     }
 }
+
+pub struct WasmLd<'a> {
+    pub cmd: &'a mut Command,
+    pub sess: &'a Session,
+}
+
+impl<'a> Linker for WasmLd<'a> {
+    fn link_dylib(&mut self, lib: &str) {
+        self.cmd.arg("-l").arg(lib);
+    }
+
+    fn link_staticlib(&mut self, lib: &str) {
+        self.cmd.arg("-l").arg(lib);
+    }
+
+    fn link_rlib(&mut self, lib: &Path) {
+        self.cmd.arg(lib);
+    }
+
+    fn include_path(&mut self, path: &Path) {
+        self.cmd.arg("-L").arg(path);
+    }
+
+    fn framework_path(&mut self, _path: &Path) {
+        bug!("frameworks are not supported on wasm")
+    }
+
+    fn output_filename(&mut self, path: &Path) {
+        self.cmd.arg("-o").arg(path);
+    }
+
+    fn add_object(&mut self, path: &Path) {
+        self.cmd.arg(path);
+    }
+
+    fn position_independent_executable(&mut self) {
+        // noop, there's no dynamic loading of wasm modules
+    }
+
+    fn args(&mut self, args: &[String]) {
+        self.cmd.args(args);
+    }
+
+    fn link_rust_dylib(&mut self, lib: &str, _path: &Path) {
+        self.cmd.arg("-l").arg(lib);
+    }
+
+    fn link_framework(&mut self, _framework: &str) {
+        bug!("frameworks are not supported on wasm")
+    }
+
+    fn link_whole_staticlib(&mut self, lib: &str, _search_path: &[PathBuf]) {
+        self.cmd.arg("--whole-archive").arg("-l").arg(lib);
+        self.cmd.arg("--no-whole-archive");
+    }
+
+    fn link_whole_rlib(&mut self, lib: &Path) {
+        self.cmd.arg("--whole-archive").arg(lib).arg("--no-whole-archive");
+    }
+
+    fn gc_sections(&mut self, _is_dylib: bool) {
+        self.cmd.arg("--gc-sections");
+    }
+
+    fn optimize(&mut self) {
+        // wasm-ld is an lld flavor, so it takes lld's `-O` levels directly.
+        if self.sess.opts.optimize == config::OptLevel::Default ||
+           self.sess.opts.optimize == config::OptLevel::Aggressive {
+            self.cmd.arg("-O1");
+        }
+    }
+
+    fn pgo_gen(&mut self) {
+        // There's no profiler runtime for wasm, nothing to pull in.
+    }
+
+    fn debuginfo(&mut self) {
+        // Debuginfo is carried along in custom sections, nothing to do here.
+    }
+
+    fn no_default_libraries(&mut self) {
+        // There are no default libraries to opt out of.
+    }
+
+    fn build_dylib(&mut self, _out_filename: &Path) {
+        bug!("dynamic libraries are not supported on wasm")
+    }
+
+    fn hint_static(&mut self) {}
+    fn hint_dynamic(&mut self) {}
+
+    fn whole_archives(&mut self) {
+        self.cmd.arg("--whole-archive");
+    }
+
+    fn no_whole_archives(&mut self) {
+        self.cmd.arg("--no-whole-archive");
+    }
+
+    fn export_symbols(&mut self, _: &Session, _: &CrateTranslation, _: &Path) {
+        // noop, only executables are produced and their entry point is
+        // exported through the target's `pre_link_args`
+    }
+}
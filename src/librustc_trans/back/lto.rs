@@ -8,8 +8,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use super::archive;
 use super::link;
 use super::write;
+use rustc::middle::cstore::NativeLibraryKind;
 use rustc::session::{self, config};
 use llvm;
 use llvm::archive_ro::ArchiveRO;
@@ -22,6 +24,7 @@ use libc;
 use flate;
 
 use std::ffi::CString;
+use std::path::Path;
 
 pub fn run(sess: &session::Session, llmod: ModuleRef,
            tm: TargetMachineRef, reachable: &[String],
@@ -108,8 +111,25 @@ pub fn run(sess: &session::Session, llmod: ModuleRef,
                 }
             });
         }
+
+        // Native static libraries bundled into the rlib may have been compiled
+        // to LLVM bitcode as well, e.g. by clang with `-flto`, in which case
+        // they're merged in too so that calls can be inlined across languages.
+        link_foreign_bitcode(sess, llmod, path, &archive);
     });
 
+    // Same for the static libraries this crate links to itself.
+    let search_paths = link::archive_search_paths(sess);
+    for (lib, kind) in sess.cstore.used_libraries() {
+        if kind != NativeLibraryKind::NativeStatic {
+            continue
+        }
+        let path = archive::find_library(&lib, &search_paths, sess);
+        if let Some(archive) = ArchiveRO::open(&path) {
+            link_foreign_bitcode(sess, llmod, &path, &archive);
+        }
+    }
+
     // Internalize everything but the reachable symbols of the current module
     let cstrs: Vec<CString> = reachable.iter().map(|s| {
         CString::new(s.clone()).unwrap()
@@ -167,6 +187,115 @@ pub fn run(sess: &session::Session, llmod: ModuleRef,
     debug!("lto done");
 }
 
+/// Returns whether `data` is LLVM bitcode, either bare or in the wrapper
+/// format used on Darwin.
+pub fn is_llvm_bitcode(data: &[u8]) -> bool {
+    data.starts_with(b"BC\xC0\xDE") || data.starts_with(b"\xDE\xC0\x17\x0B")
+}
+
+/// Returns whether `member` of the archive at `path` is one of the object
+/// files rustc put into an rlib itself. Those are named `{stem}.{n}.o` after
+/// the rlib's own `lib{stem}.rlib`, anything else came from a native library.
+pub fn is_rust_object(path: &Path, member: &str) -> bool {
+    if path.extension().and_then(|e| e.to_str()) != Some("rlib") {
+        return false
+    }
+    let stem = match path.file_stem().and_then(|s| s.to_str()) {
+        Some(stem) if stem.starts_with("lib") => stem[3..].replace("-", "_"),
+        _ => return false,
+    };
+    let member = member.replace("-", "_");
+    if member.len() < stem.len() + 4 || !member.starts_with(&stem) ||
+       !member.ends_with(".o") {
+        return false
+    }
+    let num = &member[stem.len()..member.len() - 2];
+    num.starts_with(".") && num[1..].parse::<u32>().is_ok()
+}
+
+/// Lists the members of the archive at `path` which are LLVM bitcode from a
+/// native library. Under LTO these have been merged into the crate's module,
+/// so they mustn't reach the linker a second time.
+pub fn bitcode_members(path: &Path) -> Vec<String> {
+    let archive = match ArchiveRO::open(path) {
+        Some(archive) => archive,
+        None => return Vec::new(),
+    };
+    archive.iter()
+           .filter_map(|child| child.ok())
+           .filter(|child| is_llvm_bitcode(child.data()))
+           .filter_map(|child| child.name().map(|name| name.to_string()))
+           .filter(|name| !is_rust_object(path, name))
+           .collect()
+}
+
+/// Links every LLVM bitcode member of `archive` not produced by rustc itself
+/// into `llmod`. These are exactly the members `bitcode_members` lists.
+fn link_foreign_bitcode(sess: &session::Session, llmod: ModuleRef, path: &Path,
+                        archive: &ArchiveRO) {
+    for child in archive.iter().filter_map(|child| child.ok()) {
+        let name = match child.name() {
+            Some(name) if !is_rust_object(path, name) => name,
+            _ => continue,
+        };
+        let bc = child.data();
+        if !is_llvm_bitcode(bc) {
+            continue
+        }
+
+        // Bitcode from a newer LLVM than ours may fail to load in confusing
+        // ways or not at all, so check where it came from first.
+        let producer = llvm::bitcode_producer(bc);
+        let ours = unsafe { (llvm::LLVMVersionMajor(), llvm::LLVMVersionMinor()) };
+        let too_new = producer.as_ref()
+                              .and_then(|p| parse_llvm_version(p))
+                              .map_or(false, |theirs| theirs > ours);
+
+        debug!("linking foreign bitcode {} from {:?}", name, path);
+        let linked = !too_new && time(sess.time_passes(), &format!("ll link {}", name), || {
+            unsafe {
+                llvm::LLVMRustLinkInExternalBitcode(llmod,
+                                                    bc.as_ptr() as *const libc::c_char,
+                                                    bc.len() as libc::size_t)
+            }
+        });
+        if linked {
+            continue
+        }
+
+        let mut err = sess.struct_err(&format!("failed to load LLVM bitcode `{}` from `{}`",
+                                               name, path.display()));
+        if !too_new {
+            if let Some(msg) = llvm::last_error() {
+                err.note(&msg);
+            }
+        }
+        if let Some(ref producer) = producer {
+            err.note(&format!("it was produced by {}, but rustc uses LLVM {}.{}",
+                              producer, ours.0, ours.1));
+        }
+        err.note("cross-language LTO needs the C or C++ compiler to use the same \
+                  version of LLVM as rustc")
+           .emit();
+    }
+    sess.abort_if_errors();
+}
+
+/// Parses the `LLVM3.9.0` style producer of bitcode into a major and minor
+/// version. Producers of other compilers like Apple's clang aren't understood.
+fn parse_llvm_version(producer: &str) -> Option<(u32, u32)> {
+    if !producer.starts_with("LLVM") {
+        return None
+    }
+    let mut parts = producer[4..].split('.');
+    let major = parts.next().and_then(|p| p.parse().ok());
+    let minor = parts.next().and_then(|p| p.parse().ok());
+    match (major, minor) {
+        (Some(major), Some(minor)) => Some((major, minor)),
+        _ => None,
+    }
+}
+
 fn is_versioned_bytecode_format(bc: &[u8]) -> bool {
     let magic_id_byte_count = link::RLIB_BYTECODE_OBJECT_MAGIC.len();
     return bc.len() > magic_id_byte_count &&
@@ -0,0 +1,372 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The code size report printed by `-Z print-code-size`.
+//!
+//! Once an executable or dynamic library has been linked, the size of each
+//! function in it is read back out of its symbol table and attributed to the
+//! crate, module and function that the code came from. This covers upstream
+//! crates and std as well as the crate being compiled, and leaves out whatever
+//! the linker's section GC removed. Monomorphizations are additionally grouped
+//! by the generic function they were instantiated from, which is usually where
+//! the surprises in binary size are.
+//!
+//! Symbol names alone don't say much about where code came from, so for the
+//! crate being compiled the attribution is recorded during translation from the
+//! items found by the translation item collector, and carried along in the
+//! `CrateTranslation`. Code from other crates is attributed by demangling its
+//! symbol names.
+
+use collector::TransItem;
+use common::CrateContext;
+use context::SharedCrateContext;
+use glue::DropGlueKind;
+use llvm::{self, False, ObjectFile, ValueRef, mk_section_iter, mk_symbol_iter};
+use monomorphize::Instance;
+use session::Session;
+use util::common::path2cstr;
+use util::nodemap::FnvHashSet;
+use CrateTranslation;
+use super::symbol_names;
+
+use rustc::hir::def_id::DefId;
+use rustc::hir::map::definitions::DefPathData;
+use rustc::ty::item_path::characteristic_def_id_of_type;
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::slice;
+use std::str;
+
+/// Where the code behind a symbol came from.
+pub struct CodeOrigin {
+    /// The crate the code was defined in.
+    pub krate: String,
+    /// The path of the module the code was defined in.
+    pub module: String,
+    /// A description of the function, including any type parameters.
+    pub function: String,
+    /// The path of the generic function this is an instantiation of, if any.
+    pub generic: Option<String>,
+}
+
+/// The origins of all of the functions in a crate, keyed by symbol name.
+pub type CodeOrigins = HashMap<String, CodeOrigin>;
+
+/// Records where each function translated into this crate's objects came from.
+pub fn collect_origins<'a, 'tcx>(scx: &SharedCrateContext<'a, 'tcx>,
+                                 items: &FnvHashSet<TransItem<'tcx>>)
+                                 -> CodeOrigins {
+    let ccx = scx.get_ccx(0);
+    let mut origins = HashMap::new();
+    for item in items {
+        match *item {
+            TransItem::Fn(instance) => {
+                let symbol = match instance_symbol(scx, &instance) {
+                    Some(symbol) => symbol,
+                    None => symbol_names::exported_name(&ccx, &instance),
+                };
+                origins.insert(symbol, instance_origin(&ccx, &instance));
+            }
+            TransItem::DropGlue(glue) => {
+                for ccx in scx.iter() {
+                    if let Some(&llfn) = ccx.drop_glues().borrow().get(&glue) {
+                        origins.insert(value_name(llfn), glue_origin(&ccx, &glue));
+                    }
+                }
+            }
+            // Statics are data rather than code, so they're not reported.
+            TransItem::Static(..) => {}
+        }
+    }
+    origins
+}
+
+/// Returns the symbol an instance was actually translated to. This can differ
+/// from its mangled name, e.g. for `#[no_mangle]` functions.
+fn instance_symbol<'a, 'tcx>(scx: &SharedCrateContext<'a, 'tcx>,
+                             instance: &Instance<'tcx>)
+                             -> Option<String> {
+    for ccx in scx.iter() {
+        if let Some(&llfn) = ccx.instances().borrow().get(instance) {
+            return Some(value_name(llfn))
+        }
+    }
+    scx.tcx().map.as_local_node_id(instance.def).and_then(|id| {
+        scx.item_symbols().borrow().get(&id).cloned()
+    })
+}
+
+fn value_name(val: ValueRef) -> String {
+    unsafe {
+        CStr::from_ptr(llvm::LLVMGetValueName(val)).to_string_lossy().into_owned()
+    }
+}
+
+fn instance_origin<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>,
+                             instance: &Instance<'tcx>)
+                             -> CodeOrigin {
+    let path = ccx.tcx().absolute_item_path_str(instance.def);
+    let mut params = Vec::new();
+    for ty in &instance.substs.types {
+        params.push(ty.to_string());
+    }
+    for konst in &instance.substs.consts {
+        params.push(konst.to_string());
+    }
+
+    let (krate, module) = def_location(ccx, instance.def);
+    if params.is_empty() {
+        CodeOrigin { krate: krate, module: module, function: path, generic: None }
+    } else {
+        CodeOrigin {
+            krate: krate,
+            module: module,
+            function: format!("{}::<{}>", path, params.join(", ")),
+            generic: Some(path),
+        }
+    }
+}
+
+fn glue_origin<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>,
+                         glue: &DropGlueKind<'tcx>)
+                         -> CodeOrigin {
+    // Drop glue is attributed to wherever the type being dropped lives.
+    let (krate, module) = match characteristic_def_id_of_type(glue.ty()) {
+        Some(def_id) => def_location(ccx, def_id),
+        None => {
+            let krate = ccx.link_meta().crate_name.clone();
+            (krate.clone(), krate)
+        }
+    };
+    CodeOrigin {
+        krate: krate,
+        module: module,
+        function: format!("drop glue for {}", glue.ty()),
+        generic: Some("drop glue".to_string()),
+    }
+}
+
+/// Returns the crate and module that `def_id` is defined in.
+fn def_location(ccx: &CrateContext, def_id: DefId) -> (String, String) {
+    let krate = ccx.tcx().crate_name(def_id.krate).to_string();
+    let mut module = krate.clone();
+    for part in ccx.tcx().def_path(def_id).data {
+        match part.data {
+            DefPathData::Module(name) => {
+                module.push_str("::");
+                module.push_str(&name.as_str());
+            }
+            _ => break,
+        }
+    }
+    (krate, module)
+}
+
+/// Prints the code size report for each of the given linked outputs.
+pub fn report(sess: &Session, trans: &CrateTranslation, outputs: &[PathBuf]) {
+    for output in outputs {
+        report_output(sess, trans, output);
+    }
+}
+
+fn report_output(sess: &Session, trans: &CrateTranslation, output: &Path) {
+    let empty = HashMap::new();
+    let origins = trans.code_origins.as_ref().unwrap_or(&empty);
+
+    let mut crates = HashMap::new();
+    let mut modules = HashMap::new();
+    let mut functions = HashMap::new();
+    let mut generics = HashMap::new();
+    let mut total = 0;
+
+    let symbols = match symbol_sizes(output, origins) {
+        Ok(symbols) => symbols,
+        Err(e) => {
+            sess.warn(&format!("couldn't read `{}` for the code size report: {}",
+                               output.display(), e));
+            return
+        }
+    };
+    for (symbol, size) in symbols {
+        total += size;
+        match lookup(origins, &symbol) {
+            Some(origin) => {
+                *crates.entry(origin.krate.clone()).or_insert(0) += size;
+                *modules.entry(origin.module.clone()).or_insert(0) += size;
+                *functions.entry(origin.function.clone()).or_insert(0) += size;
+                if let Some(ref generic) = origin.generic {
+                    let entry = generics.entry(generic.clone()).or_insert((0, 0));
+                    entry.0 += size;
+                    entry.1 += 1;
+                }
+            }
+            None => {
+                // Code from other crates, or not from Rust at all.
+                let (krate, module, function) = match demangle_path(&symbol) {
+                    Some(path) => {
+                        let module = path[..path.len() - 1].join("::");
+                        (path[0].clone(), module, path.join("::"))
+                    }
+                    None => {
+                        ("<unknown>".to_string(), "<unknown>".to_string(), symbol)
+                    }
+                };
+                *crates.entry(krate).or_insert(0) += size;
+                *modules.entry(module).or_insert(0) += size;
+                *functions.entry(function).or_insert(0) += size;
+            }
+        }
+    }
+
+    println!("--- code size of `{}`: {} bytes ---", output.display(), total);
+    println!("crates:");
+    print_sizes(crates.into_iter().map(|(name, size)| (size, name)).collect());
+    println!("modules:");
+    print_sizes(modules.into_iter().map(|(name, size)| (size, name)).collect());
+    println!("functions:");
+    print_sizes(functions.into_iter().map(|(name, size)| (size, name)).collect());
+    println!("generic functions:");
+    print_sizes(generics.into_iter().map(|(name, (size, count))| {
+        (size, format!("{} ({} instantiations)", name, count))
+    }).collect());
+}
+
+fn lookup<'a>(origins: &'a CodeOrigins, symbol: &str) -> Option<&'a CodeOrigin> {
+    // Some platforms prefix all symbols with an underscore.
+    origins.get(symbol).or_else(|| {
+        if symbol.starts_with("_") {
+            origins.get(&symbol[1..])
+        } else {
+            None
+        }
+    })
+}
+
+/// Splits a mangled Rust symbol such as `_ZN3std2io5stdio6_print17h0123E` into
+/// the components of its path, leaving off the trailing hash. Returns `None`
+/// for anything that doesn't look like a Rust symbol.
+fn demangle_path(symbol: &str) -> Option<Vec<String>> {
+    // Some platforms prefix all symbols with an extra underscore.
+    let symbol = if symbol.starts_with("__ZN") { &symbol[1..] } else { symbol };
+    if !symbol.starts_with("_ZN") || !symbol.ends_with("E") || symbol.len() < 4 {
+        return None
+    }
+    let mut rest = &symbol[3..symbol.len() - 1];
+    let mut path = Vec::new();
+    while !rest.is_empty() {
+        let digits = rest.chars().take_while(|c| c.is_digit(10)).count();
+        let len = match rest[..digits].parse::<usize>() {
+            Ok(len) if digits + len <= rest.len() => len,
+            _ => return None,
+        };
+        let component = &rest[digits..digits + len];
+        rest = &rest[digits + len..];
+        path.push(component.replace("..", "::")
+                           .replace("$LT$", "<")
+                           .replace("$GT$", ">")
+                           .replace("$RF$", "&")
+                           .replace("$BP$", "*")
+                           .replace("$C$", ",")
+                           .replace("$u20$", " "));
+    }
+    // The last component is the hash which tells instantiations apart.
+    match path.last() {
+        Some(hash) if path.len() > 1 && hash.starts_with("h") => {}
+        _ => return None,
+    }
+    path.pop();
+    Some(path)
+}
+
+fn print_sizes(mut sizes: Vec<(u64, String)>) {
+    // Largest first, and alphabetically among equal sizes.
+    sizes.sort_by(|a, b| (b.0, &a.1).cmp(&(a.0, &b.1)));
+    for (size, name) in sizes {
+        println!("{:10} {}", size, name);
+    }
+}
+
+fn is_code_section(name: &str) -> bool {
+    name == ".text" || name.starts_with(".text.") || name.starts_with(".text$") ||
+        name == "__text"
+}
+
+/// Reads the size of every function defined in the code sections of `path`.
+///
+/// Object files don't reliably record how large a symbol is, so instead a
+/// function is taken to extend up to the next symbol in its section, or to the
+/// end of the section. With function sections each function is in a section of
+/// its own anyway.
+fn symbol_sizes(path: &Path, origins: &CodeOrigins) -> Result<Vec<(String, u64)>, String> {
+    // Symbols in each code section, keyed by the section's contents.
+    let mut sections = HashMap::new();
+
+    unsafe {
+        let buf = path2cstr(path);
+        let mb = llvm::LLVMRustCreateMemoryBufferWithContentsOfFile(buf.as_ptr());
+        if mb.is_null() {
+            return Err("couldn't open the file".to_string())
+        }
+        let of = match ObjectFile::new(mb) {
+            Some(of) => of,
+            None => return Err("not an object file".to_string()),
+        };
+        let si = mk_section_iter(of.llof);
+        let symbols = mk_symbol_iter(of.llof);
+        while llvm::LLVMIsSymbolIteratorAtEnd(of.llof, symbols.llsi) == False {
+            let name = CStr::from_ptr(llvm::LLVMGetSymbolName(symbols.llsi));
+            let name = name.to_string_lossy().into_owned();
+            // Skip section symbols and ARM mapping symbols such as `$t`.
+            if !name.is_empty() && !name.starts_with("$") {
+                llvm::LLVMMoveToContainingSection(si.llsi, symbols.llsi);
+                // Undefined symbols aren't contained in any section.
+                if llvm::LLVMIsSectionIteratorAtEnd(of.llof, si.llsi) == False {
+                    let mut name_buf = ptr::null();
+                    let name_len = llvm::LLVMRustGetSectionName(si.llsi, &mut name_buf);
+                    let section = slice::from_raw_parts(name_buf as *const u8,
+                                                        name_len as usize);
+                    if is_code_section(str::from_utf8(section).unwrap_or("")) {
+                        let start = llvm::LLVMGetSectionAddress(si.llsi);
+                        let end = start + llvm::LLVMGetSectionSize(si.llsi);
+                        let key = llvm::LLVMGetSectionContents(si.llsi) as usize;
+                        let addr = llvm::LLVMGetSymbolAddress(symbols.llsi);
+                        sections.entry(key).or_insert((end, Vec::new())).1.push((addr, name));
+                    }
+                }
+            }
+            llvm::LLVMMoveToNextSymbol(symbols.llsi);
+        }
+    }
+
+    let mut sizes = Vec::new();
+    for (_, (end, mut symbols)) in sections {
+        symbols.sort_by_key(|&(addr, _)| addr);
+        let mut i = 0;
+        while i < symbols.len() {
+            // Several symbols can share an address (e.g. aliases, or local
+            // labels at the start of a section), in which case the size is
+            // only counted once, preferring a symbol we know the origin of.
+            let addr = symbols[i].0;
+            let mut j = i;
+            while j < symbols.len() && symbols[j].0 == addr {
+                j += 1;
+            }
+            let next = if j < symbols.len() { symbols[j].0 } else { end };
+            let chosen = (i..j).find(|&k| lookup(origins, &symbols[k].1).is_some())
+                               .unwrap_or(i);
+            sizes.push((symbols[chosen].1.clone(), next - addr));
+            i = j;
+        }
+    }
+    Ok(sizes)
+}
@@ -10,9 +10,10 @@
 
 use back::lto;
 use back::link::{get_linker, remove};
+use back::linker;
 use session::config::{OutputFilenames, Passes, SomePasses, AllPasses};
 use session::Session;
-use session::config::{self, OutputType};
+use session::config::{self, OutputType, Sanitizer, SplitDebuginfo};
 use llvm;
 use llvm::{ModuleRef, TargetMachineRef, PassManagerRef, DiagnosticInfoRef, ContextRef};
 use llvm::SMDiagnosticRef;
@@ -27,6 +28,8 @@ use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::ptr;
 use std::str;
 use std::sync::{Arc, Mutex};
 use std::sync::mpsc::channel;
@@ -178,7 +181,7 @@ pub fn create_target_machine(sess: &Session) -> TargetMachineRef {
         *ty != config::CrateTypeExecutable
     });
 
-    let ffunction_sections = sess.target.target.options.function_sections;
+    let ffunction_sections = linker::function_sections(sess);
     let fdata_sections = ffunction_sections;
 
     let code_model_arg = match sess.opts.cg.code_model {
@@ -221,6 +224,7 @@ pub fn create_target_machine(sess: &Session) -> TargetMachineRef {
             !any_library && reloc_model == llvm::RelocPIC,
             ffunction_sections,
             fdata_sections,
+            sess.opts.debugging_opts.compress_debug_sections,
         )
     };
 
@@ -265,10 +269,18 @@ pub struct ModuleConfig {
     vectorize_slp: bool,
     merge_functions: bool,
     inline_threshold: Option<usize>,
+    // Where instrumented code writes its execution profiles to, and which
+    // profile to optimize with, for profile-guided optimization.
+    pgo_gen: Option<String>,
+    pgo_use: Option<String>,
     // Instead of creating an object file by doing LLVM codegen, just
     // make the object file bitcode. Provides easy compatibility with
-    // emscripten's ecc compiler, when used as the linker.
+    // emscripten's ecc compiler, when used as the linker, and with linkers
+    // doing LTO across languages under `-Z cross-lang-lto`.
     obj_is_bitcode: bool,
+    // Whether the DWARF in each object file is moved out into a `.dwo` file
+    // next to it, leaving only a skeleton behind.
+    split_dwarf: bool,
 }
 
 unsafe impl Send for ModuleConfig { }
@@ -288,6 +300,7 @@ impl ModuleConfig {
             emit_asm: false,
             emit_obj: false,
             obj_is_bitcode: false,
+            split_dwarf: false,
 
             no_verify: false,
             no_prepopulate_passes: false,
@@ -296,7 +309,9 @@ impl ModuleConfig {
             vectorize_loop: false,
             vectorize_slp: false,
             merge_functions: false,
-            inline_threshold: None
+            inline_threshold: None,
+            pgo_gen: None,
+            pgo_use: None,
         }
     }
 
@@ -306,7 +321,14 @@ impl ModuleConfig {
         self.no_builtins = trans.no_builtins;
         self.time_passes = sess.time_passes();
         self.inline_threshold = sess.opts.cg.inline_threshold;
-        self.obj_is_bitcode = sess.target.target.options.obj_is_bitcode;
+        self.pgo_gen = sess.opts.cg.profile_generate.as_ref().map(|dir| {
+            // `%m` is replaced by the runtime with a signature of the binary.
+            let path = Path::new(dir).join("default_%m.profraw");
+            path.to_str().unwrap().to_string()
+        });
+        self.pgo_use = sess.opts.cg.profile_use.clone();
+        self.obj_is_bitcode = sess.target.target.options.obj_is_bitcode ||
+                              emit_cross_lang_lto_bitcode(sess);
 
         // Copy what clang does by turning on loop vectorization at O2 and
         // slp vectorization at O3. Otherwise configure other optimization aspects
@@ -602,6 +624,11 @@ unsafe fn optimize_and_codegen(cgcx: &CodegenContext,
         }
     });
 
+    if write_obj && config.split_dwarf {
+        let dwo_out = output_names.with_extension(&format!("{}.dwo", name_extra));
+        split_dwarf(cgcx.handler, &obj_out, &dwo_out);
+    }
+
     if copy_bc_to_obj {
         debug!("copying bitcode {:?} to obj {:?}", bc_out, obj_out);
         if let Err(e) = fs::copy(&bc_out, &obj_out) {
@@ -621,6 +648,50 @@ unsafe fn optimize_and_codegen(cgcx: &CodegenContext,
     llvm::LLVMRustDisposeTargetMachine(tm);
 }
 
+/// Moves the `.dwo` sections LLVM emitted into `obj` out into `dwo`, which is
+/// where the skeleton compile unit left behind says to find them.
+fn split_dwarf(handler: &Handler, obj: &Path, dwo: &Path) {
+    let mut extract = Command::new("objcopy");
+    extract.arg("--extract-dwo").arg(obj).arg(dwo);
+    let mut strip = Command::new("objcopy");
+    strip.arg("--strip-dwo").arg(obj);
+
+    for cmd in &mut [extract, strip] {
+        debug!("{:?}", cmd);
+        match cmd.output() {
+            Ok(ref prog) if !prog.status.success() => {
+                let mut note = prog.stderr.clone();
+                note.extend_from_slice(&prog.stdout);
+                handler.struct_err(&format!("splitting debuginfo with `objcopy` failed: {}",
+                                            prog.status))
+                    .note(&format!("{:?}", cmd))
+                    .note(&String::from_utf8_lossy(&note))
+                    .emit();
+                return
+            }
+            Ok(..) => {}
+            Err(e) => {
+                handler.err(&format!("could not exec `objcopy`: {}", e));
+                return
+            }
+        }
+    }
+}
+
+/// Whether `-Z cross-lang-lto` turns the object files into bitcode.
+///
+/// Only a linker performing LTO can make sense of bitcode objects, and rustc
+/// doesn't set one up when it links, so this is limited to objects which leave
+/// rustc unlinked: those requested with `--emit=obj` alone and those going
+/// into static libraries.
+fn emit_cross_lang_lto_bitcode(sess: &Session) -> bool {
+    if !sess.opts.debugging_opts.cross_lang_lto {
+        return false
+    }
+    !sess.opts.output_types.contains_key(&OutputType::Exe) ||
+        sess.crate_types.borrow().iter().all(|ty| *ty == config::CrateTypeStaticlib)
+}
+
 pub fn run_passes(sess: &Session,
                   trans: &CrateTranslation,
                   output_types: &HashMap<OutputType, Option<PathBuf>>,
@@ -641,6 +712,18 @@ pub fn run_passes(sess: &Session,
     // Sanity check
     assert!(trans.modules.len() == sess.opts.cg.codegen_units);
 
+    // LLVM learned how to instrument and optimize with profiles on its own in
+    // 3.9, earlier versions leave that to the frontend.
+    if (sess.opts.cg.profile_generate.is_some() || sess.opts.cg.profile_use.is_some()) &&
+       unsafe { llvm::LLVMVersionMinor() < 9 } {
+        sess.fatal("profile-guided optimization requires LLVM 3.9 or later");
+    }
+
+    if sess.opts.debugging_opts.cross_lang_lto && !emit_cross_lang_lto_bitcode(sess) {
+        sess.warn("`-Z cross-lang-lto` only applies to `--emit=obj` and static \
+                   libraries, emitting native object files instead");
+    }
+
     let tm = create_target_machine(sess);
 
     // Figure out what we actually need to build.
@@ -648,6 +731,24 @@ pub fn run_passes(sess: &Session,
     let mut modules_config = ModuleConfig::new(tm, sess.opts.cg.passes.clone());
     let mut metadata_config = ModuleConfig::new(tm, vec!());
 
+    // Sanitizers are implemented as LLVM instrumentation passes, the matching
+    // runtime is linked in later on by the metadata::creader module.
+    match sess.opts.debugging_opts.sanitizer {
+        Some(Sanitizer::Address) => {
+            modules_config.passes.push("asan".to_string());
+            modules_config.passes.push("asan-module".to_string());
+        }
+        Some(Sanitizer::Memory) => modules_config.passes.push("msan".to_string()),
+        Some(Sanitizer::Thread) => modules_config.passes.push("tsan".to_string()),
+        Some(Sanitizer::Leak) | None => {}
+    }
+
+    // Coverage counters are emitted by trans as `llvm.instrprof.increment`
+    // calls which need lowering to the profiler runtime's data structures.
+    if sess.opts.debugging_opts.instrument_coverage {
+        modules_config.passes.push("instrprof".to_string());
+    }
+
     modules_config.opt_level = Some(get_llvm_opt_level(sess.opts.optimize));
     modules_config.opt_size = Some(get_llvm_opt_size(sess.opts.optimize));
 
@@ -696,6 +797,15 @@ pub fn run_passes(sess: &Session,
     modules_config.set_flags(sess, trans);
     metadata_config.set_flags(sess, trans);
 
+    // Only the crate's own modules carry debuginfo worth splitting out.
+    if sess.opts.debuginfo != config::NoDebugInfo &&
+       sess.opts.cg.split_debuginfo != SplitDebuginfo::Off &&
+       sess.split_debuginfo() == SplitDebuginfo::Off {
+        sess.warn("ignoring -C split-debuginfo, debuginfo can only be split out of \
+                   ELF object files");
+    }
+    modules_config.split_dwarf = sess.split_debuginfo() != SplitDebuginfo::Off;
+
 
     // Populate a buffer with a list of codegen threads.  Items are processed in
     // LIFO order, just because it's a tiny bit simpler that way.  (The order
@@ -1008,10 +1118,17 @@ pub unsafe fn with_llvm_pmb(llmod: ModuleRef,
     let opt_size = config.opt_size.unwrap_or(llvm::CodeGenOptSizeNone);
     let inline_threshold = config.inline_threshold;
 
+    let pgo_gen_path = config.pgo_gen.as_ref().map(|s| CString::new(&s[..]).unwrap());
+    let pgo_use_path = config.pgo_use.as_ref().map(|s| CString::new(&s[..]).unwrap());
+
     llvm::LLVMRustConfigurePassManagerBuilder(builder, opt_level,
                                               config.merge_functions,
                                               config.vectorize_slp,
-                                              config.vectorize_loop);
+                                              config.vectorize_loop,
+                                              pgo_gen_path.as_ref().map_or(ptr::null(),
+                                                                           |s| s.as_ptr()),
+                                              pgo_use_path.as_ref().map_or(ptr::null(),
+                                                                           |s| s.as_ptr()));
     llvm::LLVMPassManagerBuilderSetSizeLevel(builder, opt_size as u32);
 
     if opt_size != llvm::CodeGenOptSizeNone {
@@ -30,7 +30,7 @@ pub use self::ValueOrigin::*;
 use super::CrateTranslation;
 use super::ModuleTranslation;
 
-use back::{link, symbol_names};
+use back::{link, size_report, symbol_names};
 use lint;
 use llvm::{BasicBlockRef, Linkage, ValueRef, Vector, get_param};
 use llvm;
@@ -71,6 +71,7 @@ use common;
 use consts;
 use context::SharedCrateContext;
 use controlflow;
+use coverageinfo;
 use datum;
 use debuginfo::{self, DebugLoc, ToDebugLoc};
 use declare;
@@ -92,7 +93,7 @@ use value::Value;
 use Disr;
 use util::common::indenter;
 use util::sha2::Sha256;
-use util::nodemap::{NodeMap, NodeSet};
+use util::nodemap::{FnvHashSet, NodeMap, NodeSet};
 
 use arena::TypedArena;
 use libc::c_uint;
@@ -436,6 +437,10 @@ pub fn iter_structural_ty<'blk, 'tcx, F>(cx: Block<'blk, 'tcx>,
 
     let mut cx = cx;
     match t.sty {
+        ty::TyStruct(def, _) if def.is_union() => {
+            // The fields of a union share their storage, none of them
+            // is ever visited (or dropped) on its own.
+        }
         ty::TyStruct(..) => {
             let repr = adt::represent_type(cx.ccx(), t);
             let VariantInfo { fields, discr } = VariantInfo::from_ty(cx.tcx(), t, None);
@@ -1426,7 +1431,9 @@ impl<'blk, 'tcx> FunctionContext<'blk, 'tcx> {
         };
 
         let check_attrs = |attrs: &[ast::Attribute]| {
-            let default_to_mir = ccx.sess().opts.debugging_opts.orbit;
+            // Coverage counters are attached to MIR basic blocks.
+            let default_to_mir = ccx.sess().opts.debugging_opts.orbit ||
+                                 ccx.sess().opts.debugging_opts.instrument_coverage;
             let invert = if default_to_mir { "rustc_no_mir" } else { "rustc_mir" };
             (default_to_mir ^ attrs.iter().any(|item| item.check_name(invert)),
              attrs.iter().any(|item| item.check_name("no_debug")))
@@ -1464,6 +1471,7 @@ impl<'blk, 'tcx> FunctionContext<'blk, 'tcx> {
             landingpad_alloca: Cell::new(None),
             lllocals: RefCell::new(NodeMap()),
             llupvars: RefCell::new(NodeMap()),
+            loop_dests: RefCell::new(NodeMap()),
             lldropflag_hints: RefCell::new(DropFlagHintsMap::new()),
             fn_ty: fn_ty,
             param_substs: param_substs,
@@ -2258,7 +2266,7 @@ pub fn trans_item(ccx: &CrateContext, item: &hir::Item) {
 
     match item.node {
         hir::ItemFn(ref decl, _, _, _, ref generics, ref body) => {
-            if !generics.is_type_parameterized() {
+            if !generics.is_type_parameterized() && !generics.is_const_parameterized() {
                 let trans_everywhere = attr::requests_inline(&item.attrs);
                 // Ignore `trans_everywhere` for cross-crate inlined items
                 // (`from_external`).  `trans_item` will be called once for each
@@ -2292,16 +2300,16 @@ pub fn trans_item(ccx: &CrateContext, item: &hir::Item) {
                 }
             }
         }
-        hir::ItemImpl(_, _, ref generics, _, _, ref impl_items) => {
+        hir::ItemImpl(_, _, _, ref generics, _, _, ref impl_items) => {
             // Both here and below with generic methods, be sure to recurse and look for
             // items that we need to translate.
-            if !generics.ty_params.is_empty() {
+            if !generics.ty_params.is_empty() || !generics.const_params.is_empty() {
                 return;
             }
 
             for impl_item in impl_items {
                 if let hir::ImplItemKind::Method(ref sig, ref body) = impl_item.node {
-                    if sig.generics.ty_params.is_empty() {
+                    if sig.generics.ty_params.is_empty() && sig.generics.const_params.is_empty() {
                         let trans_everywhere = attr::requests_inline(&impl_item.attrs);
                         for (ref ccx, is_origin) in ccx.maybe_iter(trans_everywhere) {
                             let def_id = tcx.map.local_def_id(impl_item.id);
@@ -2320,7 +2328,7 @@ pub fn trans_item(ccx: &CrateContext, item: &hir::Item) {
             }
         }
         hir::ItemEnum(ref enum_definition, ref gens) => {
-            if gens.ty_params.is_empty() {
+            if gens.ty_params.is_empty() && gens.const_params.is_empty() {
                 // sizes only make sense for non-generic types
                 enum_variant_size_lint(ccx, enum_definition, item.span, item.id);
             }
@@ -2689,7 +2697,8 @@ pub fn filter_reachable_ids(ccx: &SharedCrateContext) -> NodeSet {
 
 pub fn trans_crate<'tcx>(tcx: &TyCtxt<'tcx>,
                          mir_map: &MirMap<'tcx>,
-                         analysis: ty::CrateAnalysis)
+                         analysis: ty::CrateAnalysis,
+                         outputs: &config::OutputFilenames)
                          -> CrateTranslation {
     let _task = tcx.dep_graph.in_task(DepNode::TransCrate);
 
@@ -2720,6 +2729,7 @@ pub fn trans_crate<'tcx>(tcx: &TyCtxt<'tcx>,
                                              codegen_units,
                                              tcx,
                                              &mir_map,
+                                             outputs,
                                              export_map,
                                              Sha256::new(),
                                              link_meta.clone(),
@@ -2727,9 +2737,9 @@ pub fn trans_crate<'tcx>(tcx: &TyCtxt<'tcx>,
                                              check_overflow,
                                              check_dropflag);
 
-    {
+    let trans_items = {
         let ccx = shared_ccx.get_ccx(0);
-        collect_translation_items(&ccx);
+        let trans_items = collect_translation_items(&ccx);
 
         // Translate all items. See `TransModVisitor` for
         // details on why we walk in this particular way.
@@ -2742,12 +2752,15 @@ pub fn trans_crate<'tcx>(tcx: &TyCtxt<'tcx>,
         collector::print_collection_results(&ccx);
 
         symbol_names_test::report_symbol_names(&ccx);
-    }
+
+        trans_items
+    };
 
     for ccx in shared_ccx.iter() {
         if ccx.sess().opts.debuginfo != NoDebugInfo {
             debuginfo::finalize(&ccx);
         }
+        coverageinfo::finalize(&ccx);
         for &(old_g, new_g) in ccx.statics_to_rauw().borrow().iter() {
             unsafe {
                 let bitcast = llvm::LLVMConstPointerCast(new_g, llvm::LLVMTypeOf(old_g));
@@ -2835,6 +2848,12 @@ pub fn trans_crate<'tcx>(tcx: &TyCtxt<'tcx>,
     };
     let no_builtins = attr::contains_name(&krate.attrs, "no_builtins");
 
+    let code_origins = if sess.opts.debugging_opts.print_code_size {
+        Some(size_report::collect_origins(&shared_ccx, &trans_items))
+    } else {
+        None
+    };
+
     CrateTranslation {
         modules: modules,
         metadata_module: metadata_module,
@@ -2842,6 +2861,7 @@ pub fn trans_crate<'tcx>(tcx: &TyCtxt<'tcx>,
         metadata: metadata,
         reachable: reachable_symbols,
         no_builtins: no_builtins,
+        code_origins: code_origins,
     }
 }
 
@@ -2911,7 +2931,8 @@ impl<'a, 'tcx, 'v> Visitor<'v> for TransItemsWithinModVisitor<'a, 'tcx> {
     }
 }
 
-fn collect_translation_items<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>) {
+fn collect_translation_items<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>)
+                                       -> FnvHashSet<TransItem<'tcx>> {
     let time_passes = ccx.sess().time_passes();
 
     let collection_mode = match ccx.sess().opts.debugging_opts.print_trans_items {
@@ -3006,8 +3027,10 @@ fn collect_translation_items<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>) {
 
         let mut ccx_map = ccx.translation_items().borrow_mut();
 
-        for cgi in items {
+        for &cgi in &items {
             ccx_map.insert(cgi, TransItemState::PredictedButNotGenerated);
         }
     }
+
+    items
 }
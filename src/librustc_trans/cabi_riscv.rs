@@ -0,0 +1,59 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Calling convention for the RV32 integer ABI (ILP32), as used by the soft
+// float `riscv32imac-unknown-none-elf` target.
+//
+// Scalars are passed in the argument registers a0-a7, widened to XLEN bits.
+// Aggregates of up to 2*XLEN bits are also passed in registers, coerced to
+// integers, and anything larger is passed by reference. The same rules apply
+// to return values, which come back in a0 and a1.
+//
+// See https://github.com/riscv/riscv-elf-psabi-doc for the full details.
+
+use llvm::{Struct, Array};
+use abi::{FnType, ArgType};
+use context::CrateContext;
+use machine::{llsize_of_alloc, llalign_of_min};
+use type_::Type;
+
+const XLEN: u64 = 32;
+
+fn classify(ccx: &CrateContext, arg: &mut ArgType) {
+    match arg.ty.kind() {
+        Struct | Array => {}
+        _ => {
+            arg.extend_integer_width_to(XLEN);
+            return
+        }
+    }
+
+    let size = llsize_of_alloc(ccx, arg.ty) * 8;
+    if size > 2 * XLEN {
+        arg.make_indirect(ccx);
+    } else if size <= XLEN {
+        arg.cast = Some(Type::i32(ccx));
+    } else if llalign_of_min(ccx, arg.ty) as u64 * 8 == 2 * XLEN {
+        arg.cast = Some(Type::i64(ccx));
+    } else {
+        arg.cast = Some(Type::array(&Type::i32(ccx), 2));
+    }
+}
+
+pub fn compute_abi_info(ccx: &CrateContext, fty: &mut FnType) {
+    if !fty.ret.is_ignore() {
+        classify(ccx, &mut fty.ret);
+    }
+
+    for arg in &mut fty.args {
+        if arg.is_ignore() { continue; }
+        classify(ccx, arg);
+    }
+}
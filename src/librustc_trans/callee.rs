@@ -462,7 +462,8 @@ fn get_fn<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>,
         }
     }
     let must_monomorphise =
-        !substs.types.is_empty() || is_named_tuple_constructor(tcx, def_id);
+        !substs.types.is_empty() || !substs.consts.is_empty() ||
+        is_named_tuple_constructor(tcx, def_id);
 
     debug!("get_fn({:?}) must_monomorphise: {}",
            def_id, must_monomorphise);
@@ -723,6 +723,7 @@ fn find_drop_glue_neighbors<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>,
         ty::TyInt(_)    |
         ty::TyUint(_)   |
         ty::TyStr       |
+        ty::TyNever     |
         ty::TyFloat(_)  |
         ty::TyRawPtr(_) |
         ty::TyRef(..)   |
@@ -769,7 +770,9 @@ fn find_drop_glue_neighbors<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>,
             }
         }
         ty::TyProjection(_) |
+        ty::TyAnon(..)      |
         ty::TyParam(_)      |
+        ty::TyArrayParam(..) |
         ty::TyInfer(_)      |
         ty::TyError         => {
             bug!("encountered unexpected type");
@@ -1053,6 +1056,7 @@ impl<'b, 'a, 'v> hir_visit::Visitor<'v> for RootCollector<'b, 'a, 'v> {
             hir::ItemTy(..)          |
             hir::ItemDefaultImpl(..) |
             hir::ItemTrait(..)       |
+            hir::ItemTraitAlias(..)  |
             hir::ItemConst(..)       |
             hir::ItemMod(..)         => {
                 // Nothing to do, just keep recursing...
@@ -1067,7 +1071,8 @@ impl<'b, 'a, 'v> hir_visit::Visitor<'v> for RootCollector<'b, 'a, 'v> {
             }
 
             hir::ItemEnum(_, ref generics)        |
-            hir::ItemStruct(_, ref generics)      => {
+            hir::ItemStruct(_, ref generics)      |
+            hir::ItemUnion(_, ref generics)       => {
                 if !generics.is_parameterized() {
                     let ty = {
                         let tables = self.ccx.tcx().tables.borrow();
@@ -1092,6 +1097,7 @@ impl<'b, 'a, 'v> hir_visit::Visitor<'v> for RootCollector<'b, 'a, 'v> {
             }
             hir::ItemFn(_, _, constness, _, ref generics, _) => {
                 if !generics.is_type_parameterized() &&
+                   !generics.is_const_parameterized() &&
                    constness == hir::Constness::NotConst {
                     let def_id = self.ccx.tcx().map.local_def_id(item.id);
 
@@ -1119,17 +1125,19 @@ impl<'b, 'a, 'v> hir_visit::Visitor<'v> for RootCollector<'b, 'a, 'v> {
                 let parent_node_id = hir_map.get_parent_node(ii.id);
                 let is_impl_generic = match hir_map.expect_item(parent_node_id) {
                     &hir::Item {
-                        node: hir::ItemImpl(_, _, ref generics, _, _, _),
+                        node: hir::ItemImpl(_, _, _, ref generics, _, _, _),
                         ..
                     } => {
-                        generics.is_type_parameterized()
+                        generics.is_type_parameterized() || generics.is_const_parameterized()
                     }
                     _ => {
                         bug!()
                     }
                 };
 
-                if !generics.is_type_parameterized() && !is_impl_generic {
+                if !generics.is_type_parameterized() &&
+                   !generics.is_const_parameterized() &&
+                   !is_impl_generic {
                     let def_id = self.ccx.tcx().map.local_def_id(ii.id);
 
                     debug!("RootCollector: MethodImplItem({})",
@@ -1151,12 +1159,13 @@ fn create_trans_items_for_default_impls<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>,
                                                   output: &mut Vec<TransItem<'tcx>>) {
     match item.node {
         hir::ItemImpl(_,
+                      _,
                       _,
                       ref generics,
                       _,
                       _,
                       ref items) => {
-            if generics.is_type_parameterized() {
+            if generics.is_type_parameterized() || generics.is_const_parameterized() {
                 return
             }
 
@@ -1177,7 +1186,8 @@ fn create_trans_items_for_default_impls<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>,
                         continue;
                     }
 
-                    if default_impl.generics.has_type_params(subst::FnSpace) {
+                    if default_impl.generics.has_type_params(subst::FnSpace) ||
+                       default_impl.generics.has_const_params(subst::FnSpace) {
                         continue;
                     }
 
@@ -1233,6 +1243,7 @@ pub fn push_unique_type_name<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         ty::TyBool              => output.push_str("bool"),
         ty::TyChar              => output.push_str("char"),
         ty::TyStr               => output.push_str("str"),
+        ty::TyNever             => output.push_str("!"),
         ty::TyInt(ast::IntTy::Is)    => output.push_str("isize"),
         ty::TyInt(ast::IntTy::I8)    => output.push_str("i8"),
         ty::TyInt(ast::IntTy::I16)   => output.push_str("i16"),
@@ -1248,7 +1259,7 @@ pub fn push_unique_type_name<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         ty::TyStruct(adt_def, substs) |
         ty::TyEnum(adt_def, substs) => {
             push_item_name(cx, adt_def.did, output);
-            push_type_params(cx, &substs.types, &[], output);
+            push_type_params(cx, substs, &[], output);
         },
         ty::TyTuple(ref component_types) => {
             output.push('(');
@@ -1298,7 +1309,7 @@ pub fn push_unique_type_name<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         ty::TyTrait(ref trait_data) => {
             push_item_name(cx, trait_data.principal.skip_binder().def_id, output);
             push_type_params(cx,
-                             &trait_data.principal.skip_binder().substs.types,
+                             trait_data.principal.skip_binder().substs,
                              &trait_data.bounds.projection_bounds,
                              output);
         },
@@ -1352,11 +1363,13 @@ pub fn push_unique_type_name<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
             output.push_str("{");
             output.push_str(&format!("{}:{}", def_id.krate, def_id.index.as_usize()));
             output.push_str("}");
-            push_type_params(cx, &closure_substs.func_substs.types, &[], output);
+            push_type_params(cx, closure_substs.func_substs, &[], output);
         }
         ty::TyError |
         ty::TyInfer(_) |
         ty::TyProjection(..) |
+        ty::TyAnon(..) |
+        ty::TyArrayParam(..) |
         ty::TyParam(_) => {
             bug!("debuginfo: Trying to create type name for \
                   unexpected type: {:?}", t);
@@ -1386,20 +1399,25 @@ fn push_item_name(ccx: &CrateContext,
 }
 
 fn push_type_params<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
-                              types: &'tcx subst::VecPerParamSpace<Ty<'tcx>>,
+                              substs: &'tcx Substs<'tcx>,
                               projections: &[ty::PolyProjectionPredicate<'tcx>],
                               output: &mut String) {
-    if types.is_empty() && projections.is_empty() {
+    if substs.types.is_empty() && substs.consts.is_empty() && projections.is_empty() {
         return;
     }
 
     output.push('<');
 
-    for &type_parameter in types {
+    for &type_parameter in &substs.types {
         push_unique_type_name(cx, type_parameter, output);
         output.push_str(", ");
     }
 
+    for const_parameter in &substs.consts {
+        output.push_str(&format!("{}", const_parameter));
+        output.push_str(", ");
+    }
+
     for projection in projections {
         let projection = projection.skip_binder();
         let name = token::get_ident_interner().get(projection.projection_ty.item_name);
@@ -1419,7 +1437,7 @@ fn push_instance_as_string<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>,
                                      instance: Instance<'tcx>,
                                      output: &mut String) {
     push_item_name(ccx, instance.def, output);
-    push_type_params(ccx, &instance.substs.types, &[], output);
+    push_type_params(ccx, instance.substs, &[], output);
 }
 
 pub fn def_id_to_string(ccx: &CrateContext, def_id: DefId) -> String {
@@ -1459,7 +1477,9 @@ impl<'tcx> TransItem<'tcx> {
 
     pub fn is_lazily_instantiated(&self) -> bool {
         match *self {
-            TransItem::Fn(ref instance) => !instance.substs.types.is_empty(),
+            TransItem::Fn(ref instance) => {
+                !instance.substs.types.is_empty() || !instance.substs.consts.is_empty()
+            }
             TransItem::DropGlue(..) => true,
             TransItem::Static(..)   => false,
         }
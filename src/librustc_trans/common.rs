@@ -32,6 +32,7 @@ use consts;
 use datum;
 use debuginfo::{self, DebugLoc};
 use declare;
+use expr;
 use machine;
 use mir::CachedMir;
 use monomorphize;
@@ -316,6 +317,10 @@ pub struct FunctionContext<'a, 'tcx: 'a> {
     // Same as above, but for closure upvars
     pub llupvars: RefCell<NodeMap<ValueRef>>,
 
+    // Maps the ids of the `loop`s being translated to the destinations
+    // that `break` stores their values into.
+    pub loop_dests: RefCell<NodeMap<expr::Dest>>,
+
     // Carries info about drop-flags for local bindings (longer term,
     // paths) for the code being compiled.
     pub lldropflag_hints: RefCell<DropFlagHintsMap<'tcx>>,
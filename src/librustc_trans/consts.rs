@@ -36,6 +36,7 @@ use Disr;
 use rustc::ty::subst::Substs;
 use rustc::ty::adjustment::{AdjustDerefRef, AdjustReifyFnPointer};
 use rustc::ty::adjustment::{AdjustUnsafeFnPointer, AdjustMutToConstPointer};
+use rustc::ty::adjustment::AdjustNeverToAny;
 use rustc::ty::{self, Ty, TyCtxt};
 use rustc::ty::cast::{CastTy,IntTy};
 use util::nodemap::NodeMap;
@@ -363,6 +364,9 @@ pub fn const_expr<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         Some(AdjustUnsafeFnPointer) | Some(AdjustMutToConstPointer) => {
             // purely a type-level thing
         }
+        Some(AdjustNeverToAny(..)) => {
+            span_bug!(e.span, "const expression of type ! encountered");
+        }
         Some(AdjustDerefRef(adj)) => {
             let mut ty = ety;
             // Save the last autoderef in case we can avoid it.
@@ -837,8 +841,16 @@ fn const_expr_unadjusted<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                 None => None
             };
 
+            let is_union = match *repr {
+                adt::UntaggedUnion(..) => true,
+                _ => false
+            };
+
             let VariantInfo { discr, fields } = VariantInfo::of_node(cx.tcx(), ety, e.id);
-            let cs = fields.iter().enumerate().map(|(ix, &Field(f_name, _))| {
+            let cs = fields.iter().enumerate().filter(|&(_, &Field(f_name, _))| {
+                // Only the initialized field of a union has a value.
+                !is_union || fs.iter().any(|f| f_name == f.name.node)
+            }).map(|(ix, &Field(f_name, _))| {
                 match (fs.iter().find(|f| f_name == f.name.node), base_val) {
                     (Some(ref f), _) => {
                         const_expr(cx, &f.expr, param_substs, fn_args, trueconst).map(|(l, _)| l)
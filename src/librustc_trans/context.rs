@@ -21,6 +21,7 @@ use adt;
 use base;
 use builder::Builder;
 use common::BuilderRef_res;
+use coverageinfo;
 use debuginfo;
 use declare;
 use glue::DropGlueKind;
@@ -31,7 +32,7 @@ use collector::{TransItem, TransItemState};
 use type_::{Type, TypeNames};
 use rustc::ty::subst::{Substs, VecPerParamSpace};
 use rustc::ty::{self, Ty, TyCtxt};
-use session::config::NoDebugInfo;
+use session::config::{NoDebugInfo, OutputFilenames};
 use session::Session;
 use util::sha2::Sha256;
 use util::nodemap::{NodeMap, NodeSet, DefIdMap, FnvHashMap, FnvHashSet};
@@ -80,6 +81,7 @@ pub struct SharedCrateContext<'a, 'tcx: 'a> {
     check_drop_flag_for_sanity: bool,
     mir_map: &'a MirMap<'tcx>,
     mir_cache: RefCell<DefIdMap<Rc<mir::Mir<'tcx>>>>,
+    outputs: &'a OutputFilenames,
 
     available_monomorphizations: RefCell<FnvHashSet<String>>,
     available_drop_glues: RefCell<FnvHashMap<DropGlueKind<'tcx>, String>>,
@@ -155,6 +157,8 @@ pub struct LocalCrateContext<'tcx> {
 
     dbg_cx: Option<debuginfo::CrateDebugContext<'tcx>>,
 
+    coverage_cx: Option<coverageinfo::CrateCoverageContext>,
+
     eh_personality: Cell<Option<ValueRef>>,
     eh_unwind_resume: Cell<Option<ValueRef>>,
     rust_try_fn: Cell<Option<ValueRef>>,
@@ -289,6 +293,7 @@ impl<'b, 'tcx> SharedCrateContext<'b, 'tcx> {
                local_count: usize,
                tcx: &'b TyCtxt<'tcx>,
                mir_map: &'b MirMap<'tcx>,
+               outputs: &'b OutputFilenames,
                export_map: ExportMap,
                symbol_hasher: Sha256,
                link_meta: LinkMeta,
@@ -357,6 +362,7 @@ impl<'b, 'tcx> SharedCrateContext<'b, 'tcx> {
             tcx: tcx,
             mir_map: mir_map,
             mir_cache: RefCell::new(DefIdMap()),
+            outputs: outputs,
             stats: Stats {
                 n_glues_created: Cell::new(0),
                 n_null_glues: Cell::new(0),
@@ -456,6 +462,10 @@ impl<'b, 'tcx> SharedCrateContext<'b, 'tcx> {
         &self.tcx.sess
     }
 
+    pub fn outputs<'a>(&'a self) -> &'a OutputFilenames {
+        self.outputs
+    }
+
     pub fn stats<'a>(&'a self) -> &'a Stats {
         &self.stats
     }
@@ -478,6 +488,17 @@ impl<'tcx> LocalCrateContext<'tcx> {
                 None
             };
 
+            let coverage_cx = if shared.tcx.sess.opts.debugging_opts.instrument_coverage {
+                // The coverage mapping format we emit is the one of LLVM 3.9.
+                if llvm::LLVMVersionMinor() < 9 {
+                    shared.tcx.sess.fatal("coverage instrumentation requires LLVM 3.9 \
+                                           or later");
+                }
+                Some(coverageinfo::CrateCoverageContext::new())
+            } else {
+                None
+            };
+
             let mut local_ccx = LocalCrateContext {
                 llmod: llmod,
                 llcx: llcx,
@@ -507,6 +528,7 @@ impl<'tcx> LocalCrateContext<'tcx> {
                 builder: BuilderRef_res(llvm::LLVMCreateBuilderInContext(llcx)),
                 closure_vals: RefCell::new(FnvHashMap()),
                 dbg_cx: dbg_cx,
+                coverage_cx: coverage_cx,
                 eh_personality: Cell::new(None),
                 eh_unwind_resume: Cell::new(None),
                 rust_try_fn: Cell::new(None),
@@ -566,6 +588,12 @@ impl<'b, 'tcx> CrateContext<'b, 'tcx> {
         self.local
     }
 
+    /// The index of this context's compilation unit, which is also the number
+    /// its output files are named with, e.g. `crate.0.o`.
+    pub fn codegen_unit_index(&self) -> usize {
+        self.index
+    }
+
     /// Get a (possibly) different `CrateContext` from the same
     /// `SharedCrateContext`.
     pub fn rotate(&self) -> CrateContext<'b, 'tcx> {
@@ -760,6 +788,10 @@ impl<'b, 'tcx> CrateContext<'b, 'tcx> {
         &self.local.dbg_cx
     }
 
+    pub fn coverage_cx<'a>(&'a self) -> &'a Option<coverageinfo::CrateCoverageContext> {
+        &self.local.coverage_cx
+    }
+
     pub fn eh_personality<'a>(&'a self) -> &'a Cell<Option<ValueRef>> {
         &self.local.eh_personality
     }
@@ -866,6 +898,7 @@ impl<'b, 'tcx> CrateContext<'b, 'tcx> {
     pub fn empty_substs_for_scheme(&self, scheme: &ty::TypeScheme<'tcx>)
                                    -> &'tcx Substs<'tcx> {
         assert!(scheme.generics.types.is_empty());
+        assert!(scheme.generics.consts.is_empty());
         self.tcx().mk_substs(
             Substs::new(VecPerParamSpace::empty(),
                         scheme.generics.regions.map(|_| ty::ReStatic)))
@@ -933,6 +966,7 @@ fn declare_intrinsic(ccx: &CrateContext, key: &str) -> Option<ValueRef> {
     ifn!("llvm.memset.p0i8.i64", fn(i8p, t_i8, t_i64, t_i32, i1) -> void);
 
     ifn!("llvm.trap", fn() -> void);
+    ifn!("llvm.instrprof.increment", fn(i8p, t_i64, t_i32, t_i32) -> void);
     ifn!("llvm.debugtrap", fn() -> void);
     ifn!("llvm.frameaddress", fn(t_i32) -> i8p);
 
@@ -260,7 +260,8 @@ pub fn trans_while<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
 
 pub fn trans_loop<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                               loop_expr: &hir::Expr,
-                              body: &hir::Block)
+                              body: &hir::Block,
+                              dest: expr::Dest)
                               -> Block<'blk, 'tcx> {
     let _icx = push_ctxt("trans_loop");
 
@@ -279,17 +280,19 @@ pub fn trans_loop<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
     // next_bcx
     //
     // Links between body_bcx_in and next_bcx are created by
-    // break statements.
+    // break statements, which also store their value into `dest`.
 
     let next_bcx_in = bcx.fcx.new_id_block("loop_exit", loop_expr.id);
     let body_bcx_in = bcx.fcx.new_id_block("loop_body", body.id);
 
     fcx.push_loop_cleanup_scope(loop_expr.id, [next_bcx_in, body_bcx_in]);
+    fcx.loop_dests.borrow_mut().insert(loop_expr.id, dest);
 
     Br(bcx, body_bcx_in.llbb, loop_expr.debug_loc());
     let body_bcx_out = trans_block(body_bcx_in, body, expr::Ignore);
     Br(body_bcx_out, body_bcx_in.llbb, DebugLoc::None);
 
+    fcx.loop_dests.borrow_mut().remove(&loop_expr.id);
     fcx.pop_loop_cleanup_scope(loop_expr.id);
 
     // If there are no predecessors for the next block, we just translated an endless loop and the
@@ -304,9 +307,11 @@ pub fn trans_loop<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
 pub fn trans_break_cont<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                                     expr: &hir::Expr,
                                     opt_label: Option<ast::Name>,
+                                    opt_value: Option<&hir::Expr>,
                                     exit: usize)
                                     -> Block<'blk, 'tcx> {
     let _icx = push_ctxt("trans_break_cont");
+    let mut bcx = bcx;
 
     if bcx.unreachable.get() {
         return bcx;
@@ -327,6 +332,15 @@ pub fn trans_break_cont<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
         }
     };
 
+    // Store the value given to `break`, if any, into the loop's destination
+    if let Some(value) = opt_value {
+        let dest = fcx.loop_dests.borrow().get(&loop_id).cloned().unwrap_or(expr::Ignore);
+        bcx = expr::trans_into(bcx, value, dest);
+        if bcx.unreachable.get() {
+            return bcx;
+        }
+    }
+
     // Generate appropriate cleanup code and branch
     let cleanup_llbb = fcx.normal_exit_block(loop_id, exit);
     Br(bcx, cleanup_llbb, expr.debug_loc());
@@ -336,16 +350,17 @@ pub fn trans_break_cont<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
 
 pub fn trans_break<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                                expr: &hir::Expr,
-                               label_opt: Option<ast::Name>)
+                               label_opt: Option<ast::Name>,
+                               value_opt: Option<&hir::Expr>)
                                -> Block<'blk, 'tcx> {
-    return trans_break_cont(bcx, expr, label_opt, cleanup::EXIT_BREAK);
+    return trans_break_cont(bcx, expr, label_opt, value_opt, cleanup::EXIT_BREAK);
 }
 
 pub fn trans_cont<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                               expr: &hir::Expr,
                               label_opt: Option<ast::Name>)
                               -> Block<'blk, 'tcx> {
-    return trans_break_cont(bcx, expr, label_opt, cleanup::EXIT_LOOP);
+    return trans_break_cont(bcx, expr, label_opt, None, cleanup::EXIT_LOOP);
 }
 
 pub fn trans_ret<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
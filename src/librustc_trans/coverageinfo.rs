@@ -0,0 +1,171 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! # Source-based code coverage
+//!
+//! With `-Z instrument-coverage` every MIR basic block gets a counter which
+//! is bumped whenever the block is entered, see `mir::coverage`. LLVM's
+//! `instrprof` pass turns these counters into data for the profiler runtime,
+//! but it has no idea which source code they count. This module records the
+//! source regions of all counters of a compilation unit and emits them as the
+//! coverage mapping which `llvm-cov` reads back when producing reports.
+
+use llvm::{self, CounterMappingRegion};
+use common::{C_array, C_bytes, C_struct, C_u32, C_u64, CrateContext, val_ty};
+use debuginfo::CoverageRegion;
+use declare;
+use type_::Type;
+
+use libc::c_uint;
+use std::cell::RefCell;
+use std::ffi::CString;
+
+/// The counters of an instrumented function and the code they count.
+pub struct FunctionCoverage {
+    /// The symbol name the profiler runtime knows the function by.
+    pub name: String,
+    /// The hash passed along with every counter increment of the function.
+    pub hash: u64,
+    /// The counter index and source region of every counter which maps to
+    /// source code. All regions lie within the same file.
+    pub regions: Vec<(u32, CoverageRegion)>,
+}
+
+/// The coverage information collected while translating a compilation unit.
+pub struct CrateCoverageContext {
+    functions: RefCell<Vec<FunctionCoverage>>,
+}
+
+impl CrateCoverageContext {
+    pub fn new() -> CrateCoverageContext {
+        CrateCoverageContext {
+            functions: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn add_function(&self, function: FunctionCoverage) {
+        self.functions.borrow_mut().push(function);
+    }
+}
+
+/// Emits the coverage mapping of all functions instrumented in this
+/// compilation unit into the `__llvm_coverage_mapping` global.
+pub fn finalize(cx: &CrateContext) {
+    let coverage_cx = match *cx.coverage_cx() {
+        Some(ref coverage_cx) => coverage_cx,
+        None => return,
+    };
+    let functions = coverage_cx.functions.borrow();
+
+    // All functions share one table of file names, each function's mapping
+    // then refers to it through its own table of file IDs. As a function's
+    // regions never leave its file that table always has a single entry.
+    let mut filenames: Vec<String> = Vec::new();
+    let mut mappings = Vec::new();
+    let mut records = Vec::new();
+    for function in functions.iter() {
+        let file = match function.regions.first() {
+            Some(&(_, ref region)) => &region.file,
+            None => continue,
+        };
+        let file = cx.sess().working_dir.join(file).to_string_lossy().into_owned();
+        let file_id = match filenames.iter().position(|f| *f == file) {
+            Some(i) => i,
+            None => {
+                filenames.push(file);
+                filenames.len() - 1
+            }
+        };
+
+        let regions = function.regions.iter().map(|&(counter, ref region)| {
+            CounterMappingRegion {
+                counter: counter,
+                file_id: 0,
+                line_start: region.line_start as u32,
+                column_start: region.col_start as u32,
+                line_end: region.line_end as u32,
+                column_end: region.col_end as u32,
+            }
+        }).collect::<Vec<_>>();
+        let file_ids = [file_id as c_uint];
+        let mapping = llvm::build_byte_buffer(|s| unsafe {
+            llvm::LLVMRustCoverageWriteMappingToBuffer(file_ids.as_ptr(),
+                                                       file_ids.len() as c_uint,
+                                                       regions.as_ptr(),
+                                                       regions.len() as c_uint,
+                                                       s);
+        });
+
+        let name = CString::new(&function.name[..]).unwrap();
+        let name_ref = unsafe { llvm::LLVMRustCoverageComputeHash(name.as_ptr()) };
+        records.push(C_struct(cx, &[C_u64(cx, name_ref),
+                                    C_u32(cx, mapping.len() as u32),
+                                    C_u64(cx, function.hash)], true));
+        mappings.push(mapping);
+    }
+
+    if records.is_empty() {
+        return
+    }
+
+    let c_filenames = filenames.iter().map(|f| {
+        CString::new(&f[..]).unwrap()
+    }).collect::<Vec<_>>();
+    let c_filename_ptrs = c_filenames.iter().map(|f| f.as_ptr()).collect::<Vec<_>>();
+    let mut data = llvm::build_byte_buffer(|s| unsafe {
+        llvm::LLVMRustCoverageWriteFilenamesSectionToBuffer(c_filename_ptrs.as_ptr(),
+                                                            c_filename_ptrs.len(),
+                                                            s);
+    });
+    let filenames_len = data.len();
+    for mapping in &mappings {
+        data.extend_from_slice(mapping);
+    }
+    // The coverage section is a sequence of these globals, keep each of them
+    // a multiple of 8 bytes long like clang does.
+    while data.len() % 8 != 0 {
+        data.push(0);
+    }
+    let mappings_len = data.len() - filenames_len;
+
+    let version = unsafe { llvm::LLVMRustCoverageMappingVersion() };
+    let header = C_struct(cx, &[C_u32(cx, records.len() as u32),
+                                C_u32(cx, filenames_len as u32),
+                                C_u32(cx, mappings_len as u32),
+                                C_u32(cx, version)], false);
+    let records = C_array(val_ty(records[0]), &records);
+    let llconst = C_struct(cx, &[header, records, C_bytes(cx, &data)], false);
+
+    let llglobal = declare::define_global(cx, "__llvm_coverage_mapping", val_ty(llconst))
+        .unwrap_or_else(|| bug!("symbol `__llvm_coverage_mapping` is already defined"));
+    let section = llvm::build_string(|s| unsafe {
+        llvm::LLVMRustCoverageWriteSectionNameToString(cx.llmod(), s);
+    }).expect("got a non-UTF8 section name from LLVM");
+    let section = CString::new(section).unwrap();
+    unsafe {
+        llvm::LLVMSetInitializer(llglobal, llconst);
+        llvm::LLVMSetGlobalConstant(llglobal, llvm::True);
+        llvm::SetLinkage(llglobal, llvm::InternalLinkage);
+        llvm::LLVMSetSection(llglobal, section.as_ptr());
+        llvm::LLVMSetAlignment(llglobal, 8);
+    }
+
+    // Nothing refers to the mapping, so make sure LLVM doesn't throw it away.
+    let i8p = Type::i8p(cx);
+    let used = C_array(i8p, &[unsafe { llvm::LLVMConstPointerCast(llglobal, i8p.to_ref()) }]);
+    unsafe {
+        let llused = llvm::LLVMAddGlobal(cx.llmod(),
+                                         val_ty(used).to_ref(),
+                                         "llvm.used\0".as_ptr() as *const _);
+        llvm::LLVMSetInitializer(llused, used);
+        llvm::SetLinkage(llused, llvm::AppendingLinkage);
+        llvm::LLVMSetSection(llused, "llvm.metadata\0".as_ptr() as *const _);
+    }
+}
@@ -394,7 +394,7 @@ fn walk_expr(cx: &CrateContext,
 
     match exp.node {
         hir::ExprLit(_)   |
-        hir::ExprBreak(_) |
+        hir::ExprBreak(_, None) |
         hir::ExprAgain(_) |
         hir::ExprPath(..) => {}
 
@@ -409,6 +409,10 @@ fn walk_expr(cx: &CrateContext,
             walk_expr(cx, &sub_expr, scope_stack, scope_map);
         }
 
+        hir::ExprBreak(_, Some(ref sub_exp)) => {
+            walk_expr(cx, &sub_exp, scope_stack, scope_map)
+        }
+
         hir::ExprRet(ref exp_opt) => match *exp_opt {
             Some(ref sub_exp) => walk_expr(cx, &sub_exp, scope_stack, scope_map),
             None => ()
@@ -468,7 +472,7 @@ fn walk_expr(cx: &CrateContext,
             })
         }
 
-        hir::ExprLoop(ref block, _) |
+        hir::ExprLoop(ref block, _, _) |
         hir::ExprBlock(ref block)   => {
             with_new_scope(cx,
                            block.span,
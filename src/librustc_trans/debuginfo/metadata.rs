@@ -172,6 +172,7 @@ impl<'tcx> TypeMap<'tcx> {
         unique_type_id.push('{');
 
         match type_.sty {
+            ty::TyNever    |
             ty::TyBool     |
             ty::TyChar     |
             ty::TyStr      |
@@ -705,6 +706,7 @@ pub fn type_metadata<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
 
     let sty = &t.sty;
     let MetadataCreationResult { metadata, already_stored_in_typemap } = match *sty {
+        ty::TyNever    |
         ty::TyBool     |
         ty::TyChar     |
         ty::TyInt(_)   |
@@ -933,6 +935,7 @@ fn basic_type_metadata<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
     let (name, encoding) = match t.sty {
         ty::TyTuple(ref elements) if elements.is_empty() =>
             ("()", DW_ATE_unsigned),
+        ty::TyNever => ("!", DW_ATE_unsigned),
         ty::TyBool => ("bool", DW_ATE_boolean),
         ty::TyChar => ("char", DW_ATE_unsigned_char),
         ty::TyInt(int_ty) => {
@@ -1012,7 +1015,14 @@ pub fn compile_unit_metadata(cx: &CrateContext) -> DIDescriptor {
     let work_dir = path2cstr(&work_dir);
     let producer = CString::new(producer).unwrap();
     let flags = "\0";
-    let split_name = "\0";
+    // The skeleton left in the object tells debuggers where to find the
+    // `.dwo` file that `back::write` splits the rest of the DWARF into.
+    let split_name = if cx.sess().split_debuginfo() != config::SplitDebuginfo::Off {
+        let index = cx.codegen_unit_index();
+        path2cstr(&cx.shared().outputs().with_extension(&format!("{}.dwo", index)))
+    } else {
+        CString::new("").unwrap()
+    };
     return unsafe {
         llvm::LLVMDIBuilderCreateCompileUnit(
             debug_context(cx).builder,
@@ -1023,7 +1033,8 @@ pub fn compile_unit_metadata(cx: &CrateContext) -> DIDescriptor {
             cx.sess().opts.optimize != config::OptLevel::No,
             flags.as_ptr() as *const _,
             0,
-            split_name.as_ptr() as *const _)
+            split_name.as_ptr(),
+            cx.sess().opts.debuginfo == config::LimitedDebugInfo)
     };
 
     fn fallback_path(cx: &CrateContext) -> CString {
@@ -1103,6 +1114,7 @@ struct StructMemberDescriptionFactory<'tcx> {
     variant: ty::VariantDef<'tcx>,
     substs: &'tcx subst::Substs<'tcx>,
     is_simd: bool,
+    is_union: bool,
     span: Span,
 }
 
@@ -1135,6 +1147,9 @@ impl<'tcx> StructMemberDescriptionFactory<'tcx> {
 
             let offset = if self.is_simd {
                 FixedMemberOffset { bytes: i * field_size.unwrap() }
+            } else if self.is_union {
+                // All the fields of a union start at its beginning.
+                FixedMemberOffset { bytes: 0 }
             } else {
                 ComputedMemberOffset
             };
@@ -1159,18 +1174,26 @@ fn prepare_struct_metadata<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
     let struct_name = compute_debuginfo_type_name(cx, struct_type, false);
     let struct_llvm_type = type_of::in_memory_type_of(cx, struct_type);
 
-    let (variant, substs) = match struct_type.sty {
-        ty::TyStruct(def, substs) => (def.struct_variant(), substs),
+    let (def, variant, substs) = match struct_type.sty {
+        ty::TyStruct(def, substs) => (def, def.struct_variant(), substs),
         _ => bug!("prepare_struct_metadata on a non-struct")
     };
 
     let (containing_scope, _) = get_namespace_and_span_for_item(cx, variant.did);
 
-    let struct_metadata_stub = create_struct_stub(cx,
-                                                  struct_llvm_type,
-                                                  &struct_name,
-                                                  unique_type_id,
-                                                  containing_scope);
+    let struct_metadata_stub = if def.is_union() {
+        create_union_stub(cx,
+                          struct_llvm_type,
+                          &struct_name,
+                          unique_type_id,
+                          containing_scope)
+    } else {
+        create_struct_stub(cx,
+                           struct_llvm_type,
+                           &struct_name,
+                           unique_type_id,
+                           containing_scope)
+    };
 
     create_and_register_recursive_type_forward_declaration(
         cx,
@@ -1182,6 +1205,7 @@ fn prepare_struct_metadata<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
             variant: variant,
             substs: substs,
             is_simd: struct_type.is_simd(),
+            is_union: def.is_union(),
             span: span,
         })
     )
@@ -1451,7 +1475,9 @@ impl<'tcx> EnumMemberDescriptionFactory<'tcx> {
                     }
                 ]
             },
-            adt::CEnum(..) => span_bug!(self.span, "This should be unreachable.")
+            adt::CEnum(..) | adt::UntaggedUnion(..) => {
+                span_bug!(self.span, "This should be unreachable.")
+            }
         }
     }
 }
@@ -1650,6 +1676,7 @@ fn prepare_enum_metadata<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         adt::RawNullablePointer { .. }           |
         adt::StructWrappedNullablePointer { .. } |
         adt::Univariant(..)                      => None,
+        adt::UntaggedUnion(..) => bug!("prepare_enum_metadata on a union"),
         adt::General(inttype, _, _) => Some(discriminant_type_metadata(inttype)),
     };
 
@@ -1828,6 +1855,40 @@ fn create_struct_stub(cx: &CrateContext,
     return metadata_stub;
 }
 
+// Creates an empty union type stub, to be filled in later by
+// set_members_of_composite_type(), like create_struct_stub().
+fn create_union_stub(cx: &CrateContext,
+                     union_llvm_type: Type,
+                     union_type_name: &str,
+                     unique_type_id: UniqueTypeId,
+                     containing_scope: DIScope)
+                   -> DICompositeType {
+    let (union_size, union_align) = size_and_align_of(cx, union_llvm_type);
+
+    let unique_type_id_str = debug_context(cx).type_map
+                                              .borrow()
+                                              .get_unique_type_id_as_string(unique_type_id);
+    let name = CString::new(union_type_name).unwrap();
+    let unique_type_id = CString::new(unique_type_id_str.as_bytes()).unwrap();
+    unsafe {
+        // See create_struct_stub() for why an empty array is used here.
+        let empty_array = create_DIArray(DIB(cx), &[]);
+
+        llvm::LLVMDIBuilderCreateUnionType(
+            DIB(cx),
+            containing_scope,
+            name.as_ptr(),
+            NO_FILE_METADATA,
+            UNKNOWN_LINE_NUMBER,
+            bytes_to_bits(union_size),
+            bytes_to_bits(union_align),
+            0, // Flags
+            empty_array,
+            0, // RuntimeLang
+            unique_type_id.as_ptr())
+    }
+}
+
 /// Creates debug information for the given global variable.
 ///
 /// Adds the created metadata nodes directly to the crate's IR.
@@ -60,6 +60,8 @@ pub use self::create_scope_map::create_mir_scopes;
 pub use self::source_loc::start_emitting_source_locations;
 pub use self::source_loc::get_cleanup_debug_loc_for_ast_node;
 pub use self::source_loc::with_source_location_override;
+pub use self::source_loc::coverage_region;
+pub use self::source_loc::CoverageRegion;
 pub use self::metadata::create_match_binding_metadata;
 pub use self::metadata::create_argument_metadata;
 pub use self::metadata::create_captured_var_metadata;
@@ -158,6 +158,34 @@ pub fn with_source_location_override<F, R>(fcx: &FunctionContext,
     }
 }
 
+/// The source region a coverage counter is attributed to, with lines and
+/// columns starting at 1 as the LLVM coverage mapping format expects them.
+#[derive(Clone, Debug)]
+pub struct CoverageRegion {
+    pub file: String,
+    pub line_start: usize,
+    pub col_start: usize,
+    pub line_end: usize,
+    pub col_end: usize,
+}
+
+/// Computes the coverage region spanned by `span`.
+///
+/// Unlike debug locations, coverage regions keep both ends of the span so
+/// that `llvm-cov` can highlight exactly the code a counter belongs to.
+pub fn coverage_region(cx: &CrateContext, span: Span) -> CoverageRegion {
+    let codemap = cx.sess().codemap();
+    let lo = codemap.lookup_char_pos(span.lo);
+    let hi = codemap.lookup_char_pos(span.hi);
+    CoverageRegion {
+        file: lo.file.name.clone(),
+        line_start: lo.line,
+        col_start: lo.col.to_usize() + 1,
+        line_end: hi.line,
+        col_end: hi.col.to_usize() + 1,
+    }
+}
+
 /// Enables emitting source locations for the given functions.
 ///
 /// Since we don't want source locations to be emitted for the function prelude,
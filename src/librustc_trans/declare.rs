@@ -80,6 +80,8 @@ fn declare_raw_fn(ccx: &CrateContext, name: &str, callconv: llvm::CallConv, ty:
         _ => {},
     }
 
+    attributes::sanitize(ccx, llfn);
+
     llfn
 }
 
@@ -54,7 +54,7 @@ use self::lazy_binop_ty::*;
 use llvm::{self, ValueRef, TypeKind};
 use middle::const_qualif::ConstQualif;
 use rustc::hir::def::Def;
-use rustc::ty::subst::Substs;
+use rustc::ty::subst::{ParamSpace, Substs};
 use {_match, abi, adt, asm, base, closure, consts, controlflow};
 use base::*;
 use build::*;
@@ -72,6 +72,7 @@ use value::Value;
 use Disr;
 use rustc::ty::adjustment::{AdjustDerefRef, AdjustReifyFnPointer};
 use rustc::ty::adjustment::{AdjustUnsafeFnPointer, AdjustMutToConstPointer};
+use rustc::ty::adjustment::AdjustNeverToAny;
 use rustc::ty::adjustment::CustomCoerceUnsized;
 use rustc::ty::{self, Ty, TyCtxt};
 use rustc::ty::MethodCall;
@@ -348,7 +349,7 @@ fn adjustment_required<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
     }
 
     match adjustment {
-        AdjustReifyFnPointer => true,
+        AdjustReifyFnPointer | AdjustNeverToAny(..) => true,
         AdjustUnsafeFnPointer | AdjustMutToConstPointer => {
             // purely a type-level thing
             false
@@ -394,6 +395,14 @@ fn apply_adjustments<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
         AdjustUnsafeFnPointer | AdjustMutToConstPointer => {
             // purely a type-level thing
         }
+        AdjustNeverToAny(ref target) => {
+            // The source diverged, so this code is unreachable and any
+            // value of the target type will do.
+            let mono_target = bcx.monomorphize(target);
+            let llty = type_of::type_of(bcx.ccx(), mono_target);
+            let dummy = C_undef(llty.ptr_to());
+            datum = Datum::new(dummy, mono_target, Lvalue::new("never")).to_expr_datum();
+        }
         AdjustDerefRef(ref adj) => {
             let skip_reborrows = if adj.autoderefs == 1 && adj.autoref.is_some() {
                 // We are a bit paranoid about adjustments and thus might have a re-
@@ -650,6 +659,9 @@ fn trans_datum_unadjusted<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
             trans(bcx, &e)
         }
         hir::ExprPath(..) => {
+            if let Def::ConstParam(space, index, _, _) = bcx.def(expr.id) {
+                return trans_const_param(bcx, expr, space, index);
+            }
             let var = trans_var(bcx, bcx.def(expr.id));
             DatumBlock::new(bcx, var.to_expr_datum())
         }
@@ -880,6 +892,24 @@ fn trans_index<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
     DatumBlock::new(bcx, elt_datum)
 }
 
+/// Translates a reference to a const parameter into its value in the
+/// current monomorphization.
+fn trans_const_param<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
+                                 expr: &hir::Expr,
+                                 space: ParamSpace,
+                                 index: u32)
+                                 -> DatumBlock<'blk, 'tcx, Expr> {
+    let ty = expr_ty(bcx, expr);
+    let value = match *bcx.fcx.param_substs.consts.get(space, index as usize) {
+        ty::Const::Value(value) => value,
+        c => bug!("const parameter {:?} not monomorphized", c)
+    };
+    let llval = C_integral(type_of::type_of(bcx.ccx(), ty),
+                           value.to_u64_unchecked(),
+                           value.is_negative());
+    immediate_rvalue_bcx(bcx, llval, ty).to_expr_datumblock()
+}
+
 /// Translates a reference to a variable.
 pub fn trans_var<'blk, 'tcx>(bcx: Block<'blk, 'tcx>, def: Def)
                              -> Datum<'tcx, Lvalue> {
@@ -926,8 +956,9 @@ fn trans_rvalue_stmt_unadjusted<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
     expr.debug_loc().apply(bcx.fcx);
 
     match expr.node {
-        hir::ExprBreak(label_opt) => {
-            controlflow::trans_break(bcx, expr, label_opt.map(|l| l.node.name))
+        hir::ExprBreak(label_opt, ref value) => {
+            controlflow::trans_break(bcx, expr, label_opt.map(|l| l.node.name),
+                                     value.as_ref().map(|e| &**e))
         }
         hir::ExprType(ref e, _) => {
             trans_into(bcx, &e, Ignore)
@@ -963,9 +994,6 @@ fn trans_rvalue_stmt_unadjusted<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
         hir::ExprWhile(ref cond, ref body, _) => {
             controlflow::trans_while(bcx, expr, &cond, &body)
         }
-        hir::ExprLoop(ref body, _) => {
-            controlflow::trans_loop(bcx, expr, &body)
-        }
         hir::ExprAssign(ref dst, ref src) => {
             let src_datum = unpack_datum!(bcx, trans(bcx, &src));
             let dst_datum = unpack_datum!(bcx, trans_to_lvalue(bcx, &dst, "assign"));
@@ -1083,6 +1111,9 @@ fn trans_rvalue_dps_unadjusted<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
         hir::ExprBlock(ref blk) => {
             controlflow::trans_block(bcx, &blk, dest)
         }
+        hir::ExprLoop(ref body, _, _) => {
+            controlflow::trans_loop(bcx, expr, &body, dest)
+        }
         hir::ExprStruct(_, ref fields, ref base) => {
             trans_struct(bcx,
                          &fields[..],
@@ -1293,7 +1324,12 @@ fn trans_struct<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                                  fields: leftovers })
         }
         None => {
-            if need_base.iter().any(|b| *b) {
+            // Only one of the fields of a union is initialized.
+            let is_union = match ty.sty {
+                ty::TyStruct(def, _) => def.is_union(),
+                _ => false
+            };
+            if !is_union && need_base.iter().any(|b| *b) {
                 span_bug!(expr_span, "missing fields and no base expr")
             }
             None
@@ -1400,6 +1436,7 @@ pub fn trans_adt<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
         debug_location.apply(bcx.fcx);
 
         // Second, trans the base to the dest.
+        // Only single-variant enums get here, see `check_expr_struct_fields`.
         assert_eq!(discr, Disr(0));
 
         let addr = adt::MaybeSizedValue::sized(addr);
@@ -2412,7 +2449,8 @@ fn expr_kind(tcx: &TyCtxt, expr: &hir::Expr) -> ExprKind {
                 Def::Local(..) => ExprKind::Lvalue,
 
                 Def::Const(..) |
-                Def::AssociatedConst(..) => ExprKind::RvalueDatum,
+                Def::AssociatedConst(..) |
+                Def::ConstParam(..) => ExprKind::RvalueDatum,
 
                 def => {
                     span_bug!(
@@ -2443,6 +2481,7 @@ fn expr_kind(tcx: &TyCtxt, expr: &hir::Expr) -> ExprKind {
         hir::ExprMatch(..) |
         hir::ExprClosure(..) |
         hir::ExprBlock(..) |
+        hir::ExprLoop(..) |
         hir::ExprRepeat(..) |
         hir::ExprVec(..) => {
             ExprKind::RvalueDps
@@ -2456,7 +2495,6 @@ fn expr_kind(tcx: &TyCtxt, expr: &hir::Expr) -> ExprKind {
         hir::ExprAgain(..) |
         hir::ExprRet(..) |
         hir::ExprWhile(..) |
-        hir::ExprLoop(..) |
         hir::ExprAssign(..) |
         hir::ExprInlineAsm(..) |
         hir::ExprAssignOp(..) => {
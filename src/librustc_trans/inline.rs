@@ -56,7 +56,7 @@ fn instantiate_inline(ccx: &CrateContext, fn_id: DefId) -> Option<DefId> {
 
             if let hir::ItemFn(_, _, _, _, ref generics, _) = item.node {
                 // Generics have no symbol, so they can't be given any linkage.
-                if !generics.is_type_parameterized() {
+                if !generics.is_type_parameterized() && !generics.is_const_parameterized() {
                     let linkage = if ccx.sess().opts.cg.codegen_units == 1 {
                         // We could use AvailableExternallyLinkage here,
                         // but InternalLinkage allows LLVM to optimize more
@@ -145,7 +145,9 @@ fn instantiate_inline(ccx: &CrateContext, fn_id: DefId) -> Option<DefId> {
             if let hir::ImplItemKind::Method(ref sig, ref body) = impl_item.node {
                 let impl_tpt = tcx.lookup_item_type(impl_did);
                 if impl_tpt.generics.types.is_empty() &&
-                        sig.generics.ty_params.is_empty() {
+                        impl_tpt.generics.consts.is_empty() &&
+                        sig.generics.ty_params.is_empty() &&
+                        sig.generics.const_params.is_empty() {
                     let def_id = tcx.map.local_def_id(impl_item.id);
                     let empty_substs = ccx.empty_substs_for_def_id(def_id);
                     let llfn = Callee::def(ccx, def_id, empty_substs).reify(ccx).val;
@@ -73,6 +73,7 @@ pub mod back {
     pub mod linker;
     pub mod link;
     pub mod lto;
+    pub mod size_report;
     pub mod symbol_names;
     pub mod write;
     pub mod msvc;
@@ -97,6 +98,7 @@ mod cabi_asmjs;
 mod cabi_mips;
 mod cabi_powerpc;
 mod cabi_powerpc64;
+mod cabi_riscv;
 mod cabi_x86;
 mod cabi_x86_64;
 mod cabi_x86_win64;
@@ -108,6 +110,7 @@ mod common;
 mod consts;
 mod context;
 mod controlflow;
+mod coverageinfo;
 mod datum;
 mod debuginfo;
 mod declare;
@@ -144,6 +147,7 @@ pub struct CrateTranslation {
     pub metadata: Vec<u8>,
     pub reachable: Vec<String>,
     pub no_builtins: bool,
+    pub code_origins: Option<back::size_report::CodeOrigins>,
 }
 
 __build_diagnostic_array! { librustc_trans, DIAGNOSTICS }
@@ -50,6 +50,8 @@ impl<'bcx, 'tcx> MirContext<'bcx, 'tcx> {
             bcx.br(llbb);
         };
 
+        self.increment_coverage_counter(&bcx, bb);
+
         for statement in &data.statements {
             bcx = self.trans_statement(bcx, statement);
         }
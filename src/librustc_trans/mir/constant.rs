@@ -9,7 +9,7 @@
 // except according to those terms.
 
 use llvm::ValueRef;
-use rustc::ty::{Ty, TypeFoldable};
+use rustc::ty::{self, Ty, TypeFoldable};
 use rustc::middle::const_val::ConstVal;
 use rustc_const_math::ConstInt::*;
 use rustc_const_eval::lookup_const_by_id;
@@ -138,6 +138,19 @@ impl<'bcx, 'tcx> MirContext<'bcx, 'tcx> {
             mir::Literal::Value { ref value } => {
                 self.trans_constval(bcx, value, ty)
             }
+            mir::Literal::ConstParam { param } => {
+                let value = match bcx.monomorphize(&ty::Const::Param(param)) {
+                    ty::Const::Value(value) => value,
+                    c => bug!("const parameter {} monomorphized to {:?}", param, c)
+                };
+                let llty = type_of::type_of(bcx.ccx(), ty);
+                OperandRef {
+                    val: OperandValue::Immediate(C_integral(llty,
+                                                            value.to_u64_unchecked(),
+                                                            value.is_negative())),
+                    ty: ty
+                }
+            }
         }
     }
 }
@@ -0,0 +1,138 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Coverage counters for MIR basic blocks, see `coverageinfo` for how they
+//! are mapped back to the source code.
+
+use llvm::{self, ValueRef};
+use rustc::mir::repr as mir;
+use common::{BlockAndBuilder, C_u32, C_u64, FunctionContext};
+use consts;
+use coverageinfo::FunctionCoverage;
+use debuginfo::coverage_region;
+use type_::Type;
+
+use std::cmp;
+use std::ffi::{CStr, CString};
+use syntax::codemap::{Span, NO_EXPANSION};
+
+use super::MirContext;
+
+pub struct CoverageCounters {
+    /// The variable holding the function's name for the profiler runtime.
+    name_var: ValueRef,
+    num_counters: u32,
+    coverage: FunctionCoverage,
+}
+
+/// Allocates a counter for every basic block of `mir` if coverage
+/// instrumentation is enabled.
+///
+/// The counter of a block is the block's index, blocks which don't map to any
+/// source code, like cleanup blocks, simply never have their counter bumped.
+pub fn create_counters<'blk, 'tcx>(fcx: &FunctionContext<'blk, 'tcx>,
+                                   mir: &mir::Mir<'tcx>)
+                                   -> Option<CoverageCounters> {
+    let ccx = fcx.ccx;
+    if ccx.coverage_cx().is_none() {
+        return None;
+    }
+    // Compiler-generated shims have nothing to map their counters to.
+    let fn_span = match fcx.span {
+        Some(span) if span.expn_id == NO_EXPANSION => span,
+        _ => return None,
+    };
+    let fn_file = ccx.sess().codemap().lookup_char_pos(fn_span.lo).file.name.clone();
+
+    let blocks = mir.all_basic_blocks();
+    let mut regions = Vec::new();
+    for &bb in &blocks {
+        let data = mir.basic_block_data(bb);
+        if data.is_cleanup {
+            continue;
+        }
+
+        // A block covers all the code of its statements and its terminator,
+        // leaving out code expanded from macros defined elsewhere.
+        let spans = data.statements.iter()
+                                   .map(|statement| statement.span)
+                                   .chain(Some(data.terminator().span))
+                                   .filter(|span| span.expn_id == NO_EXPANSION);
+        let span = spans.fold(None, |acc: Option<Span>, span| Some(match acc {
+            Some(acc) => Span {
+                lo: cmp::min(acc.lo, span.lo),
+                hi: cmp::max(acc.hi, span.hi),
+                expn_id: NO_EXPANSION,
+            },
+            None => span,
+        }));
+        if let Some(span) = span {
+            let region = coverage_region(ccx, span);
+            if region.file == fn_file {
+                regions.push((bb.index() as u32, region));
+            }
+        }
+    }
+
+    let name = unsafe { CStr::from_ptr(llvm::LLVMGetValueName(fcx.llfn)) };
+    let name = name.to_string_lossy().into_owned();
+    let c_name = CString::new(&name[..]).unwrap();
+    let name_var = unsafe {
+        llvm::LLVMRustCoverageCreatePGOFuncNameVar(fcx.llfn, c_name.as_ptr())
+    };
+
+    Some(CoverageCounters {
+        name_var: name_var,
+        num_counters: blocks.len() as u32,
+        coverage: FunctionCoverage {
+            name: name,
+            // The hash is only used to detect a profile which doesn't match
+            // the counters of the function anymore.
+            hash: blocks.len() as u64,
+            regions: regions,
+        },
+    })
+}
+
+impl<'bcx, 'tcx> MirContext<'bcx, 'tcx> {
+    /// Bumps the coverage counter of `bb`, unless coverage instrumentation
+    /// is disabled or `bb` is a cleanup block.
+    pub fn increment_coverage_counter(&self,
+                                      bcx: &BlockAndBuilder<'bcx, 'tcx>,
+                                      bb: mir::BasicBlock) {
+        let counters = match self.coverage_counters {
+            Some(ref counters) => counters,
+            None => return,
+        };
+        if self.mir.basic_block_data(bb).is_cleanup {
+            return;
+        }
+
+        let ccx = bcx.ccx();
+        let llfn = ccx.get_intrinsic(&"llvm.instrprof.increment");
+        let name = consts::ptrcast(counters.name_var, Type::i8p(ccx));
+        bcx.call(llfn,
+                 &[name,
+                   C_u64(ccx, counters.coverage.hash),
+                   C_u32(ccx, counters.num_counters),
+                   C_u32(ccx, bb.index() as u32)],
+                 None);
+    }
+
+    /// Hands the coverage regions of this function over to `coverageinfo`.
+    pub fn finish_coverage(&mut self) {
+        if let Some(counters) = self.coverage_counters.take() {
+            let ccx = self.fcx.ccx;
+            if let Some(ref coverage_cx) = *ccx.coverage_cx() {
+                coverage_cx.add_function(counters.coverage);
+            }
+        }
+    }
+}
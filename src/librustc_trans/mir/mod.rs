@@ -100,7 +100,10 @@ pub struct MirContext<'bcx, 'tcx:'bcx> {
     args: Vec<LvalueRef<'tcx>>,
 
     /// Debug information for MIR scopes.
-    scopes: Vec<DIScope>
+    scopes: Vec<DIScope>,
+
+    /// Coverage counters for the basic blocks, if instrumenting for coverage.
+    coverage_counters: Option<coverage::CoverageCounters>
 }
 
 enum TempRef<'tcx> {
@@ -207,7 +210,8 @@ pub fn trans_mir<'blk, 'tcx: 'blk>(fcx: &'blk FunctionContext<'blk, 'tcx>) {
         vars: vars,
         temps: temps,
         args: args,
-        scopes: scopes
+        scopes: scopes,
+        coverage_counters: coverage::create_counters(fcx, &mir)
     };
 
     let mut visited = BitVector::new(mir_blocks.len());
@@ -232,6 +236,8 @@ pub fn trans_mir<'blk, 'tcx: 'blk>(fcx: &'blk FunctionContext<'blk, 'tcx>) {
         }
     }
 
+    mircx.finish_coverage();
+
     DebugLoc::None.apply(fcx);
     fcx.cleanup();
 }
@@ -424,6 +430,7 @@ fn arg_value_refs<'bcx, 'tcx>(bcx: &BlockAndBuilder<'bcx, 'tcx>,
 mod analyze;
 mod block;
 mod constant;
+mod coverage;
 mod drop;
 mod lvalue;
 mod operand;
@@ -12,7 +12,6 @@ use llvm::ValueRef;
 use rustc::ty::{self, Ty};
 use rustc::ty::cast::{CastTy, IntTy};
 use middle::const_val::ConstVal;
-use rustc_const_math::ConstInt;
 use rustc::mir::repr as mir;
 
 use asm;
@@ -100,7 +99,10 @@ impl<'bcx, 'tcx> MirContext<'bcx, 'tcx> {
 
             mir::Rvalue::Repeat(ref elem, ref count) => {
                 let tr_elem = self.trans_operand(&bcx, elem);
-                let count = ConstVal::Integral(ConstInt::Usize(count.value));
+                let count = match bcx.monomorphize(&count.value) {
+                    ty::Const::Value(value) => ConstVal::Integral(value),
+                    c => bug!("repeat count {} monomorphized to {:?}", count.value, c)
+                };
                 let size = self.trans_constval(&bcx, &count, bcx.tcx().types.usize).immediate();
                 let base = get_dataptr(&bcx, dest.llval);
                 let bcx = bcx.map_block(|block| {
@@ -218,7 +218,7 @@ fn write_content<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                     return expr::trans_into(bcx, &element, Ignore);
                 }
                 SaveIn(lldest) => {
-                    match eval_repeat_count(bcx.tcx(), &count_expr) {
+                    match repeat_count(bcx, content_expr, &count_expr) {
                         0 => expr::trans_into(bcx, &element, Ignore),
                         1 => expr::trans_into(bcx, &element, SaveIn(lldest)),
                         count => {
@@ -268,12 +268,25 @@ fn elements_required(bcx: Block, content_expr: &hir::Expr) -> usize {
         },
         hir::ExprVec(ref es) => es.len(),
         hir::ExprRepeat(_, ref count_expr) => {
-            eval_repeat_count(bcx.tcx(), &count_expr)
+            repeat_count(bcx, content_expr, &count_expr)
         }
         _ => span_bug!(content_expr.span, "unexpected vec content")
     }
 }
 
+fn repeat_count(bcx: Block, repeat_expr: &hir::Expr, count_expr: &hir::Expr) -> usize {
+    //! A count given by a const parameter is only known from the
+    //! monomorphized type of the repeat expression.
+
+    match bcx.tcx().expr_ty(repeat_expr).sty {
+        ty::TyArrayParam(..) => match node_id_type(bcx, repeat_expr.id).sty {
+            ty::TyArray(_, count) => count,
+            ref sty => span_bug!(repeat_expr.span, "unexpected repeat type {:?}", sty)
+        },
+        _ => eval_repeat_count(bcx.tcx(), count_expr)
+    }
+}
+
 /// Converts a fixed-length vector into the slice pair. The vector should be stored in `llval`
 /// which should be by ref.
 pub fn get_fixed_base_and_len(bcx: Block,
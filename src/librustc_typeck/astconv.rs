@@ -63,13 +63,13 @@ use rustc::ty::wf::object_region_bounds;
 use require_c_abi_if_variadic;
 use rscope::{self, UnelidableRscope, RegionScope, ElidableRscope,
              ObjectLifetimeDefaultRscope, ShiftedRscope, BindingRscope,
-             ElisionFailureInfo, ElidedLifetime};
+             ElisionFailureInfo, ElidedLifetime, AnonTypeScope, MaybeWithAnonTypes};
 use util::common::{ErrorReported, FN_OUTPUT_NAME};
 use util::nodemap::FnvHashSet;
 
 use rustc_const_math::ConstInt;
 
-use syntax::{abi, ast};
+use syntax::{abi, ast, attr};
 use syntax::codemap::{Span, Pos};
 use syntax::errors::DiagnosticBuilder;
 use syntax::feature_gate::{GateIssue, emit_feature_err};
@@ -77,7 +77,6 @@ use syntax::parse::token::{self, keywords};
 
 use rustc::hir::print as pprust;
 use rustc::hir;
-use rustc_back::slice;
 
 pub trait AstConv<'tcx> {
     fn tcx<'a>(&'a self) -> &'a TyCtxt<'tcx>;
@@ -124,6 +123,9 @@ pub trait AstConv<'tcx> {
                     space: Option<ParamSpace>,
                     span: Span) -> Ty<'tcx>;
 
+    /// What const should we use when a const argument is omitted?
+    fn const_infer(&self, span: Span) -> ty::Const;
+
     /// Projecting an associated type from a (potentially)
     /// higher-ranked trait reference is more complicated, because of
     /// the possibility of late-bound regions appearing in the
@@ -134,11 +136,12 @@ pub trait AstConv<'tcx> {
     fn projected_ty_from_poly_trait_ref(&self,
                                         span: Span,
                                         poly_trait_ref: ty::PolyTraitRef<'tcx>,
-                                        item_name: ast::Name)
+                                        item_name: ast::Name,
+                                        item_substs: &'tcx Substs<'tcx>)
                                         -> Ty<'tcx>
     {
         if let Some(trait_ref) = self.tcx().no_late_bound_regions(&poly_trait_ref) {
-            self.projected_ty(span, trait_ref, item_name)
+            self.projected_ty(span, trait_ref, item_name, item_substs)
         } else {
             // no late-bound regions, we can just ignore the binder
             span_err!(self.tcx().sess, span, E0212,
@@ -153,7 +156,8 @@ pub trait AstConv<'tcx> {
     fn projected_ty(&self,
                     span: Span,
                     _trait_ref: ty::TraitRef<'tcx>,
-                    _item_name: ast::Name)
+                    _item_name: ast::Name,
+                    _item_substs: &'tcx Substs<'tcx>)
                     -> Ty<'tcx>;
 
     /// Invoked when we encounter an error from some prior pass
@@ -323,6 +327,7 @@ pub fn ast_path_substs_for_ty<'tcx>(
     // `Self` because it is implicit.
     assert!(decl_generics.regions.all(|d| d.space == TypeSpace));
     assert!(decl_generics.types.all(|d| d.space != FnSpace));
+    assert!(decl_generics.consts.all(|d| d.space == TypeSpace));
 
     let (regions, types, assoc_bindings) = match item_segment.parameters {
         hir::AngleBracketedParameters(ref data) => {
@@ -453,6 +458,25 @@ fn create_substs_for_ast_path<'tcx>(
     let mut substs = region_substs;
     substs.types.extend(TypeSpace, type_substs.into_iter());
 
+    // Check the const arguments supplied by the user, inferring all of
+    // them if none were given and inference is allowed here.
+    let formal_const_count = decl_generics.consts.len(TypeSpace);
+    let supplied_const_count = substs.consts.len(TypeSpace);
+    if supplied_const_count != formal_const_count {
+        if param_mode == PathParamMode::Optional && supplied_const_count == 0 {
+            let consts = (0..formal_const_count).map(|_| this.const_infer(span)).collect();
+            substs.consts.replace(TypeSpace, consts);
+        } else {
+            span_err!(tcx.sess, span, E0568,
+                      "wrong number of const arguments: expected {}, found {}",
+                      formal_const_count, supplied_const_count);
+            substs.consts.truncate(TypeSpace, formal_const_count);
+            while substs.consts.len(TypeSpace) < formal_const_count {
+                substs.consts.push(TypeSpace, const_err());
+            }
+        }
+    }
+
     match self_ty {
         None => {
             // If no self-type is provided, it's still possible that
@@ -557,9 +581,22 @@ fn convert_angle_bracketed_parameters<'tcx>(this: &AstConv<'tcx>,
                       .map(|l| ast_region_to_region(this.tcx(), l))
                       .collect();
 
-    let region_substs =
+    let mut region_substs =
         create_region_substs(this, rscope, span, decl_generics, regions);
 
+    // Extra const arguments are reported along with the other argument
+    // counts, in `create_substs_for_ast_path`.
+    let const_param_defs = decl_generics.consts.get_slice(TypeSpace);
+    let consts: Vec<_> =
+        data.consts.iter()
+                   .enumerate()
+                   .map(|(i, e)| match const_param_defs.get(i) {
+                       Some(param) => ast_const_arg_to_const(this, param, e),
+                       None => const_err(),
+                   })
+                   .collect();
+    region_substs.consts.replace(TypeSpace, consts);
+
     let types: Vec<_> =
         data.types.iter()
                   .enumerate()
@@ -577,6 +614,87 @@ fn convert_angle_bracketed_parameters<'tcx>(this: &AstConv<'tcx>,
     (region_substs, types, assoc_bindings)
 }
 
+/// The const used in place of an erroneous const argument, once the
+/// error has been reported.
+pub fn const_err() -> ty::Const {
+    ty::Const::Value(ConstInt::Infer(0))
+}
+
+/// If `expr` names a const parameter, either directly as in `[T; N]` or
+/// in braces as in `Foo<{N}>`, returns that parameter and its def-id.
+pub fn const_param_in_expr(tcx: &TyCtxt, expr: &hir::Expr) -> Option<(ty::ParamConst, DefId)> {
+    match expr.node {
+        hir::ExprBlock(ref block) if block.stmts.is_empty() &&
+                                     block.rules == hir::DefaultBlock => {
+            block.expr.as_ref().and_then(|e| const_param_in_expr(tcx, e))
+        }
+        hir::ExprPath(None, _) => {
+            match tcx.def_map.borrow().get(&expr.id).map(|d| d.full_def()) {
+                Some(Def::ConstParam(space, index, def_id, name)) => {
+                    Some((ty::ParamConst::new(space, index, name), def_id))
+                }
+                _ => None
+            }
+        }
+        _ => None
+    }
+}
+
+fn const_int_has_ty(value: ConstInt, ty: Ty) -> bool {
+    match (value.int_type(), &ty.sty) {
+        (Some(attr::SignedInt(a)), &ty::TyInt(b)) => a == b,
+        (Some(attr::UnsignedInt(a)), &ty::TyUint(b)) => a == b,
+        _ => false
+    }
+}
+
+/// Converts the const argument `expr` supplied for `param`. The argument
+/// must either name a const parameter of the same type, or evaluate to an
+/// integer of that type.
+pub fn ast_const_arg_to_const<'tcx>(this: &AstConv<'tcx>,
+                                    param: &ty::ConstParameterDef<'tcx>,
+                                    expr: &hir::Expr)
+                                    -> ty::Const
+{
+    let tcx = this.tcx();
+    if param.ty.references_error() {
+        return const_err();
+    }
+
+    if let Some((param_const, def_id)) = const_param_in_expr(tcx, expr) {
+        let ty = tcx.lookup_item_type(def_id).ty;
+        if ty == param.ty {
+            return ty::Const::Param(param_const);
+        }
+        if !ty.references_error() {
+            span_err!(tcx.sess, expr.span, E0569,
+                      "mismatched const argument: expected a value of type `{}`, \
+                       found const parameter `{}` of type `{}`",
+                      param.ty, param_const, ty);
+        }
+        return const_err();
+    }
+
+    match eval_const_expr_partial(tcx, expr, UncheckedExprHint(param.ty), None) {
+        Ok(ConstVal::Integral(value)) if const_int_has_ty(value, param.ty) => {
+            ty::Const::Value(value)
+        }
+        Ok(val) => {
+            span_err!(tcx.sess, expr.span, E0569,
+                      "mismatched const argument: expected a value of type `{}`, found {}",
+                      param.ty, val.description());
+            const_err()
+        }
+        Err(ConstEvalErr { kind: ErroneousReferencedConstant(box r), ..}) |
+        Err(r) => {
+            span_err!(tcx.sess, r.span, E0569,
+                      "const argument evaluation error: {}",
+                      r.description());
+            const_err()
+        }
+    }
+}
+
 /// Returns the appropriate lifetime to use for any output lifetimes
 /// (if one exists) and a vector of the (pattern, number of lifetimes)
 /// corresponding to each input type/pattern.
@@ -617,20 +735,21 @@ fn find_implied_output_region<'tcx>(tcx: &TyCtxt<'tcx>,
 
 fn convert_ty_with_lifetime_elision<'tcx>(this: &AstConv<'tcx>,
                                           elided_lifetime: ElidedLifetime,
-                                          ty: &hir::Ty)
+                                          ty: &hir::Ty,
+                                          anon_scope: Option<&AnonTypeScope>)
                                           -> Ty<'tcx>
 {
     match elided_lifetime {
         Ok(implied_output_region) => {
             let rb = ElidableRscope::new(implied_output_region);
-            ast_ty_to_ty(this, &rb, ty)
+            ast_ty_to_ty(this, &MaybeWithAnonTypes::new(rb, anon_scope), ty)
         }
         Err(param_lifetimes) => {
             // All regions must be explicitly specified in the output
             // if the lifetime elision rules do not apply. This saves
             // the user from potentially-confusing errors.
             let rb = UnelidableRscope::new(param_lifetimes);
-            ast_ty_to_ty(this, &rb, ty)
+            ast_ty_to_ty(this, &MaybeWithAnonTypes::new(rb, anon_scope), ty)
         }
     }
 }
@@ -663,7 +782,8 @@ fn convert_parenthesized_parameters<'tcx>(this: &AstConv<'tcx>,
         Some(ref output_ty) => {
             (convert_ty_with_lifetime_elision(this,
                                               implied_output_region,
-                                              &output_ty),
+                                              &output_ty,
+                                              None),
              output_ty.span)
         }
         None => {
@@ -919,6 +1039,7 @@ fn ast_type_binding_to_poly_projection_predicate<'tcx>(
             projection_ty: ty::ProjectionTy {               //                     |
                 trait_ref: trait_ref.skip_binder().clone(), // Binder moved here --+
                 item_name: binding.item_name,
+                item_substs: tcx.mk_substs(Substs::empty()),
             },
             ty: binding.ty,
         }));
@@ -970,6 +1091,7 @@ fn ast_type_binding_to_poly_projection_predicate<'tcx>(
         projection_ty: ty::ProjectionTy {               //                           |
             trait_ref: candidate.skip_binder().clone(), // binder is moved up here --+
             item_name: binding.item_name,
+            item_substs: tcx.mk_substs(Substs::empty()),
         },
         ty: binding.ty,
     }))
@@ -1124,16 +1246,39 @@ fn make_object_type<'tcx>(this: &AstConv<'tcx>,
                           bounds: ty::ExistentialBounds<'tcx>)
                           -> Ty<'tcx> {
     let tcx = this.tcx();
-    let object = ty::TraitTy {
+
+    // ensure the super predicates and stop if we encountered an error
+    if this.ensure_super_predicates(span, principal.def_id()).is_err() {
+        return tcx.types.err;
+    }
+
+    let is_alias = match this.get_trait_def(span, principal.def_id()) {
+        Ok(trait_def) => trait_def.is_alias,
+        Err(ErrorReported) => return tcx.types.err,
+    };
+
+    let mut object = ty::TraitTy {
         principal: principal,
         bounds: bounds
     };
     let object_trait_ref =
         object.principal_trait_ref_with_self_ty(tcx, tcx.types.err);
 
-    // ensure the super predicates and stop if we encountered an error
-    if this.ensure_super_predicates(span, principal.def_id()).is_err() {
-        return tcx.types.err;
+    // A trait alias may fix the associated types of the traits it stands
+    // for, e.g. `trait IntIter = Iterator<Item=i32>;`. Carry those over
+    // into the object type as if they had been written out.
+    if is_alias {
+        let mut projection_bounds = object.bounds.projection_bounds.clone();
+        for predicate in traits::elaborate_trait_ref(tcx, object_trait_ref.clone()) {
+            if let ty::Predicate::Projection(projection) = predicate {
+                if !projection_bounds.contains(&projection) {
+                    projection_bounds.push(projection);
+                }
+            }
+        }
+        object.bounds = ty::ExistentialBounds::new(object.bounds.region_bound,
+                                                   object.bounds.builtin_bounds,
+                                                   projection_bounds);
     }
 
     // check that there are no gross object safety violations,
@@ -1269,6 +1414,7 @@ fn one_bound_for_assoc_type<'tcx>(tcx: &TyCtxt<'tcx>,
 // Will fail except for T::A and Self::A; i.e., if ty/ty_path_def are not a type
 // parameter or Self.
 fn associated_path_def_to_ty<'tcx>(this: &AstConv<'tcx>,
+                                   rscope: &RegionScope,
                                    span: Span,
                                    ty: Ty<'tcx>,
                                    ty_path_def: Def,
@@ -1280,8 +1426,6 @@ fn associated_path_def_to_ty<'tcx>(this: &AstConv<'tcx>,
 
     debug!("associated_path_def_to_ty: {:?}::{}", ty, assoc_name);
 
-    prohibit_type_params(tcx, slice::ref_slice(item_segment));
-
     // Find the type of the associated item, and the trait where the associated
     // item is declared.
     let bound = match (&ty.sty, ty_path_def) {
@@ -1347,7 +1491,8 @@ fn associated_path_def_to_ty<'tcx>(this: &AstConv<'tcx>,
     };
 
     let trait_did = bound.0.def_id;
-    let ty = this.projected_ty_from_poly_trait_ref(span, bound, assoc_name);
+    let item_substs = assoc_type_item_substs(this, rscope, span, trait_did, item_segment);
+    let ty = this.projected_ty_from_poly_trait_ref(span, bound, assoc_name, item_substs);
 
     let item_did = if let Some(trait_id) = tcx.map.as_local_node_id(trait_did) {
         // `ty::trait_items` used below requires information generated
@@ -1382,8 +1527,6 @@ fn qpath_to_ty<'tcx>(this: &AstConv<'tcx>,
 {
     let tcx = this.tcx();
 
-    prohibit_type_params(tcx, slice::ref_slice(item_segment));
-
     let self_ty = if let Some(ty) = opt_self_ty {
         ty
     } else {
@@ -1408,7 +1551,101 @@ fn qpath_to_ty<'tcx>(this: &AstConv<'tcx>,
 
     debug!("qpath_to_ty: trait_ref={:?}", trait_ref);
 
-    this.projected_ty(span, trait_ref, item_segment.identifier.name)
+    let item_substs = assoc_type_item_substs(this, rscope, span, trait_def_id, item_segment);
+    this.projected_ty(span, trait_ref, item_segment.identifier.name, item_substs)
+}
+
+/// Returns the number of lifetime and type parameters declared by the
+/// associated type `assoc_name` of the trait `trait_def_id`.
+fn assoc_type_param_counts(tcx: &TyCtxt, trait_def_id: DefId, assoc_name: ast::Name)
+                           -> (usize, usize)
+{
+    if let Some(trait_id) = tcx.map.as_local_node_id(trait_def_id) {
+        // `ty::trait_items` requires information generated by type
+        // collection, which may be in progress at this point.
+        if let hir::ItemTrait(_, _, _, ref trait_items) = tcx.map.expect_item(trait_id).node {
+            for trait_item in trait_items.iter().filter(|i| i.name == assoc_name) {
+                if let hir::TypeTraitItem(_, _, ref generics) = trait_item.node {
+                    return (generics.lifetimes.len(), generics.ty_params.len());
+                }
+            }
+        }
+    } else {
+        for trait_item in tcx.trait_items(trait_def_id).iter() {
+            if let ty::TypeTraitItem(ref assoc_ty) = *trait_item {
+                if assoc_ty.name == assoc_name {
+                    return (assoc_ty.generics.regions.len(FnSpace),
+                            assoc_ty.generics.types.len(FnSpace));
+                }
+            }
+        }
+    }
+    (0, 0)
+}
+
+/// Converts the arguments supplied to an associated type, as in
+/// `T::Item<'a>`, into substitutions for the associated type's own
+/// parameters, which live in the `FnSpace`.
+fn assoc_type_item_substs<'tcx>(this: &AstConv<'tcx>,
+                                rscope: &RegionScope,
+                                span: Span,
+                                trait_def_id: DefId,
+                                item_segment: &hir::PathSegment)
+                                -> &'tcx Substs<'tcx>
+{
+    let tcx = this.tcx();
+    let (expected_num_regions, expected_num_types) =
+        assoc_type_param_counts(tcx, trait_def_id, item_segment.identifier.name);
+
+    let data = match item_segment.parameters {
+        hir::AngleBracketedParameters(ref data) => data,
+        hir::ParenthesizedParameters(..) => {
+            span_err!(tcx.sess, span, E0214,
+                      "parenthesized parameters may only be used with a trait");
+            return tcx.mk_substs(Substs::empty());
+        }
+    };
+
+    if !data.consts.is_empty() {
+        span_err!(tcx.sess, span, E0568,
+                  "wrong number of const arguments: expected 0, found {}",
+                  data.consts.len());
+    }
+    for binding in data.bindings.iter().take(1) {
+        prohibit_projection(tcx, binding.span);
+    }
+
+    let supplied_num_regions = data.lifetimes.len();
+    let regions = if supplied_num_regions == expected_num_regions {
+        data.lifetimes.iter().map(|l| ast_region_to_region(tcx, l)).collect()
+    } else {
+        let anon_regions = rscope.anon_regions(span, expected_num_regions);
+
+        if supplied_num_regions != 0 || anon_regions.is_err() {
+            report_lifetime_number_error(tcx, span,
+                                         supplied_num_regions,
+                                         expected_num_regions);
+        }
+
+        match anon_regions {
+            Ok(anon_regions) => anon_regions,
+            Err(_) => (0..expected_num_regions).map(|_| ty::ReStatic).collect()
+        }
+    };
+
+    let supplied_num_types = data.types.len();
+    check_type_argument_count(tcx, span, supplied_num_types,
+                              expected_num_types, expected_num_types);
+    let mut types: Vec<_> =
+        data.types.iter()
+                  .take(expected_num_types)
+                  .map(|t| ast_ty_to_ty(this, rscope, t))
+                  .collect();
+    while types.len() < expected_num_types {
+        types.push(tcx.types.err);
+    }
+
+    tcx.mk_substs(Substs::empty().with_method(types, regions))
 }
 
 /// Convert a type supplied as value for a type argument from AST into our
@@ -1576,6 +1813,7 @@ pub fn finish_resolving_def_to_ty<'tcx>(this: &AstConv<'tcx>,
         }
         // This is pretty bad (it will fail except for T::A and Self::A).
         let (a_ty, a_def) = associated_path_def_to_ty(this,
+                                                      rscope,
                                                       span,
                                                       ty,
                                                       def,
@@ -1638,6 +1876,9 @@ pub fn ast_ty_to_ty<'tcx>(this: &AstConv<'tcx>,
             let t = ast_ty_to_ty(this, rscope1, &mt.ty);
             tcx.mk_ref(tcx.mk_region(r), ty::TypeAndMut {ty: t, mutbl: mt.mutbl})
         }
+        hir::TyNever => {
+            tcx.types.never
+        },
         hir::TyTup(ref fields) => {
             let flds = fields.iter()
                              .map(|t| ast_ty_to_ty(this, rscope, &t))
@@ -1646,11 +1887,27 @@ pub fn ast_ty_to_ty<'tcx>(this: &AstConv<'tcx>,
         }
         hir::TyBareFn(ref bf) => {
             require_c_abi_if_variadic(tcx, &bf.decl, bf.abi, ast_ty.span);
-            tcx.mk_fn_ptr(ty_of_bare_fn(this, bf.unsafety, bf.abi, &bf.decl))
+            tcx.mk_fn_ptr(ty_of_bare_fn(this, bf.unsafety, bf.abi, &bf.decl, None))
         }
         hir::TyPolyTraitRef(ref bounds) => {
             conv_ty_poly_trait_ref(this, rscope, ast_ty.span, bounds)
         }
+        hir::TyImplTrait(_) => {
+            // The bounds are recorded by collect, as the predicates
+            // of the anonymized type.
+            match rscope.anon_type_scope() {
+                Some(anon_scope) => {
+                    let def_id = tcx.map.local_def_id(ast_ty.id);
+                    tcx.mk_anon(def_id, anon_scope.fresh_substs(tcx))
+                }
+                None => {
+                    span_err!(tcx.sess, ast_ty.span, E0562,
+                              "`impl Trait` not allowed outside of function \
+                               and inherent method return types");
+                    tcx.types.err
+                }
+            }
+        }
         hir::TyPath(ref maybe_qself, ref path) => {
             let path_res = if let Some(&d) = tcx.def_map.borrow().get(&ast_ty.id) {
                 d
@@ -1688,31 +1945,47 @@ pub fn ast_ty_to_ty<'tcx>(this: &AstConv<'tcx>,
             ty
         }
         hir::TyFixedLengthVec(ref ty, ref e) => {
-            let hint = UncheckedExprHint(tcx.types.usize);
-            match eval_const_expr_partial(tcx, &e, hint, None) {
-                Ok(ConstVal::Integral(ConstInt::Usize(i))) => {
-                    let i = i.as_u64(tcx.sess.target.uint_type);
-                    assert_eq!(i as usize as u64, i);
-                    tcx.mk_array(ast_ty_to_ty(this, rscope, &ty), i as usize)
-                },
-                Ok(val) => {
-                    span_err!(tcx.sess, ast_ty.span, E0249,
-                              "expected usize value for array length, got {}", val.description());
-                    this.tcx().types.err
-                },
-                // array length errors happen before the global constant check
-                // so we need to report the real error
-                Err(ConstEvalErr { kind: ErroneousReferencedConstant(box r), ..}) |
-                Err(r) => {
-                    let mut err = struct_span_err!(tcx.sess, r.span, E0250,
-                                                   "array length constant evaluation error: {}",
-                                                   r.description());
-                    if !ast_ty.span.contains(r.span) {
-                        span_note!(&mut err, ast_ty.span, "for array length here")
+            if let Some((param_const, def_id)) = const_param_in_expr(tcx, &e) {
+                let len_ty = tcx.lookup_item_type(def_id).ty;
+                if len_ty == tcx.types.usize {
+                    let ty = ast_ty_to_ty(this, rscope, &ty);
+                    tcx.mk_array_const(ty, ty::Const::Param(param_const))
+                } else {
+                    if !len_ty.references_error() {
+                        span_err!(tcx.sess, ast_ty.span, E0249,
+                                  "expected usize value for array length, \
+                                   got const parameter of type `{}`", len_ty);
                     }
-                    err.emit();
                     this.tcx().types.err
                 }
+            } else {
+                let hint = UncheckedExprHint(tcx.types.usize);
+                match eval_const_expr_partial(tcx, &e, hint, None) {
+                    Ok(ConstVal::Integral(ConstInt::Usize(i))) => {
+                        let i = i.as_u64(tcx.sess.target.uint_type);
+                        assert_eq!(i as usize as u64, i);
+                        tcx.mk_array(ast_ty_to_ty(this, rscope, &ty), i as usize)
+                    },
+                    Ok(val) => {
+                        span_err!(tcx.sess, ast_ty.span, E0249,
+                                  "expected usize value for array length, got {}",
+                                  val.description());
+                        this.tcx().types.err
+                    },
+                    // array length errors happen before the global constant check
+                    // so we need to report the real error
+                    Err(ConstEvalErr { kind: ErroneousReferencedConstant(box r), ..}) |
+                    Err(r) => {
+                        let mut err = struct_span_err!(tcx.sess, r.span, E0250,
+                                                       "array length constant evaluation error: {}",
+                                                       r.description());
+                        if !ast_ty.span.contains(r.span) {
+                            span_note!(&mut err, ast_ty.span, "for array length here")
+                        }
+                        err.emit();
+                        this.tcx().types.err
+                    }
+                }
             }
         }
         hir::TyTypeof(ref _e) => {
@@ -1754,7 +2027,8 @@ struct SelfInfo<'a, 'tcx> {
 
 pub fn ty_of_method<'tcx>(this: &AstConv<'tcx>,
                           sig: &hir::MethodSig,
-                          untransformed_self_ty: Ty<'tcx>)
+                          untransformed_self_ty: Ty<'tcx>,
+                          anon_scope: Option<&AnonTypeScope>)
                           -> (ty::BareFnTy<'tcx>, ty::ExplicitSelfCategory) {
     let self_info = Some(SelfInfo {
         untransformed_self_ty: untransformed_self_ty,
@@ -1765,13 +2039,15 @@ pub fn ty_of_method<'tcx>(this: &AstConv<'tcx>,
                                 sig.unsafety,
                                 sig.abi,
                                 self_info,
-                                &sig.decl);
+                                &sig.decl,
+                                anon_scope);
     (bare_fn_ty, optional_explicit_self_category.unwrap())
 }
 
 pub fn ty_of_bare_fn<'tcx>(this: &AstConv<'tcx>, unsafety: hir::Unsafety, abi: abi::Abi,
-                                              decl: &hir::FnDecl) -> ty::BareFnTy<'tcx> {
-    let (bare_fn_ty, _) = ty_of_method_or_bare_fn(this, unsafety, abi, None, decl);
+                           decl: &hir::FnDecl, anon_scope: Option<&AnonTypeScope>)
+                           -> ty::BareFnTy<'tcx> {
+    let (bare_fn_ty, _) = ty_of_method_or_bare_fn(this, unsafety, abi, None, decl, anon_scope);
     bare_fn_ty
 }
 
@@ -1779,7 +2055,8 @@ fn ty_of_method_or_bare_fn<'a, 'tcx>(this: &AstConv<'tcx>,
                                      unsafety: hir::Unsafety,
                                      abi: abi::Abi,
                                      opt_self_info: Option<SelfInfo<'a, 'tcx>>,
-                                     decl: &hir::FnDecl)
+                                     decl: &hir::FnDecl,
+                                     anon_scope: Option<&AnonTypeScope>)
                                      -> (ty::BareFnTy<'tcx>, Option<ty::ExplicitSelfCategory>)
 {
     debug!("ty_of_method_or_bare_fn");
@@ -1821,7 +2098,8 @@ fn ty_of_method_or_bare_fn<'a, 'tcx>(this: &AstConv<'tcx>,
         hir::Return(ref output) =>
             ty::FnConverging(convert_ty_with_lifetime_elision(this,
                                                               implied_output_region,
-                                                              &output)),
+                                                              &output,
+                                                              anon_scope)),
         hir::DefaultReturn(..) => ty::FnConverging(this.tcx().mk_nil()),
         hir::NoReturn(..) => ty::FnDiverging
     };
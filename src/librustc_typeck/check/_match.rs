@@ -590,12 +590,12 @@ pub fn check_pat_struct<'a, 'tcx>(pcx: &pat_ctxt<'a, 'tcx>, pat: &'tcx hir::Pat,
     };
 
     let pat_ty = pcx.fcx.instantiate_type(def.def_id(), path);
-    let item_substs = match pat_ty.sty {
-        ty::TyStruct(_, substs) | ty::TyEnum(_, substs) => substs,
+    let (adt, item_substs) = match pat_ty.sty {
+        ty::TyStruct(adt, substs) | ty::TyEnum(adt, substs) => (adt, substs),
         _ => span_bug!(pat.span, "struct variant is not an ADT")
     };
     demand::eqtype(fcx, pat.span, expected, pat_ty);
-    check_struct_pat_fields(pcx, pat.span, fields, variant, &item_substs, etc);
+    check_struct_pat_fields(pcx, pat.span, fields, adt, variant, &item_substs, etc);
 
     fcx.write_ty(pat.id, pat_ty);
     fcx.write_substs(pat.id, ty::ItemSubsts { substs: item_substs.clone() });
@@ -763,11 +763,12 @@ fn check_pat_enum<'a, 'tcx>(pcx: &pat_ctxt<'a, 'tcx>,
 /// `path` is the AST path item naming the type of this struct.
 /// `fields` is the field patterns of the struct pattern.
 /// `struct_fields` describes the type of each field of the struct.
-/// `struct_id` is the ID of the struct.
+/// `adt` is the struct, enum or union the variant belongs to.
 /// `etc` is true if the pattern said '...' and false otherwise.
 pub fn check_struct_pat_fields<'a, 'tcx>(pcx: &pat_ctxt<'a, 'tcx>,
                                          span: Span,
                                          fields: &'tcx [Spanned<hir::FieldPat>],
+                                         adt: ty::AdtDef<'tcx>,
                                          variant: ty::VariantDef<'tcx>,
                                          substs: &Substs<'tcx>,
                                          etc: bool) {
@@ -812,8 +813,18 @@ pub fn check_struct_pat_fields<'a, 'tcx>(pcx: &pat_ctxt<'a, 'tcx>,
         check_pat(pcx, &field.pat, field_ty);
     }
 
-    // Report an error if not all the fields were specified.
-    if !etc {
+    // A union pattern reads exactly one of the overlapping fields;
+    // otherwise, report an error if not all the fields were specified.
+    if adt.is_union() {
+        if fields.len() != 1 {
+            span_err!(tcx.sess, span, E0565,
+                      "union patterns should have exactly one field");
+        }
+        if etc {
+            span_err!(tcx.sess, span, E0565,
+                      "`..` cannot be used in union patterns");
+        }
+    } else if !etc {
         for field in variant.fields
             .iter()
             .filter(|field| !used_fields.contains_key(&field.name)) {
@@ -68,6 +68,7 @@ use rustc::traits::{predicate_for_trait_def, report_selection_error};
 use rustc::ty::adjustment::{AutoAdjustment, AutoDerefRef, AdjustDerefRef};
 use rustc::ty::adjustment::{AutoPtr, AutoUnsafe, AdjustReifyFnPointer};
 use rustc::ty::adjustment::{AdjustUnsafeFnPointer, AdjustMutToConstPointer};
+use rustc::ty::adjustment::AdjustNeverToAny;
 use rustc::ty::{self, LvaluePreference, TypeAndMut, Ty, TyCtxt};
 use rustc::ty::fold::TypeFoldable;
 use rustc::ty::error::TypeError;
@@ -165,6 +166,11 @@ impl<'f, 'tcx> Coerce<'f, 'tcx> {
             return self.identity(b);
         }
 
+        // A value of type `!` never exists, so it coerces to anything.
+        if a.is_never() {
+            return Ok((b, AdjustNeverToAny(b)));
+        }
+
         // Consider coercing the subtype to a DST
         let unsize = self.coerce_unsized(a, b);
         if unsize.is_ok() {
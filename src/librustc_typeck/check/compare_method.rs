@@ -92,6 +92,21 @@ pub fn compare_impl_method<'tcx>(tcx: &TyCtxt<'tcx>,
         return;
     }
 
+    let impl_m_consts = impl_m.generics.consts.get_slice(subst::FnSpace);
+    let trait_m_consts = trait_m.generics.consts.get_slice(subst::FnSpace);
+    if impl_m_consts.len() != trait_m_consts.len() ||
+       impl_m_consts.iter().zip(trait_m_consts).any(|(i, t)| i.ty != t.ty) {
+        span_err!(tcx.sess, impl_m_span, E0571,
+            "method `{}` has const parameters `<{}>` \
+             but its trait declaration has const parameters `<{}>`",
+            trait_m.name,
+            impl_m_consts.iter().map(|c| format!("const {}: {}", c.name, c.ty))
+                                .collect::<Vec<_>>().join(", "),
+            trait_m_consts.iter().map(|c| format!("const {}: {}", c.name, c.ty))
+                                 .collect::<Vec<_>>().join(", "));
+        return;
+    }
+
     if impl_m.fty.sig.0.inputs.len() != trait_m.fty.sig.0.inputs.len() {
         span_err!(tcx.sess, impl_m_span, E0050,
             "method `{}` has {} parameter{} \
@@ -179,8 +194,7 @@ pub fn compare_impl_method<'tcx>(tcx: &TyCtxt<'tcx>,
     let trait_to_skol_substs =
         trait_to_impl_substs
         .subst(tcx, impl_to_skol_substs)
-        .with_method(impl_to_skol_substs.types.get_slice(subst::FnSpace).to_vec(),
-                     impl_to_skol_substs.regions.get_slice(subst::FnSpace).to_vec());
+        .with_method_from_subst(impl_to_skol_substs);
     debug!("compare_impl_method: trait_to_skol_substs={:?}",
            trait_to_skol_substs);
 
@@ -438,8 +452,7 @@ pub fn compare_const_impl<'tcx>(tcx: &TyCtxt<'tcx>,
     let trait_to_skol_substs =
         trait_to_impl_substs
         .subst(tcx, impl_to_skol_substs)
-        .with_method(impl_to_skol_substs.types.get_slice(subst::FnSpace).to_vec(),
-                     impl_to_skol_substs.regions.get_slice(subst::FnSpace).to_vec());
+        .with_method_from_subst(impl_to_skol_substs);
     debug!("compare_const_impl: trait_to_skol_substs={:?}",
            trait_to_skol_substs);
 
@@ -333,9 +333,15 @@ impl<'a,'tcx> ConfirmContext<'a,'tcx> {
                 pick.item.as_opt_method().unwrap()
                     .generics.regions.get_slice(subst::FnSpace));
 
-        let subst::Substs { types, regions } = substs;
+        // Const parameters on methods cannot be supplied explicitly
+        // and are always inferred.
+        let method_consts =
+            self.fcx.infcx().const_vars_for_defs(method.generics.consts.get_slice(subst::FnSpace));
+
+        let subst::Substs { types, regions, consts } = substs;
         let regions = regions.with_slice(subst::FnSpace, &method_regions);
-        let mut final_substs = subst::Substs { types: types, regions: regions };
+        let consts = consts.with_slice(subst::FnSpace, &method_consts);
+        let mut final_substs = subst::Substs { types: types, regions: regions, consts: consts };
 
         if num_supplied_types == 0 {
             self.fcx.infcx().type_vars_for_defs(
@@ -216,7 +216,7 @@ fn create_steps<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
     });
 
     match final_ty.sty {
-        ty::TyArray(elem_ty, _) => {
+        ty::TyArray(elem_ty, _) | ty::TyArrayParam(elem_ty, _) => {
             steps.push(CandidateStep {
                 self_ty: fcx.tcx().mk_slice(elem_ty),
                 autoderefs: dereferences,
@@ -775,16 +775,17 @@ impl<'a,'tcx> ProbeContext<'a,'tcx> {
             debug!("assemble_projection_candidates: step={:?}",
                    step);
 
-            let projection_trait_ref = match step.self_ty.sty {
-                ty::TyProjection(ref data) => &data.trait_ref,
+            let (def_id, substs) = match step.self_ty.sty {
+                ty::TyProjection(ref data) => self.tcx().projection_bounds_source(data),
+                ty::TyAnon(def_id, substs) => (def_id, substs),
                 _ => continue,
             };
 
-            debug!("assemble_projection_candidates: projection_trait_ref={:?}",
-                   projection_trait_ref);
+            debug!("assemble_projection_candidates: def_id={:?} substs={:?}",
+                   def_id, substs);
 
-            let trait_predicates = self.tcx().lookup_predicates(projection_trait_ref.def_id);
-            let bounds = trait_predicates.instantiate(self.tcx(), projection_trait_ref.substs);
+            let trait_predicates = self.tcx().lookup_predicates(def_id);
+            let bounds = trait_predicates.instantiate(self.tcx(), substs);
             let predicates = bounds.predicates.into_vec();
             debug!("assemble_projection_candidates: predicates={:?}",
                    predicates);
@@ -795,8 +796,7 @@ impl<'a,'tcx> ProbeContext<'a,'tcx> {
             {
                 let bound = self.erase_late_bound_regions(&poly_bound);
 
-                debug!("assemble_projection_candidates: projection_trait_ref={:?} bound={:?}",
-                       projection_trait_ref,
+                debug!("assemble_projection_candidates: bound={:?}",
                        bound);
 
                 if self.infcx().can_equate(&step.self_ty, &bound.self_ty()).is_ok() {
@@ -1222,7 +1222,8 @@ impl<'a,'tcx> ProbeContext<'a,'tcx> {
         let mut substs = substs;
         if
             !method.generics.types.is_empty_in(subst::FnSpace) ||
-            !method.generics.regions.is_empty_in(subst::FnSpace)
+            !method.generics.regions.is_empty_in(subst::FnSpace) ||
+            !method.generics.consts.is_empty_in(subst::FnSpace)
         {
             // In general, during probe we erase regions. See
             // `impl_self_ty()` for an explanation.
@@ -1240,6 +1241,10 @@ impl<'a,'tcx> ProbeContext<'a,'tcx> {
                 &mut placeholder,
                 method.generics.types.get_slice(subst::FnSpace));
 
+            let method_consts = self.infcx().const_vars_for_defs(
+                method.generics.consts.get_slice(subst::FnSpace));
+            placeholder.consts.replace(subst::FnSpace, method_consts);
+
             substs = &placeholder;
         }
 
@@ -1267,7 +1272,11 @@ impl<'a,'tcx> ProbeContext<'a,'tcx> {
             impl_pty.generics.regions.map(
                 |_| ty::ReStatic); // see erase_late_bound_regions() for an expl of why 'static
 
-        let substs = subst::Substs::new(type_vars, region_placeholders);
+        let const_vars =
+            impl_pty.generics.consts.map(
+                |_| self.infcx().next_const_var());
+
+        let substs = subst::Substs::new_with_consts(type_vars, region_placeholders, const_vars);
         (impl_pty.ty, substs)
     }
 
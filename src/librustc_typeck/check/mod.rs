@@ -99,7 +99,7 @@ use rustc::ty::{self, ToPolyTraitRef, Ty, TyCtxt, Visibility};
 use rustc::ty::{MethodCall, MethodCallee};
 use rustc::ty::adjustment;
 use rustc::ty::error::TypeError;
-use rustc::ty::fold::{TypeFolder, TypeFoldable};
+use rustc::ty::fold::{BottomUpFolder, TypeFolder, TypeFoldable};
 use rustc::ty::relate::TypeRelation;
 use rustc::ty::util::{Representability, IntTypeExt};
 use require_c_abi_if_variadic;
@@ -120,6 +120,7 @@ use syntax::attr;
 use syntax::attr::AttrMetaMethods;
 use syntax::codemap::{self, Span, Spanned};
 use syntax::errors::DiagnosticBuilder;
+use syntax::feature_gate::{GateIssue, emit_feature_err};
 use syntax::parse::token::{self, InternedString, keywords};
 use syntax::ptr::P;
 use syntax::util::lev_distance::find_best_match_for_name;
@@ -174,6 +175,12 @@ pub struct Inherited<'a, 'tcx: 'a> {
     deferred_call_resolutions: RefCell<DefIdMap<Vec<DeferredCallResolutionHandler<'tcx>>>>,
 
     deferred_cast_checks: RefCell<Vec<cast::CastCheck<'tcx>>>,
+
+    // Anonymized types found in explicit return types and their
+    // associated fresh inference variable. Writeback resolves these
+    // variables to get the concrete type, which can be used to
+    // deanonymize TyAnon, after typeck is done with all functions.
+    anon_types: RefCell<DefIdMap<Ty<'tcx>>>,
 }
 
 trait DeferredCallResolution<'tcx> {
@@ -294,11 +301,23 @@ pub struct FnCtxt<'a, 'tcx: 'a> {
 
     ps: RefCell<UnsafetyState>,
 
+    // The loops enclosing the expression being checked, innermost last.
+    loop_stack: RefCell<Vec<LoopCtxt<'tcx>>>,
+
     inh: &'a Inherited<'a, 'tcx>,
 
     ccx: &'a CrateCtxt<'a, 'tcx>,
 }
 
+/// A loop that a `break` within it may refer to.
+#[derive(Copy, Clone)]
+struct LoopCtxt<'tcx> {
+    id: ast::NodeId,
+    /// The type that values given to `break` are coerced to. Only
+    /// `loop` has one; other loops always have type `()`.
+    break_ty: Option<Ty<'tcx>>,
+}
+
 impl<'a, 'tcx> Inherited<'a, 'tcx> {
     fn new(tcx: &'a TyCtxt<'tcx>,
            tables: &'a RefCell<ty::Tables<'tcx>>,
@@ -312,6 +331,7 @@ impl<'a, 'tcx> Inherited<'a, 'tcx> {
             tables: tables,
             deferred_call_resolutions: RefCell::new(DefIdMap()),
             deferred_cast_checks: RefCell::new(Vec::new()),
+            anon_types: RefCell::new(DefIdMap()),
         }
     }
 
@@ -329,6 +349,46 @@ impl<'a, 'tcx> Inherited<'a, 'tcx> {
                                              value)
     }
 
+    /// Replace all anonymized types with fresh inference variables
+    /// and register the bounds of each one as obligations on its
+    /// variable, so the concrete type can be inferred from the body.
+    fn instantiate_anon_types<T>(&self,
+                                 span: Span,
+                                 body_id: ast::NodeId,
+                                 value: &T)
+                                 -> T
+        where T : TypeFoldable<'tcx>
+    {
+        let tcx = self.infcx.tcx;
+        value.fold_with(&mut BottomUpFolder { tcx: tcx, fldop: |ty| {
+            let (def_id, substs) = match ty.sty {
+                ty::TyAnon(def_id, substs) => (def_id, substs),
+                _ => return ty
+            };
+
+            // Use the same type variable if the same anonymized
+            // type appears more than once.
+            if let Some(&ty_var) = self.anon_types.borrow().get(&def_id) {
+                return ty_var;
+            }
+
+            let ty_var = self.infcx.next_ty_var();
+            self.anon_types.borrow_mut().insert(def_id, ty_var);
+
+            let bounds = tcx.lookup_predicates(def_id).instantiate(tcx, substs);
+            for predicate in bounds.predicates.into_vec() {
+                // Change the predicate to refer to the type variable,
+                // which will be the concrete type, instead of the TyAnon.
+                let predicate = self.instantiate_anon_types(span, body_id, &predicate);
+                let cause = traits::ObligationCause::new(span, body_id, traits::ReturnType);
+                let obligation = traits::Obligation::new(cause, predicate);
+                self.fulfillment_cx.borrow_mut()
+                    .register_predicate_obligation(&self.infcx, obligation);
+            }
+
+            ty_var
+        }})
+    }
 }
 
 // Used by check_const and check_enum_variants
@@ -343,6 +403,7 @@ pub fn blank_fn_ctxt<'a, 'tcx>(ccx: &'a CrateCtxt<'a, 'tcx>,
         err_count_on_creation: ccx.tcx.sess.err_count(),
         ret_ty: rty,
         ps: RefCell::new(UnsafetyState::function(hir::Unsafety::Normal, 0)),
+        loop_stack: RefCell::new(Vec::new()),
         inh: inh,
         ccx: ccx
     }
@@ -405,6 +466,32 @@ pub fn check_item_bodies(ccx: &CrateCtxt) -> CompileResult {
     })
 }
 
+/// Checks the obligations which had to wait for the concrete type of
+/// an anonymized type, now that all function bodies have been inferred.
+pub fn check_deferred_obligations(ccx: &CrateCtxt) -> CompileResult {
+    ccx.tcx.sess.track_errors(|| {
+        let mut deferred_obligations = ccx.deferred_obligations.borrow_mut();
+        for (&item_id, obligations) in deferred_obligations.iter_mut() {
+            let item_def_id = ccx.tcx.map.local_def_id(item_id);
+            let _task = ccx.tcx.dep_graph.in_task(DepNode::TypeckItemBody(item_def_id));
+            let param_env = ParameterEnvironment::for_item(ccx.tcx, item_id);
+            let infcx = infer::new_infer_ctxt(ccx.tcx,
+                                              &ccx.tcx.tables,
+                                              Some(param_env),
+                                              ProjectionMode::AnyFinal);
+            let mut fulfillment_cx = traits::FulfillmentContext::new();
+            for obligation in obligations.drain(..) {
+                fulfillment_cx.register_predicate_obligation(&infcx,
+                                                             obligation.to_obligation());
+            }
+
+            if let Err(errors) = fulfillment_cx.select_all_or_error(&infcx) {
+                report_fulfillment_errors(&infcx, &errors);
+            }
+        }
+    })
+}
+
 pub fn check_drop_impls(ccx: &CrateCtxt) -> CompileResult {
     ccx.tcx.sess.track_errors(|| {
         let _task = ccx.tcx.dep_graph.in_task(DepNode::Dropck);
@@ -449,6 +536,11 @@ fn check_bare_fn<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
                                                   body.id,
                                                   &fn_sig);
 
+            // The `impl Trait` types in the signature belong to this
+            // function, so their concrete types are inferred from its body.
+            let fn_sig =
+                inh.instantiate_anon_types(decl.output.span(), body.id, &fn_sig);
+
             let fcx = check_fn(ccx, fn_ty.unsafety, fn_id, &fn_sig,
                                decl, fn_id, body, &inh);
 
@@ -458,6 +550,11 @@ fn check_bare_fn<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
             fcx.check_casts();
             fcx.select_all_obligations_or_error(); // Casts can introduce new obligations.
 
+            let deferred = fcx.inh.fulfillment_cx.borrow_mut().take_deferred_obligations();
+            if !deferred.is_empty() {
+                ccx.deferred_obligations.borrow_mut().insert(fn_id, deferred);
+            }
+
             regionck::regionck_fn(&fcx, fn_id, fn_span, decl, body);
             writeback::resolve_type_vars_in_fn(&fcx, decl, body);
         }
@@ -584,6 +681,7 @@ fn check_fn<'a, 'tcx>(ccx: &'a CrateCtxt<'a, 'tcx>,
         err_count_on_creation: err_count_on_creation,
         ret_ty: ret_ty,
         ps: RefCell::new(UnsafetyState::function(unsafety, unsafety_id)),
+        loop_stack: RefCell::new(Vec::new()),
         inh: inherited,
         ccx: ccx
     };
@@ -652,6 +750,10 @@ pub fn check_struct(ccx: &CrateCtxt, id: ast::NodeId, span: Span) {
     }
 }
 
+fn check_union(ccx: &CrateCtxt, id: ast::NodeId, span: Span) {
+    check_representable(ccx.tcx, span, id, "union");
+}
+
 pub fn check_item_type<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>, it: &'tcx hir::Item) {
     debug!("check_item_type(it.id={}, it.name={})",
            it.id,
@@ -668,7 +770,7 @@ pub fn check_item_type<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>, it: &'tcx hir::Item) {
                             it.id);
       }
       hir::ItemFn(..) => {} // entirely within check_item_body
-      hir::ItemImpl(_, _, _, _, _, ref impl_items) => {
+      hir::ItemImpl(_, _, _, _, _, _, ref impl_items) => {
           debug!("ItemImpl {} with id {}", it.name, it.id);
           let impl_def_id = ccx.tcx.map.local_def_id(it.id);
           match ccx.tcx.impl_trait_ref(impl_def_id) {
@@ -688,6 +790,9 @@ pub fn check_item_type<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>, it: &'tcx hir::Item) {
       hir::ItemStruct(..) => {
         check_struct(ccx, it.id, it.span);
       }
+      hir::ItemUnion(..) => {
+        check_union(ccx, it.id, it.span);
+      }
       hir::ItemTy(_, ref generics) => {
         let pty_ty = ccx.tcx.node_id_to_type(it.id);
         check_bounds_are_used(ccx, &generics.ty_params, pty_ty);
@@ -734,7 +839,7 @@ pub fn check_item_body<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>, it: &'tcx hir::Item) {
         let param_env = ParameterEnvironment::for_item(ccx.tcx, it.id);
         check_bare_fn(ccx, &decl, &body, it.id, it.span, fn_pty.ty, param_env);
       }
-      hir::ItemImpl(_, _, _, _, _, ref impl_items) => {
+      hir::ItemImpl(_, _, _, _, _, _, ref impl_items) => {
         debug!("ItemImpl {} with id {}", it.name, it.id);
 
         let impl_pty = ccx.tcx.lookup_item_type(ccx.tcx.map.local_def_id(it.id));
@@ -748,7 +853,7 @@ pub fn check_item_body<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>, it: &'tcx hir::Item) {
                     check_method_body(ccx, &impl_pty.generics, sig, body,
                                       impl_item.id, impl_item.span);
                 }
-                hir::ImplItemKind::Type(_) => {
+                hir::ImplItemKind::Type(..) => {
                     // Nothing to do here.
                 }
             }
@@ -900,7 +1005,7 @@ fn check_specialization_validity<'tcx>(tcx: &TyCtxt<'tcx>, trait_def: &ty::Trait
                 .map(|node_item| node_item.map(|parent| parent.defaultness))
 
         }
-        hir::ImplItemKind::Type(_) => {
+        hir::ImplItemKind::Type(..) => {
             ancestors.type_defs(tcx, impl_item.name).skip(1).next()
                 .map(|node_item| node_item.map(|parent| parent.defaultness))
         }
@@ -984,7 +1089,7 @@ fn check_impl_items_against_trait<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
                                   impl_trait_ref)
                     }
                 }
-                hir::ImplItemKind::Type(_) => {
+                hir::ImplItemKind::Type(..) => {
                     let impl_type = match ty_impl_item {
                         ty::TypeTraitItem(ref tti) => tti,
                         _ => span_bug!(impl_item.span, "non-type impl-item for type")
@@ -994,6 +1099,17 @@ fn check_impl_items_against_trait<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
                         if let Some(_) = at.ty {
                             overridden_associated_type = Some(impl_item);
                         }
+
+                        let impl_generics = &impl_type.generics;
+                        if impl_generics.regions.len(subst::FnSpace) !=
+                               at.generics.regions.len(subst::FnSpace) ||
+                           impl_generics.types.len(subst::FnSpace) !=
+                               at.generics.types.len(subst::FnSpace) {
+                            span_err!(tcx.sess, impl_item.span, E0573,
+                                      "generic parameters of associated type `{}` \
+                                       do not match the trait declaration",
+                                      impl_type.name);
+                        }
                     } else {
                         span_err!(tcx.sess, impl_item.span, E0325,
                                   "item `{}` is an associated type, \
@@ -1008,6 +1124,12 @@ fn check_impl_items_against_trait<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         check_specialization_validity(tcx, trait_def, impl_id, impl_item);
     }
 
+    // A `default impl` need not be complete: it only supplies items for the
+    // impls that specialize it, and never satisfies an obligation itself.
+    if tcx.impl_is_default(impl_id) {
+        return;
+    }
+
     // Check for missing items from trait
     let provided_methods = tcx.provided_trait_methods(impl_trait_ref.def_id);
     let mut missing_items = Vec::new();
@@ -1159,10 +1281,15 @@ impl<'a, 'tcx> AstConv<'tcx> for FnCtxt<'a, 'tcx> {
         }
     }
 
+    fn const_infer(&self, _span: Span) -> ty::Const {
+        self.infcx().next_const_var()
+    }
+
     fn projected_ty_from_poly_trait_ref(&self,
                                         span: Span,
                                         poly_trait_ref: ty::PolyTraitRef<'tcx>,
-                                        item_name: ast::Name)
+                                        item_name: ast::Name,
+                                        item_substs: &'tcx Substs<'tcx>)
                                         -> Ty<'tcx>
     {
         let (trait_ref, _) =
@@ -1171,16 +1298,17 @@ impl<'a, 'tcx> AstConv<'tcx> for FnCtxt<'a, 'tcx> {
                 infer::LateBoundRegionConversionTime::AssocTypeProjection(item_name),
                 &poly_trait_ref);
 
-        self.normalize_associated_type(span, trait_ref, item_name)
+        self.normalize_associated_type(span, trait_ref, item_name, item_substs)
     }
 
     fn projected_ty(&self,
                     span: Span,
                     trait_ref: ty::TraitRef<'tcx>,
-                    item_name: ast::Name)
+                    item_name: ast::Name,
+                    item_substs: &'tcx Substs<'tcx>)
                     -> Ty<'tcx>
     {
-        self.normalize_associated_type(span, trait_ref, item_name)
+        self.normalize_associated_type(span, trait_ref, item_name, item_substs)
     }
 
     fn set_tainted_by_errors(&self) {
@@ -1354,7 +1482,8 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
     fn normalize_associated_type(&self,
                                  span: Span,
                                  trait_ref: ty::TraitRef<'tcx>,
-                                 item_name: ast::Name)
+                                 item_name: ast::Name,
+                                 item_substs: &'tcx Substs<'tcx>)
                                  -> Ty<'tcx>
     {
         let cause = traits::ObligationCause::new(span,
@@ -1367,6 +1496,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
                                        ty::ProjectionTy {
                                            trait_ref: trait_ref,
                                            item_name: item_name,
+                                           item_substs: item_substs,
                                        },
                                        cause)
     }
@@ -1436,6 +1566,17 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
          }
     }
 
+    /// Runs `f` with the loop `id` pushed onto the stack of loops that
+    /// `break` expressions may refer to.
+    fn with_loop<F, R>(&self, id: ast::NodeId, break_ty: Option<Ty<'tcx>>, f: F) -> R
+        where F: FnOnce() -> R
+    {
+        self.loop_stack.borrow_mut().push(LoopCtxt { id: id, break_ty: break_ty });
+        let result = f();
+        self.loop_stack.borrow_mut().pop();
+        result
+    }
+
     pub fn write_nil(&self, node_id: ast::NodeId) {
         self.write_ty(node_id, self.tcx().mk_nil());
     }
@@ -1701,8 +1842,18 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         }
     }
 
+    /// The type unconstrained diverging type variables fall back to.
+    fn diverging_default(&self) -> Ty<'tcx> {
+        if self.tcx().sess.features.borrow().never_type {
+            self.tcx().types.never
+        } else {
+            self.tcx().mk_nil()
+        }
+    }
+
     /// Apply "fallbacks" to some types
-    /// ! gets replaced with (), unconstrained ints with i32, and unconstrained floats with f64.
+    /// Diverging type variables get replaced with `!` under `#![feature(never_type)]` and with
+    /// `()` otherwise, unconstrained ints with i32, and unconstrained floats with f64.
     fn default_type_parameters(&self) {
         use rustc::ty::error::UnconstrainedNumeric::Neither;
         use rustc::ty::error::UnconstrainedNumeric::{UnconstrainedInt, UnconstrainedFloat};
@@ -1724,9 +1875,9 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         for ty in &self.infcx().unsolved_variables() {
             let resolved = self.infcx().resolve_type_vars_if_possible(ty);
             if self.infcx().type_var_diverges(resolved) {
-                debug!("default_type_parameters: defaulting `{:?}` to `()` because it diverges",
+                debug!("default_type_parameters: defaulting diverging `{:?}`",
                        resolved);
-                demand::eqtype(self, codemap::DUMMY_SP, *ty, self.tcx().mk_nil());
+                demand::eqtype(self, codemap::DUMMY_SP, *ty, self.diverging_default());
             } else {
                 match self.infcx().type_is_unconstrained_numeric(resolved) {
                     UnconstrainedInt => {
@@ -1800,7 +1951,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
             for ty in &unsolved_variables {
                 let resolved = self.infcx().resolve_type_vars_if_possible(ty);
                 if self.infcx().type_var_diverges(resolved) {
-                    demand::eqtype(self, codemap::DUMMY_SP, *ty, self.tcx().mk_nil());
+                    demand::eqtype(self, codemap::DUMMY_SP, *ty, self.diverging_default());
                 } else {
                     match self.infcx().type_is_unconstrained_numeric(resolved) {
                         UnconstrainedInt | UnconstrainedFloat => {
@@ -1857,7 +2008,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
             let _ = self.infcx().commit_if_ok(|_: &infer::CombinedSnapshot| {
                 for ty in &unbound_tyvars {
                     if self.infcx().type_var_diverges(ty) {
-                        demand::eqtype(self, codemap::DUMMY_SP, *ty, self.tcx().mk_nil());
+                        demand::eqtype(self, codemap::DUMMY_SP, *ty, self.diverging_default());
                     } else {
                         match self.infcx().type_is_unconstrained_numeric(ty) {
                             UnconstrainedInt => {
@@ -1953,7 +2104,7 @@ impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
         // reporting for more then one conflict.
         for ty in &unbound_tyvars {
             if self.infcx().type_var_diverges(ty) {
-                demand::eqtype(self, codemap::DUMMY_SP, *ty, self.tcx().mk_nil());
+                demand::eqtype(self, codemap::DUMMY_SP, *ty, self.diverging_default());
             } else {
                 match self.infcx().type_is_unconstrained_numeric(ty) {
                     UnconstrainedInt => {
@@ -2205,7 +2356,7 @@ fn lookup_indexing<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
 
     // After we have fully autoderef'd, if the resulting type is [T; n], then
     // do a final unsized coercion to yield [T].
-    if let ty::TyArray(element_ty, _) = ty.sty {
+    if let Some((element_ty, _)) = ty.array_const(fcx.tcx()) {
         let adjusted_ty = fcx.tcx().mk_slice(element_ty);
         try_index_step(fcx, MethodCall::expr(expr.id), expr, base_expr,
                        adjusted_ty, autoderefs, true, lvalue_pref, idx_ty)
@@ -2687,9 +2838,12 @@ pub fn impl_self_ty<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
     debug!("impl_self_ty: tps={:?} rps={:?} raw_ty={:?}", tps, rps, raw_ty);
 
     let rps = fcx.inh.infcx.region_vars_for_defs(span, rps);
-    let mut substs = subst::Substs::new(
+    let cps = fcx.inh.infcx.const_vars_for_defs(
+        ity.generics.consts.get_slice(subst::TypeSpace));
+    let mut substs = subst::Substs::new_with_consts(
         VecPerParamSpace::empty(),
-        VecPerParamSpace::new(rps, Vec::new(), Vec::new()));
+        VecPerParamSpace::new(rps, Vec::new(), Vec::new()),
+        VecPerParamSpace::new(cps, Vec::new(), Vec::new()));
     fcx.inh.infcx.type_vars_for_defs(span, ParamSpace::TypeSpace, &mut substs, tps);
     let substd_ty = fcx.instantiate_type_scheme(span, &substs, &raw_ty);
 
@@ -3087,12 +3241,19 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
                                       skip_fields: &[hir::Field]) {
         let mut err = fcx.type_error_struct(
             field.name.span,
-            |actual| if let ty::TyEnum(..) = ty.sty {
-                format!("struct variant `{}::{}` has no field named `{}`",
-                        actual, variant.name.as_str(), field.name.node)
-            } else {
-                format!("structure `{}` has no field named `{}`",
-                        actual, field.name.node)
+            |actual| match ty.sty {
+                ty::TyEnum(..) => {
+                    format!("struct variant `{}::{}` has no field named `{}`",
+                            actual, variant.name.as_str(), field.name.node)
+                }
+                ty::TyStruct(def, _) if def.is_union() => {
+                    format!("union `{}` has no field named `{}`",
+                            actual, field.name.node)
+                }
+                _ => {
+                    format!("structure `{}` has no field named `{}`",
+                            actual, field.name.node)
+                }
             },
             ty,
             None);
@@ -3104,13 +3265,15 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
 
     fn check_expr_struct_fields<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
                                           adt_ty: Ty<'tcx>,
+                                          expr_id: ast::NodeId,
                                           span: Span,
                                           variant: ty::VariantDef<'tcx>,
                                           ast_fields: &'tcx [hir::Field],
-                                          check_completeness: bool) {
+                                          base_expr: &'tcx Option<P<hir::Expr>>) {
         let tcx = fcx.ccx.tcx;
-        let substs = match adt_ty.sty {
-            ty::TyStruct(_, substs) | ty::TyEnum(_, substs) => substs,
+        let (substs, is_union, enum_variants) = match adt_ty.sty {
+            ty::TyStruct(def, substs) => (substs, def.is_union(), None),
+            ty::TyEnum(def, substs) => (substs, false, Some(def.variants.len())),
             _ => span_bug!(span, "non-ADT passed to check_expr_struct_fields")
         };
 
@@ -3144,8 +3307,15 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
             check_expr_coercable_to_type(fcx, &field.expr, expected_field_type);
         }
 
-            // Make sure the programmer specified all the fields.
-        if check_completeness &&
+        // A union is initialized through exactly one of its fields.
+        if is_union && ast_fields.len() != 1 {
+            span_err!(tcx.sess, span, E0564,
+                      "union expressions should have exactly one field");
+        }
+
+        // Make sure the programmer specified all the fields.
+        if base_expr.is_none() &&
+            !is_union &&
             !error_happened &&
             !remaining_fields.is_empty()
         {
@@ -3159,6 +3329,37 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
                       adt_ty);
         }
 
+        // The fields not given explicitly are moved out of the base. For an
+        // enum, the base can only be known to hold the same variant if the
+        // enum has no other.
+        if let Some(ref base_expr) = *base_expr {
+            check_expr_has_type(fcx, base_expr, adt_ty);
+            if is_union {
+                span_err!(tcx.sess, base_expr.span, E0436,
+                          "functional record update syntax requires a struct");
+                return;
+            }
+            if let Some(n_variants) = enum_variants {
+                if !tcx.sess.features.borrow().struct_variant_update {
+                    emit_feature_err(&tcx.sess.parse_sess.span_diagnostic,
+                                     "struct_variant_update", base_expr.span,
+                                     GateIssue::Language,
+                                     "functional record update of an enum variant is unstable");
+                }
+                if n_variants != 1 {
+                    span_err!(tcx.sess, base_expr.span, E0576,
+                              "functional record update of an enum variant requires \
+                               an enum with a single variant");
+                    return;
+                }
+            }
+            fcx.inh.tables.borrow_mut().fru_field_types.insert(
+                expr_id,
+                variant.fields.iter().map(|f| {
+                    fcx.normalize_associated_types_in(span, &f.ty(tcx, substs))
+                }).collect()
+            );
+        }
     }
 
     fn check_struct_fields_on_error<'a,'tcx>(fcx: &FnCtxt<'a,'tcx>,
@@ -3206,27 +3407,7 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
         let expr_ty = fcx.instantiate_type(def.def_id(), path);
         fcx.write_ty(expr.id, expr_ty);
 
-        check_expr_struct_fields(fcx, expr_ty, expr.span, variant, fields,
-                                 base_expr.is_none());
-        if let &Some(ref base_expr) = base_expr {
-            check_expr_has_type(fcx, base_expr, expr_ty);
-            match expr_ty.sty {
-                ty::TyStruct(adt, substs) => {
-                    fcx.inh.tables.borrow_mut().fru_field_types.insert(
-                        expr.id,
-                        adt.struct_variant().fields.iter().map(|f| {
-                            fcx.normalize_associated_types_in(
-                                expr.span, &f.ty(tcx, substs)
-                            )
-                        }).collect()
-                    );
-                }
-                _ => {
-                    span_err!(tcx.sess, base_expr.span, E0436,
-                              "functional record update syntax requires a struct");
-                }
-            }
-        }
+        check_expr_struct_fields(fcx, expr_ty, expr.id, expr.span, variant, fields, base_expr);
     }
 
     type ExprCheckerWithTy = fn(&FnCtxt, &hir::Expr, Ty);
@@ -3412,7 +3593,33 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
           }
           fcx.write_nil(id);
       }
-      hir::ExprBreak(_) => { fcx.write_ty(id, fcx.infcx().next_diverging_ty_var()); }
+      hir::ExprBreak(ref label, ref expr_opt) => {
+        let target = if label.is_some() {
+            match lookup_full_def(tcx, expr.span, id) {
+                Def::Label(loop_id) => {
+                    fcx.loop_stack.borrow().iter().rev().find(|l| l.id == loop_id).cloned()
+                }
+                _ => None
+            }
+        } else {
+            fcx.loop_stack.borrow().last().cloned()
+        };
+        match (target.and_then(|l| l.break_ty), expr_opt) {
+            (Some(break_ty), &Some(ref e)) => {
+                check_expr_coercable_to_type(fcx, &e, break_ty);
+            }
+            (Some(break_ty), &None) => {
+                demand::suptype(fcx, expr.span, break_ty, tcx.mk_nil());
+            }
+            // A value given to `break` out of any other kind of loop has
+            // already been reported by the loop checking pass.
+            (None, &Some(ref e)) => {
+                check_expr(fcx, &e);
+            }
+            (None, &None) => {}
+        }
+        fcx.write_ty(id, fcx.infcx().next_diverging_ty_var());
+      }
       hir::ExprAgain(_) => { fcx.write_ty(id, fcx.infcx().next_diverging_ty_var()); }
       hir::ExprRet(ref expr_opt) => {
         match fcx.ret_ty {
@@ -3467,7 +3674,7 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
       }
       hir::ExprWhile(ref cond, ref body, _) => {
         check_expr_has_type(fcx, &cond, tcx.types.bool);
-        check_block_no_value(fcx, &body);
+        fcx.with_loop(id, None, || check_block_no_value(fcx, &body));
         let cond_ty = fcx.expr_ty(&cond);
         let body_ty = fcx.node_ty(body.id);
         if cond_ty.references_error() || body_ty.references_error() {
@@ -3477,10 +3684,16 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
             fcx.write_nil(id);
         }
       }
-      hir::ExprLoop(ref body, _) => {
-        check_block_no_value(fcx, &body);
+      hir::ExprLoop(ref body, _, source) => {
+        let break_ty = match source {
+            hir::LoopSource::Loop => Some(fcx.infcx().next_ty_var()),
+            hir::LoopSource::WhileLet | hir::LoopSource::ForLoop => None
+        };
+        fcx.with_loop(id, break_ty, || check_block_no_value(fcx, &body));
         if !may_break(tcx, expr.id, &body) {
             fcx.write_ty(id, fcx.infcx().next_diverging_ty_var());
+        } else if let Some(break_ty) = break_ty {
+            fcx.write_ty(id, break_ty);
         } else {
             fcx.write_nil(id);
         }
@@ -3551,7 +3764,7 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
       hir::ExprVec(ref args) => {
         let uty = expected.to_option(fcx).and_then(|uty| {
             match uty.sty {
-                ty::TyArray(ty, _) | ty::TySlice(ty) => Some(ty),
+                ty::TyArray(ty, _) | ty::TyArrayParam(ty, _) | ty::TySlice(ty) => Some(ty),
                 _ => None
             }
         });
@@ -3583,12 +3796,20 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
       }
       hir::ExprRepeat(ref element, ref count_expr) => {
         check_expr_has_type(fcx, &count_expr, tcx.types.usize);
-        let count = eval_repeat_count(fcx.tcx(), &count_expr);
+
+        // A const parameter used as the count gives an array whose
+        // length is only known once the parameter is substituted.
+        let count_param = astconv::const_param_in_expr(tcx, &count_expr);
+        let count = if count_param.is_some() {
+            0
+        } else {
+            eval_repeat_count(fcx.tcx(), &count_expr)
+        };
 
         let uty = match expected {
             ExpectHasType(uty) => {
                 match uty.sty {
-                    ty::TyArray(ty, _) | ty::TySlice(ty) => Some(ty),
+                    ty::TyArray(ty, _) | ty::TyArrayParam(ty, _) | ty::TySlice(ty) => Some(ty),
                     _ => None
                 }
             }
@@ -3607,9 +3828,9 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
             }
         };
 
-        if count > 1 {
-            // For [foo, ..n] where n > 1, `foo` must have
-            // Copy type:
+        if count > 1 || count_param.is_some() {
+            // For [foo, ..n] where n > 1 (or n is a const parameter),
+            // `foo` must have Copy type:
             fcx.require_type_meets(
                 t,
                 expr.span,
@@ -3620,7 +3841,10 @@ fn check_expr_with_expectation_and_lvalue_pref<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
         if element_ty.references_error() {
             fcx.write_error(id);
         } else {
-            let t = tcx.mk_array(t, count);
+            let t = match count_param {
+                Some((param_const, _)) => tcx.mk_array_const(t, ty::Const::Param(param_const)),
+                None => tcx.mk_array(t, count),
+            };
             fcx.write_ty(id, t);
         }
       }
@@ -4190,6 +4414,9 @@ fn type_scheme_and_predicates_for_def<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
         Def::Struct(id) | Def::Const(id) | Def::AssociatedConst(id) => {
             (fcx.tcx().lookup_item_type(id), fcx.tcx().lookup_predicates(id))
         }
+        Def::ConstParam(_, _, id, _) => {
+            (fcx.tcx().lookup_item_type(id), ty::GenericPredicates::empty())
+        }
         Def::Trait(_) |
         Def::Enum(..) |
         Def::TyAlias(..) |
@@ -4369,6 +4596,7 @@ pub fn instantiate_path<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
         Def::Mod(..) |
         Def::ForeignMod(..) |
         Def::Local(..) |
+        Def::ConstParam(..) |
         Def::Label(..) |
         Def::Upvar(..) => {
             segment_spaces = vec![None; segments.len()];
@@ -4392,6 +4620,7 @@ pub fn instantiate_path<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
     // parameters we expect from each space.
     let type_defs = &type_scheme.generics.types;
     let region_defs = &type_scheme.generics.regions;
+    let const_defs = &type_scheme.generics.consts;
 
     // Now that we have categorized what space the parameters for each
     // segment belong to, let's sort out the parameters that the user
@@ -4410,6 +4639,7 @@ pub fn instantiate_path<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
                                                                 span,
                                                                 type_defs,
                                                                 region_defs,
+                                                                const_defs,
                                                                 segment,
                                                                 &mut substs);
             }
@@ -4434,6 +4664,9 @@ pub fn instantiate_path<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
 
         adjust_region_parameters(fcx, span, space, region_defs, &mut substs);
         assert_eq!(substs.regions.len(space), region_defs.len(space));
+
+        adjust_const_parameters(fcx, span, space, const_defs, &mut substs);
+        assert_eq!(substs.consts.len(space), const_defs.len(space));
     }
 
     // The things we are substituting into the type should not contain
@@ -4495,13 +4728,14 @@ pub fn instantiate_path<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
         span: Span,
         type_defs: &VecPerParamSpace<ty::TypeParameterDef<'tcx>>,
         region_defs: &VecPerParamSpace<ty::RegionParameterDef>,
+        const_defs: &VecPerParamSpace<ty::ConstParameterDef<'tcx>>,
         segment: &hir::PathSegment,
         substs: &mut Substs<'tcx>)
     {
         match segment.parameters {
             hir::AngleBracketedParameters(ref data) => {
                 push_explicit_angle_bracketed_parameters_from_segment_to_substs(
-                    fcx, space, type_defs, region_defs, data, substs);
+                    fcx, space, type_defs, region_defs, const_defs, data, substs);
             }
 
             hir::ParenthesizedParameters(ref data) => {
@@ -4518,6 +4752,7 @@ pub fn instantiate_path<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
         space: subst::ParamSpace,
         type_defs: &VecPerParamSpace<ty::TypeParameterDef<'tcx>>,
         region_defs: &VecPerParamSpace<ty::RegionParameterDef>,
+        const_defs: &VecPerParamSpace<ty::ConstParameterDef<'tcx>>,
         data: &hir::AngleBracketedParameterData,
         substs: &mut Substs<'tcx>)
     {
@@ -4569,6 +4804,27 @@ pub fn instantiate_path<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
                 }
             }
         }
+
+        {
+            let const_defs = const_defs.get_slice(space);
+            assert_eq!(substs.consts.len(space), 0);
+            for (i, expr) in data.consts.iter().enumerate() {
+                if i < const_defs.len() {
+                    let c = astconv::ast_const_arg_to_const(fcx, &const_defs[i], expr);
+                    substs.consts.push(space, c);
+                } else {
+                    span_err!(fcx.tcx().sess, expr.span, E0568,
+                        "too many const parameters provided: \
+                         expected {} parameter{}, found {} parameter{}",
+                        const_defs.len(),
+                        if const_defs.len() == 1 {""} else {"s"},
+                        data.consts.len(),
+                        if data.consts.len() == 1 {""} else {"s"});
+                    substs.consts.truncate(space, 0);
+                    break;
+                }
+            }
+        }
     }
 
     /// As with
@@ -4720,6 +4976,40 @@ pub fn instantiate_path<'a, 'tcx>(fcx: &FnCtxt<'a, 'tcx>,
             space,
             fcx.infcx().region_vars_for_defs(span, desired));
     }
+
+    fn adjust_const_parameters<'a, 'tcx>(
+        fcx: &FnCtxt<'a, 'tcx>,
+        span: Span,
+        space: ParamSpace,
+        defs: &VecPerParamSpace<ty::ConstParameterDef<'tcx>>,
+        substs: &mut Substs<'tcx>)
+    {
+        let provided_len = substs.consts.len(space);
+        let desired = defs.get_slice(space);
+
+        // Enforced by `push_explicit_parameters_from_segment_to_substs()`.
+        assert!(provided_len <= desired.len());
+
+        // If just the right number were provided, everybody is happy.
+        if provided_len == desired.len() {
+            return;
+        }
+
+        // Const arguments are either all given or all inferred. If some
+        // but not all were provided, report an error and then use
+        // inference variables.
+        if provided_len != 0 {
+            span_err!(fcx.tcx().sess, span, E0568,
+                "too few const parameters provided: expected {} parameter{}, \
+                 found {} parameter{}",
+                desired.len(),
+                if desired.len() == 1 {""} else {"s"},
+                provided_len,
+                if provided_len == 1 {""} else {"s"});
+        }
+
+        substs.consts.replace(space, fcx.infcx().const_vars_for_defs(desired));
+    }
 }
 
 fn structurally_resolve_type_or_else<'a, 'tcx, F>(fcx: &FnCtxt<'a, 'tcx>,
@@ -4769,14 +5059,14 @@ pub fn may_break(cx: &TyCtxt, id: ast::NodeId, b: &hir::Block) -> bool {
     // inside the loop?
     (loop_query(&b, |e| {
         match *e {
-            hir::ExprBreak(None) => true,
+            hir::ExprBreak(None, _) => true,
             _ => false
         }
     })) ||
     // Second: is there a labeled break with label
     // <id> nested anywhere inside the loop?
     (block_query(b, |e| {
-        if let hir::ExprBreak(Some(_)) = e.node {
+        if let hir::ExprBreak(Some(_), _) = e.node {
             lookup_full_def(cx, e.span, e.id) == Def::Label(id)
         } else {
             false
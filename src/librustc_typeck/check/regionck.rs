@@ -96,6 +96,7 @@ use rustc::infer::{self, GenericKind, InferCtxt, InferOk, SubregionOrigin, TypeO
 use hir::pat_util;
 use rustc::ty::adjustment;
 use rustc::ty::wf::ImpliedBound;
+use util::nodemap::FnvHashSet;
 
 use std::mem;
 use syntax::ast;
@@ -150,6 +151,7 @@ pub fn regionck_fn(fcx: &FnCtxt,
     if fcx.err_count_since_creation() == 0 {
         // regionck assumes typeck succeeded
         rcx.visit_fn_body(fn_id, decl, blk, fn_span);
+        rcx.constrain_anon_types(decl.output.span());
     }
 
     let tcx = fcx.tcx();
@@ -337,6 +339,37 @@ impl<'a, 'tcx> Rcx<'a, 'tcx> {
         self.set_call_site_scope(old_call_site_scope);
     }
 
+    /// The concrete type behind an anonymized type may only mention
+    /// the regions in the anonymized type's substs, which are the
+    /// early-bound regions of the function. Every region in the
+    /// concrete type must thus outlive all of those regions, or
+    /// `'static` if there are none.
+    fn constrain_anon_types(&mut self, span: Span) {
+        let anon_types = self.fcx.inh.anon_types.borrow();
+        if anon_types.is_empty() {
+            return;
+        }
+
+        let free_regions: Vec<ty::Region> =
+            self.infcx().parameter_environment.free_substs.regions.iter().cloned().collect();
+
+        for (_, &concrete_ty) in anon_types.iter() {
+            let concrete_ty = self.resolve_type(concrete_ty);
+            debug!("constrain_anon_types: concrete_ty={:?}", concrete_ty);
+
+            let mut regions = FnvHashSet();
+            self.tcx().collect_regions(&concrete_ty, &mut regions);
+            for &r in regions.iter().filter(|r| !r.is_bound()) {
+                if free_regions.is_empty() {
+                    self.fcx.mk_subr(infer::CallReturn(span), ty::ReStatic, r);
+                }
+                for &free_region in &free_regions {
+                    self.fcx.mk_subr(infer::CallReturn(span), free_region, r);
+                }
+            }
+        }
+    }
+
     fn visit_region_obligations(&mut self, node_id: ast::NodeId)
     {
         debug!("visit_region_obligations: node_id={}", node_id);
@@ -786,7 +819,7 @@ fn visit_expr(rcx: &mut Rcx, expr: &hir::Expr) {
             check_expr_fn_block(rcx, expr, &body);
         }
 
-        hir::ExprLoop(ref body, _) => {
+        hir::ExprLoop(ref body, _, _) => {
             let repeating_scope = rcx.set_repeating_scope(body.id);
             intravisit::walk_expr(rcx, expr);
             rcx.set_repeating_scope(repeating_scope);
@@ -1616,18 +1649,26 @@ fn projection_must_outlive<'a, 'tcx>(rcx: &Rcx<'a, 'tcx>,
     // the problem is to add `T: 'r`, which isn't true. So, if there are no
     // inference variables, we use a verify constraint instead of adding
     // edges, which winds up enforcing the same condition.
+    let component_tys = || {
+        projection_ty.trait_ref.substs.types.iter()
+                     .chain(projection_ty.item_substs.types.iter())
+    };
+    let component_regions = || {
+        projection_ty.trait_ref.substs.regions.iter()
+                     .chain(projection_ty.item_substs.regions.iter())
+    };
     let needs_infer = {
-        projection_ty.trait_ref.substs.types.iter().any(|t| t.needs_infer()) ||
-            projection_ty.trait_ref.substs.regions.iter().any(|r| r.needs_infer())
+        component_tys().any(|t| t.needs_infer()) ||
+            component_regions().any(|r| r.needs_infer())
     };
     if env_bounds.is_empty() && needs_infer {
         debug!("projection_must_outlive: no declared bounds");
 
-        for &component_ty in &projection_ty.trait_ref.substs.types {
+        for &component_ty in component_tys() {
             type_must_outlive(rcx, origin.clone(), component_ty, region);
         }
 
-        for &r in &projection_ty.trait_ref.substs.regions {
+        for &r in component_regions() {
             rcx.fcx.mk_subr(origin.clone(), region, r);
         }
 
@@ -1644,9 +1685,7 @@ fn projection_must_outlive<'a, 'tcx>(rcx: &Rcx<'a, 'tcx>,
     if !env_bounds.is_empty() && env_bounds[1..].iter().all(|b| *b == env_bounds[0]) {
         let unique_bound = env_bounds[0];
         debug!("projection_must_outlive: unique declared bound = {:?}", unique_bound);
-        if projection_ty.trait_ref.substs.regions
-                                         .iter()
-                                         .any(|r| env_bounds.contains(r))
+        if component_regions().any(|r| env_bounds.contains(r))
         {
             debug!("projection_must_outlive: unique declared bound appears in trait ref");
             rcx.fcx.mk_subr(origin.clone(), region, unique_bound);
@@ -1720,7 +1759,9 @@ fn projection_bound<'a, 'tcx>(rcx: &Rcx<'a, 'tcx>,
 
     // see the extensive comment in projection_must_outlive
 
-    let ty = rcx.tcx().mk_projection(projection_ty.trait_ref, projection_ty.item_name);
+    let ty = rcx.tcx().mk_projection(projection_ty.trait_ref,
+                                     projection_ty.item_name,
+                                     projection_ty.item_substs);
     let recursive_bound = recursive_type_bound(rcx, span, ty);
 
     VerifyBound::AnyRegion(declared_bounds).or(recursive_bound)
@@ -1795,7 +1836,9 @@ fn declared_projection_bounds_from_trait<'a,'tcx>(rcx: &Rcx<'a, 'tcx>,
     debug!("projection_bounds(projection_ty={:?})",
            projection_ty);
 
-    let ty = tcx.mk_projection(projection_ty.trait_ref.clone(), projection_ty.item_name);
+    let ty = tcx.mk_projection(projection_ty.trait_ref.clone(),
+                               projection_ty.item_name,
+                               projection_ty.item_substs);
 
     // Say we have a projection `<T as SomeTrait<'a>>::SomeType`. We are interested
     // in looking for a trait definition like:
@@ -1807,7 +1850,8 @@ fn declared_projection_bounds_from_trait<'a,'tcx>(rcx: &Rcx<'a, 'tcx>,
     // ```
     //
     // we can thus deduce that `<T as SomeTrait<'a>>::SomeType : 'a`.
-    let trait_predicates = tcx.lookup_predicates(projection_ty.trait_ref.def_id);
+    let (bounds_def_id, bounds_substs) = tcx.projection_bounds_source(&projection_ty);
+    let trait_predicates = tcx.lookup_predicates(bounds_def_id);
     let predicates = trait_predicates.predicates.as_slice().to_vec();
     traits::elaborate_predicates(tcx, predicates)
         .filter_map(|predicate| {
@@ -1822,7 +1866,7 @@ fn declared_projection_bounds_from_trait<'a,'tcx>(rcx: &Rcx<'a, 'tcx>,
 
             // apply the substitutions (and normalize any projected types)
             let outlives = fcx.instantiate_type_scheme(span,
-                                                       projection_ty.trait_ref.substs,
+                                                       bounds_substs,
                                                        &outlives);
 
             debug!("projection_bounds: outlives={:?} (2)",
@@ -13,7 +13,7 @@
 // substitutions.
 use self::ResolveReason::*;
 
-use astconv::AstConv;
+use astconv::{self, AstConv};
 use check::FnCtxt;
 use hir::def_id::DefId;
 use hir::pat_util;
@@ -65,6 +65,7 @@ pub fn resolve_type_vars_in_fn(fcx: &FnCtxt,
     wbcx.visit_closures();
     wbcx.visit_liberated_fn_sigs();
     wbcx.visit_fru_field_types();
+    wbcx.visit_anon_types(decl.output.span());
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -291,6 +292,10 @@ impl<'cx, 'tcx> WritebackCx<'cx, 'tcx> {
                         adjustment::AdjustMutToConstPointer
                     }
 
+                    adjustment::AdjustNeverToAny(ty) => {
+                        adjustment::AdjustNeverToAny(self.resolve(&ty, reason))
+                    }
+
                     adjustment::AdjustUnsafeFnPointer => {
                         adjustment::AdjustUnsafeFnPointer
                     }
@@ -360,6 +365,28 @@ impl<'cx, 'tcx> WritebackCx<'cx, 'tcx> {
         }
     }
 
+    fn visit_anon_types(&self, span: Span) {
+        if self.fcx.inh.anon_types.borrow().is_empty() {
+            return;
+        }
+
+        let tcx = self.tcx();
+        let item_id = tcx.map.get_parent(self.fcx.body_id);
+        let generics = tcx.lookup_item_type(tcx.map.local_def_id(item_id)).generics;
+
+        for (&def_id, &concrete_ty) in self.fcx.inh.anon_types.borrow().iter() {
+            let concrete_ty = self.resolve(&concrete_ty, ResolvingAnonTy(span));
+
+            // Regions are not tracked through an anonymized type, so
+            // they are erased from the concrete type it stands for.
+            let concrete_ty = tcx.erase_regions(&concrete_ty);
+            tcx.register_item_type(def_id, ty::TypeScheme {
+                ty: concrete_ty,
+                generics: generics.clone()
+            });
+        }
+    }
+
     fn resolve<T:TypeFoldable<'tcx>>(&self, t: &T, reason: ResolveReason) -> T {
         t.fold_with(&mut Resolver::new(self.fcx, reason))
     }
@@ -376,7 +403,8 @@ enum ResolveReason {
     ResolvingUpvar(ty::UpvarId),
     ResolvingClosure(DefId),
     ResolvingFnSig(ast::NodeId),
-    ResolvingFieldTypes(ast::NodeId)
+    ResolvingFieldTypes(ast::NodeId),
+    ResolvingAnonTy(Span)
 }
 
 impl ResolveReason {
@@ -385,6 +413,7 @@ impl ResolveReason {
             ResolvingExpr(s) => s,
             ResolvingLocal(s) => s,
             ResolvingPattern(s) => s,
+            ResolvingAnonTy(s) => s,
             ResolvingUpvar(upvar_id) => {
                 tcx.expr_span(upvar_id.closure_expr_id)
             }
@@ -471,6 +500,13 @@ impl<'cx, 'tcx> Resolver<'cx, 'tcx> {
                               "cannot determine a type for this closure")
                 }
 
+                ResolvingAnonTy(_) => {
+                    let span = self.reason.span(self.tcx);
+                    span_err!(self.tcx.sess, span, E0563,
+                              "cannot determine a type for this `impl Trait`: {}",
+                              infer::fixup_err_to_string(e))
+                }
+
                 ResolvingFnSig(id) | ResolvingFieldTypes(id) => {
                     // any failures here should also fail when
                     // resolving the patterns, closure types, or
@@ -511,6 +547,16 @@ impl<'cx, 'tcx> TypeFolder<'tcx> for Resolver<'cx, 'tcx> {
             }
         }
     }
+
+    fn fold_const(&mut self, c: ty::Const) -> ty::Const {
+        match self.infcx.fully_resolve(&c) {
+            Ok(c) => c,
+            Err(e) => {
+                self.report_error(e);
+                astconv::const_err()
+            }
+        }
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -376,13 +376,20 @@ impl<'a, 'tcx> AstConv<'tcx> for ItemCtxt<'a, 'tcx> {
         self.tcx().types.err
     }
 
+    fn const_infer(&self, span: Span) -> ty::Const {
+        // Paths in item signatures are converted with `PathParamMode::Explicit`,
+        // so const arguments are never omitted there.
+        span_bug!(span, "const argument inferred within an item signature")
+    }
+
     fn projected_ty(&self,
                     _span: Span,
                     trait_ref: ty::TraitRef<'tcx>,
-                    item_name: ast::Name)
+                    item_name: ast::Name,
+                    item_substs: &'tcx Substs<'tcx>)
                     -> Ty<'tcx>
     {
-        self.tcx().mk_projection(trait_ref, item_name)
+        self.tcx().mk_projection(trait_ref, item_name, item_substs)
     }
 
     fn set_tainted_by_errors(&self) {
@@ -544,14 +551,23 @@ fn convert_method<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
                             untransformed_rcvr_ty: Ty<'tcx>,
                             rcvr_ty_generics: &ty::Generics<'tcx>,
                             rcvr_ty_predicates: &ty::GenericPredicates<'tcx>) {
-    let ty_generics = ty_generics_for_fn(ccx, &sig.generics, rcvr_ty_generics);
+    let ty_generics = ty_generics_for_fn(ccx, &sig.generics, &sig.decl, rcvr_ty_generics);
 
     let ty_generic_predicates =
-        ty_generic_predicates_for_fn(ccx, &sig.generics, rcvr_ty_predicates);
+        ty_generic_predicates_for_fn(ccx, &sig.generics, &sig.decl, rcvr_ty_predicates);
 
+    // `impl Trait` is only allowed in the return type of inherent methods.
+    let anon_scope = match container {
+        ImplContainer(impl_def_id) if ccx.tcx.impl_trait_ref(impl_def_id).is_none() => {
+            Some(AnonTypeScope::new(&ty_generics))
+        }
+        _ => None
+    };
+
+    let icx = ccx.icx(&(rcvr_ty_predicates, &sig.generics));
     let (fty, explicit_self_category) =
-        astconv::ty_of_method(&ccx.icx(&(rcvr_ty_predicates, &sig.generics)),
-                              sig, untransformed_rcvr_ty);
+        astconv::ty_of_method(&icx, sig, untransformed_rcvr_ty, anon_scope.as_ref());
+    convert_anon_types(&icx, &sig.decl, &fty);
 
     let def_id = ccx.tcx.map.local_def_id(id);
     let substs = ccx.tcx.mk_substs(mk_item_substs(ccx, &ty_generics));
@@ -584,6 +600,57 @@ fn convert_method<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         ty::MethodTraitItem(Rc::new(ty_method)));
 }
 
+/// Records the bounds of each `impl Trait` in the return type of `decl`
+/// as the predicates of the corresponding anonymized type, with the
+/// anonymized type itself as `Self`.
+fn convert_anon_types<'tcx>(astconv: &AstConv<'tcx>,
+                            decl: &hir::FnDecl,
+                            fty: &ty::BareFnTy<'tcx>) {
+    let output = match (&decl.output, fty.sig.0.output) {
+        (&hir::Return(ref ast_ty), ty::FnConverging(ty)) => (ast_ty, ty),
+        _ => return
+    };
+
+    // Only the `impl Trait` nodes that were actually converted to
+    // anonymized types get predicates; the rest were already reported.
+    let anon_tys: FnvHashMap<DefId, Ty<'tcx>> = output.1.walk().filter_map(|ty| {
+        match ty.sty {
+            ty::TyAnon(def_id, _) => Some((def_id, ty)),
+            _ => None
+        }
+    }).collect();
+
+    let mut visitor = AnonTypeBoundsVisitor {
+        astconv: astconv,
+        anon_tys: &anon_tys
+    };
+    intravisit::Visitor::visit_ty(&mut visitor, output.0);
+}
+
+struct AnonTypeBoundsVisitor<'a, 'tcx: 'a> {
+    astconv: &'a AstConv<'tcx>,
+    anon_tys: &'a FnvHashMap<DefId, Ty<'tcx>>
+}
+
+impl<'a, 'tcx, 'v> intravisit::Visitor<'v> for AnonTypeBoundsVisitor<'a, 'tcx> {
+    fn visit_ty(&mut self, ast_ty: &'v hir::Ty) {
+        let tcx = self.astconv.tcx();
+        if let hir::TyImplTrait(ref bounds) = ast_ty.node {
+            let def_id = tcx.map.local_def_id(ast_ty.id);
+            if let Some(&anon_ty) = self.anon_tys.get(&def_id) {
+                let bounds = compute_bounds(self.astconv, anon_ty, bounds,
+                                            SizedByDefault::Yes, ast_ty.span);
+                let predicates = bounds.predicates(tcx, anon_ty);
+                tcx.predicates.borrow_mut().insert(def_id, ty::GenericPredicates {
+                    predicates: VecPerParamSpace::new(vec![], vec![], predicates)
+                });
+            }
+        } else {
+            intravisit::walk_ty(self, ast_ty);
+        }
+    }
+}
+
 fn convert_field<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
                            struct_generics: &ty::Generics<'tcx>,
                            struct_predicates: &ty::GenericPredicates<'tcx>,
@@ -637,10 +704,17 @@ fn convert_associated_type<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
                                      id: ast::NodeId,
                                      vis: &hir::Visibility,
                                      defaultness: hir::Defaultness,
-                                     ty: Option<Ty<'tcx>>)
+                                     ty: Option<Ty<'tcx>>,
+                                     generics: ty::Generics<'tcx>,
+                                     predicates: ty::GenericPredicates<'tcx>)
 {
+    ccx.tcx.predicates.borrow_mut().insert(ccx.tcx.map.local_def_id(id),
+                                           predicates.clone());
+
     let associated_type = Rc::new(ty::AssociatedType {
         name: name,
+        generics: generics,
+        predicates: predicates,
         vis: ty::Visibility::from_hir(vis, id, ccx.tcx),
         defaultness: defaultness,
         ty: ty,
@@ -708,12 +782,17 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
                                                     ast_trait_ref,
                                                     None);
 
+            if ccx.get_trait_def(trait_ref.def_id).is_alias {
+                span_err!(tcx.sess, ast_trait_ref.path.span, E0575,
+                          "cannot implement a trait alias");
+            }
+
             tcx.record_trait_has_default_impl(trait_ref.def_id);
 
             tcx.impl_trait_refs.borrow_mut().insert(ccx.tcx.map.local_def_id(it.id),
                                                     Some(trait_ref));
         }
-        hir::ItemImpl(_, _,
+        hir::ItemImpl(_, _, impl_defaultness,
                       ref generics,
                       ref opt_trait_ref,
                       ref selfty,
@@ -740,6 +819,18 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
             });
             tcx.impl_trait_refs.borrow_mut().insert(def_id, trait_ref);
 
+            if trait_ref.is_none() && impl_defaultness.is_default() {
+                span_err!(tcx.sess, it.span, E0574,
+                          "inherent impls cannot be declared `default`");
+            }
+
+            if let (Some(trait_ref), Some(ast_trait_ref)) = (trait_ref, opt_trait_ref.as_ref()) {
+                if ccx.get_trait_def(trait_ref.def_id).is_alias {
+                    span_err!(tcx.sess, ast_trait_ref.path.span, E0575,
+                              "cannot implement a trait alias");
+                }
+            }
+
             enforce_impl_params_are_constrained(tcx, generics, &mut ty_predicates, def_id);
             tcx.predicates.borrow_mut().insert(def_id, ty_predicates.clone());
 
@@ -751,7 +842,7 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
 
             for impl_item in impl_items {
                 let seen_items = match impl_item.node {
-                    hir::ImplItemKind::Type(_) => &mut seen_type_items,
+                    hir::ImplItemKind::Type(..) => &mut seen_type_items,
                     _                    => &mut seen_value_items,
                 };
                 match seen_items.entry(impl_item.name) {
@@ -783,24 +874,30 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
                     convert_associated_const(ccx, ImplContainer(def_id),
                                              impl_item.name, impl_item.id,
                                              visibility,
-                                             impl_item.defaultness,
+                                             impl_item_defaultness(impl_defaultness, impl_item),
                                              ty, true /* has_value */);
                 }
             }
 
             // Convert all the associated types.
             for impl_item in impl_items {
-                if let hir::ImplItemKind::Type(ref ty) = impl_item.node {
+                if let hir::ImplItemKind::Type(ref ty, ref item_generics) = impl_item.node {
                     if opt_trait_ref.is_none() {
                         span_err!(tcx.sess, impl_item.span, E0202,
                                   "associated types are not allowed in inherent impls");
                     }
 
-                    let typ = ccx.icx(&ty_predicates).to_ty(&ExplicitRscope, ty);
+                    let assoc_generics =
+                        ty_generics_for_assoc_type(ccx, item_generics, &ty_generics);
+                    let assoc_predicates =
+                        ty_generic_predicates_for_assoc_type(ccx, item_generics, &ty_predicates);
+                    let typ = ccx.icx(&(&ty_predicates, item_generics))
+                                 .to_ty(&ExplicitRscope, ty);
 
                     convert_associated_type(ccx, ImplContainer(def_id),
                                             impl_item.name, impl_item.id, &impl_item.vis,
-                                            impl_item.defaultness, Some(typ));
+                                            impl_item_defaultness(impl_defaultness, impl_item),
+                                            Some(typ), assoc_generics, assoc_predicates);
                 }
             }
 
@@ -812,8 +909,8 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
 
                     convert_method(ccx, ImplContainer(def_id),
                                    impl_item.name, impl_item.id, method_vis,
-                                   sig, impl_item.defaultness, selfty, &ty_generics,
-                                   &ty_predicates);
+                                   sig, impl_item_defaultness(impl_defaultness, impl_item),
+                                   selfty, &ty_generics, &ty_predicates);
                 }
             }
 
@@ -855,9 +952,34 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
 
             // Convert all the associated types.
             for trait_item in trait_items {
-                if let hir::TypeTraitItem(_, ref opt_ty) = trait_item.node {
+                if let hir::TypeTraitItem(ref bounds, ref opt_ty, ref item_generics) =
+                        trait_item.node {
+                    let assoc_generics =
+                        ty_generics_for_assoc_type(ccx, item_generics, &trait_def.generics);
+                    let mut assoc_predicates =
+                        ty_generic_predicates_for_assoc_type(ccx, item_generics, &trait_predicates);
+                    let icx = ccx.icx(&(&trait_predicates, item_generics));
+
+                    // The bounds of a generic associated type mention its own
+                    // parameters, so they are kept with the associated type
+                    // rather than with the trait.
+                    if item_generics.is_parameterized() {
+                        let item_substs = Substs::empty().with_method_from_subst(
+                            &mk_item_substs(ccx, &assoc_generics));
+                        let assoc_ty = tcx.mk_projection(trait_def.trait_ref,
+                                                         trait_item.name,
+                                                         tcx.mk_substs(item_substs));
+                        let bounds = compute_bounds(&icx,
+                                                    assoc_ty,
+                                                    bounds,
+                                                    SizedByDefault::Yes,
+                                                    trait_item.span);
+                        assoc_predicates.predicates.extend(FnSpace,
+                            bounds.predicates(tcx, assoc_ty).into_iter());
+                    }
+
                     let typ = opt_ty.as_ref().map({
-                        |ty| ccx.icx(&trait_predicates).to_ty(&ExplicitRscope, &ty)
+                        |ty| icx.to_ty(&ExplicitRscope, &ty)
                     });
 
                     convert_associated_type(ccx,
@@ -866,7 +988,9 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
                                             trait_item.id,
                                             &hir::Public,
                                             hir::Defaultness::Default,
-                                            typ);
+                                            typ,
+                                            assoc_generics,
+                                            assoc_predicates);
                 }
             }
 
@@ -899,6 +1023,17 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
             tcx.trait_item_def_ids.borrow_mut().insert(ccx.tcx.map.local_def_id(it.id),
                                                        trait_item_def_ids);
         },
+        hir::ItemTraitAlias(..) => {
+            let trait_def = trait_def_of_item(ccx, it);
+            let def_id = trait_def.trait_ref.def_id;
+            let _: Result<(), ErrorReported> = // any error is already reported, can ignore
+                ccx.ensure_super_predicates(it.span, def_id);
+            convert_trait_predicates(ccx, it);
+
+            // A trait alias has no items of its own; everything it offers
+            // comes from the traits it is an alias for.
+            tcx.trait_item_def_ids.borrow_mut().insert(def_id, Rc::new(vec![]));
+        },
         hir::ItemStruct(ref struct_def, _) => {
             let (scheme, predicates) = convert_typed_item(ccx, it);
             write_ty_to_tcx(tcx, it.id, scheme.ty);
@@ -914,6 +1049,17 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
                 convert_variant_ctor(ccx, struct_def.id(), variant, scheme, predicates);
             }
         },
+        hir::ItemUnion(ref struct_def, _) => {
+            let (scheme, predicates) = convert_typed_item(ccx, it);
+            write_ty_to_tcx(tcx, it.id, scheme.ty);
+
+            let it_def_id = ccx.tcx.map.local_def_id(it.id);
+            let variant = tcx.lookup_adt_def_master(it_def_id).struct_variant();
+
+            for (f, ty_f) in struct_def.fields().iter().zip(variant.fields.iter()) {
+                convert_field(ccx, &scheme.generics, &predicates, f, ty_f)
+            }
+        },
         hir::ItemTy(_, ref generics) => {
             ensure_no_ty_param_bounds(ccx, it.span, generics, "type");
             let (scheme, _) = convert_typed_item(ccx, it);
@@ -1038,6 +1184,19 @@ fn convert_struct_def<'tcx>(tcx: &TyCtxt<'tcx>,
     )
 }
 
+fn convert_union_def<'tcx>(tcx: &TyCtxt<'tcx>,
+                           it: &hir::Item,
+                           def: &hir::VariantData)
+                           -> ty::AdtDefMaster<'tcx>
+{
+    let did = tcx.map.local_def_id(it.id);
+    tcx.intern_adt_def(
+        did,
+        ty::AdtKind::Union,
+        vec![convert_struct_variant(tcx, did, it.name, ConstInt::Infer(0), def)]
+    )
+}
+
 fn convert_enum_def<'tcx>(tcx: &TyCtxt<'tcx>,
                           it: &hir::Item,
                           def: &hir::EnumDef)
@@ -1182,6 +1341,7 @@ fn ensure_super_predicates_step(ccx: &CrateCtxt,
 
         let (generics, bounds) = match item.node {
             hir::ItemTrait(_, ref generics, ref supertraits, _) => (generics, supertraits),
+            hir::ItemTraitAlias(ref generics, ref bounds) => (generics, bounds),
             _ => span_bug!(item.span,
                            "ensure_super_predicates_step invoked on non-trait"),
         };
@@ -1247,8 +1407,13 @@ fn trait_def_of_item<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         return def.clone();
     }
 
-    let (unsafety, generics, items) = match it.node {
-        hir::ItemTrait(unsafety, ref generics, _, ref items) => (unsafety, generics, items),
+    let (unsafety, generics, items, is_alias) = match it.node {
+        hir::ItemTrait(unsafety, ref generics, _, ref items) => {
+            (unsafety, generics, &items[..], false)
+        }
+        hir::ItemTraitAlias(ref generics, _) => {
+            (hir::Unsafety::Normal, generics, &[][..], true)
+        }
         _ => span_bug!(it.span, "trait_def_of_item invoked on non-trait"),
     };
 
@@ -1282,6 +1447,7 @@ fn trait_def_of_item<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
 
     let trait_def = ty::TraitDef::new(unsafety,
                                       paren_sugar,
+                                      is_alias,
                                       ty_generics,
                                       trait_ref,
                                       associated_type_names);
@@ -1318,7 +1484,19 @@ fn trait_def_of_item<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         // ...and also create the `Self` parameter.
         let self_ty = tcx.mk_self_type();
 
-        Substs::new_trait(types, regions, self_ty)
+        let mut substs = Substs::new_trait(types, regions, self_ty);
+
+        let consts =
+            generics.const_params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, def)| ty::Const::Param(ty::ParamConst::new(TypeSpace,
+                                                                         i as u32,
+                                                                         def.name)))
+                    .collect();
+        substs.consts.replace(TypeSpace, consts);
+
+        substs
     }
 }
 
@@ -1334,6 +1512,7 @@ fn trait_defines_associated_type_named(ccx: &CrateCtxt,
 
     let trait_items = match item.node {
         hir::ItemTrait(_, _, _, ref trait_items) => trait_items,
+        hir::ItemTraitAlias(..) => return false,
         _ => bug!("trait_node_id {} is not a trait", trait_node_id)
     };
 
@@ -1352,7 +1531,8 @@ fn convert_trait_predicates<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>, it: &hir::Item)
     let def_id = ccx.tcx.map.local_def_id(it.id);
 
     let (generics, items) = match it.node {
-        hir::ItemTrait(_, ref generics, _, ref items) => (generics, items),
+        hir::ItemTrait(_, ref generics, _, ref items) => (generics, &items[..]),
+        hir::ItemTraitAlias(ref generics, _) => (generics, &[][..]),
         ref s => {
             span_bug!(
                 it.span,
@@ -1377,7 +1557,7 @@ fn convert_trait_predicates<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>, it: &hir::Item)
 
     // add in the explicit where-clauses
     let mut trait_predicates =
-        ty_generic_predicates(ccx, TypeSpace, generics, &base_predicates);
+        ty_generic_predicates(ccx, TypeSpace, generics, None, &base_predicates);
 
     let assoc_predicates = predicates_for_associated_types(ccx,
                                                            generics,
@@ -1400,14 +1580,18 @@ fn convert_trait_predicates<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>, it: &hir::Item)
     {
         trait_items.iter().flat_map(|trait_item| {
             let bounds = match trait_item.node {
-                hir::TypeTraitItem(ref bounds, _) => bounds,
+                // Bounds of generic associated types are collected with
+                // the associated type itself.
+                hir::TypeTraitItem(ref bounds, _, ref generics)
+                    if !generics.is_parameterized() => bounds,
                 _ => {
                     return vec!().into_iter();
                 }
             };
 
             let assoc_ty = ccx.tcx.mk_projection(self_trait_ref,
-                                                 trait_item.name);
+                                                 trait_item.name,
+                                                 ccx.tcx.mk_substs(Substs::empty()));
 
             let bounds = compute_bounds(&ccx.icx(&(ast_generics, trait_predicates)),
                                         assoc_ty,
@@ -1468,8 +1652,11 @@ fn compute_type_scheme_of_item<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
             ty::TypeScheme { ty: ty, generics: ty::Generics::empty() }
         }
         hir::ItemFn(ref decl, unsafety, _, abi, ref generics, _) => {
-            let ty_generics = ty_generics_for_fn(ccx, generics, &ty::Generics::empty());
-            let tofd = astconv::ty_of_bare_fn(&ccx.icx(generics), unsafety, abi, &decl);
+            let ty_generics = ty_generics_for_fn(ccx, generics, decl, &ty::Generics::empty());
+            let anon_scope = AnonTypeScope::new(&ty_generics);
+            let icx = ccx.icx(generics);
+            let tofd = astconv::ty_of_bare_fn(&icx, unsafety, abi, &decl, Some(&anon_scope));
+            convert_anon_types(&icx, &decl, &tofd);
             let def_id = ccx.tcx.map.local_def_id(it.id);
             let substs = tcx.mk_substs(mk_item_substs(ccx, &ty_generics));
             let ty = tcx.mk_fn_def(def_id, substs, tofd);
@@ -1494,8 +1681,16 @@ fn compute_type_scheme_of_item<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
             let t = tcx.mk_struct(def, tcx.mk_substs(substs));
             ty::TypeScheme { ty: t, generics: ty_generics }
         }
+        hir::ItemUnion(ref un, ref generics) => {
+            let ty_generics = ty_generics_for_type(ccx, generics);
+            let substs = mk_item_substs(ccx, &ty_generics);
+            let def = convert_union_def(tcx, it, un);
+            let t = tcx.mk_struct(def, tcx.mk_substs(substs));
+            ty::TypeScheme { ty: t, generics: ty_generics }
+        }
         hir::ItemDefaultImpl(..) |
         hir::ItemTrait(..) |
+        hir::ItemTraitAlias(..) |
         hir::ItemImpl(..) |
         hir::ItemMod(..) |
         hir::ItemForeignMod(..) |
@@ -1521,8 +1716,9 @@ fn convert_typed_item<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         hir::ItemStatic(..) | hir::ItemConst(..) => {
             ty::GenericPredicates::empty()
         }
-        hir::ItemFn(_, _, _, _, ref ast_generics, _) => {
-            ty_generic_predicates_for_fn(ccx, ast_generics, &ty::GenericPredicates::empty())
+        hir::ItemFn(ref decl, _, _, _, ref ast_generics, _) => {
+            ty_generic_predicates_for_fn(ccx, ast_generics, decl,
+                                         &ty::GenericPredicates::empty())
         }
         hir::ItemTy(_, ref generics) => {
             ty_generic_predicates_for_type_or_impl(ccx, generics)
@@ -1533,8 +1729,12 @@ fn convert_typed_item<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         hir::ItemStruct(_, ref generics) => {
             ty_generic_predicates_for_type_or_impl(ccx, generics)
         }
+        hir::ItemUnion(_, ref generics) => {
+            ty_generic_predicates_for_type_or_impl(ccx, generics)
+        }
         hir::ItemDefaultImpl(..) |
         hir::ItemTrait(..) |
+        hir::ItemTraitAlias(..) |
         hir::ItemExternCrate(..) |
         hir::ItemUse(..) |
         hir::ItemImpl(..) |
@@ -1619,8 +1819,8 @@ fn convert_foreign_item<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
     write_ty_to_tcx(ccx.tcx, it.id, scheme.ty);
 
     let predicates = match it.node {
-        hir::ForeignItemFn(_, ref generics) => {
-            ty_generic_predicates_for_fn(ccx, generics, &ty::GenericPredicates::empty())
+        hir::ForeignItemFn(ref decl, ref generics) => {
+            ty_generic_predicates_for_fn(ccx, generics, decl, &ty::GenericPredicates::empty())
         }
         hir::ForeignItemStatic(..) => {
             ty::GenericPredicates::empty()
@@ -1634,19 +1834,19 @@ fn convert_foreign_item<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
 
 fn ty_generics_for_type<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>, generics: &hir::Generics)
                                   -> ty::Generics<'tcx> {
-    ty_generics(ccx, TypeSpace, generics, &ty::Generics::empty(), true)
+    ty_generics(ccx, TypeSpace, generics, None, &ty::Generics::empty(), true)
 }
 
 fn ty_generics_for_impl<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>, generics: &hir::Generics)
                                   -> ty::Generics<'tcx> {
-    ty_generics(ccx, TypeSpace, generics, &ty::Generics::empty(), false)
+    ty_generics(ccx, TypeSpace, generics, None, &ty::Generics::empty(), false)
 }
 
 fn ty_generic_predicates_for_type_or_impl<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                                                    generics: &hir::Generics)
                                                    -> ty::GenericPredicates<'tcx>
 {
-    ty_generic_predicates(ccx, TypeSpace, generics, &ty::GenericPredicates::empty())
+    ty_generic_predicates(ccx, TypeSpace, generics, None, &ty::GenericPredicates::empty())
 }
 
 fn ty_generics_for_trait<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
@@ -1687,18 +1887,52 @@ fn ty_generics_for_trait<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
 
 fn ty_generics_for_fn<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                                generics: &hir::Generics,
+                               decl: &hir::FnDecl,
                                base_generics: &ty::Generics<'tcx>)
                                -> ty::Generics<'tcx>
 {
-    ty_generics(ccx, FnSpace, generics, base_generics, false)
+    ty_generics(ccx, FnSpace, generics, Some(decl), base_generics, false)
 }
 
 fn ty_generic_predicates_for_fn<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                                          generics: &hir::Generics,
+                                         decl: &hir::FnDecl,
                                          base_predicates: &ty::GenericPredicates<'tcx>)
                                          -> ty::GenericPredicates<'tcx>
 {
-    ty_generic_predicates(ccx, FnSpace, generics, base_predicates)
+    ty_generic_predicates(ccx, FnSpace, generics, Some(decl), base_predicates)
+}
+
+/// The items of a `default impl` are all implicitly `default`.
+fn impl_item_defaultness(impl_defaultness: hir::Defaultness,
+                         impl_item: &hir::ImplItem)
+                         -> hir::Defaultness
+{
+    if impl_defaultness.is_default() {
+        hir::Defaultness::Default
+    } else {
+        impl_item.defaultness
+    }
+}
+
+fn ty_generics_for_assoc_type<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
+                                       generics: &hir::Generics,
+                                       base_generics: &ty::Generics<'tcx>)
+                                       -> ty::Generics<'tcx>
+{
+    for param in generics.const_params.iter() {
+        span_err!(ccx.tcx.sess, param.span, E0572,
+                  "const parameters are not allowed on associated types");
+    }
+    ty_generics(ccx, FnSpace, generics, None, base_generics, false)
+}
+
+fn ty_generic_predicates_for_assoc_type<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
+                                                 generics: &hir::Generics,
+                                                 base_predicates: &ty::GenericPredicates<'tcx>)
+                                                 -> ty::GenericPredicates<'tcx>
+{
+    ty_generic_predicates(ccx, FnSpace, generics, None, base_predicates)
 }
 
 // Add the Sized bound, unless the type parameter is marked as `?Sized`.
@@ -1751,21 +1985,26 @@ fn add_unsized_bound<'tcx>(astconv: &AstConv<'tcx>,
 /// Returns the early-bound lifetimes declared in this generics
 /// listing.  For anything other than fns/methods, this is just all
 /// the lifetimes that are declared. For fns or methods, we have to
-/// screen out those that do not appear in any where-clauses etc using
+/// screen out those that do not appear in any where-clauses or
+/// `impl Trait` return types etc using
 /// `resolve_lifetime::early_bound_lifetimes`.
 fn early_bound_lifetimes_from_generics(space: ParamSpace,
-                                       ast_generics: &hir::Generics)
+                                       ast_generics: &hir::Generics,
+                                       decl: Option<&hir::FnDecl>)
                                        -> Vec<hir::LifetimeDef>
 {
-    match space {
-        SelfSpace | TypeSpace => ast_generics.lifetimes.to_vec(),
-        FnSpace => resolve_lifetime::early_bound_lifetimes(ast_generics),
+    match (space, decl) {
+        (SelfSpace, _) | (TypeSpace, _) => ast_generics.lifetimes.to_vec(),
+        (FnSpace, Some(decl)) => resolve_lifetime::early_bound_lifetimes(ast_generics, Some(decl)),
+        // Associated types have no signature, so all of their lifetimes are early-bound.
+        (FnSpace, None) => ast_generics.lifetimes.to_vec(),
     }
 }
 
 fn ty_generic_predicates<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                                   space: ParamSpace,
                                   ast_generics: &hir::Generics,
+                                  decl: Option<&hir::FnDecl>,
                                   base_predicates: &ty::GenericPredicates<'tcx>)
                                   -> ty::GenericPredicates<'tcx>
 {
@@ -1789,7 +2028,7 @@ fn ty_generic_predicates<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
     // Collect the region predicates that were declared inline as
     // well. In the case of parameters declared on a fn or method, we
     // have to be careful to only iterate over early-bound regions.
-    let early_lifetimes = early_bound_lifetimes_from_generics(space, ast_generics);
+    let early_lifetimes = early_bound_lifetimes_from_generics(space, ast_generics, decl);
     for (index, param) in early_lifetimes.iter().enumerate() {
         let index = index as u32;
         let region =
@@ -1865,6 +2104,7 @@ fn ty_generic_predicates<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
 fn ty_generics<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                         space: ParamSpace,
                         ast_generics: &hir::Generics,
+                        decl: Option<&hir::FnDecl>,
                         base_generics: &ty::Generics<'tcx>,
                         allow_defaults: bool)
                         -> ty::Generics<'tcx>
@@ -1872,7 +2112,7 @@ fn ty_generics<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
     let tcx = ccx.tcx;
     let mut result = base_generics.clone();
 
-    let early_lifetimes = early_bound_lifetimes_from_generics(space, ast_generics);
+    let early_lifetimes = early_bound_lifetimes_from_generics(space, ast_generics, decl);
     for (i, l) in early_lifetimes.iter().enumerate() {
         let bounds = l.bounds.iter()
                              .map(|l| ast_region_to_region(tcx, l))
@@ -1895,9 +2135,53 @@ fn ty_generics<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
         result.types.push(space, def);
     }
 
+    assert!(result.consts.is_empty_in(space));
+
+    // And finally the const parameters.
+    for (i, param) in ast_generics.const_params.iter().enumerate() {
+        let def = const_parameter_def(ccx, param, space, i as u32);
+        debug!("ty_generics: def for const param: {:?}, {:?}", def, space);
+        result.consts.push(space, def);
+    }
+
     result
 }
 
+fn const_parameter_def<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
+                                param: &hir::ConstParam,
+                                space: ParamSpace,
+                                index: u32)
+                                -> ty::ConstParameterDef<'tcx>
+{
+    let tcx = ccx.tcx;
+    let mut ty = ast_ty_to_ty(&ccx.icx(&()), &ExplicitRscope, &param.ty);
+    match ty.sty {
+        ty::TyInt(_) | ty::TyUint(_) | ty::TyError => {}
+        _ => {
+            span_err!(tcx.sess, param.ty.span, E0567,
+                      "the type of const parameter `{}` must be an integer type, found `{}`",
+                      param.name, ty);
+            ty = tcx.types.err;
+        }
+    }
+
+    let def_id = tcx.map.local_def_id(param.id);
+
+    // Record the type of the parameter, for uses of it as a value.
+    tcx.register_item_type(def_id, TypeScheme {
+        generics: ty::Generics::empty(),
+        ty: ty
+    });
+
+    ty::ConstParameterDef {
+        name: param.name,
+        def_id: def_id,
+        space: space,
+        index: index,
+        ty: ty,
+    }
+}
+
 fn convert_default_type_parameter<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
                                             path: &P<hir::Ty>,
                                             space: ParamSpace,
@@ -2169,7 +2453,7 @@ fn compute_type_scheme_of_foreign_fn_decl<'a, 'tcx>(
         }
     }
 
-    let ty_generics = ty_generics_for_fn(ccx, ast_generics, &ty::Generics::empty());
+    let ty_generics = ty_generics_for_fn(ccx, ast_generics, decl, &ty::Generics::empty());
 
     let rb = BindingRscope::new();
     let input_tys = decl.inputs
@@ -2235,7 +2519,11 @@ fn mk_item_substs<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         ty_generics.regions.map(
             |def| def.to_early_bound_region());
 
-    Substs::new(types, regions)
+    let consts =
+        ty_generics.consts.map(
+            |def| def.to_const());
+
+    Substs::new_with_consts(types, regions, consts)
 }
 
 /// Checks that all the type parameters on an impl
@@ -2272,6 +2560,13 @@ fn enforce_impl_params_are_constrained<'tcx>(tcx: &TyCtxt<'tcx>,
             report_unused_parameter(tcx, ty_param.span, "type", &param_ty.to_string());
         }
     }
+
+    for (index, const_param) in ast_generics.const_params.iter().enumerate() {
+        let param_const = ty::ParamConst::new(TypeSpace, index as u32, const_param.name);
+        if !input_parameters.contains(&ctp::Parameter::Const(param_const)) {
+            report_unused_parameter(tcx, const_param.span, "const", &param_const.to_string());
+        }
+    }
 }
 
 fn enforce_impl_lifetimes_are_constrained<'tcx>(tcx: &TyCtxt<'tcx>,
@@ -2301,7 +2596,7 @@ fn enforce_impl_lifetimes_are_constrained<'tcx>(tcx: &TyCtxt<'tcx>,
                   })
                   .flat_map(|ty| ctp::parameters_for_type(ty, true))
                   .filter_map(|p| match p {
-                      ctp::Parameter::Type(_) => None,
+                      ctp::Parameter::Type(_) | ctp::Parameter::Const(_) => None,
                       ctp::Parameter::Region(r) => Some(r),
                   })
                   .collect();
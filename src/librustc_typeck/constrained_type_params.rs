@@ -17,6 +17,7 @@ use std::collections::HashSet;
 pub enum Parameter {
     Type(ty::ParamTy),
     Region(ty::EarlyBoundRegion),
+    Const(ty::ParamConst),
 }
 
 /// If `include_projections` is false, returns the list of parameters that are
@@ -28,9 +29,9 @@ pub fn parameters_for_type<'tcx>(ty: Ty<'tcx>,
                                  include_projections: bool) -> Vec<Parameter> {
     let mut result = vec![];
     ty.maybe_walk(|t| match t.sty {
-        ty::TyProjection(..) if !include_projections => {
+        ty::TyProjection(..) | ty::TyAnon(..) if !include_projections => {
 
-            false // projections are not injective.
+            false // projections and anonymized types are not injective.
         }
         _ => {
             result.append(&mut parameters_for_type_shallow(t));
@@ -45,6 +46,7 @@ pub fn parameters_for_trait_ref<'tcx>(trait_ref: &ty::TraitRef<'tcx>,
                                       include_projections: bool) -> Vec<Parameter> {
     let mut region_parameters =
         parameters_for_regions_in_substs(&trait_ref.substs);
+    region_parameters.extend(parameters_for_consts_in_substs(&trait_ref.substs));
 
     let type_parameters =
         trait_ref.substs
@@ -63,15 +65,20 @@ fn parameters_for_type_shallow<'tcx>(ty: Ty<'tcx>) -> Vec<Parameter> {
             vec![Parameter::Type(d.clone())],
         ty::TyRef(region, _) =>
             parameters_for_region(region).into_iter().collect(),
+        ty::TyArrayParam(_, c) =>
+            parameters_for_const(c).into_iter().collect(),
         ty::TyStruct(_, substs) |
-        ty::TyEnum(_, substs) =>
-            parameters_for_regions_in_substs(substs),
+        ty::TyEnum(_, substs) => {
+            let mut parameters = parameters_for_regions_in_substs(substs);
+            parameters.extend(parameters_for_consts_in_substs(substs));
+            parameters
+        }
         ty::TyTrait(ref data) =>
             parameters_for_regions_in_substs(&data.principal.skip_binder().substs),
         ty::TyProjection(ref pi) =>
             parameters_for_regions_in_substs(&pi.trait_ref.substs),
         ty::TyBool | ty::TyChar | ty::TyInt(..) | ty::TyUint(..) |
-        ty::TyFloat(..) | ty::TyBox(..) | ty::TyStr |
+        ty::TyFloat(..) | ty::TyBox(..) | ty::TyStr | ty::TyNever |
         ty::TyArray(..) | ty::TySlice(..) |
         ty::TyFnDef(..) | ty::TyFnPtr(_) |
         ty::TyTuple(..) | ty::TyRawPtr(..) |
@@ -94,6 +101,20 @@ fn parameters_for_region(region: &ty::Region) -> Option<Parameter> {
     }
 }
 
+fn parameters_for_consts_in_substs(substs: &subst::Substs) -> Vec<Parameter> {
+    substs.consts
+          .iter()
+          .filter_map(|&c| parameters_for_const(c))
+          .collect()
+}
+
+fn parameters_for_const(c: ty::Const) -> Option<Parameter> {
+    match c {
+        ty::Const::Param(p) => Some(Parameter::Const(p)),
+        _ => None,
+    }
+}
+
 pub fn identify_constrained_type_params<'tcx>(_tcx: &TyCtxt<'tcx>,
                                               predicates: &[ty::Predicate<'tcx>],
                                               impl_trait_ref: Option<ty::TraitRef<'tcx>>,
@@ -3790,5 +3790,18 @@ register_diagnostics! {
            // type `{}` was overridden
     E0436, // functional record update requires a struct
     E0513, // no type for local variable ..
-    E0521  // redundant default implementations of trait
+    E0521, // redundant default implementations of trait
+    E0562, // `impl Trait` not allowed outside of function and inherent method return types
+    E0563, // cannot determine a type for this `impl Trait`
+    E0564, // union expressions should have exactly one field
+    E0565, // union patterns should have exactly one field
+    E0567, // const parameters must have an integer type
+    E0568, // wrong number of const arguments
+    E0569, // const argument is not a constant integer of the parameter's type
+    E0571, // method const parameters differ from the trait declaration
+    E0572, // const parameters are not allowed on associated types
+    E0573, // associated type generic parameters differ from the trait declaration
+    E0574, // inherent impls cannot be declared `default`
+    E0575, // cannot implement a trait alias
+    E0576, // functional record update of an enum variant requires a single-variant enum
 }
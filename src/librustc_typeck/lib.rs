@@ -107,9 +107,10 @@ use hir::def::Def;
 use rustc::infer::{self, TypeOrigin};
 use rustc::ty::subst::Substs;
 use rustc::ty::{self, Ty, TyCtxt, TypeFoldable};
-use rustc::traits::ProjectionMode;
+use rustc::traits::{self, ProjectionMode};
 use session::{config, CompileResult};
 use util::common::time;
+use util::nodemap::NodeMap;
 
 use syntax::codemap::Span;
 use syntax::ast;
@@ -142,6 +143,12 @@ pub struct CrateCtxt<'a, 'tcx: 'a> {
     /// error reporting, and so is lazily initialised and generally
     /// shouldn't taint the common path (hence the RefCell).
     pub all_traits: RefCell<Option<check::method::AllTraitsVec>>,
+
+    /// Obligations which will have to be checked at the end of
+    /// type-checking, after all functions have been inferred.
+    /// The key is the NodeId of the item the obligations were from.
+    pub deferred_obligations: RefCell<NodeMap<Vec<traits::DeferredObligation<'tcx>>>>,
+
     pub tcx: &'a TyCtxt<'tcx>,
 }
 
@@ -336,6 +343,7 @@ pub fn check_crate(tcx: &TyCtxt, trait_map: hir::TraitMap) -> CompileResult {
     let ccx = CrateCtxt {
         trait_map: trait_map,
         all_traits: RefCell::new(None),
+        deferred_obligations: RefCell::new(NodeMap()),
         tcx: tcx
     };
 
@@ -361,6 +369,9 @@ pub fn check_crate(tcx: &TyCtxt, trait_map: hir::TraitMap) -> CompileResult {
 
     time(time_passes, "item-bodies checking", || check::check_item_bodies(&ccx))?;
 
+    time(time_passes, "deferred obligation checking",
+         || check::check_deferred_obligations(&ccx))?;
+
     time(time_passes, "drop-impl checking", || check::check_drop_impls(&ccx))?;
 
     check_for_entry_fn(&ccx);
@@ -9,7 +9,8 @@
 // except according to those terms.
 
 
-use rustc::ty;
+use rustc::ty::{self, TyCtxt};
+use rustc::ty::subst::{Substs, VecPerParamSpace};
 
 use std::cell::Cell;
 use syntax::codemap::Span;
@@ -50,6 +51,75 @@ pub trait RegionScope {
     /// computing `object_lifetime_default` (in particular, in legacy
     /// modes, it may not be relevant).
     fn base_object_lifetime_default(&self, span: Span) -> ty::Region;
+
+    /// If this scope allows anonymized types (i.e. `impl Trait`), returns
+    /// the generics the anonymized types are parameterized over.
+    fn anon_type_scope(&self) -> Option<&AnonTypeScope> {
+        None
+    }
+}
+
+/// The generics in scope for an `impl Trait` type, i.e. those of the
+/// function or inherent method in whose return type it appears.
+#[derive(Clone)]
+pub struct AnonTypeScope {
+    types: VecPerParamSpace<ty::ParamTy>,
+    regions: VecPerParamSpace<ty::Region>,
+    consts: VecPerParamSpace<ty::Const>,
+}
+
+impl AnonTypeScope {
+    pub fn new(generics: &ty::Generics) -> AnonTypeScope {
+        AnonTypeScope {
+            types: generics.types.map(ty::ParamTy::for_def),
+            regions: generics.regions.map(|def| def.to_early_bound_region()),
+            consts: generics.consts.map(|def| def.to_const()),
+        }
+    }
+
+    /// The identity substitutions for the generics in scope, which
+    /// the anonymized types are instantiated with.
+    pub fn fresh_substs<'tcx>(&self, tcx: &TyCtxt<'tcx>) -> &'tcx Substs<'tcx> {
+        let types = self.types.map(|param| param.to_ty(tcx));
+        tcx.mk_substs(Substs::new_with_consts(types, self.regions.clone(), self.consts.clone()))
+    }
+}
+
+/// A scope which allows anonymized types in addition to whatever the
+/// base scope allows.
+pub struct MaybeWithAnonTypes<'a, R> {
+    base_scope: R,
+    anon_scope: Option<&'a AnonTypeScope>
+}
+
+impl<'a, R: RegionScope> MaybeWithAnonTypes<'a, R> {
+    pub fn new(base_scope: R, anon_scope: Option<&'a AnonTypeScope>) -> Self {
+        MaybeWithAnonTypes {
+            base_scope: base_scope,
+            anon_scope: anon_scope
+        }
+    }
+}
+
+impl<'a, R: RegionScope> RegionScope for MaybeWithAnonTypes<'a, R> {
+    fn object_lifetime_default(&self, span: Span) -> Option<ty::Region> {
+        self.base_scope.object_lifetime_default(span)
+    }
+
+    fn anon_regions(&self,
+                    span: Span,
+                    count: usize)
+                    -> Result<Vec<ty::Region>, Option<Vec<ElisionFailureInfo>>> {
+        self.base_scope.anon_regions(span, count)
+    }
+
+    fn base_object_lifetime_default(&self, span: Span) -> ty::Region {
+        self.base_scope.base_object_lifetime_default(span)
+    }
+
+    fn anon_type_scope(&self) -> Option<&AnonTypeScope> {
+        self.anon_scope
+    }
 }
 
 // A scope in which all regions must be explicitly named. This is used
@@ -221,6 +291,10 @@ impl<'r> RegionScope for ObjectLifetimeDefaultRscope<'r> {
     {
         self.base_scope.anon_regions(span, count)
     }
+
+    fn anon_type_scope(&self) -> Option<&AnonTypeScope> {
+        self.base_scope.anon_type_scope()
+    }
 }
 
 /// A scope which simply shifts the Debruijn index of other scopes
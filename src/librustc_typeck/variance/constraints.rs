@@ -82,7 +82,7 @@ impl<'a, 'tcx, 'v> Visitor<'v> for ConstraintContext<'a, 'tcx> {
         debug!("visit_item item={}", tcx.map.node_to_string(item.id));
 
         match item.node {
-            hir::ItemEnum(..) | hir::ItemStruct(..) => {
+            hir::ItemEnum(..) | hir::ItemStruct(..) | hir::ItemUnion(..) => {
                 let scheme = tcx.lookup_item_type(did);
 
                 // Not entirely obvious: constraints on structs/enums do not
@@ -97,7 +97,7 @@ impl<'a, 'tcx, 'v> Visitor<'v> for ConstraintContext<'a, 'tcx> {
                                                  self.covariant);
                 }
             }
-            hir::ItemTrait(..) => {
+            hir::ItemTrait(..) | hir::ItemTraitAlias(..) => {
                 let trait_def = tcx.lookup_trait_def(did);
                 self.add_constraints_from_trait_ref(&trait_def.generics,
                                                     trait_def.trait_ref,
@@ -187,7 +187,9 @@ impl<'a, 'tcx> ConstraintContext<'a, 'tcx> {
                         hir::ItemTy(..) |
                         hir::ItemEnum(..) |
                         hir::ItemStruct(..) |
-                        hir::ItemTrait(..)   => is_inferred = true,
+                        hir::ItemUnion(..) |
+                        hir::ItemTrait(..) |
+                        hir::ItemTraitAlias(..) => is_inferred = true,
                         hir::ItemFn(..)      => is_inferred = false,
                         _                    => cannot_happen!(),
                     }
@@ -322,7 +324,7 @@ impl<'a, 'tcx> ConstraintContext<'a, 'tcx> {
         match ty.sty {
             ty::TyBool |
             ty::TyChar | ty::TyInt(_) | ty::TyUint(_) |
-            ty::TyFloat(_) | ty::TyStr => {
+            ty::TyFloat(_) | ty::TyStr | ty::TyNever => {
                 /* leaf type -- noop */
             }
 
@@ -336,7 +338,7 @@ impl<'a, 'tcx> ConstraintContext<'a, 'tcx> {
                 self.add_constraints_from_mt(generics, mt, variance);
             }
 
-            ty::TyBox(typ) | ty::TyArray(typ, _) | ty::TySlice(typ) => {
+            ty::TyBox(typ) | ty::TyArray(typ, _) | ty::TyArrayParam(typ, _) | ty::TySlice(typ) => {
                 self.add_constraints_from_ty(generics, typ, variance);
             }
 
@@ -366,6 +368,7 @@ impl<'a, 'tcx> ConstraintContext<'a, 'tcx> {
                 assert!(item_type.generics.types.is_empty_in(subst::FnSpace));
                 assert!(item_type.generics.regions.is_empty_in(subst::SelfSpace));
                 assert!(item_type.generics.regions.is_empty_in(subst::FnSpace));
+                assert!(item_type.generics.consts.is_empty_in(subst::FnSpace));
 
                 self.add_constraints_from_substs(
                     generics,
@@ -392,6 +395,25 @@ impl<'a, 'tcx> ConstraintContext<'a, 'tcx> {
                     trait_def.generics.regions.as_slice(),
                     trait_ref.substs,
                     variance);
+
+                // The arguments of a generic associated type are invariant.
+                for &ty in data.item_substs.types.iter() {
+                    self.add_constraints_from_ty(generics, ty, self.invariant);
+                }
+                for &region in data.item_substs.regions.iter() {
+                    self.add_constraints_from_region(generics, region, self.invariant);
+                }
+            }
+
+            ty::TyAnon(_, substs) => {
+                // The concrete type behind `impl Trait` may use its
+                // parameters in any way, so they are all invariant.
+                for &ty in substs.types.iter() {
+                    self.add_constraints_from_ty(generics, ty, self.invariant);
+                }
+                for &region in substs.regions.iter() {
+                    self.add_constraints_from_region(generics, region, self.invariant);
+                }
             }
 
             ty::TyTrait(ref data) => {
@@ -28,7 +28,7 @@ use syntax;
 use syntax::abi::Abi;
 use syntax::ast;
 use syntax::attr;
-use syntax::attr::{AttributeMethods, AttrMetaMethods};
+use syntax::attr::{AttributeMethods, AttrMetaMethods, AttrNestedMetaItemMethods};
 use syntax::codemap;
 use syntax::codemap::{DUMMY_SP, Pos, Spanned};
 use syntax::parse::token::{self, InternedString, keywords};
@@ -498,7 +498,12 @@ impl Clean<Attribute> for ast::MetaItem {
         match self.node {
             ast::MetaItemKind::Word(ref s) => Word(s.to_string()),
             ast::MetaItemKind::List(ref s, ref l) => {
-                List(s.to_string(), l.clean(cx))
+                // Literals in the list have no representation here; none of
+                // the attributes rustdoc looks at use them.
+                List(s.to_string(), l.iter()
+                                     .filter_map(|item| item.meta_item())
+                                     .map(|mi| mi.clean(cx))
+                                     .collect())
             }
             ast::MetaItemKind::NameValue(ref s, ref v) => {
                 NameValue(s.to_string(), lit_to_string(v))
@@ -531,7 +536,13 @@ impl attr::AttrMetaMethods for Attribute {
             _ => None,
         }
     }
-    fn meta_item_list<'a>(&'a self) -> Option<&'a [P<ast::MetaItem>]> { None }
+    fn meta_item_list<'a>(&'a self) -> Option<&'a [ast::NestedMetaItem]> { None }
+    fn is_word(&self) -> bool {
+        match *self {
+            Word(_) => true,
+            _ => false,
+        }
+    }
     fn span(&self) -> codemap::Span { unimplemented!() }
 }
 
@@ -1294,7 +1305,7 @@ impl Clean<Item> for hir::TraitItem {
             hir::MethodTraitItem(ref sig, None) => {
                 TyMethodItem(sig.clean(cx))
             }
-            hir::TypeTraitItem(ref bounds, ref default) => {
+            hir::TypeTraitItem(ref bounds, ref default, _) => {
                 AssociatedTypeItem(bounds.clean(cx), default.clean(cx))
             }
         };
@@ -1321,7 +1332,7 @@ impl Clean<Item> for hir::ImplItem {
             hir::ImplItemKind::Method(ref sig, _) => {
                 MethodItem(sig.clean(cx))
             }
-            hir::ImplItemKind::Type(ref ty) => TypedefItem(Typedef {
+            hir::ImplItemKind::Type(ref ty, _) => TypedefItem(Typedef {
                 type_: ty.clean(cx),
                 generics: Generics {
                     lifetimes: Vec::new(),
@@ -1480,6 +1491,9 @@ pub enum Type {
 
     // for<'a> Foo(&'a)
     PolyTraitRef(Vec<TyParamBound>),
+
+    // impl TraitA+TraitB
+    ImplTrait(Vec<TyParamBound>),
 }
 
 #[derive(Clone, RustcEncodable, RustcDecodable, PartialEq, Eq, Hash, Copy, Debug)]
@@ -1637,6 +1651,7 @@ impl Clean<Type> for hir::Ty {
             TyVec(ref ty) => Vector(box ty.clean(cx)),
             TyFixedLengthVec(ref ty, ref e) => FixedVector(box ty.clean(cx),
                                                            e.span.to_src(cx)),
+            TyNever => Bottom,
             TyTup(ref tys) => Tuple(tys.clean(cx)),
             TyPath(None, ref p) => {
                 resolve_type(cx, p.clean(cx), self.id)
@@ -1673,6 +1688,7 @@ impl Clean<Type> for hir::Ty {
             }
             TyBareFn(ref barefn) => BareFunction(box barefn.clean(cx)),
             TyPolyTraitRef(ref bounds) => PolyTraitRef(bounds.clean(cx)),
+            TyImplTrait(ref bounds) => ImplTrait(bounds.clean(cx)),
             TyInfer => Infer,
             TyTypeof(..) => panic!("Unimplemented type {:?}", self.node),
         }
@@ -1697,6 +1713,7 @@ impl<'tcx> Clean<Type> for ty::Ty<'tcx> {
             ty::TyFloat(ast::FloatTy::F32) => Primitive(F32),
             ty::TyFloat(ast::FloatTy::F64) => Primitive(F64),
             ty::TyStr => Primitive(Str),
+            ty::TyNever => Bottom,
             ty::TyBox(t) => {
                 let box_did = cx.tcx_opt().and_then(|tcx| {
                     tcx.lang_items.owned_box()
@@ -1706,6 +1723,8 @@ impl<'tcx> Clean<Type> for ty::Ty<'tcx> {
             ty::TySlice(ty) => Vector(box ty.clean(cx)),
             ty::TyArray(ty, i) => FixedVector(box ty.clean(cx),
                                               format!("{}", i)),
+            ty::TyArrayParam(ty, c) => FixedVector(box ty.clean(cx),
+                                                   format!("{}", c)),
             ty::TyRawPtr(mt) => RawPointer(mt.mutbl.clean(cx), box mt.ty.clean(cx)),
             ty::TyRef(r, mt) => BorrowedRef {
                 lifetime: r.clean(cx),
@@ -1759,6 +1778,22 @@ impl<'tcx> Clean<Type> for ty::Ty<'tcx> {
 
             ty::TyParam(ref p) => Generic(p.name.to_string()),
 
+            ty::TyAnon(def_id, _) => {
+                // Recover `impl TraitA + TraitB` from the bounds recorded
+                // for the anonymized type.
+                let tcx = cx.tcx();
+                let predicates = tcx.lookup_predicates(def_id).predicates;
+                ImplTrait(predicates.iter().filter_map(|predicate| {
+                    match *predicate {
+                        ty::Predicate::Trait(ref data)
+                            if Some(data.def_id()) != tcx.lang_items.sized_trait() => {
+                            Some(data.0.trait_ref.clean(cx))
+                        }
+                        _ => None
+                    }
+                }).collect())
+            }
+
             ty::TyClosure(..) => Tuple(vec![]), // FIXME(pcwalton)
 
             ty::TyInfer(..) => panic!("TyInfer"),
@@ -2404,7 +2439,7 @@ impl Clean<Vec<Item>> for doctree::Import {
         // #[doc(no_inline)] attribute is present.
         let denied = self.vis != hir::Public || self.attrs.iter().any(|a| {
             &a.name()[..] == "doc" && match a.meta_item_list() {
-                Some(l) => attr::contains_name(l, "no_inline"),
+                Some(l) => attr::list_contains_name(l, "no_inline"),
                 None => false,
             }
         });
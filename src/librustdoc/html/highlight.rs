@@ -159,7 +159,7 @@ fn write_source(sess: &parse::ParseSess,
             }
 
             // keywords are also included in the identifier set
-            token::Ident(ident) => {
+            token::Ident(ident, _) => {
                 match &*ident.name.as_str() {
                     "ref" | "mut" => "kw-2",
 
@@ -23,7 +23,8 @@ fn main() {
 
     let target = env::var("TARGET").unwrap();
     let host = env::var("HOST").unwrap();
-    if !target.contains("apple") && !target.contains("msvc") && !target.contains("emscripten"){
+    if !target.contains("apple") && !target.contains("msvc") &&
+       !target.contains("emscripten") && !target.contains("wasm32") {
         build_libbacktrace(&host, &target);
     }
 
@@ -18,6 +18,7 @@ use hash::{Hash, SipHasher, BuildHasher};
 use iter::FromIterator;
 use mem::{self, replace};
 use ops::{Deref, Index};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 use rand::{self, Rng};
 
 use super::table::{
@@ -1652,6 +1653,10 @@ impl<'a, K, V, S> Extend<(&'a K, &'a V)> for HashMap<K, V, S>
 /// A particular instance `RandomState` will create the same instances of
 /// `Hasher`, but the hashers created by two different `RandomState`
 /// instances are unlikely to produce the same result for the same values.
+///
+/// On `wasm32-unknown-unknown` there is no source of randomness, so every
+/// `RandomState` uses the same fixed keys and hash maps are *not* protected
+/// against collision attacks there.
 #[derive(Clone)]
 #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
 pub struct RandomState {
@@ -1664,10 +1669,20 @@ impl RandomState {
     #[inline]
     #[allow(deprecated)] // rand
     #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
     pub fn new() -> RandomState {
         let mut r = rand::thread_rng();
         RandomState { k0: r.gen(), k1: r.gen() }
     }
+
+    /// Constructs a new `RandomState` with fixed keys, as there is no
+    /// randomness available to initialize them with.
+    #[inline]
+    #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    pub fn new() -> RandomState {
+        RandomState { k0: 0x736f6d6570736575, k1: 0x646f72616e646f6d }
+    }
 }
 
 #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
@@ -17,7 +17,8 @@ use error::Error;
 use fmt::{self, Write};
 use io;
 use iter::Iterator;
-use libc;
+#[cfg(not(target_arch = "wasm32"))]
+use libc::strlen;
 use mem;
 use memchr;
 use ops;
@@ -226,7 +227,7 @@ impl CString {
     /// of the string will be recalculated from the pointer.
     #[stable(feature = "cstr_memory", since = "1.4.0")]
     pub unsafe fn from_raw(ptr: *mut c_char) -> CString {
-        let len = libc::strlen(ptr) + 1; // Including the NUL byte
+        let len = strlen(ptr) + 1; // Including the NUL byte
         let slice = slice::from_raw_parts(ptr, len as usize);
         CString { inner: mem::transmute(slice) }
     }
@@ -432,7 +433,7 @@ impl CStr {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub unsafe fn from_ptr<'a>(ptr: *const c_char) -> &'a CStr {
-        let len = libc::strlen(ptr);
+        let len = strlen(ptr);
         mem::transmute(slice::from_raw_parts(ptr, len as usize + 1))
     }
 
@@ -624,6 +625,17 @@ impl AsRef<CStr> for CString {
     }
 }
 
+// There's no libc to lean on with wasm, so count the bytes ourselves.
+#[cfg(target_arch = "wasm32")]
+unsafe fn strlen(mut ptr: *const c_char) -> usize {
+    let mut len = 0;
+    while *ptr != 0 {
+        ptr = ptr.offset(1);
+        len += 1;
+    }
+    len
+}
+
 #[cfg(test)]
 mod tests {
     use prelude::v1::*;
@@ -239,6 +239,7 @@
 #![feature(link_args)]
 #![feature(linkage)]
 #![feature(macro_reexport)]
+#![cfg_attr(not(stage0), feature(needs_panic_runtime))]
 #![cfg_attr(test, feature(map_values_mut))]
 #![feature(num_bits_bytes)]
 #![feature(old_wrapping)]
@@ -278,6 +279,10 @@
 // Don't link to std. We are std.
 #![no_std]
 
+// The panic runtime (unwinding or aborting) is chosen when linking the final
+// artifact, see `sys_common::unwind`.
+#![cfg_attr(not(stage0), needs_panic_runtime)]
+
 #![deny(missing_docs)]
 #![allow(unused_features)] // std may use features in a platform-specific way
 #![cfg_attr(not(stage0), deny(warnings))]
@@ -300,6 +305,11 @@ extern crate alloc;
 extern crate rustc_unicode;
 extern crate libc;
 
+// The stage0 compiler does not know how to inject a panic runtime, so link
+// the unwinding one explicitly.
+#[cfg(stage0)]
+extern crate panic_unwind;
+
 #[cfg(stage0)]
 extern crate alloc_system;
 
@@ -439,6 +449,8 @@ mod memchr;
 #[path = "sys/unix/mod.rs"] mod sys;
 #[cfg(windows)]
 #[path = "sys/windows/mod.rs"] mod sys;
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[path = "sys/wasm/mod.rs"] mod sys;
 
 pub mod rt;
 mod panicking;
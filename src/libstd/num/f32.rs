@@ -20,7 +20,7 @@ use core::num;
 #[cfg(not(test))]
 use intrinsics;
 #[cfg(not(test))]
-use libc::c_int;
+use os::raw::c_int;
 #[cfg(not(test))]
 use num::FpCategory;
 
@@ -38,7 +38,7 @@ pub use core::f32::consts;
 
 #[allow(dead_code)]
 mod cmath {
-    use libc::{c_float, c_int};
+    use os::raw::{c_float, c_int};
 
     extern {
         pub fn cbrtf(n: c_float) -> c_float;
@@ -82,7 +82,7 @@ mod cmath {
     pub use self::shims::*;
     #[cfg(target_env = "msvc")]
     mod shims {
-        use libc::{c_float, c_int};
+        use os::raw::{c_float, c_int};
 
         #[inline]
         pub unsafe fn acosf(n: c_float) -> c_float {
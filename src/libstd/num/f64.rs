@@ -20,7 +20,7 @@ use core::num;
 #[cfg(not(test))]
 use intrinsics;
 #[cfg(not(test))]
-use libc::c_int;
+use os::raw::c_int;
 #[cfg(not(test))]
 use num::FpCategory;
 
@@ -37,7 +37,7 @@ pub use core::f64::consts;
 
 #[allow(dead_code)]
 mod cmath {
-    use libc::{c_double, c_int};
+    use os::raw::{c_double, c_int};
 
     #[link_name = "m"]
     extern {
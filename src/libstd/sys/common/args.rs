@@ -85,7 +85,8 @@ mod imp {
 
 #[cfg(any(target_os = "macos",
           target_os = "ios",
-          target_os = "windows"))]
+          target_os = "windows",
+          all(target_arch = "wasm32", target_os = "unknown")))]
 mod imp {
     use vec::Vec;
 
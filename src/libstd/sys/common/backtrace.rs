@@ -8,11 +8,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-#![cfg_attr(target_os = "nacl", allow(dead_code))]
+#![cfg_attr(any(target_os = "nacl", target_arch = "wasm32"), allow(dead_code))]
 
 use env;
 use io::prelude::*;
 use io;
+#[cfg(not(target_arch = "wasm32"))]
 use libc;
 use str;
 use sync::atomic::{self, Ordering};
@@ -44,6 +45,7 @@ pub fn log_enabled() -> bool {
 }
 
 // These output functions should now be used everywhere to ensure consistency.
+#[cfg(not(target_arch = "wasm32"))]
 pub fn output(w: &mut Write, idx: isize, addr: *mut libc::c_void,
               s: Option<&[u8]>) -> io::Result<()> {
     write!(w, "  {:2}: {:2$?} - ", idx, addr, HEX_WIDTH)?;
@@ -55,6 +57,7 @@ pub fn output(w: &mut Write, idx: isize, addr: *mut libc::c_void,
 }
 
 #[allow(dead_code)]
+#[cfg(not(target_arch = "wasm32"))]
 pub fn output_fileline(w: &mut Write, file: &[u8], line: libc::c_int,
                        more: bool) -> io::Result<()> {
     let file = str::from_utf8(file).unwrap_or("<unknown>");
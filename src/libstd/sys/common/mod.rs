@@ -30,14 +30,14 @@ pub mod args;
 pub mod at_exit_imp;
 pub mod backtrace;
 pub mod condvar;
-pub mod dwarf;
 pub mod io;
-pub mod libunwind;
 pub mod mutex;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod net;
 pub mod poison;
 pub mod remutex;
 pub mod rwlock;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod thread;
 pub mod thread_info;
 pub mod thread_local;
@@ -45,6 +45,11 @@ pub mod unwind;
 pub mod util;
 pub mod wtf8;
 
+// There are no sockets to build on top of with wasm, so its sys module
+// provides the (unsupported) networking types directly.
+#[cfg(target_arch = "wasm32")]
+pub use sys::net;
+
 #[cfg(any(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "emscripten"))),
           all(windows, target_env = "gnu")))]
 pub mod gnu;
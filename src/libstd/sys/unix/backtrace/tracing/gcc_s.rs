@@ -135,6 +135,26 @@ mod uw {
             extern fn(ctx: *mut _Unwind_Context,
                       arg: *mut libc::c_void) -> _Unwind_Reason_Code;
 
+    // The unwinder is also linked by the `panic_unwind` crate, but that crate
+    // isn't linked at all with `-C panic=abort`, so name it here as well.
+    #[cfg_attr(any(all(target_os = "linux", not(target_env = "musl")),
+                   target_os = "freebsd",
+                   target_os = "solaris",
+                   all(target_os = "linux",
+                       target_env = "musl",
+                       not(target_arch = "x86"),
+                       not(target_arch = "x86_64"))),
+               link(name = "gcc_s"))]
+    #[cfg_attr(any(target_os = "android", target_os = "openbsd"),
+               link(name = "gcc"))]
+    #[cfg_attr(all(target_os = "netbsd", not(target_vendor = "rumprun")),
+               link(name = "gcc"))]
+    #[cfg_attr(all(target_os = "netbsd", target_vendor = "rumprun"),
+               link(name = "unwind"))]
+    #[cfg_attr(target_os = "dragonfly",
+               link(name = "gcc_pic"))]
+    #[cfg_attr(target_os = "bitrig",
+               link(name = "c++abi"))]
     extern {
         // No native _Unwind_Backtrace on iOS
         #[cfg(not(all(target_os = "ios", target_arch = "arm")))]
@@ -0,0 +1,68 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! System bindings for the wasm32-unknown-unknown target.
+//!
+//! WebAssembly modules built for this target run on the bare machine: there's
+//! no libc, no threads, no filesystem and no networking. Everything here is
+//! either implemented on top of the wasm instruction set itself or returns an
+//! "unsupported" error, so that the rest of the standard library can still be
+//! compiled and used for its platform-independent parts.
+
+#![allow(missing_docs, bad_style)]
+
+use io::{self, ErrorKind};
+
+pub mod backtrace;
+pub mod condvar;
+pub mod fs;
+pub mod mutex;
+pub mod net;
+pub mod os;
+#[path = "../unix/os_str.rs"]
+pub mod os_str;
+pub mod pipe;
+pub mod process;
+pub mod rand;
+pub mod rwlock;
+pub mod stack_overflow;
+pub mod stdio;
+pub mod thread;
+pub mod thread_local;
+pub mod time;
+
+#[cfg(not(test))]
+pub fn init() {
+}
+
+pub fn unsupported<T>() -> io::Result<T> {
+    Err(unsupported_err())
+}
+
+pub fn unsupported_err() -> io::Error {
+    io::Error::new(ErrorKind::Other,
+                   "operation not supported on wasm32-unknown-unknown")
+}
+
+pub fn decode_error_kind(_code: i32) -> ErrorKind {
+    ErrorKind::Other
+}
+
+/// An uninhabited type, used for the handles of resources which can never be
+/// created on this platform (files, sockets, processes, ...).
+pub enum Void {}
+
+impl Clone for Void {
+    fn clone(&self) -> Void {
+        match *self {}
+    }
+}
+
+impl Copy for Void {}
@@ -0,0 +1,38 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use io;
+use rand::Rng;
+use sys::{unsupported, Void};
+
+// There's no source of entropy available to a wasm module on its own, so an
+// `OsRng` can never be created here. `RandomState` knows about this and falls
+// back to fixed keys instead of failing.
+
+pub struct OsRng(Void);
+
+impl OsRng {
+    /// Create a new `OsRng`.
+    pub fn new() -> io::Result<OsRng> {
+        unsupported()
+    }
+}
+
+impl Rng for OsRng {
+    fn next_u32(&mut self) -> u32 {
+        match self.0 {}
+    }
+    fn next_u64(&mut self) -> u64 {
+        match self.0 {}
+    }
+    fn fill_bytes(&mut self, _v: &mut [u8]) {
+        match self.0 {}
+    }
+}
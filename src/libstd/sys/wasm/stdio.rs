@@ -0,0 +1,78 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use prelude::v1::*;
+
+use io;
+
+// Standard input is always empty. Standard output and error are forwarded to
+// the embedder through the `rust_wasm_write` import, which receives the file
+// descriptor (1 or 2) along with the bytes to write and returns how many of
+// them it consumed, or a negative number on failure.
+
+extern {
+    fn rust_wasm_write(fd: u32, data: *const u8, len: usize) -> isize;
+}
+
+fn write(fd: u32, data: &[u8]) -> io::Result<usize> {
+    let ret = unsafe { rust_wasm_write(fd, data.as_ptr(), data.len()) };
+    if ret < 0 {
+        Err(io::Error::new(io::ErrorKind::Other,
+                           "the embedder failed to write to the stream"))
+    } else {
+        Ok(ret as usize)
+    }
+}
+
+pub struct Stdin(());
+pub struct Stdout(());
+pub struct Stderr(());
+
+impl Stdin {
+    pub fn new() -> io::Result<Stdin> { Ok(Stdin(())) }
+
+    pub fn read(&self, _data: &mut [u8]) -> io::Result<usize> {
+        Ok(0)
+    }
+
+    pub fn read_to_end(&self, _buf: &mut Vec<u8>) -> io::Result<usize> {
+        Ok(0)
+    }
+}
+
+impl Stdout {
+    pub fn new() -> io::Result<Stdout> { Ok(Stdout(())) }
+
+    pub fn write(&self, data: &[u8]) -> io::Result<usize> {
+        write(1, data)
+    }
+}
+
+impl Stderr {
+    pub fn new() -> io::Result<Stderr> { Ok(Stderr(())) }
+
+    pub fn write(&self, data: &[u8]) -> io::Result<usize> {
+        write(2, data)
+    }
+}
+
+// FIXME: right now this raw stderr handle is used in a few places because
+//        std::io::stderr_raw isn't exposed, but once that's exposed this impl
+//        should go away
+impl io::Write for Stderr {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        Stderr::write(self, data)
+    }
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+pub fn is_ebadf(_err: &io::Error) -> bool {
+    false
+}
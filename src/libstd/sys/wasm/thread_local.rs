@@ -0,0 +1,52 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)] // not used on all platforms
+
+use boxed::Box;
+use collections::BTreeMap;
+use ptr;
+
+// With only one thread around, a TLS key is just an index into a map of
+// values. Keys are never reused and destructors never run as the thread
+// never exits.
+
+pub type Key = usize;
+
+static mut KEYS: *mut BTreeMap<Key, *mut u8> = 0 as *mut _;
+static mut NEXT_KEY: Key = 0;
+
+unsafe fn keys() -> &'static mut BTreeMap<Key, *mut u8> {
+    if KEYS.is_null() {
+        KEYS = Box::into_raw(Box::new(BTreeMap::new()));
+    }
+    &mut *KEYS
+}
+
+#[inline]
+pub unsafe fn create(_dtor: Option<unsafe extern fn(*mut u8)>) -> Key {
+    NEXT_KEY += 1;
+    NEXT_KEY
+}
+
+#[inline]
+pub unsafe fn set(key: Key, value: *mut u8) {
+    keys().insert(key, value);
+}
+
+#[inline]
+pub unsafe fn get(key: Key) -> *mut u8 {
+    keys().get(&key).cloned().unwrap_or(ptr::null_mut())
+}
+
+#[inline]
+pub unsafe fn destroy(key: Key) {
+    keys().remove(&key);
+}
@@ -0,0 +1,100 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use time::Duration;
+
+// There's no clock to read on this target, but the types are still useful to
+// do arithmetic on, so they're represented as a plain timestamp since some
+// arbitrary epoch.
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Timespec {
+    secs: u64,
+    nanos: u32,
+}
+
+impl Timespec {
+    fn to_duration(&self) -> Duration {
+        Duration::new(self.secs, self.nanos)
+    }
+
+    fn from_duration(dur: Duration) -> Timespec {
+        Timespec { secs: dur.as_secs(), nanos: dur.subsec_nanos() }
+    }
+
+    fn sub_timespec(&self, other: &Timespec) -> Result<Duration, Duration> {
+        if self >= other {
+            Ok(self.to_duration() - other.to_duration())
+        } else {
+            Err(other.to_duration() - self.to_duration())
+        }
+    }
+
+    fn add_duration(&self, other: &Duration) -> Timespec {
+        Timespec::from_duration(self.to_duration() + *other)
+    }
+
+    fn sub_duration(&self, other: &Duration) -> Timespec {
+        Timespec::from_duration(self.to_duration() - *other)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Instant {
+    t: Timespec,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct SystemTime {
+    t: Timespec,
+}
+
+pub const UNIX_EPOCH: SystemTime = SystemTime {
+    t: Timespec { secs: 0, nanos: 0 },
+};
+
+impl Instant {
+    pub fn now() -> Instant {
+        panic!("time not implemented on wasm32-unknown-unknown")
+    }
+
+    pub fn sub_instant(&self, other: &Instant) -> Duration {
+        self.t.sub_timespec(&other.t).unwrap_or_else(|_| {
+            panic!("other was less than the current instant")
+        })
+    }
+
+    pub fn add_duration(&self, other: &Duration) -> Instant {
+        Instant { t: self.t.add_duration(other) }
+    }
+
+    pub fn sub_duration(&self, other: &Duration) -> Instant {
+        Instant { t: self.t.sub_duration(other) }
+    }
+}
+
+impl SystemTime {
+    pub fn now() -> SystemTime {
+        panic!("time not implemented on wasm32-unknown-unknown")
+    }
+
+    pub fn sub_time(&self, other: &SystemTime)
+                    -> Result<Duration, Duration> {
+        self.t.sub_timespec(&other.t)
+    }
+
+    pub fn add_duration(&self, other: &Duration) -> SystemTime {
+        SystemTime { t: self.t.add_duration(other) }
+    }
+
+    pub fn sub_duration(&self, other: &Duration) -> SystemTime {
+        SystemTime { t: self.t.sub_duration(other) }
+    }
+}
@@ -249,6 +249,7 @@ impl PathParameters {
         PathParameters::AngleBracketed(AngleBracketedParameterData {
             lifetimes: Vec::new(),
             types: P::new(),
+            consts: P::new(),
             bindings: P::new(),
         })
     }
@@ -303,6 +304,17 @@ impl PathParameters {
         }
     }
 
+    pub fn consts(&self) -> Vec<&P<Expr>> {
+        match *self {
+            PathParameters::AngleBracketed(ref data) => {
+                data.consts.iter().collect()
+            }
+            PathParameters::Parenthesized(_) => {
+                Vec::new()
+            }
+        }
+    }
+
     pub fn bindings(&self) -> Vec<&TypeBinding> {
         match *self {
             PathParameters::AngleBracketed(ref data) => {
@@ -322,6 +334,9 @@ pub struct AngleBracketedParameterData {
     pub lifetimes: Vec<Lifetime>,
     /// The type parameters for this path segment, if present.
     pub types: P<[P<Ty>]>,
+    /// The const parameters for this path segment, if present.
+    /// e.g., the `3` in `Foo<T, 3>`.
+    pub consts: P<[P<Expr>]>,
     /// Bindings (equality constraints) on associated types, if present.
     /// e.g., `Foo<A=Bar>`.
     pub bindings: P<[TypeBinding]>,
@@ -329,7 +344,8 @@ pub struct AngleBracketedParameterData {
 
 impl AngleBracketedParameterData {
     fn is_empty(&self) -> bool {
-        self.lifetimes.is_empty() && self.types.is_empty() && self.bindings.is_empty()
+        self.lifetimes.is_empty() && self.types.is_empty() &&
+            self.consts.is_empty() && self.bindings.is_empty()
     }
 }
 
@@ -396,12 +412,23 @@ pub struct TyParam {
     pub span: Span
 }
 
-/// Represents lifetimes and type parameters attached to a declaration
-/// of a function, enum, trait, etc.
+/// A const generic parameter, e.g. `const N: usize` in
+/// `struct Foo<T, const N: usize>`.
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub struct ConstParam {
+    pub ident: Ident,
+    pub id: NodeId,
+    pub ty: P<Ty>,
+    pub span: Span
+}
+
+/// Represents lifetimes, type and const parameters attached to a
+/// declaration of a function, enum, trait, etc.
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub struct Generics {
     pub lifetimes: Vec<LifetimeDef>,
     pub ty_params: P<[TyParam]>,
+    pub const_params: P<[ConstParam]>,
     pub where_clause: WhereClause,
 }
 
@@ -412,8 +439,12 @@ impl Generics {
     pub fn is_type_parameterized(&self) -> bool {
         !self.ty_params.is_empty()
     }
+    pub fn is_const_parameterized(&self) -> bool {
+        !self.const_params.is_empty()
+    }
     pub fn is_parameterized(&self) -> bool {
-        self.is_lt_parameterized() || self.is_type_parameterized()
+        self.is_lt_parameterized() || self.is_type_parameterized() ||
+            self.is_const_parameterized()
     }
 }
 
@@ -422,6 +453,7 @@ impl Default for Generics {
         Generics {
             lifetimes: Vec::new(),
             ty_params: P::new(),
+            const_params: P::new(),
             where_clause: WhereClause {
                 id: DUMMY_NODE_ID,
                 predicates: Vec::new(),
@@ -490,12 +522,54 @@ pub struct Crate {
     pub exported_macros: Vec<MacroDef>,
 }
 
+/// Possible values inside of compile-time attribute lists.
+///
+/// E.g. the '..' in `#[name(..)]`.
+pub type NestedMetaItem = Spanned<NestedMetaItemKind>;
+
+#[derive(Clone, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub enum NestedMetaItemKind {
+    /// A full MetaItem, for recursive meta items.
+    MetaItem(P<MetaItem>),
+    /// A literal.
+    ///
+    /// E.g. "foo", 64, true
+    Literal(Lit),
+}
+
+// can't be derived because spans are ignored when comparing meta items
+impl PartialEq for NestedMetaItemKind {
+    fn eq(&self, other: &NestedMetaItemKind) -> bool {
+        use self::NestedMetaItemKind::*;
+        match (self, other) {
+            (&MetaItem(ref ms), &MetaItem(ref mo)) => ms.node == mo.node,
+            (&Literal(ref ls), &Literal(ref lo)) => ls.node == lo.node,
+            _ => false
+        }
+    }
+}
+
+/// A spanned compile-time attribute item.
+///
+/// E.g. `#[test]`, `#[derive(..)]` or `#[feature = "foo"]`
 pub type MetaItem = Spanned<MetaItemKind>;
 
+/// A compile-time attribute item.
+///
+/// E.g. `#[test]`, `#[derive(..)]` or `#[feature = "foo"]`
 #[derive(Clone, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub enum MetaItemKind {
+    /// Word meta item.
+    ///
+    /// E.g. `test` as in `#[test]`
     Word(InternedString),
-    List(InternedString, Vec<P<MetaItem>>),
+    /// List meta item.
+    ///
+    /// E.g. `derive(..)` as in `#[derive(..)]`
+    List(InternedString, Vec<NestedMetaItem>),
+    /// Name value meta item.
+    ///
+    /// E.g. `feature = "foo"` as in `#[feature = "foo"]`
     NameValue(InternedString, Lit),
 }
 
@@ -867,6 +941,8 @@ pub struct Field {
     pub ident: SpannedIdent,
     pub expr: P<Expr>,
     pub span: Span,
+    /// Whether the field was written as just `ident`, standing for `ident: ident`
+    pub is_shorthand: bool,
 }
 
 pub type SpannedIdent = Spanned<Ident>;
@@ -1018,8 +1094,8 @@ pub enum ExprKind {
 
     /// A referencing operation (`&a` or `&mut a`)
     AddrOf(Mutability, P<Expr>),
-    /// A `break`, with an optional label to break
-    Break(Option<SpannedIdent>),
+    /// A `break`, with an optional label to break, and an optional expression
+    Break(Option<SpannedIdent>, Option<P<Expr>>),
     /// A `continue`, with an optional label
     Again(Option<SpannedIdent>),
     /// A `return`, with an optional value to be returned
@@ -1048,6 +1124,13 @@ pub enum ExprKind {
 
     /// `expr?`
     Try(P<Expr>),
+
+    /// A `do catch { ... }` block, to which `?` inside it propagates errors
+    Catch(P<Block>),
+
+    /// Placeholder for an expression that failed to parse, synthesised by
+    /// the parser in error-recovery mode
+    Err,
 }
 
 /// The explicit Self type in a "qualified path". The actual
@@ -1208,7 +1291,7 @@ impl TokenTree {
                 TokenTree::Delimited(sp, Rc::new(Delimited {
                     delim: token::Bracket,
                     open_span: sp,
-                    tts: vec![TokenTree::Token(sp, token::Ident(token::str_to_ident("doc"))),
+                    tts: vec![TokenTree::Token(sp, token::Ident(token::str_to_ident("doc"), false)),
                               TokenTree::Token(sp, token::Eq),
                               TokenTree::Token(sp, token::Literal(
                                   token::StrRaw(token::intern(&stripped), num_of_hashes), None))],
@@ -1225,14 +1308,15 @@ impl TokenTree {
                 delimed.tts[index - 1].clone()
             }
             (&TokenTree::Token(sp, token::SpecialVarNt(var)), _) => {
+                let ident = token::str_to_ident(var.as_str());
                 let v = [TokenTree::Token(sp, token::Dollar),
-                         TokenTree::Token(sp, token::Ident(token::str_to_ident(var.as_str())))];
+                         TokenTree::Token(sp, token::Ident(ident, false))];
                 v[index].clone()
             }
             (&TokenTree::Token(sp, token::MatchNt(name, kind)), _) => {
                 let v = [TokenTree::Token(sp, token::SubstNt(name)),
                          TokenTree::Token(sp, token::Colon),
-                         TokenTree::Token(sp, token::Ident(kind))];
+                         TokenTree::Token(sp, token::Ident(kind, false))];
                 v[index].clone()
             }
             (&TokenTree::Sequence(_, ref seq), _) => {
@@ -1327,6 +1411,30 @@ impl LitKind {
             _ => false,
         }
     }
+
+    /// Returns true if this literal has no suffix. Note: this will return true
+    /// for literals with prefixes such as raw strings and byte strings.
+    pub fn is_unsuffixed(&self) -> bool {
+        match *self {
+            // unsuffixed variants
+            LitKind::Str(..) |
+            LitKind::ByteStr(..) |
+            LitKind::Byte(..) |
+            LitKind::Char(..) |
+            LitKind::Int(_, LitIntType::Unsuffixed) |
+            LitKind::FloatUnsuffixed(..) |
+            LitKind::Bool(..) => true,
+            // suffixed variants
+            LitKind::Int(_, LitIntType::Signed(..)) |
+            LitKind::Int(_, LitIntType::Unsigned(..)) |
+            LitKind::Float(..) => false,
+        }
+    }
+
+    /// Returns true if this literal has a suffix.
+    pub fn is_suffixed(&self) -> bool {
+        !self.is_unsuffixed()
+    }
 }
 
 // NB: If you change this, you'll probably want to change the corresponding
@@ -1366,7 +1474,7 @@ pub struct TraitItem {
 pub enum TraitItemKind {
     Const(P<Ty>, Option<P<Expr>>),
     Method(MethodSig, Option<P<Block>>),
-    Type(TyParamBounds, Option<P<Ty>>),
+    Type(TyParamBounds, Option<P<Ty>>, Generics),
 }
 
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
@@ -1384,7 +1492,7 @@ pub struct ImplItem {
 pub enum ImplItemKind {
     Const(P<Ty>, P<Expr>),
     Method(MethodSig, P<Block>),
-    Type(P<Ty>),
+    Type(P<Ty>, Generics),
     Macro(Mac),
 }
 
@@ -1579,6 +1687,8 @@ pub enum TyKind {
     Rptr(Option<Lifetime>, MutTy),
     /// A bare function (e.g. `fn(usize) -> bool`)
     BareFn(P<BareFnTy>),
+    /// The never type (`!`)
+    Never,
     /// A tuple (`(A, B, C, D,...)`)
     Tup(Vec<P<Ty>> ),
     /// A path (`module::module::...::Type`), optionally
@@ -1590,6 +1700,9 @@ pub enum TyKind {
     ObjectSum(P<Ty>, TyParamBounds),
     /// A type like `for<'a> Foo<&'a Bar>`
     PolyTraitRef(TyParamBounds),
+    /// An `impl Bound1 + Bound2 + Bound3` type
+    /// where `Bound` is a trait or a lifetime.
+    ImplTrait(TyParamBounds),
     /// No-op; kept solely so that we can pretty-print faithfully
     Paren(P<Ty>),
     /// Unused for now
@@ -1991,11 +2104,15 @@ pub enum ItemKind {
     Enum(EnumDef, Generics),
     /// A struct definition, e.g. `struct Foo<A> {x: A}`
     Struct(VariantData, Generics),
+    /// A union definition, e.g. `union Foo<A, B> {x: A, y: B}`
+    Union(VariantData, Generics),
     /// Represents a Trait Declaration
     Trait(Unsafety,
               Generics,
               TyParamBounds,
               Vec<TraitItem>),
+    /// A trait alias, e.g. `trait Foo = Bar + Quux;`
+    TraitAlias(Generics, TyParamBounds),
 
     // Default trait implementations
     ///
@@ -2004,6 +2121,7 @@ pub enum ItemKind {
     /// An implementation, eg `impl<A> Trait for Foo { .. }`
     Impl(Unsafety,
              ImplPolarity,
+             Defaultness,
              Generics,
              Option<TraitRef>, // (optional) trait this impl implements
              P<Ty>, // self
@@ -2025,7 +2143,9 @@ impl ItemKind {
             ItemKind::Ty(..) => "type alias",
             ItemKind::Enum(..) => "enum",
             ItemKind::Struct(..) => "struct",
+            ItemKind::Union(..) => "union",
             ItemKind::Trait(..) => "trait",
+            ItemKind::TraitAlias(..) => "trait alias",
             ItemKind::Mac(..) |
             ItemKind::Impl(..) |
             ItemKind::DefaultImpl(..) => "item"
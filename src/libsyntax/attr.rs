@@ -16,6 +16,7 @@ pub use self::IntType::*;
 
 use ast;
 use ast::{AttrId, Attribute, Attribute_, MetaItem, MetaItemKind};
+use ast::{NestedMetaItem, NestedMetaItemKind, Lit};
 use ast::{Stmt, StmtKind, DeclKind};
 use ast::{Expr, Item, Local, Decl};
 use codemap::{Span, Spanned, spanned, dummy_spanned};
@@ -70,11 +71,110 @@ pub trait AttrMetaMethods {
     /// containing a string, otherwise None.
     fn value_str(&self) -> Option<InternedString>;
     /// Gets a list of inner meta items from a list MetaItem type.
-    fn meta_item_list(&self) -> Option<&[P<MetaItem>]>;
+    fn meta_item_list(&self) -> Option<&[NestedMetaItem]>;
+
+    /// Returns `true` if the meta item is a word, e.g. `#[test]`.
+    fn is_word(&self) -> bool;
+
+    /// Returns `true` if the meta item is a `name = "string"` pair.
+    fn is_value_str(&self) -> bool {
+        self.value_str().is_some()
+    }
+
+    /// Returns `true` if the meta item is a list, e.g. `#[derive(..)]`.
+    fn is_meta_item_list(&self) -> bool {
+        self.meta_item_list().is_some()
+    }
 
     fn span(&self) -> Span;
 }
 
+/// Accessors for the items of a meta item list, which are either meta items
+/// themselves or literals.
+pub trait AttrNestedMetaItemMethods {
+    /// Returns true if this list item is a meta item with a name of `name`.
+    fn check_name(&self, name: &str) -> bool {
+        self.meta_item().map_or(false, |meta_item| meta_item.check_name(name))
+    }
+
+    /// Returns the name of the meta item, e.g. `foo` in `#[foo]`,
+    /// `#[foo="bar"]` and `#[foo(bar)]`, if self is a meta item.
+    fn name(&self) -> Option<InternedString> {
+        self.meta_item().map(|meta_item| meta_item.name())
+    }
+
+    /// Returns the meta item if self is a NestedMetaItemKind::MetaItem.
+    fn meta_item(&self) -> Option<&P<MetaItem>>;
+
+    /// Returns the literal if self is a NestedMetaItemKind::Literal.
+    fn literal(&self) -> Option<&Lit>;
+
+    /// Gets the string value if self is a meta item that is a
+    /// MetaItemKind::NameValue variant containing a string, otherwise None.
+    fn value_str(&self) -> Option<InternedString> {
+        self.meta_item().and_then(|meta_item| meta_item.value_str())
+    }
+
+    /// Returns the meta item if self is a meta item that is a word.
+    fn word(&self) -> Option<&P<MetaItem>> {
+        self.meta_item().and_then(|meta_item| if meta_item.is_word() {
+            Some(meta_item)
+        } else {
+            None
+        })
+    }
+
+    /// Gets a list of inner meta items if self is a list meta item.
+    fn meta_item_list(&self) -> Option<&[NestedMetaItem]> {
+        self.meta_item().and_then(|meta_item| meta_item.meta_item_list())
+    }
+
+    /// Returns `true` if self is a meta item.
+    fn is_meta_item(&self) -> bool {
+        self.meta_item().is_some()
+    }
+
+    /// Returns `true` if self is a literal.
+    fn is_literal(&self) -> bool {
+        self.literal().is_some()
+    }
+
+    /// Returns `true` if self is a meta item that is a word.
+    fn is_word(&self) -> bool {
+        self.word().is_some()
+    }
+
+    /// Returns `true` if self is a meta item that is a `name = "string"` pair.
+    fn is_value_str(&self) -> bool {
+        self.value_str().is_some()
+    }
+
+    /// Returns `true` if self is a meta item that is a list.
+    fn is_meta_item_list(&self) -> bool {
+        self.meta_item_list().is_some()
+    }
+
+    fn span(&self) -> Span;
+}
+
+impl AttrNestedMetaItemMethods for NestedMetaItem {
+    fn meta_item(&self) -> Option<&P<MetaItem>> {
+        match self.node {
+            NestedMetaItemKind::MetaItem(ref item) => Some(item),
+            _ => None
+        }
+    }
+
+    fn literal(&self) -> Option<&Lit> {
+        match self.node {
+            NestedMetaItemKind::Literal(ref lit) => Some(lit),
+            _ => None
+        }
+    }
+
+    fn span(&self) -> Span { self.span }
+}
+
 impl AttrMetaMethods for Attribute {
     fn check_name(&self, name: &str) -> bool {
         let matches = name == &self.name()[..];
@@ -87,9 +187,10 @@ impl AttrMetaMethods for Attribute {
     fn value_str(&self) -> Option<InternedString> {
         self.meta().value_str()
     }
-    fn meta_item_list(&self) -> Option<&[P<MetaItem>]> {
+    fn meta_item_list(&self) -> Option<&[NestedMetaItem]> {
         self.node.value.meta_item_list()
     }
+    fn is_word(&self) -> bool { self.meta().is_word() }
     fn span(&self) -> Span { self.meta().span }
 }
 
@@ -114,12 +215,20 @@ impl AttrMetaMethods for MetaItem {
         }
     }
 
-    fn meta_item_list(&self) -> Option<&[P<MetaItem>]> {
+    fn meta_item_list(&self) -> Option<&[NestedMetaItem]> {
         match self.node {
             MetaItemKind::List(_, ref l) => Some(&l[..]),
             _ => None
         }
     }
+
+    fn is_word(&self) -> bool {
+        match self.node {
+            MetaItemKind::Word(_) => true,
+            _ => false
+        }
+    }
+
     fn span(&self) -> Span { self.span }
 }
 
@@ -127,9 +236,10 @@ impl AttrMetaMethods for MetaItem {
 impl AttrMetaMethods for P<MetaItem> {
     fn name(&self) -> InternedString { (**self).name() }
     fn value_str(&self) -> Option<InternedString> { (**self).value_str() }
-    fn meta_item_list(&self) -> Option<&[P<MetaItem>]> {
+    fn meta_item_list(&self) -> Option<&[NestedMetaItem]> {
         (**self).meta_item_list()
     }
+    fn is_word(&self) -> bool { (**self).is_word() }
     fn span(&self) -> Span { (**self).span() }
 }
 
@@ -182,10 +292,14 @@ pub fn mk_name_value_item(name: InternedString, value: ast::Lit)
     P(dummy_spanned(MetaItemKind::NameValue(name, value)))
 }
 
-pub fn mk_list_item(name: InternedString, items: Vec<P<MetaItem>>) -> P<MetaItem> {
+pub fn mk_list_item(name: InternedString, items: Vec<NestedMetaItem>) -> P<MetaItem> {
     P(dummy_spanned(MetaItemKind::List(name, items)))
 }
 
+pub fn mk_list_word_item(name: InternedString) -> NestedMetaItem {
+    dummy_spanned(NestedMetaItemKind::MetaItem(mk_word_item(name)))
+}
+
 pub fn mk_word_item(name: InternedString) -> P<MetaItem> {
     P(dummy_spanned(MetaItemKind::Word(name)))
 }
@@ -255,6 +369,14 @@ pub fn contains_name<AM: AttrMetaMethods>(metas: &[AM], name: &str) -> bool {
     })
 }
 
+pub fn list_contains_name<AM: AttrNestedMetaItemMethods>(items: &[AM], name: &str) -> bool {
+    debug!("attr::list_contains_name (name={})", name);
+    items.iter().any(|item| {
+        debug!("  testing: {:?}", item.name());
+        item.check_name(name)
+    })
+}
+
 pub fn first_attr_value_str_by_name(attrs: &[Attribute], name: &str)
                                  -> Option<InternedString> {
     attrs.iter()
@@ -285,7 +407,7 @@ pub fn sort_meta_items(items: Vec<P<MetaItem>>) -> Vec<P<MetaItem>> {
     v.into_iter().map(|(_, m)| m.map(|Spanned {node, span}| {
         Spanned {
             node: match node {
-                MetaItemKind::List(n, mis) => MetaItemKind::List(n, sort_meta_items(mis)),
+                MetaItemKind::List(n, mis) => MetaItemKind::List(n, sort_nested_meta_items(mis)),
                 _ => node
             },
             span: span
@@ -293,6 +415,22 @@ pub fn sort_meta_items(items: Vec<P<MetaItem>>) -> Vec<P<MetaItem>> {
     })).collect()
 }
 
+/// Sorts the meta items of a list like `sort_meta_items`, keeping any
+/// literals in their original order ahead of them.
+fn sort_nested_meta_items(items: Vec<NestedMetaItem>) -> Vec<NestedMetaItem> {
+    let (literals, meta_items): (Vec<_>, Vec<_>) = items.into_iter().partition(|item| {
+        item.is_literal()
+    });
+    let meta_items = meta_items.into_iter().filter_map(|item| match item.node {
+        NestedMetaItemKind::MetaItem(mi) => Some(mi),
+        NestedMetaItemKind::Literal(_) => None,
+    }).collect();
+    literals.into_iter().chain(sort_meta_items(meta_items).into_iter().map(|mi| {
+        let span = mi.span;
+        Spanned { node: NestedMetaItemKind::MetaItem(mi), span: span }
+    })).collect()
+}
+
 pub fn find_crate_name(attrs: &[Attribute]) -> Option<InternedString> {
     first_attr_value_str_by_name(attrs, "crate_name")
 }
@@ -342,12 +480,12 @@ pub fn find_inline_attr(diagnostic: Option<&Handler>, attrs: &[Attribute]) -> In
                 if items.len() != 1 {
                     diagnostic.map(|d|{ d.span_err(attr.span, "expected one argument"); });
                     InlineAttr::None
-                } else if contains_name(&items[..], "always") {
+                } else if list_contains_name(&items[..], "always") {
                     InlineAttr::Always
-                } else if contains_name(&items[..], "never") {
+                } else if list_contains_name(&items[..], "never") {
                     InlineAttr::Never
                 } else {
-                    diagnostic.map(|d|{ d.span_err((*items[0]).span, "invalid argument"); });
+                    diagnostic.map(|d|{ d.span_err(items[0].span, "invalid argument"); });
                     InlineAttr::None
                 }
             }
@@ -369,25 +507,42 @@ pub fn cfg_matches<T: CfgDiag>(cfgs: &[P<MetaItem>],
                            cfg: &ast::MetaItem,
                            diag: &mut T) -> bool {
     match cfg.node {
-        ast::MetaItemKind::List(ref pred, ref mis) if &pred[..] == "any" =>
-            mis.iter().any(|mi| cfg_matches(cfgs, &mi, diag)),
-        ast::MetaItemKind::List(ref pred, ref mis) if &pred[..] == "all" =>
-            mis.iter().all(|mi| cfg_matches(cfgs, &mi, diag)),
-        ast::MetaItemKind::List(ref pred, ref mis) if &pred[..] == "not" => {
-            if mis.len() != 1 {
-                diag.emit_error(|diagnostic| {
-                    diagnostic.span_err(cfg.span, "expected 1 cfg-pattern");
-                });
-                return false;
+        ast::MetaItemKind::List(ref pred, ref mis) => {
+            for mi in mis.iter() {
+                if !mi.is_meta_item() {
+                    diag.emit_error(|diagnostic| {
+                        diagnostic.span_err(mi.span, "unsupported literal");
+                    });
+                    return false;
+                }
+            }
+
+            // The unwraps below may look dangerous, but we've already asserted
+            // that they won't fail with the loop above.
+            match &pred[..] {
+                "any" => mis.iter().any(|mi| {
+                    cfg_matches(cfgs, mi.meta_item().unwrap(), diag)
+                }),
+                "all" => mis.iter().all(|mi| {
+                    cfg_matches(cfgs, mi.meta_item().unwrap(), diag)
+                }),
+                "not" => {
+                    if mis.len() != 1 {
+                        diag.emit_error(|diagnostic| {
+                            diagnostic.span_err(cfg.span, "expected 1 cfg-pattern");
+                        });
+                        return false;
+                    }
+                    !cfg_matches(cfgs, mis[0].meta_item().unwrap(), diag)
+                }
+                p => {
+                    diag.emit_error(|diagnostic| {
+                        diagnostic.span_err(cfg.span,
+                            &format!("invalid predicate `{}`", p));
+                    });
+                    false
+                }
             }
-            !cfg_matches(cfgs, &mis[0], diag)
-        }
-        ast::MetaItemKind::List(ref pred, _) => {
-            diag.emit_error(|diagnostic| {
-                diagnostic.span_err(cfg.span,
-                    &format!("invalid predicate `{}`", pred));
-            });
-            false
         },
         ast::MetaItemKind::Word(_) | ast::MetaItemKind::NameValue(..) => {
             diag.flag_gated(|feature_gated_cfgs| {
@@ -476,14 +631,19 @@ fn find_stability_generic<'a, I>(diagnostic: &Handler,
                     let mut since = None;
                     let mut reason = None;
                     for meta in metas {
-                        match &*meta.name() {
-                            "since" => if !get(meta, &mut since) { continue 'outer },
-                            "reason" => if !get(meta, &mut reason) { continue 'outer },
-                            _ => {
-                                diagnostic.span_err(meta.span, &format!("unknown meta item '{}'",
-                                                                        meta.name()));
-                                continue 'outer
+                        if let Some(mi) = meta.meta_item() {
+                            match &*mi.name() {
+                                "since" => if !get(mi, &mut since) { continue 'outer },
+                                "reason" => if !get(mi, &mut reason) { continue 'outer },
+                                _ => {
+                                    diagnostic.span_err(mi.span, &format!("unknown meta item '{}'",
+                                                                          mi.name()));
+                                    continue 'outer
+                                }
                             }
+                        } else {
+                            diagnostic.span_err(meta.span, "unsupported literal");
+                            continue 'outer
                         }
                     }
 
@@ -514,15 +674,20 @@ fn find_stability_generic<'a, I>(diagnostic: &Handler,
                     let mut reason = None;
                     let mut issue = None;
                     for meta in metas {
-                        match &*meta.name() {
-                            "feature" => if !get(meta, &mut feature) { continue 'outer },
-                            "reason" => if !get(meta, &mut reason) { continue 'outer },
-                            "issue" => if !get(meta, &mut issue) { continue 'outer },
-                            _ => {
-                                diagnostic.span_err(meta.span, &format!("unknown meta item '{}'",
-                                                                        meta.name()));
-                                continue 'outer
+                        if let Some(mi) = meta.meta_item() {
+                            match &*mi.name() {
+                                "feature" => if !get(mi, &mut feature) { continue 'outer },
+                                "reason" => if !get(mi, &mut reason) { continue 'outer },
+                                "issue" => if !get(mi, &mut issue) { continue 'outer },
+                                _ => {
+                                    diagnostic.span_err(mi.span, &format!("unknown meta item '{}'",
+                                                                          mi.name()));
+                                    continue 'outer
+                                }
                             }
+                        } else {
+                            diagnostic.span_err(meta.span, "unsupported literal");
+                            continue 'outer
                         }
                     }
 
@@ -563,14 +728,19 @@ fn find_stability_generic<'a, I>(diagnostic: &Handler,
                     let mut feature = None;
                     let mut since = None;
                     for meta in metas {
-                        match &*meta.name() {
-                            "feature" => if !get(meta, &mut feature) { continue 'outer },
-                            "since" => if !get(meta, &mut since) { continue 'outer },
-                            _ => {
-                                diagnostic.span_err(meta.span, &format!("unknown meta item '{}'",
-                                                                        meta.name()));
-                                continue 'outer
+                        if let Some(mi) = meta.meta_item() {
+                            match &*mi.name() {
+                                "feature" => if !get(mi, &mut feature) { continue 'outer },
+                                "since" => if !get(mi, &mut since) { continue 'outer },
+                                _ => {
+                                    diagnostic.span_err(mi.span, &format!("unknown meta item '{}'",
+                                                                          mi.name()));
+                                    continue 'outer
+                                }
                             }
+                        } else {
+                            diagnostic.span_err(meta.span, "unsupported literal");
+                            continue 'outer
                         }
                     }
 
@@ -657,14 +827,19 @@ fn find_deprecation_generic<'a, I>(diagnostic: &Handler,
             let mut since = None;
             let mut note = None;
             for meta in metas {
-                match &*meta.name() {
-                    "since" => if !get(meta, &mut since) { continue 'outer },
-                    "note" => if !get(meta, &mut note) { continue 'outer },
-                    _ => {
-                        diagnostic.span_err(meta.span, &format!("unknown meta item '{}'",
-                                                                meta.name()));
-                        continue 'outer
+                if let Some(mi) = meta.meta_item() {
+                    match &*mi.name() {
+                        "since" => if !get(mi, &mut since) { continue 'outer },
+                        "note" => if !get(mi, &mut note) { continue 'outer },
+                        _ => {
+                            diagnostic.span_err(mi.span, &format!("unknown meta item '{}'",
+                                                                  mi.name()));
+                            continue 'outer
+                        }
                     }
+                } else {
+                    diagnostic.span_err(meta.span, "unsupported literal");
+                    continue 'outer
                 }
             }
 
@@ -714,8 +889,9 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
         ast::MetaItemKind::List(ref s, ref items) if s == "repr" => {
             mark_used(attr);
             for item in items {
-                match item.node {
-                    ast::MetaItemKind::Word(ref word) => {
+                match item.word() {
+                    Some(mi) => {
+                        let word = mi.name();
                         let hint = match &word[..] {
                             // Can't use "extern" because it's not a lexical identifier.
                             "C" => Some(ReprExtern),
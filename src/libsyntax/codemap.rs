@@ -645,10 +645,14 @@ impl FileMap {
     /// so the safe way to call this is with value calculated as
     /// filemap.start_pos + newline_offset_relative_to_the_start_of_filemap.
     pub fn next_line(&self, pos: BytePos) {
-        // the new charpos must be > the last one (or it's the first one).
+        // the new charpos must be > the last one (or it's the first one),
+        // unless the file is being lexed again and the line already known.
         let mut lines = self.lines.borrow_mut();
         let line_len = lines.len();
-        assert!(line_len == 0 || ((*lines)[line_len - 1] < pos));
+        if line_len > 0 && pos <= (*lines)[line_len - 1] {
+            assert!(lines.binary_search(&pos).is_ok());
+            return;
+        }
         lines.push(pos);
     }
 
@@ -677,11 +681,15 @@ impl FileMap {
 
     pub fn record_multibyte_char(&self, pos: BytePos, bytes: usize) {
         assert!(bytes >=2 && bytes <= 4);
-        let mbc = MultiByteChar {
+        let mut multibyte_chars = self.multibyte_chars.borrow_mut();
+        // As with lines, a file lexed again records nothing new.
+        if multibyte_chars.last().map_or(false, |mbc| pos <= mbc.pos) {
+            return;
+        }
+        multibyte_chars.push(MultiByteChar {
             pos: pos,
             bytes: bytes,
-        };
-        self.multibyte_chars.borrow_mut().push(mbc);
+        });
     }
 
     pub fn is_real_file(&self) -> bool {
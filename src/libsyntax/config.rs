@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use attr::AttrMetaMethods;
+use attr::{AttrMetaMethods, AttrNestedMetaItemMethods};
 use errors::Handler;
 use feature_gate::GatedCfgAttr;
 use fold::Folder;
@@ -133,11 +133,11 @@ fn fold_item_kind<F>(cx: &mut Context<F>, item: ast::ItemKind) -> ast::ItemKind
     F: FnMut(&[ast::Attribute]) -> bool
 {
     let item = match item {
-        ast::ItemKind::Impl(u, o, a, b, c, impl_items) => {
+        ast::ItemKind::Impl(u, o, d, a, b, c, impl_items) => {
             let impl_items = impl_items.into_iter()
                                        .filter(|ii| (cx.in_cfg)(&ii.attrs))
                                        .collect();
-            ast::ItemKind::Impl(u, o, a, b, c, impl_items)
+            ast::ItemKind::Impl(u, o, d, a, b, c, impl_items)
         }
         ast::ItemKind::Trait(u, a, b, methods) => {
             let methods = methods.into_iter()
@@ -148,6 +148,9 @@ fn fold_item_kind<F>(cx: &mut Context<F>, item: ast::ItemKind) -> ast::ItemKind
         ast::ItemKind::Struct(def, generics) => {
             ast::ItemKind::Struct(fold_struct(cx, def), generics)
         }
+        ast::ItemKind::Union(def, generics) => {
+            ast::ItemKind::Union(fold_struct(cx, def), generics)
+        }
         ast::ItemKind::Enum(def, generics) => {
             let variants = def.variants.into_iter().filter_map(|v| {
                 if !(cx.in_cfg)(&v.node.attrs) {
@@ -278,7 +281,15 @@ fn in_cfg<T: CfgDiag>(cfg: &[P<ast::MetaItem>],
             return true;
         }
 
-        attr::cfg_matches(cfg, &mis[0], diag)
+        if !mis[0].is_meta_item() {
+            diag.emit_error(|diagnostic| {
+                diagnostic.span_err(mis[0].span, "unsupported literal");
+            });
+            return true;
+        }
+
+        // The unwrap is safe because we just checked that it's a meta item.
+        attr::cfg_matches(cfg, mis[0].meta_item().unwrap(), diag)
     })
 }
 
@@ -316,7 +327,9 @@ impl<'a, T: CfgDiag> fold::Folder for CfgAttrFolder<'a, T> {
                 return None;
             }
         };
-        let (cfg, mi) = match (attr_list.len(), attr_list.get(0), attr_list.get(1)) {
+        let (cfg, mi) = match (attr_list.len(),
+                               attr_list.get(0).and_then(|item| item.meta_item()),
+                               attr_list.get(1).and_then(|item| item.meta_item())) {
             (2, Some(cfg), Some(mi)) => (cfg, mi),
             _ => {
                 self.diag.emit_error(|diag| {
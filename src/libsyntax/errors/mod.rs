@@ -229,6 +229,16 @@ impl<'a> DiagnosticBuilder<'a> {
         self.level == Level::Fatal
     }
 
+    /// The main message of the diagnostic.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The span(s) the diagnostic points at.
+    pub fn span(&self) -> &MultiSpan {
+        &self.span
+    }
+
     /// Add a span/label to be included in the resulting snippet.
     /// This is pushed onto the `MultiSpan` that was created when the
     /// diagnostic was first built. If you don't call this function at
@@ -260,11 +260,13 @@ pub type NamedParseResult = ParseResult<HashMap<Name, Rc<NamedMatch>>>;
 pub type PositionalParseResult = ParseResult<Vec<Rc<NamedMatch>>>;
 
 /// Perform a token equality check, ignoring syntax context (that is, an
-/// unhygienic comparison)
+/// unhygienic comparison). Raw identifiers only match raw identifiers, so that
+/// `r#match` isn't taken for the `match` keyword
 pub fn token_name_eq(t1 : &Token, t2 : &Token) -> bool {
     match (t1,t2) {
-        (&token::Ident(id1),&token::Ident(id2))
-        | (&token::Lifetime(id1),&token::Lifetime(id2)) =>
+        (&token::Ident(id1, _),&token::Ident(id2, _)) =>
+            id1.name == id2.name && t1.is_raw_ident() == t2.is_raw_ident(),
+        (&token::Lifetime(id1),&token::Lifetime(id2)) =>
             id1.name == id2.name,
         _ => *t1 == *t2
     }
@@ -534,9 +536,9 @@ pub fn parse_nt<'a>(p: &mut Parser<'a>, sp: Span, name: &str) -> Nonterminal {
         "ty" => token::NtTy(panictry!(p.parse_ty())),
         // this could be handled like a token, since it is one
         "ident" => match p.token {
-            token::Ident(sn) => {
+            token::Ident(sn, is_raw) => {
                 p.bump();
-                token::NtIdent(Box::new(Spanned::<Ident>{node: sn, span: p.span}))
+                token::NtIdent(Box::new(Spanned::<Ident>{node: sn, span: p.span}), is_raw)
             }
             _ => {
                 let token_str = pprust::token_to_string(&p.token);
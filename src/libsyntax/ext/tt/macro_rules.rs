@@ -1004,7 +1004,7 @@ fn is_in_follow(_: &ExtCtxt, tok: &Token, frag: &str) -> Result<bool, String> {
             "pat" => {
                 match *tok {
                     FatArrow | Comma | Eq | BinOp(token::Or) => Ok(true),
-                    Ident(i) if (i.name.as_str() == "if" ||
+                    Ident(i, false) if (i.name.as_str() == "if" ||
                                  i.name.as_str() == "in") => Ok(true),
                     _ => Ok(false)
                 }
@@ -1014,7 +1014,8 @@ fn is_in_follow(_: &ExtCtxt, tok: &Token, frag: &str) -> Result<bool, String> {
                     OpenDelim(token::DelimToken::Brace) | OpenDelim(token::DelimToken::Bracket) |
                     Comma | FatArrow | Colon | Eq | Gt | Semi | BinOp(token::Or) => Ok(true),
                     MatchNt(_, ref frag) if frag.name.as_str() == "block" => Ok(true),
-                    Ident(i) if i.name.as_str() == "as" || i.name.as_str() == "where" => Ok(true),
+                    Ident(i, false) if i.name.as_str() == "as" ||
+                                       i.name.as_str() == "where" => Ok(true),
                     _ => Ok(false)
                 }
             },
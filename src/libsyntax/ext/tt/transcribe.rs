@@ -188,7 +188,7 @@ pub fn tt_next_token(r: &mut TtReader) -> TokenAndSpan {
             None => (),
             Some(sp) => {
                 r.cur_span = sp;
-                r.cur_tok = token::Ident(r.imported_from.unwrap());
+                r.cur_tok = token::Ident(r.imported_from.unwrap(), false);
                 return ret_val;
             },
         }
@@ -294,9 +294,9 @@ pub fn tt_next_token(r: &mut TtReader) -> TokenAndSpan {
                             // sidestep the interpolation tricks for ident because
                             // (a) idents can be in lots of places, so it'd be a pain
                             // (b) we actually can, since it's a token.
-                            MatchedNonterminal(NtIdent(ref sn)) => {
+                            MatchedNonterminal(NtIdent(ref sn, is_raw)) => {
                                 r.cur_span = sn.span;
-                                r.cur_tok = token::Ident(sn.node);
+                                r.cur_tok = token::Ident(sn.node, is_raw);
                                 return ret_val;
                             }
                             MatchedNonterminal(ref other_whole_nt) => {
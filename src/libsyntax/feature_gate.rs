@@ -29,11 +29,12 @@ use abi::Abi;
 use ast::{NodeId, PatKind};
 use ast;
 use attr;
-use attr::AttrMetaMethods;
+use attr::{AttrMetaMethods, AttrNestedMetaItemMethods};
 use codemap::{CodeMap, Span};
 use errors::Handler;
 use visit;
 use visit::{FnKind, Visitor};
+use parse::ParseSess;
 use parse::token::InternedString;
 
 use std::ascii::AsciiExt;
@@ -266,7 +267,56 @@ declare_features! (
     (active, specialization, "1.7.0", Some(31844)),
 
     // pub(restricted) visibilities (RFC 1422)
-    (active, pub_restricted, "1.9.0", Some(32409))
+    (active, pub_restricted, "1.9.0", Some(32409)),
+
+    // Raw identifiers, `r#ident`, allowing keywords to be used as names
+    (active, raw_identifiers, "1.10.0", None),
+
+    // `do catch { ... }` blocks, the scope of `?` inside them
+    (active, catch_expr, "1.10.0", Some(31436)),
+
+    // `impl Trait` in function return types
+    (active, conservative_impl_trait, "1.10.0", Some(34511)),
+
+    // Untagged unions, `union U { a: u8, b: u16 }`
+    (active, untagged_unions, "1.10.0", Some(32836)),
+
+    // The `!` type, usable anywhere a type is expected
+    (active, never_type, "1.10.0", Some(35121)),
+
+    // `break` with a value, from a `loop`
+    (active, loop_break_value, "1.10.0", Some(37339)),
+
+    // Allows all literals in attribute lists and values of key-value pairs.
+    (active, attr_literals, "1.10.0", Some(34981)),
+
+    // Allows generic parameters of integer type, e.g. `struct Foo<const N: usize>`.
+    (active, const_generics, "1.10.0", Some(44580)),
+
+    // Allows associated types to have their own generic parameters.
+    (active, generic_associated_types, "1.10.0", Some(44265)),
+
+    // Allows `Foo { a, b }` as shorthand for `Foo { a: a, b: b }`.
+    (active, field_init_shorthand, "1.10.0", Some(37340)),
+
+    // Allows `..base` in struct expressions naming an enum variant.
+    (active, struct_variant_update, "1.10.0", Some(37341)),
+
+    // Allows `trait Foo = Bar + Quux;`, naming a combination of bounds.
+    (active, trait_alias, "1.10.0", Some(41517)),
+
+    // Allows `#![panic_runtime]` and `#![needs_panic_runtime]`, used by the
+    // standard library to choose how `panic!` is implemented at link time.
+    (active, panic_runtime, "1.10.0", Some(32837)),
+    (active, needs_panic_runtime, "1.10.0", Some(32837)),
+
+    // Allows `#![sanitizer_runtime]`, used by the crates which link in the
+    // compiler-rt runtime for `-Z sanitizer`.
+    (active, sanitizer_runtime, "1.10.0", None),
+
+    // Allows `#![profiler_runtime]`, used by the crate which links in the
+    // compiler-rt profiler runtime for `-C profile-generate`.
+    (active, profiler_runtime, "1.10.0", None)
 );
 
 declare_features! (
@@ -432,6 +482,25 @@ pub const KNOWN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeGat
                                        attribute is an experimental \
                                        feature",
                                       cfg_fn!(needs_allocator))),
+    ("panic_runtime", Whitelisted, Gated("panic_runtime",
+                                         "the `#[panic_runtime]` attribute is \
+                                          an experimental feature",
+                                         cfg_fn!(panic_runtime))),
+    ("needs_panic_runtime", Whitelisted, Gated("needs_panic_runtime",
+                                               "the `#[needs_panic_runtime]` \
+                                                attribute is an experimental \
+                                                feature",
+                                               cfg_fn!(needs_panic_runtime))),
+    ("sanitizer_runtime", Whitelisted, Gated("sanitizer_runtime",
+                                             "the `#[sanitizer_runtime]` attribute is used to \
+                                              identify crates that contain the runtime of a \
+                                              sanitizer and will never be stable",
+                                             cfg_fn!(sanitizer_runtime))),
+    ("profiler_runtime", Whitelisted, Gated("profiler_runtime",
+                                            "the `#[profiler_runtime]` attribute is used to \
+                                             identify the crate that contains the profiler \
+                                             runtime and will never be stable",
+                                            cfg_fn!(profiler_runtime))),
     ("rustc_variance", Normal, Gated("rustc_attrs",
                                      "the `#[rustc_variance]` attribute \
                                       is just used for rustc unit tests \
@@ -681,6 +750,11 @@ macro_rules! gate_feature {
 impl<'a> Context<'a> {
     fn check_attribute(&self, attr: &ast::Attribute, is_macro: bool) {
         debug!("check_attribute(attr = {:?})", attr);
+        if contains_novel_literal(&attr.node.value) {
+            gate_feature!(self, attr_literals, attr.span,
+                          "non-string literals in attributes, or string \
+                           literals in top-level positions, are experimental");
+        }
         let name = &*attr.name();
         for &(n, ty, ref gateage) in KNOWN_ATTRIBUTES {
             if n == name {
@@ -724,6 +798,25 @@ impl<'a> Context<'a> {
     }
 }
 
+/// Returns true if the meta item uses a literal that only `attr_literals`
+/// allows: a non-string value in a `name = value` pair, or any literal in a
+/// list.
+fn contains_novel_literal(item: &ast::MetaItem) -> bool {
+    use ast::MetaItemKind::*;
+    use ast::NestedMetaItemKind::*;
+
+    match item.node {
+        Word(..) => false,
+        NameValue(_, ref lit) => !lit.node.is_str(),
+        List(_, ref list) => list.iter().any(|li| {
+            match li.node {
+                MetaItem(ref mi) => contains_novel_literal(&mi),
+                Literal(_) => true,
+            }
+        }),
+    }
+}
+
 pub fn check_attribute(attr: &ast::Attribute, handler: &Handler,
                        cm: &CodeMap, features: &Features) {
     let cx = Context {
@@ -896,7 +989,7 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
                 for attr in &i.attrs {
                     if attr.name() == "repr" {
                         for item in attr.meta_item_list().unwrap_or(&[]) {
-                            if item.name() == "simd" {
+                            if item.check_name("simd") {
                                 gate_feature_post!(&self, repr_simd, i.span,
                                                    "SIMD types are experimental \
                                                     and possibly buggy");
@@ -907,6 +1000,12 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
                 }
             }
 
+            ast::ItemKind::Union(..) => {
+                gate_feature_post!(&self, untagged_unions,
+                                   i.span,
+                                   "unions are unstable and possibly buggy");
+            }
+
             ast::ItemKind::DefaultImpl(..) => {
                 gate_feature_post!(&self, optin_builtin_traits,
                                    i.span,
@@ -914,7 +1013,13 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
                                     and possibly buggy");
             }
 
-            ast::ItemKind::Impl(_, polarity, _, _, _, _) => {
+            ast::ItemKind::TraitAlias(..) => {
+                gate_feature_post!(&self, trait_alias,
+                                   i.span,
+                                   "trait aliases are experimental");
+            }
+
+            ast::ItemKind::Impl(_, polarity, defaultness, _, _, _, _) => {
                 match polarity {
                     ast::ImplPolarity::Negative => {
                         gate_feature_post!(&self, optin_builtin_traits,
@@ -924,6 +1029,12 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
                     },
                     _ => {}
                 }
+
+                if let ast::Defaultness::Default = defaultness {
+                    gate_feature_post!(&self, specialization,
+                                       i.span,
+                                       "specialization is unstable");
+                }
             }
 
             _ => {}
@@ -979,11 +1090,41 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
             ast::ExprKind::Try(..) => {
                 gate_feature_post!(&self, question_mark, e.span, "the `?` operator is not stable");
             }
+            ast::ExprKind::Catch(..) => {
+                gate_feature_post!(&self, catch_expr, e.span, "`catch` expression is experimental");
+            }
+            ast::ExprKind::Break(_, Some(_)) => {
+                gate_feature_post!(&self, loop_break_value, e.span,
+                                   "`break` with a value is experimental");
+            }
+            ast::ExprKind::Struct(_, ref fields, _) => {
+                for field in fields {
+                    if field.is_shorthand {
+                        gate_feature_post!(&self, field_init_shorthand, field.span,
+                                           "struct field shorthands are unstable");
+                    }
+                }
+            }
             _ => {}
         }
         visit::walk_expr(self, e);
     }
 
+    fn visit_ty(&mut self, ty: &ast::Ty) {
+        match ty.node {
+            ast::TyKind::ImplTrait(..) => {
+                gate_feature_post!(&self, conservative_impl_trait, ty.span,
+                                   "`impl Trait` is experimental");
+            }
+            ast::TyKind::Never => {
+                gate_feature_post!(&self, never_type, ty.span,
+                                   "The `!` type is experimental");
+            }
+            _ => {}
+        }
+        visit::walk_ty(self, ty);
+    }
+
     fn visit_pat(&mut self, pattern: &ast::Pat) {
         match pattern.node {
             PatKind::Vec(_, Some(_), ref last) if !last.is_empty() => {
@@ -1062,9 +1203,15 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
                     gate_feature_post!(&self, const_fn, ti.span, "const fn is unstable");
                 }
             }
-            ast::TraitItemKind::Type(_, Some(_)) => {
-                gate_feature_post!(&self, associated_type_defaults, ti.span,
-                                  "associated type defaults are unstable");
+            ast::TraitItemKind::Type(_, ref default, ref generics) => {
+                if default.is_some() {
+                    gate_feature_post!(&self, associated_type_defaults, ti.span,
+                                      "associated type defaults are unstable");
+                }
+                if generics.is_parameterized() || !generics.where_clause.predicates.is_empty() {
+                    gate_feature_post!(&self, generic_associated_types, ti.span,
+                                      "generic associated types are unstable");
+                }
             }
             _ => {}
         }
@@ -1089,6 +1236,12 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
                     gate_feature_post!(&self, const_fn, ii.span, "const fn is unstable");
                 }
             }
+            ast::ImplItemKind::Type(_, ref generics) => {
+                if generics.is_parameterized() || !generics.where_clause.predicates.is_empty() {
+                    gate_feature_post!(&self, generic_associated_types, ii.span,
+                                      "generic associated types are unstable");
+                }
+            }
             _ => {}
         }
         visit::walk_impl_item(self, ii);
@@ -1101,6 +1254,7 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
                 // Check for type parameters
                 let found_param = path.segments.iter().any(|segment| {
                     !segment.parameters.types().is_empty() ||
+                    !segment.parameters.consts().is_empty() ||
                     !segment.parameters.lifetimes().is_empty() ||
                     !segment.parameters.bindings().is_empty()
                 });
@@ -1114,6 +1268,22 @@ impl<'a, 'v> Visitor<'v> for PostExpansionVisitor<'a> {
         };
         gate_feature_post!(&self, pub_restricted, span, "`pub(restricted)` syntax is experimental");
     }
+
+    fn visit_generics(&mut self, g: &'v ast::Generics) {
+        for param in g.const_params.iter() {
+            gate_feature_post!(&self, const_generics, param.span,
+                               "const generic parameters are experimental");
+        }
+        visit::walk_generics(self, g);
+    }
+
+    fn visit_path_parameters(&mut self, path_span: Span, params: &'v ast::PathParameters) {
+        if !params.consts().is_empty() {
+            gate_feature_post!(&self, const_generics, path_span,
+                               "const generic arguments are experimental");
+        }
+        visit::walk_path_parameters(self, path_span, params);
+    }
 }
 
 pub fn get_features(span_handler: &Handler, krate: &ast::Crate) -> Features {
@@ -1131,9 +1301,9 @@ pub fn get_features(span_handler: &Handler, krate: &ast::Crate) -> Features {
             }
             Some(list) => {
                 for mi in list {
-                    let name = match mi.node {
-                        ast::MetaItemKind::Word(ref word) => (*word).clone(),
-                        _ => {
+                    let name = match mi.word() {
+                        Some(word) => word.name(),
+                        None => {
                             span_handler.span_err(mi.span,
                                                   "malformed feature, expected just \
                                                    one word");
@@ -1162,18 +1332,22 @@ pub fn get_features(span_handler: &Handler, krate: &ast::Crate) -> Features {
     features
 }
 
-pub fn check_crate(cm: &CodeMap, span_handler: &Handler, krate: &ast::Crate,
+pub fn check_crate(sess: &ParseSess, krate: &ast::Crate,
                    plugin_attributes: &[(String, AttributeType)],
                    unstable: UnstableFeatures) -> Features {
+    let span_handler = &sess.span_diagnostic;
     maybe_stage_features(span_handler, krate, unstable);
     let features = get_features(span_handler, krate);
     {
         let ctx = Context {
             features: &features,
             span_handler: span_handler,
-            cm: cm,
+            cm: sess.codemap(),
             plugin_attributes: plugin_attributes,
         };
+        for &span in sess.raw_identifier_spans.borrow().iter() {
+            gate_feature!(&ctx, raw_identifiers, span, "raw identifiers are experimental");
+        }
         visit::walk_crate(&mut PostExpansionVisitor { context: &ctx }, krate);
     }
     features
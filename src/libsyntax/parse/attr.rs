@@ -13,7 +13,7 @@ use ast;
 use codemap::{spanned, Spanned, mk_sp, Span};
 use parse::common::SeqSep;
 use parse::PResult;
-use parse::token;
+use parse::token::{self, keywords};
 use parse::parser::{Parser, TokenType};
 use ptr::P;
 
@@ -170,16 +170,7 @@ impl<'a> Parser<'a> {
         match self.token {
             token::Eq => {
                 self.bump();
-                let lit = self.parse_lit()?;
-                // FIXME #623 Non-string meta items are not serialized correctly;
-                // just forbid them for now
-                match lit.node {
-                    ast::LitKind::Str(..) => {}
-                    _ => {
-                        self.span_err(lit.span,
-                                      "non-string literals are not allowed in meta-items");
-                    }
-                }
+                let lit = self.parse_unsuffixed_lit()?;
                 let hi = self.span.hi;
                 Ok(P(spanned(lo, hi, ast::MetaItemKind::NameValue(name, lit))))
             }
@@ -195,11 +186,40 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// matches meta_seq = ( COMMASEP(meta_item) )
-    fn parse_meta_seq(&mut self) -> PResult<'a, Vec<P<ast::MetaItem>>> {
+    /// matches meta_item_inner = meta_item | UNSUFFIXED_LIT
+    fn parse_meta_item_inner(&mut self) -> PResult<'a, ast::NestedMetaItem> {
+        let lo = self.span.lo;
+        if self.token.is_lit() ||
+           self.token.is_keyword(keywords::True) ||
+           self.token.is_keyword(keywords::False) {
+            let lit = self.parse_unsuffixed_lit()?;
+            let hi = self.last_span.hi;
+            return Ok(spanned(lo, hi, ast::NestedMetaItemKind::Literal(lit)));
+        }
+
+        let mi = self.parse_meta_item()?;
+        let hi = self.last_span.hi;
+        Ok(spanned(lo, hi, ast::NestedMetaItemKind::MetaItem(mi)))
+    }
+
+    /// matches meta_seq = ( COMMASEP(meta_item_inner) )
+    fn parse_meta_seq(&mut self) -> PResult<'a, Vec<ast::NestedMetaItem>> {
         self.parse_unspanned_seq(&token::OpenDelim(token::Paren),
                                  &token::CloseDelim(token::Paren),
                                  SeqSep::trailing_allowed(token::Comma),
-                                 |p: &mut Parser<'a>| p.parse_meta_item())
+                                 |p: &mut Parser<'a>| p.parse_meta_item_inner())
+    }
+
+    /// Parses a literal for use in an attribute, which may not have a suffix.
+    fn parse_unsuffixed_lit(&mut self) -> PResult<'a, ast::Lit> {
+        let lit = self.parse_lit()?;
+        if lit.node.is_suffixed() {
+            self.diagnostic()
+                .struct_span_err(lit.span, "suffixed literals are not allowed in attributes")
+                .help("instead of using a suffixed literal (1u8, 1.0f32, etc.), \
+                       use an unsuffixed version (1, 1.0, etc.)")
+                .emit()
+        }
+        Ok(lit)
     }
 }
@@ -0,0 +1,340 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A lossless token tree for a source file.
+//!
+//! The AST forgets about whitespace and comments, which makes it unsuitable
+//! as the basis of tools that rewrite source code. This module produces,
+//! straight from the lexer, a tree of every token in a file grouped by
+//! delimiters, with the whitespace and comments ("trivia") preceding each
+//! token attached to it. The tree prints back to exactly the text of the
+//! file it was lexed from, and since every token and piece of trivia carries
+//! its span, the parts of the tree making up any AST node can be found from
+//! the span of that node.
+
+use codemap::{self, FileMap, Pos, Span};
+use errors::Handler;
+use parse::lexer::{Reader, StringReader};
+use parse::token;
+
+use std::rc::Rc;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TriviaKind {
+    Whitespace,
+    /// A comment that isn't a doc comment; doc comments are tokens.
+    Comment,
+    /// A `#!` line at the very start of the file.
+    Shebang,
+}
+
+/// Whitespace or a comment.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+/// A token, along with the trivia between it and the previous token.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Token {
+    pub tok: token::Token,
+    pub span: Span,
+    pub leading_trivia: Vec<Trivia>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TokenTree {
+    Token(Token),
+    Delimited(Delimited),
+}
+
+/// A sequence of token trees between matching delimiters.
+///
+/// Delimiters that are never closed, or that are closed by the wrong kind
+/// of delimiter, are kept as they are: `close` is then `None` or a token
+/// other than the closing counterpart of `open`. Close delimiters without a
+/// matching open one appear as plain tokens.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Delimited {
+    pub delim: token::DelimToken,
+    pub open: Token,
+    pub tts: Vec<TokenTree>,
+    pub close: Option<Token>,
+}
+
+/// The lossless token tree of a whole file.
+pub struct SourceTree {
+    pub filemap: Rc<FileMap>,
+    pub tts: Vec<TokenTree>,
+    /// The end of the file, holding any trivia after the last token.
+    pub eof: Token,
+}
+
+impl TokenTree {
+    pub fn get_span(&self) -> Span {
+        match *self {
+            TokenTree::Token(ref tok) => tok.span,
+            TokenTree::Delimited(ref delimited) => {
+                let hi = match delimited.close {
+                    Some(ref close) => close.span.hi,
+                    None => {
+                        delimited.tts.last().map_or(delimited.open.span.hi, |tt| tt.get_span().hi)
+                    }
+                };
+                codemap::mk_sp(delimited.open.span.lo, hi)
+            }
+        }
+    }
+
+    fn push_tokens<'a>(&'a self, tokens: &mut Vec<&'a Token>) {
+        match *self {
+            TokenTree::Token(ref tok) => tokens.push(tok),
+            TokenTree::Delimited(ref delimited) => {
+                tokens.push(&delimited.open);
+                for tt in &delimited.tts {
+                    tt.push_tokens(tokens);
+                }
+                if let Some(ref close) = delimited.close {
+                    tokens.push(close);
+                }
+            }
+        }
+    }
+}
+
+impl SourceTree {
+    /// All tokens of the file in source order, ending with the end-of-file
+    /// token.
+    pub fn tokens(&self) -> Vec<&Token> {
+        let mut tokens = vec![];
+        for tt in &self.tts {
+            tt.push_tokens(&mut tokens);
+        }
+        tokens.push(&self.eof);
+        tokens
+    }
+
+    /// The tokens lying within `span`, e.g. the span of an AST node.
+    pub fn tokens_in(&self, span: Span) -> Vec<&Token> {
+        self.tokens().into_iter().filter(|tok| {
+            tok.tok != token::Eof && span.lo <= tok.span.lo && tok.span.hi <= span.hi
+        }).collect()
+    }
+
+    /// The comments belonging to the source within `span`: those inside it,
+    /// and those directly preceding its first token.
+    pub fn comments_in(&self, span: Span) -> Vec<&Trivia> {
+        let mut comments = vec![];
+        for tok in self.tokens() {
+            let first = tok.span.lo == span.lo;
+            for trivia in &tok.leading_trivia {
+                let inside = span.lo <= trivia.span.lo && trivia.span.hi <= span.hi;
+                if trivia.kind == TriviaKind::Comment && (first || inside) {
+                    comments.push(trivia);
+                }
+            }
+        }
+        comments
+    }
+
+    /// The source text of the file, reproduced byte for byte from the tree.
+    pub fn to_source(&self) -> String {
+        let mut out = String::new();
+        for tok in self.tokens() {
+            for trivia in &tok.leading_trivia {
+                out.push_str(self.text(trivia.span));
+            }
+            out.push_str(self.text(tok.span));
+        }
+        out
+    }
+
+    /// The source text of `span`, which must lie within this file.
+    pub fn text(&self, span: Span) -> &str {
+        let src = self.filemap.src.as_ref().expect("lossless tree of a file without source");
+        let lo = (span.lo - self.filemap.start_pos).to_usize();
+        let hi = (span.hi - self.filemap.start_pos).to_usize();
+        &src[lo..hi]
+    }
+}
+
+/// Lex `filemap` into a lossless token tree. Lexical errors are reported
+/// through `handler` as usual; fatal ones abort.
+pub fn lex_lossless(handler: &Handler, filemap: Rc<FileMap>) -> SourceTree {
+    let mut lexer = LosslessLexer {
+        reader: StringReader::new(handler, filemap.clone()),
+        peeked: None,
+    };
+
+    let mut tts = vec![];
+    loop {
+        let tok = lexer.next();
+        if tok.tok == token::Eof {
+            return SourceTree {
+                filemap: filemap,
+                tts: tts,
+                eof: tok,
+            };
+        }
+        tts.push(lexer.token_tree(tok));
+    }
+}
+
+struct LosslessLexer<'a> {
+    reader: StringReader<'a>,
+    /// A token pushed back by `token_tree`.
+    peeked: Option<Token>,
+}
+
+impl<'a> LosslessLexer<'a> {
+    /// The next token, with its leading trivia.
+    fn next(&mut self) -> Token {
+        if let Some(tok) = self.peeked.take() {
+            return tok;
+        }
+
+        let mut leading_trivia = vec![];
+        loop {
+            let t = self.reader.next_token();
+            let kind = match t.tok {
+                token::Whitespace => TriviaKind::Whitespace,
+                token::Comment => TriviaKind::Comment,
+                token::Shebang(_) => TriviaKind::Shebang,
+                _ => {
+                    return Token {
+                        tok: t.tok,
+                        span: t.sp,
+                        leading_trivia: leading_trivia,
+                    };
+                }
+            };
+            leading_trivia.push(Trivia { kind: kind, span: t.sp });
+        }
+    }
+
+    /// Build the token tree starting with `tok`, consuming the rest of a
+    /// delimited sequence if `tok` opens one.
+    fn token_tree(&mut self, tok: Token) -> TokenTree {
+        let delim = match tok.tok {
+            token::OpenDelim(delim) => delim,
+            _ => return TokenTree::Token(tok),
+        };
+
+        let mut tts = vec![];
+        loop {
+            let next = self.next();
+            match next.tok {
+                token::CloseDelim(_) => {
+                    return TokenTree::Delimited(Delimited {
+                        delim: delim,
+                        open: tok,
+                        tts: tts,
+                        close: Some(next),
+                    });
+                }
+                token::Eof => {
+                    // Leave the end of the file for the top level.
+                    self.peeked = Some(next);
+                    return TokenTree::Delimited(Delimited {
+                        delim: delim,
+                        open: tok,
+                        tts: tts,
+                        close: None,
+                    });
+                }
+                _ => tts.push(self.token_tree(next)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ast;
+    use codemap::CodeMap;
+    use errors;
+    use parse::{self, ParseSess};
+    use parse::token;
+    use std::io;
+    use std::rc::Rc;
+
+    fn mk_sess() -> ParseSess {
+        let cm = Rc::new(CodeMap::new());
+        let emitter = errors::emitter::EmitterWriter::new(Box::new(io::sink()), None, cm.clone());
+        let handler = errors::Handler::with_emitter(true, false, Box::new(emitter));
+        ParseSess::with_span_handler(handler, cm)
+    }
+
+    #[test]
+    fn round_trip() {
+        let sess = mk_sess();
+        let sources = [
+            "",
+            "   \n\t",
+            "#!/usr/bin/env rustc\n// comment\nfn main() { /* inner */ }\n",
+            "/// doc\nstruct S { x: u32, } // trailing",
+            "fn f() -> [u8; 2] { [0, 1]\r\n",
+            "mod m { fn g() ) }  \n\n",
+        ];
+        for source in &sources {
+            let fm = sess.codemap().new_filemap("<test>".to_string(), source.to_string());
+            let tree = lex_lossless(&sess.span_diagnostic, fm);
+            assert_eq!(tree.to_source(), *source);
+        }
+    }
+
+    #[test]
+    fn unclosed_delimiters() {
+        let sess = mk_sess();
+        let fm = sess.codemap().new_filemap("<test>".to_string(), "fn f() { x".to_string());
+        let tree = lex_lossless(&sess.span_diagnostic, fm);
+        assert_eq!(tree.tts.len(), 4);
+        match tree.tts[3] {
+            TokenTree::Delimited(ref d) => {
+                assert_eq!(d.delim, token::Brace);
+                assert_eq!(d.tts.len(), 1);
+                assert!(d.close.is_none());
+            }
+            _ => panic!("expected a delimited tree"),
+        }
+    }
+
+    #[test]
+    fn ast_spans() {
+        let sess = mk_sess();
+        let source = "fn a() {}\n\n// About b.\nfn b() { 1 /* one */ }\n";
+        let fm = sess.codemap().new_filemap("<test>".to_string(), source.to_string());
+        let tree = lex_lossless(&sess.span_diagnostic, fm.clone());
+        let krate = parse::filemap_to_parser(&sess, fm, vec![]).parse_crate_mod().unwrap();
+
+        let b = &krate.module.items[1];
+        let tokens = tree.tokens_in(b.span).iter()
+                         .map(|tok| tree.text(tok.span))
+                         .collect::<Vec<_>>();
+        assert_eq!(tokens, ["fn", "b", "(", ")", "{", "1", "}"]);
+
+        let comments = tree.comments_in(b.span).iter()
+                           .map(|trivia| tree.text(trivia.span))
+                           .collect::<Vec<_>>();
+        assert_eq!(comments, ["// About b.", "/* one */"]);
+
+        match b.node {
+            ast::ItemKind::Fn(_, _, _, _, _, ref body) => {
+                let tokens = tree.tokens_in(body.span);
+                assert_eq!(tokens.len(), 3);
+                assert_eq!(tokens[0].tok, token::OpenDelim(token::Brace));
+            }
+            _ => panic!("expected a function"),
+        }
+    }
+}
@@ -25,6 +25,7 @@ use std::rc::Rc;
 pub use ext::tt::transcribe::{TtReader, new_tt_reader, new_tt_reader_with_doc_flag};
 
 pub mod comments;
+pub mod lossless;
 mod unicode_chars;
 
 pub trait Reader {
@@ -680,9 +681,18 @@ impl<'a> StringReader<'a> {
                     // check that the hypothetical digit is actually
                     // in range for the true radix
                     if c.unwrap().to_digit(real_radix).is_none() {
-                        self.err_span_(self.last_pos,
-                                       self.pos,
-                                       &format!("invalid digit for a base {} literal", real_radix));
+                        let sp = codemap::mk_sp(self.last_pos, self.pos);
+                        let msg = format!("invalid digit for a base {} literal", real_radix);
+                        let valid = match real_radix {
+                            2 => "`0` and `1`",
+                            8 => "`0` to `7`",
+                            _ => "`0` to `9`",
+                        };
+                        self.span_diagnostic
+                            .struct_span_err(sp, &msg)
+                            .help(&format!("base {} literals may only contain the digits {}",
+                                           real_radix, valid))
+                            .emit();
                     }
                     len += 1;
                     self.bump();
@@ -692,6 +702,31 @@ impl<'a> StringReader<'a> {
         }
     }
 
+    /// Lex a raw identifier, `r#` followed by an identifier that may be a
+    /// keyword.
+    fn scan_raw_ident(&mut self) -> token::Token {
+        let start_bpos = self.last_pos;
+        self.bump();
+        self.bump();
+        let ident_bpos = self.last_pos;
+        while ident_continue(self.curr) {
+            self.bump();
+        }
+        let last_bpos = self.last_pos;
+
+        let ident = self.with_str_from(ident_bpos, |string| str_to_ident(string));
+        // `_` isn't an identifier, and `self`, `super` and `Self` are path
+        // segments rather than mere keywords, so raw versions of them would be
+        // meaningless.
+        if &*ident.name.as_str() == "_" ||
+           token::Ident(ident, false).is_path_segment_keyword() {
+            self.err_span_(start_bpos,
+                           last_bpos,
+                           &format!("`{}` cannot be a raw identifier", ident));
+        }
+        token::Ident(ident, true)
+    }
+
     /// Lex a LIT_INTEGER or a LIT_FLOAT
     fn scan_number(&mut self, c: char) -> token::Lit {
         let num_digits;
@@ -1051,6 +1086,9 @@ impl<'a> StringReader<'a> {
     /// token, and updates the interner
     fn next_token_inner(&mut self) -> Result<token::Token, ()> {
         let c = self.curr;
+        if c == Some('r') && self.nextch_is('#') && ident_start(self.nextnextch()) {
+            return Ok(self.scan_raw_ident());
+        }
         if ident_start(c) &&
            match (c.unwrap(), self.nextch(), self.nextnextch()) {
             // Note: r as in r" or r#" is part of a raw string literal,
@@ -1074,7 +1112,7 @@ impl<'a> StringReader<'a> {
                     token::Underscore
                 } else {
                     // FIXME: perform NFKC normalization here. (Issue #2253)
-                    token::Ident(str_to_ident(string))
+                    token::Ident(str_to_ident(string), false)
                 }
             }));
         }
@@ -1262,7 +1300,7 @@ impl<'a> StringReader<'a> {
                     let keyword_checking_ident = self.with_str_from(start, |lifetime_name| {
                         str_to_ident(lifetime_name)
                     });
-                    let keyword_checking_token = &token::Ident(keyword_checking_ident);
+                    let keyword_checking_token = &token::Ident(keyword_checking_ident, false);
                     let last_bpos = self.last_pos;
                     if keyword_checking_token.is_any_keyword() &&
                        !keyword_checking_token.is_keyword(keywords::Static) {
@@ -1712,7 +1750,7 @@ mod tests {
         assert_eq!(string_reader.next_token().tok, token::Whitespace);
         let tok1 = string_reader.next_token();
         let tok2 = TokenAndSpan {
-            tok: token::Ident(id),
+            tok: token::Ident(id, false),
             sp: Span {
                 lo: BytePos(21),
                 hi: BytePos(23),
@@ -1726,7 +1764,7 @@ mod tests {
         // read another token:
         let tok3 = string_reader.next_token();
         let tok4 = TokenAndSpan {
-            tok: token::Ident(str_to_ident("main")),
+            tok: token::Ident(str_to_ident("main"), false),
             sp: Span {
                 lo: BytePos(24),
                 hi: BytePos(28),
@@ -1748,7 +1786,7 @@ mod tests {
 
     // make the identifier by looking up the string in the interner
     fn mk_ident(id: &str) -> token::Token {
-        token::Ident(str_to_ident(id))
+        token::Ident(str_to_ident(id), false)
     }
 
     #[test]
@@ -1783,6 +1821,18 @@ mod tests {
                            vec![mk_ident("a"), token::ModSep, token::Whitespace, mk_ident("b")]);
     }
 
+    #[test]
+    fn raw_identifiers() {
+        let cm = Rc::new(CodeMap::new());
+        let sh = mk_sh(cm.clone());
+        check_tokenization(setup(&cm, &sh, "r#match r#\"s\"# r".to_string()),
+                           vec![token::Ident(str_to_ident("match"), true),
+                                token::Whitespace,
+                                token::Literal(token::StrRaw(token::intern("s"), 1), None),
+                                token::Whitespace,
+                                mk_ident("r")]);
+    }
+
     #[test]
     fn character_a() {
         let cm = Rc::new(CodeMap::new());
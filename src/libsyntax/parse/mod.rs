@@ -17,8 +17,10 @@ use parse::parser::Parser;
 use parse::token::InternedString;
 use ptr::P;
 use str::char_at;
+use util::lev_distance::lev_distance;
 
 use std::cell::RefCell;
+use std::cmp;
 use std::iter;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
@@ -43,6 +45,8 @@ pub struct ParseSess {
     /// Used to determine and report recursive mod inclusions
     included_mod_stack: RefCell<Vec<PathBuf>>,
     code_map: Rc<CodeMap>,
+    /// Spans of the raw identifiers lexed so far, for feature gating
+    pub raw_identifier_spans: RefCell<Vec<Span>>,
 }
 
 impl ParseSess {
@@ -56,7 +60,8 @@ impl ParseSess {
         ParseSess {
             span_diagnostic: handler,
             included_mod_stack: RefCell::new(vec![]),
-            code_map: code_map
+            code_map: code_map,
+            raw_identifier_spans: RefCell::new(vec![]),
         }
     }
 
@@ -78,6 +83,15 @@ pub fn parse_crate_from_file<'a>(input: &Path,
     parser.parse_crate_mod()
 }
 
+/// Parses a crate in error-recovery mode, for tools that need an AST even when
+/// the source contains syntax errors. See `parse_crate_with_recovery`.
+pub fn parse_crate_from_file_with_recovery<'a>(input: &Path,
+                                               cfg: ast::CrateConfig,
+                                               sess: &'a ParseSess)
+                                               -> (ast::Crate, Vec<DiagnosticBuilder<'a>>) {
+    parse_crate_with_recovery(sess, file_to_filemap(sess, input, None), cfg)
+}
+
 pub fn parse_crate_attrs_from_file<'a>(input: &Path,
                                        cfg: ast::CrateConfig,
                                        sess: &'a ParseSess)
@@ -98,6 +112,16 @@ pub fn parse_crate_from_source_str<'a>(name: String,
     p.parse_crate_mod()
 }
 
+/// Parses a crate in error-recovery mode, for tools that need an AST even when
+/// the source contains syntax errors. See `parse_crate_with_recovery`.
+pub fn parse_crate_from_source_str_with_recovery<'a>(name: String,
+                                                     source: String,
+                                                     cfg: ast::CrateConfig,
+                                                     sess: &'a ParseSess)
+                                                     -> (ast::Crate, Vec<DiagnosticBuilder<'a>>) {
+    parse_crate_with_recovery(sess, sess.codemap().new_filemap(name, source), cfg)
+}
+
 pub fn parse_crate_attrs_from_source_str<'a>(name: String,
                                              source: String,
                                              cfg: ast::CrateConfig,
@@ -253,7 +277,56 @@ pub fn filemap_to_tts(sess: &ParseSess, filemap: Rc<FileMap>)
     let cfg = Vec::new();
     let srdr = lexer::StringReader::new(&sess.span_diagnostic, filemap);
     let mut p1 = Parser::new(sess, cfg, Box::new(srdr));
-    panictry!(p1.parse_all_token_trees())
+    let tts = panictry!(p1.parse_all_token_trees());
+    record_raw_identifiers(sess, &tts);
+    tts
+}
+
+/// Record the spans of the raw identifiers in `tts`, to be checked against
+/// the `raw_identifiers` feature gate.
+fn record_raw_identifiers(sess: &ParseSess, tts: &[ast::TokenTree]) {
+    for tt in tts {
+        match *tt {
+            ast::TokenTree::Token(sp, token::Ident(_, true)) => {
+                sess.raw_identifier_spans.borrow_mut().push(sp);
+            }
+            ast::TokenTree::Delimited(_, ref delimited) => {
+                record_raw_identifiers(sess, &delimited.tts);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parse a filemap as a crate in error-recovery mode. Unclosed delimiters are
+/// closed at the end of the file or, for braces, before the first item that
+/// is not indented past the line of the opening brace; items that fail to
+/// parse are skipped and missing expressions are replaced by
+/// `ExprKind::Err` placeholders. The errors recovered from are returned,
+/// unemitted, alongside the crate. Errors the lexer considers fatal still
+/// abort parsing.
+pub fn parse_crate_with_recovery<'a>(sess: &'a ParseSess,
+                                     filemap: Rc<FileMap>,
+                                     cfg: ast::CrateConfig)
+                                     -> (ast::Crate, Vec<DiagnosticBuilder<'a>>) {
+    let end_pos = filemap.end_pos;
+    let srdr = lexer::StringReader::new(&sess.span_diagnostic, filemap);
+    let mut p1 = Parser::new(sess, Vec::new(), Box::new(srdr));
+    p1.recovery = true;
+    let tts = p1.parse_all_token_trees().unwrap_or_else(|e| {
+        p1.recovered_error(e);
+        Vec::new()
+    });
+    record_raw_identifiers(sess, &tts);
+    let mut errors = p1.take_recovered_errors();
+
+    let mut parser = tts_to_parser(sess, tts, cfg);
+    if parser.token == token::Eof && parser.span == codemap::DUMMY_SP {
+        parser.span = codemap::mk_sp(end_pos, end_pos);
+    }
+    let (krate, parse_errors) = parser.parse_crate_mod_with_recovery();
+    errors.extend(parse_errors);
+    (krate, errors)
 }
 
 /// Given tts and cfg, produce a parser
@@ -435,6 +508,59 @@ fn looks_like_width_suffix(first_chars: &[char], s: &str) -> bool {
         s[1..].chars().all(|c| '0' <= c && c <= '9')
 }
 
+const INT_SUFFIXES: &'static [&'static str] = &[
+    "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+];
+const FLOAT_SUFFIXES: &'static [&'static str] = &["f32", "f64"];
+
+/// The span of the suffix `suf` of the literal at `sp`, falling back to the
+/// whole literal if `sp` doesn't cover the source text (e.g. it was produced
+/// by a macro).
+fn suffix_span(sp: Span, suf: &str) -> Span {
+    let len = codemap::BytePos(suf.len() as u32);
+    if sp.hi - sp.lo > len {
+        Span { lo: sp.hi - len, ..sp }
+    } else {
+        sp
+    }
+}
+
+/// A valid suffix to suggest in place of the invalid suffix `suf`: the Rust
+/// spelling of a C-style suffix such as `ul`, or else a close misspelling of
+/// one of `valid`.
+fn suggest_suffix(suf: &str, valid: &[&'static str]) -> Option<&'static str> {
+    let c_style = match &*suf.to_lowercase() {
+        "u" => Some("u32"),
+        "l" | "ll" => Some("i64"),
+        "ul" | "lu" | "ull" | "llu" => Some("u64"),
+        "f" => Some("f32"),
+        _ => None,
+    };
+    c_style.or_else(|| {
+        let max_dist = cmp::max(suf.len(), 3) / 3;
+        valid.iter()
+             .map(|&v| (v, lev_distance(suf, v)))
+             .filter(|&(_, dist)| dist <= max_dist)
+             .min_by_key(|&(_, dist)| dist)
+             .map(|(v, _)| v)
+    })
+}
+
+fn invalid_suffix_err(sd: &Handler, sp: Span, suf: &str, msg: &str, help: &str,
+                      valid: &[&'static str]) {
+    let sp = suffix_span(sp, suf);
+    let mut err = sd.struct_span_err(sp, msg);
+    match suggest_suffix(suf, valid) {
+        Some(suggestion) => {
+            err.span_suggestion(sp, "try using a valid suffix:", suggestion.to_string());
+        }
+        None => {
+            err.help(help);
+        }
+    }
+    err.emit();
+}
+
 fn filtered_float_lit(data: token::InternedString, suffix: Option<&str>,
                       sd: &Handler, sp: Span) -> ast::LitKind {
     debug!("filtered_float_lit: {}, {:?}", data, suffix);
@@ -444,13 +570,15 @@ fn filtered_float_lit(data: token::InternedString, suffix: Option<&str>,
         Some(suf) => {
             if suf.len() >= 2 && looks_like_width_suffix(&['f'], suf) {
                 // if it looks like a width, lets try to be helpful.
-                sd.struct_span_err(sp, &format!("invalid width `{}` for float literal", &suf[1..]))
-                 .help("valid widths are 32 and 64")
-                 .emit();
+                invalid_suffix_err(sd, sp, suf,
+                                   &format!("invalid width `{}` for float literal", &suf[1..]),
+                                   "valid widths are 32 and 64",
+                                   FLOAT_SUFFIXES);
             } else {
-                sd.struct_span_err(sp, &format!("invalid suffix `{}` for float literal", suf))
-                  .help("valid suffixes are `f32` and `f64`")
-                  .emit();
+                invalid_suffix_err(sd, sp, suf,
+                                   &format!("invalid suffix `{}` for float literal", suf),
+                                   "valid suffixes are `f32` and `f64`",
+                                   FLOAT_SUFFIXES);
             }
 
             ast::LitKind::FloatUnsuffixed(data)
@@ -619,15 +747,17 @@ pub fn integer_lit(s: &str,
                 // i<digits> and u<digits> look like widths, so lets
                 // give an error message along those lines
                 if looks_like_width_suffix(&['i', 'u'], suf) {
-                    sd.struct_span_err(sp, &format!("invalid width `{}` for integer literal",
-                                             &suf[1..]))
-                      .help("valid widths are 8, 16, 32 and 64")
-                      .emit();
+                    invalid_suffix_err(sd, sp, suf,
+                                       &format!("invalid width `{}` for integer literal",
+                                                &suf[1..]),
+                                       "valid widths are 8, 16, 32 and 64",
+                                       INT_SUFFIXES);
                 } else {
-                    sd.struct_span_err(sp, &format!("invalid suffix `{}` for numeric literal", suf))
-                      .help("the suffix must be one of the integral types \
-                             (`u32`, `isize`, etc)")
-                      .emit();
+                    invalid_suffix_err(sd, sp, suf,
+                                       &format!("invalid suffix `{}` for numeric literal", suf),
+                                       "the suffix must be one of the integral types \
+                                        (`u32`, `isize`, etc)",
+                                       INT_SUFFIXES);
                 }
 
                 ty
@@ -734,9 +864,9 @@ mod tests {
         match (tts.len(), tts.get(0), tts.get(1), tts.get(2), tts.get(3)) {
             (
                 4,
-                Some(&TokenTree::Token(_, token::Ident(name_macro_rules))),
+                Some(&TokenTree::Token(_, token::Ident(name_macro_rules, false))),
                 Some(&TokenTree::Token(_, token::Not)),
-                Some(&TokenTree::Token(_, token::Ident(name_zip))),
+                Some(&TokenTree::Token(_, token::Ident(name_zip, false))),
                 Some(&TokenTree::Delimited(_, ref macro_delimed)),
             )
             if name_macro_rules.name.as_str() == "macro_rules"
@@ -755,7 +885,7 @@ mod tests {
                             (
                                 2,
                                 Some(&TokenTree::Token(_, token::Dollar)),
-                                Some(&TokenTree::Token(_, token::Ident(ident))),
+                                Some(&TokenTree::Token(_, token::Ident(ident, false))),
                             )
                             if first_delimed.delim == token::Paren
                             && ident.name.as_str() == "a" => {},
@@ -766,7 +896,7 @@ mod tests {
                             (
                                 2,
                                 Some(&TokenTree::Token(_, token::Dollar)),
-                                Some(&TokenTree::Token(_, token::Ident(ident))),
+                                Some(&TokenTree::Token(_, token::Ident(ident, false))),
                             )
                             if second_delimed.delim == token::Paren
                             && ident.name.as_str() == "a" => {},
@@ -785,17 +915,17 @@ mod tests {
         let tts = string_to_tts("fn a (b : i32) { b; }".to_string());
 
         let expected = vec![
-            TokenTree::Token(sp(0, 2), token::Ident(str_to_ident("fn"))),
-            TokenTree::Token(sp(3, 4), token::Ident(str_to_ident("a"))),
+            TokenTree::Token(sp(0, 2), token::Ident(str_to_ident("fn"), false)),
+            TokenTree::Token(sp(3, 4), token::Ident(str_to_ident("a"), false)),
             TokenTree::Delimited(
                 sp(5, 14),
                 Rc::new(ast::Delimited {
                     delim: token::DelimToken::Paren,
                     open_span: sp(5, 6),
                     tts: vec![
-                        TokenTree::Token(sp(6, 7), token::Ident(str_to_ident("b"))),
+                        TokenTree::Token(sp(6, 7), token::Ident(str_to_ident("b"), false)),
                         TokenTree::Token(sp(8, 9), token::Colon),
-                        TokenTree::Token(sp(10, 13), token::Ident(str_to_ident("i32"))),
+                        TokenTree::Token(sp(10, 13), token::Ident(str_to_ident("i32"), false)),
                     ],
                     close_span: sp(13, 14),
                 })),
@@ -805,7 +935,7 @@ mod tests {
                     delim: token::DelimToken::Brace,
                     open_span: sp(15, 16),
                     tts: vec![
-                        TokenTree::Token(sp(17, 18), token::Ident(str_to_ident("b"))),
+                        TokenTree::Token(sp(17, 18), token::Ident(str_to_ident("b"), false)),
                         TokenTree::Token(sp(18, 19), token::Semi),
                     ],
                     close_span: sp(20, 21),
@@ -926,6 +1056,7 @@ mod tests {
                                     ast::Generics{ // no idea on either of these:
                                         lifetimes: Vec::new(),
                                         ty_params: P::new(),
+                                        const_params: P::new(),
                                         where_clause: ast::WhereClause {
                                             id: ast::DUMMY_NODE_ID,
                                             predicates: Vec::new(),
@@ -1090,4 +1221,29 @@ mod tests {
             Err(_) => panic!("could not get snippet"),
         }
     }
+
+    #[test]
+    fn crate_with_recovery() {
+        let sess = ParseSess::new();
+        let source = "fn f() { let x = ; }\n\
+                      garbage\n\
+                      fn g() {\n\
+                      fn h() {}\n".to_string();
+        let (krate, mut errors) =
+            parse_crate_from_source_str_with_recovery("<source>".to_string(), source,
+                                                      Vec::new(), &sess);
+
+        let names = krate.module.items.iter()
+                         .map(|i| i.ident.name.as_str().to_string())
+                         .collect::<Vec<_>>();
+        assert_eq!(names, ["f", "g", "h"]);
+
+        let messages = errors.iter().map(|e| e.message().to_string()).collect::<Vec<_>>();
+        assert_eq!(messages, ["un-closed delimiter",
+                              "expected expression, found `;`",
+                              "expected item, found `garbage`"]);
+        for e in &mut errors {
+            e.cancel();
+        }
+    }
 }
@@ -15,7 +15,7 @@ use ast::Unsafety;
 use ast::{Mod, Arg, Arm, Attribute, BindingMode, TraitItemKind};
 use ast::Block;
 use ast::{BlockCheckMode, CaptureBy};
-use ast::{Constness, Crate, CrateConfig};
+use ast::{ConstParam, Constness, Crate, CrateConfig};
 use ast::{Decl, DeclKind, Defaultness};
 use ast::{EMPTY_CTXT, EnumDef, ExplicitSelf};
 use ast::{Expr, ExprKind, RangeLimits};
@@ -40,7 +40,7 @@ use ast::{Visibility, WhereClause};
 use attr::{ThinAttributes, ThinAttributesExt, AttributesExt};
 use ast::{BinOpKind, UnOp};
 use ast;
-use codemap::{self, Span, BytePos, Spanned, spanned, mk_sp, CodeMap};
+use codemap::{self, Span, BytePos, Pos, Spanned, spanned, mk_sp, CodeMap};
 use errors::{self, DiagnosticBuilder};
 use ext::tt::macro_parser;
 use parse;
@@ -56,6 +56,7 @@ use print::pprust;
 use ptr::P;
 use parse::PResult;
 
+use std::cell::RefCell;
 use std::collections::HashSet;
 use std::mem;
 use std::path::{Path, PathBuf};
@@ -72,6 +73,14 @@ bitflags! {
 
 type ItemInfo = (Ident, ItemKind, Option<Vec<Attribute> >);
 
+/// Keywords that start an item, used as synchronisation points when
+/// recovering from syntax errors.
+const ITEM_KEYWORDS: &'static [keywords::Keyword] = &[
+    keywords::Fn, keywords::Pub, keywords::Struct, keywords::Enum,
+    keywords::Impl, keywords::Trait, keywords::Mod, keywords::Use,
+    keywords::Extern, keywords::Static, keywords::Const, keywords::Type,
+];
+
 /// How to parse a path. There are three different kinds of paths, all of which
 /// are parsed somewhat differently.
 #[derive(Copy, Clone, PartialEq)]
@@ -274,6 +283,16 @@ pub struct Parser<'a> {
     /// into modules, and sub-parsers have new values for this name.
     pub root_module_name: Option<String>,
     pub expected_tokens: Vec<TokenType>,
+    /// Whether the parser is in error-recovery mode. In this mode syntax
+    /// errors are stashed rather than emitted, and the parser synthesises
+    /// placeholder nodes so that it can keep going and produce a complete
+    /// AST for malformed input. Used by tools such as IDEs.
+    pub recovery: bool,
+    /// The errors stashed in error-recovery mode.
+    recovered_errors: RefCell<Vec<DiagnosticBuilder<'a>>>,
+    /// Token trees split off the end of an un-closed delimited group in
+    /// error-recovery mode, to be spliced into the enclosing group.
+    hoisted_tts: Vec<TokenTree>,
 }
 
 #[derive(PartialEq, Eq, Clone)]
@@ -381,6 +400,9 @@ impl<'a> Parser<'a> {
             owns_directory: true,
             root_module_name: None,
             expected_tokens: Vec::new(),
+            recovery: false,
+            recovered_errors: RefCell::new(Vec::new()),
+            hoisted_tts: Vec::new(),
         }
     }
 
@@ -572,7 +594,7 @@ impl<'a> Parser<'a> {
         self.check_strict_keywords();
         self.check_reserved_keywords();
         match self.token {
-            token::Ident(i) => {
+            token::Ident(i, _) => {
                 self.bump();
                 Ok(i)
             }
@@ -639,8 +661,8 @@ impl<'a> Parser<'a> {
     }
 
     pub fn check_contextual_keyword(&mut self, ident: Ident) -> bool {
-        self.expected_tokens.push(TokenType::Token(token::Ident(ident)));
-        if let token::Ident(ref cur_ident) = self.token {
+        self.expected_tokens.push(TokenType::Token(token::Ident(ident, false)));
+        if let token::Ident(ref cur_ident, false) = self.token {
             cur_ident.name == ident.name
         } else {
             false
@@ -1095,7 +1117,11 @@ impl<'a> Parser<'a> {
         self.sess.span_diagnostic.span_warn(sp, m)
     }
     pub fn span_err(&self, sp: Span, m: &str) {
-        self.sess.span_diagnostic.span_err(sp, m)
+        if self.recovery {
+            self.recovered_error(self.sess.span_diagnostic.struct_span_err(sp, m));
+        } else {
+            self.sess.span_diagnostic.span_err(sp, m)
+        }
     }
     pub fn span_bug(&self, sp: Span, m: &str) -> ! {
         self.sess.span_diagnostic.span_bug(sp, m)
@@ -1108,6 +1134,22 @@ impl<'a> Parser<'a> {
         &self.sess.span_diagnostic
     }
 
+    /// Report an error the parser has recovered from. In error-recovery mode
+    /// the diagnostic is stashed so it can be handed back to the caller along
+    /// with the AST, otherwise it is emitted straight away.
+    pub fn recovered_error(&self, mut err: DiagnosticBuilder<'a>) {
+        if self.recovery {
+            self.recovered_errors.borrow_mut().push(err);
+        } else {
+            err.emit();
+        }
+    }
+
+    /// Take the errors stashed so far in error-recovery mode.
+    pub fn take_recovered_errors(&self) -> Vec<DiagnosticBuilder<'a>> {
+        mem::replace(&mut *self.recovered_errors.borrow_mut(), Vec::new())
+    }
+
     pub fn id_to_interned_str(&mut self, id: Ident) -> InternedString {
         id.name.as_str()
     }
@@ -1262,9 +1304,17 @@ impl<'a> Parser<'a> {
             let lo = p.span.lo;
 
             let (name, node) = if p.eat_keyword(keywords::Type) {
-                let TyParam {ident, bounds, default, ..} = p.parse_ty_param()?;
+                let ident = p.parse_ident()?;
+                let mut generics = p.parse_generics()?;
+                let bounds = p.parse_colon_then_ty_param_bounds(BoundParsingMode::Modified)?;
+                generics.where_clause = p.parse_where_clause()?;
+                let default = if p.eat(&token::Eq) {
+                    Some(p.parse_ty_sum()?)
+                } else {
+                    None
+                };
                 p.expect(&token::Semi)?;
-                (ident, TraitItemKind::Type(bounds, default))
+                (ident, TraitItemKind::Type(bounds, default, generics))
             } else if p.is_const_item() {
                 p.expect_keyword(keywords::Const)?;
                 let ident = p.parse_ident()?;
@@ -1437,6 +1487,9 @@ impl<'a> Parser<'a> {
             } else {
                 TyKind::Tup(ts)
             }
+        } else if self.eat(&token::Not) {
+            // NEVER
+            TyKind::Never
         } else if self.check(&token::BinOp(token::Star)) {
             // STAR POINTER (bare pointer?)
             self.bump();
@@ -1461,6 +1514,14 @@ impl<'a> Parser<'a> {
             self.parse_borrowed_pointee()?
         } else if self.check_keyword(keywords::For) {
             self.parse_for_in_type()?
+        } else if self.eat_keyword(keywords::Impl) {
+            // IMPL TRAIT
+            let bounds = self.parse_ty_param_bounds(BoundParsingMode::Bare)?;
+            if !bounds.iter().any(|b| if let TraitTyParamBound(..) = *b { true } else { false }) {
+                let last_span = self.last_span;
+                self.span_err(last_span, "at least one trait must be specified");
+            }
+            TyKind::ImplTrait(bounds)
         } else if self.token_is_bare_fn_keyword() {
             // BARE FUNCTION
             self.parse_ty_bare_fn(Vec::new())?
@@ -1709,7 +1770,7 @@ impl<'a> Parser<'a> {
 
     pub fn parse_path_segment_ident(&mut self) -> PResult<'a, ast::Ident> {
         match self.token {
-            token::Ident(sid) if self.token.is_path_segment_keyword() => {
+            token::Ident(sid, _) if self.token.is_path_segment_keyword() => {
                 self.bump();
                 Ok(sid)
             }
@@ -1827,11 +1888,13 @@ impl<'a> Parser<'a> {
 
             // Parse types, optionally.
             let parameters = if self.eat_lt() {
-                let (lifetimes, types, bindings) = self.parse_generic_values_after_lt()?;
+                let (lifetimes, types, consts, bindings) =
+                    self.parse_generic_values_after_lt()?;
 
                 ast::PathParameters::AngleBracketed(ast::AngleBracketedParameterData {
                     lifetimes: lifetimes,
                     types: P::from_vec(types),
+                    consts: P::from_vec(consts),
                     bindings: P::from_vec(bindings),
                 })
             } else if self.eat(&token::OpenDelim(token::Paren)) {
@@ -1890,10 +1953,12 @@ impl<'a> Parser<'a> {
             // Check for a type segment.
             if self.eat_lt() {
                 // Consumed `a::b::<`, go look for types
-                let (lifetimes, types, bindings) = self.parse_generic_values_after_lt()?;
+                let (lifetimes, types, consts, bindings) =
+                    self.parse_generic_values_after_lt()?;
                 let parameters = ast::AngleBracketedParameterData {
                     lifetimes: lifetimes,
                     types: P::from_vec(types),
+                    consts: P::from_vec(consts),
                     bindings: P::from_vec(bindings),
                 };
                 segments.push(ast::PathSegment {
@@ -2050,12 +2115,23 @@ impl<'a> Parser<'a> {
         let lo = self.span.lo;
         let i = self.parse_ident()?;
         let hi = self.last_span.hi;
-        self.expect(&token::Colon)?;
-        let e = self.parse_expr()?;
+
+        // A field name not followed by `:` is shorthand for `name: name`; the
+        // desugared path expression takes the span of the name itself.
+        let is_shorthand = !self.check(&token::Colon) &&
+            (self.check(&token::Comma) || self.check(&token::CloseDelim(token::Brace)));
+        let e = if is_shorthand {
+            let path = ast::Path::from_ident(mk_sp(lo, hi), i);
+            self.mk_expr(lo, hi, ExprKind::Path(None, path), None)
+        } else {
+            self.expect(&token::Colon)?;
+            self.parse_expr()?
+        };
         Ok(ast::Field {
             ident: spanned(lo, hi, i),
             span: mk_sp(lo, e.span.hi),
             expr: e,
+            is_shorthand: is_shorthand,
         })
     }
 
@@ -2302,6 +2378,11 @@ impl<'a> Parser<'a> {
                     let lo = self.last_span.lo;
                     return self.parse_loop_expr(None, lo, attrs);
                 }
+                if self.is_catch_expr() {
+                    self.bump();
+                    self.bump();
+                    return self.parse_catch_expr(lo, attrs);
+                }
                 if self.eat_keyword(keywords::Continue) {
                     let ex = if self.token.is_lifetime() {
                         let ex = ExprKind::Again(Some(Spanned{
@@ -2334,15 +2415,25 @@ impl<'a> Parser<'a> {
                         ex = ExprKind::Ret(None);
                     }
                 } else if self.eat_keyword(keywords::Break) {
-                    if self.token.is_lifetime() {
-                        ex = ExprKind::Break(Some(Spanned {
+                    let lt = if self.token.is_lifetime() {
+                        let spanned_lt = Spanned {
                             node: self.get_lifetime(),
                             span: self.span
-                        }));
+                        };
                         self.bump();
+                        Some(spanned_lt)
                     } else {
-                        ex = ExprKind::Break(None);
-                    }
+                        None
+                    };
+                    let e = if self.token.can_begin_expr()
+                               && !(self.token == token::OpenDelim(token::Brace)
+                                    && self.restrictions.contains(
+                                           Restrictions::RESTRICTION_NO_STRUCT_LITERAL)) {
+                        Some(self.parse_expr()?)
+                    } else {
+                        None
+                    };
+                    ex = ExprKind::Break(lt, e);
                     hi = self.last_span.hi;
                 } else if self.token.is_keyword(keywords::Let) {
                     // Catch this syntax error here, instead of in `check_strict_keywords`, so
@@ -2392,8 +2483,8 @@ impl<'a> Parser<'a> {
                                         Ok(e) => {
                                             base = Some(e);
                                         }
-                                        Err(mut e) => {
-                                            e.emit();
+                                        Err(e) => {
+                                            self.recovered_error(e);
                                             self.recover_stmt();
                                         }
                                     }
@@ -2402,8 +2493,8 @@ impl<'a> Parser<'a> {
 
                                 match self.parse_field() {
                                     Ok(f) => fields.push(f),
-                                    Err(mut e) => {
-                                        e.emit();
+                                    Err(e) => {
+                                        self.recovered_error(e);
                                         self.recover_stmt();
                                         break;
                                     }
@@ -2413,8 +2504,8 @@ impl<'a> Parser<'a> {
                                                        &[token::Comma],
                                                        &[token::CloseDelim(token::Brace)]) {
                                     Ok(()) => {}
-                                    Err(mut e) => {
-                                        e.emit();
+                                    Err(e) => {
+                                        self.recovered_error(e);
                                         self.recover_stmt();
                                         break;
                                     }
@@ -2440,7 +2531,15 @@ impl<'a> Parser<'a> {
                             err.cancel();
                             let msg = format!("expected expression, found {}",
                                               self.this_token_descr());
-                            return Err(self.fatal(&msg));
+                            let err = self.fatal(&msg);
+                            if self.recovery && self.token_terminates_expr() {
+                                // Synthesise a placeholder for the missing
+                                // expression without consuming anything, so
+                                // that the enclosing construct can finish.
+                                self.recovered_error(err);
+                                return Ok(self.mk_expr(lo, lo, ExprKind::Err, attrs));
+                            }
+                            return Err(err);
                         }
                     }
                 }
@@ -2450,6 +2549,16 @@ impl<'a> Parser<'a> {
         return Ok(self.mk_expr(lo, hi, ex, attrs));
     }
 
+    /// Can the current token legitimately follow a complete expression, so
+    /// that an expression missing before it can be recovered from?
+    fn token_terminates_expr(&self) -> bool {
+        match self.token {
+            token::Semi | token::Comma | token::FatArrow | token::Eof |
+            token::CloseDelim(_) => true,
+            _ => false,
+        }
+    }
+
     fn parse_or_use_outer_attributes(&mut self,
                                      already_parsed_attrs: Option<ThinAttributes>)
                                      -> PResult<'a, ThinAttributes> {
@@ -2581,7 +2690,7 @@ impl<'a> Parser<'a> {
             // expr.f
             if self.eat(&token::Dot) {
                 match self.token {
-                  token::Ident(i) => {
+                  token::Ident(i, _) => {
                     let dot_pos = self.last_span.hi;
                     hi = self.span.hi;
                     self.bump();
@@ -2795,16 +2904,18 @@ impl<'a> Parser<'a> {
                 // Parse the token trees within the delimiters.
                 // We stop at any delimiter so we can try to recover if the user
                 // uses an incorrect delimiter.
-                let tts = self.parse_seq_to_before_tokens(&[&token::CloseDelim(token::Brace),
-                                                            &token::CloseDelim(token::Paren),
-                                                            &token::CloseDelim(token::Bracket)],
-                                                          SeqSep::none(),
-                                                          |p| p.parse_token_tree(),
-                                                          |mut e| e.emit());
-
-                let close_span = self.span;
-                // Expand to cover the entire delimited token tree
-                let span = Span { hi: close_span.hi, ..pre_span };
+                let mut tts = if self.recovery {
+                    self.parse_tts_to_close_delim_with_recovery()
+                } else {
+                    self.parse_seq_to_before_tokens(&[&token::CloseDelim(token::Brace),
+                                                      &token::CloseDelim(token::Paren),
+                                                      &token::CloseDelim(token::Bracket)],
+                                                    SeqSep::none(),
+                                                    |p| p.parse_token_tree(),
+                                                    |mut e| e.emit())
+                };
+
+                let mut close_span = self.span;
 
                 match self.token {
                     // Correct delmiter.
@@ -2825,7 +2936,7 @@ impl<'a> Parser<'a> {
                         if let Some(&(_, sp)) = self.open_braces.last() {
                             err.span_note(sp, "unclosed delimiter");
                         };
-                        err.emit();
+                        self.recovered_error(err);
 
                         self.open_braces.pop().unwrap();
 
@@ -2840,6 +2951,26 @@ impl<'a> Parser<'a> {
                             self.bump();
                         }
                     }
+                    token::Eof if self.recovery => {
+                        // There is no later chance to report this delimiter,
+                        // so close it here. A brace is taken to end before the
+                        // first item that is not indented past the line the
+                        // brace was opened on; whatever follows is handed to
+                        // the enclosing group.
+                        self.open_braces.pop().unwrap();
+                        if delim == token::Brace {
+                            if let Some(i) = self.find_dedented_item(&tts, open_span) {
+                                self.hoisted_tts = tts.split_off(i);
+                            }
+                        }
+                        let hi = tts.last().map_or(open_span.hi, |tt| tt.get_span().hi);
+                        close_span = mk_sp(hi, hi);
+
+                        let mut err = self.diagnostic().struct_span_err(open_span,
+                                                                        "un-closed delimiter");
+                        err.span_note(close_span, "delimiter assumed to be closed here");
+                        self.recovered_error(err);
+                    }
                     token::Eof => {
                         // Silently recover, the EOF token will be seen again
                         // and an error emitted then. Thus we don't pop from
@@ -2848,6 +2979,9 @@ impl<'a> Parser<'a> {
                     _ => unreachable!(),
                 }
 
+                // Expand to cover the entire delimited token tree
+                let span = Span { hi: close_span.hi, ..pre_span };
+
                 Ok(TokenTree::Delimited(span, Rc::new(Delimited {
                     delim: delim,
                     open_span: open_span,
@@ -2887,11 +3021,77 @@ impl<'a> Parser<'a> {
     pub fn parse_all_token_trees(&mut self) -> PResult<'a, Vec<TokenTree>> {
         let mut tts = Vec::new();
         while self.token != token::Eof {
-            tts.push(self.parse_token_tree()?);
+            let consumed = self.tokens_consumed;
+            match self.parse_token_tree() {
+                Ok(tt) => {
+                    tts.push(tt);
+                    tts.extend(self.hoisted_tts.drain(..));
+                }
+                Err(e) => {
+                    if !self.recovery {
+                        return Err(e);
+                    }
+                    // E.g. an unexpected close delimiter, just skip it.
+                    self.recovered_error(e);
+                    if self.tokens_consumed == consumed {
+                        self.bump();
+                    }
+                }
+            }
         }
         Ok(tts)
     }
 
+    /// Parse token trees up to the next close delimiter or the end of the
+    /// file, in error-recovery mode.
+    fn parse_tts_to_close_delim_with_recovery(&mut self) -> Vec<TokenTree> {
+        let mut tts = Vec::new();
+        loop {
+            match self.token {
+                token::CloseDelim(_) | token::Eof => return tts,
+                _ => {}
+            }
+            let consumed = self.tokens_consumed;
+            match self.parse_token_tree() {
+                Ok(tt) => {
+                    tts.push(tt);
+                    tts.extend(self.hoisted_tts.drain(..));
+                }
+                Err(e) => {
+                    self.recovered_error(e);
+                    if self.tokens_consumed == consumed {
+                        self.bump();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Find the first token tree in `tts` that starts an item on a line of its
+    /// own, indented no further than the line containing `open_span`.
+    fn find_dedented_item(&self, tts: &[TokenTree], open_span: Span) -> Option<usize> {
+        let cm = self.sess.codemap();
+        let open = cm.lookup_char_pos(open_span.lo);
+        let indent = open.file.get_line(open.line - 1).map_or(0, |line| {
+            line.chars().take_while(|c| c.is_whitespace()).count()
+        });
+
+        let mut last_line = open.line;
+        for (i, tt) in tts.iter().enumerate() {
+            let loc = cm.lookup_char_pos(tt.get_span().lo);
+            let starts_item = match *tt {
+                TokenTree::Token(_, token::Pound) => true,
+                TokenTree::Token(_, ref tok) => ITEM_KEYWORDS.iter().any(|&kw| tok.is_keyword(kw)),
+                _ => false,
+            };
+            if starts_item && loc.line > last_line && loc.col.to_usize() <= indent {
+                return Some(i);
+            }
+            last_line = cm.lookup_char_pos(tt.get_span().hi).line;
+        }
+        None
+    }
+
     /// Parse a prefix-unary-operator expr
     pub fn parse_prefix_expr(&mut self,
                              already_parsed_attrs: Option<ThinAttributes>)
@@ -3328,6 +3528,22 @@ impl<'a> Parser<'a> {
         Ok(self.mk_expr(span_lo, hi, ExprKind::Loop(body, opt_ident), attrs))
     }
 
+    /// Is the current token the start of a `do catch {...}` expression?
+    fn is_catch_expr(&mut self) -> bool {
+        self.token.is_keyword(keywords::Do) &&
+        self.look_ahead(1, |t| t.is_keyword(keywords::Catch)) &&
+        self.look_ahead(2, |t| *t == token::OpenDelim(token::Brace))
+    }
+
+    // parse `do catch {...}`, `do catch` tokens already eaten
+    pub fn parse_catch_expr(&mut self, span_lo: BytePos, attrs: ThinAttributes)
+                            -> PResult<'a, P<Expr>> {
+        let (iattrs, body) = self.parse_inner_attrs_and_block()?;
+        let attrs = attrs.append(iattrs.into_thin_attrs());
+        let hi = body.span.hi;
+        Ok(self.mk_expr(span_lo, hi, ExprKind::Catch(body), attrs))
+    }
+
     // `match` token already eaten
     fn parse_match_expr(&mut self, attrs: ThinAttributes) -> PResult<'a, P<Expr>> {
         let match_span = self.last_span;
@@ -3347,9 +3563,9 @@ impl<'a> Parser<'a> {
         while self.token != token::CloseDelim(token::Brace) {
             match self.parse_arm() {
                 Ok(arm) => arms.push(arm),
-                Err(mut e) => {
+                Err(e) => {
                     // Recover by skipping to the end of the block.
-                    e.emit();
+                    self.recovered_error(e);
                     self.recover_stmt();
                     let hi = self.span.hi;
                     if self.token == token::CloseDelim(token::Brace) {
@@ -3707,8 +3923,8 @@ impl<'a> Parser<'a> {
                         }
                         // Parse struct pattern
                         self.bump();
-                        let (fields, etc) = self.parse_pat_fields().unwrap_or_else(|mut e| {
-                            e.emit();
+                        let (fields, etc) = self.parse_pat_fields().unwrap_or_else(|e| {
+                            self.recovered_error(e);
                             self.recover_stmt();
                             (vec![], false)
                         });
@@ -3931,8 +4147,8 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_stmt_(&mut self) -> Option<Stmt> {
-        self.parse_stmt_without_recovery().unwrap_or_else(|mut e| {
-            e.emit();
+        self.parse_stmt_without_recovery().unwrap_or_else(|e| {
+            self.recovered_error(e);
             self.recover_stmt_(SemiColonMode::Break);
             None
         })
@@ -4172,7 +4388,13 @@ impl<'a> Parser<'a> {
                 _ => { // all other kinds of statements:
                     let mut hi = span.hi;
                     if classify::stmt_ends_with_semi(&node) {
-                        self.commit_stmt_expecting(token::Semi)?;
+                        if let Err(e) = self.commit_stmt_expecting(token::Semi) {
+                            if !self.recovery {
+                                return Err(e);
+                            }
+                            // Carry on as if the semicolon had been there.
+                            self.recovered_error(e);
+                        }
                         hi = self.last_span.hi;
                     }
 
@@ -4202,10 +4424,10 @@ impl<'a> Parser<'a> {
         // expression without semicolon
         if classify::expr_requires_semi_to_be_stmt(&e) {
             // Just check for errors and recover; do not eat semicolon yet.
-            if let Err(mut e) =
+            if let Err(e) =
                 self.commit_stmt(&[], &[token::Semi, token::CloseDelim(token::Brace)])
             {
-                e.emit();
+                self.recovered_error(e);
                 self.recover_stmt();
             }
         }
@@ -4321,21 +4543,42 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Matches constparam = `const` IDENT `:` ty
+    fn parse_const_param(&mut self) -> PResult<'a, ConstParam> {
+        let span = self.span;
+        self.expect_keyword(keywords::Const)?;
+        let ident = self.parse_ident()?;
+        self.expect(&token::Colon)?;
+        let ty = self.parse_ty()?;
+
+        Ok(ConstParam {
+            ident: ident,
+            id: ast::DUMMY_NODE_ID,
+            ty: ty,
+            span: mk_sp(span.lo, self.last_span.hi),
+        })
+    }
+
     /// Parse a set of optional generic type parameter declarations. Where
     /// clauses are not parsed here, and must be added later via
     /// `parse_where_clause()`.
     ///
     /// matches generics = ( ) | ( < > ) | ( < typaramseq ( , )? > ) | ( < lifetimes ( , )? > )
     ///                  | ( < lifetimes , typaramseq ( , )? > )
+    ///                  | ( < lifetimes , typaramseq , constparamseq ( , )? > )
     /// where   typaramseq = ( typaram ) | ( typaram , typaramseq )
+    ///         constparamseq = ( constparam ) | ( constparam , constparamseq )
     pub fn parse_generics(&mut self) -> PResult<'a, ast::Generics> {
         maybe_whole!(self, NtGenerics);
 
         if self.eat(&token::Lt) {
             let lifetime_defs = self.parse_lifetime_defs()?;
             let mut seen_default = false;
-            let ty_params = self.parse_seq_to_gt(Some(token::Comma), |p| {
+            let (ty_params, returned) = self.parse_seq_to_gt_or_return(Some(token::Comma), |p| {
                 p.forbid_lifetime()?;
+                if p.check_keyword(keywords::Const) {
+                    return Ok(None);
+                }
                 let ty_param = p.parse_ty_param()?;
                 if ty_param.default.is_some() {
                     seen_default = true;
@@ -4344,11 +4587,20 @@ impl<'a> Parser<'a> {
                     p.span_err(last_span,
                                "type parameters with a default must be trailing");
                 }
-                Ok(ty_param)
+                Ok(Some(ty_param))
             })?;
+            let const_params = if returned {
+                self.parse_seq_to_gt(Some(token::Comma), |p| {
+                    p.forbid_lifetime()?;
+                    p.parse_const_param()
+                })?
+            } else {
+                P::new()
+            };
             Ok(ast::Generics {
                 lifetimes: lifetime_defs,
                 ty_params: ty_params,
+                const_params: const_params,
                 where_clause: WhereClause {
                     id: ast::DUMMY_NODE_ID,
                     predicates: Vec::new(),
@@ -4361,6 +4613,7 @@ impl<'a> Parser<'a> {
 
     fn parse_generic_values_after_lt(&mut self) -> PResult<'a, (Vec<ast::Lifetime>,
                                                             Vec<P<Ty>>,
+                                                            Vec<P<Expr>>,
                                                             Vec<TypeBinding>)> {
         let span_lo = self.span.lo;
         let lifetimes = self.parse_lifetimes(token::Comma)?;
@@ -4399,7 +4652,7 @@ impl<'a> Parser<'a> {
             Some(token::Comma),
             |p| {
                 p.forbid_lifetime()?;
-                if p.look_ahead(1, |t| t == &token::Eq) {
+                if p.look_ahead(1, |t| t == &token::Eq) || p.is_const_arg_start() {
                     Ok(None)
                 } else {
                     Ok(Some(p.parse_ty_sum()?))
@@ -4409,7 +4662,24 @@ impl<'a> Parser<'a> {
 
         // If we found the `>`, don't continue.
         if !returned {
-            return Ok((lifetimes, types.into_vec(), Vec::new()));
+            return Ok((lifetimes, types.into_vec(), Vec::new(), Vec::new()));
+        }
+
+        // Then parse const arguments.
+        let (consts, returned) = self.parse_seq_to_gt_or_return(
+            Some(token::Comma),
+            |p| {
+                p.forbid_lifetime()?;
+                if p.is_const_arg_start() {
+                    Ok(Some(p.parse_const_arg()?))
+                } else {
+                    Ok(None)
+                }
+            }
+        )?;
+
+        if !returned {
+            return Ok((lifetimes, types.into_vec(), consts.into_vec(), Vec::new()));
         }
 
         // Then parse type bindings.
@@ -4434,7 +4704,25 @@ impl<'a> Parser<'a> {
                 });
             }
         )?;
-        Ok((lifetimes, types.into_vec(), bindings.into_vec()))
+        Ok((lifetimes, types.into_vec(), consts.into_vec(), bindings.into_vec()))
+    }
+
+    /// Whether the current token starts a const argument in a list of
+    /// generic values, i.e. a (possibly negated) literal or a block.
+    fn is_const_arg_start(&self) -> bool {
+        self.token.is_lit() ||
+            self.token == token::BinOp(token::Minus) ||
+            self.token == token::OpenDelim(token::Brace)
+    }
+
+    /// Matches constarg = `-`? LIT | block
+    fn parse_const_arg(&mut self) -> PResult<'a, P<Expr>> {
+        if self.check(&token::OpenDelim(token::Brace)) {
+            let lo = self.span.lo;
+            self.parse_block_expr(lo, BlockCheckMode::Default, None)
+        } else {
+            self.parse_pat_literal_maybe_minus()
+        }
     }
 
     fn forbid_lifetime(&mut self) -> PResult<'a, ()> {
@@ -4629,7 +4917,7 @@ impl<'a> Parser<'a> {
 
     fn expect_self_ident(&mut self) -> PResult<'a, ast::Ident> {
         match self.token {
-            token::Ident(id) if id.name == keywords::SelfValue.name() => {
+            token::Ident(id, false) if id.name == keywords::SelfValue.name() => {
                 self.bump();
                 // The hygiene context of `id` needs to be preserved here,
                 // so we can't just return `SelfValue.ident()`.
@@ -4909,10 +5197,12 @@ impl<'a> Parser<'a> {
         let defaultness = self.parse_defaultness()?;
         let (name, node) = if self.eat_keyword(keywords::Type) {
             let name = self.parse_ident()?;
+            let mut generics = self.parse_generics()?;
+            generics.where_clause = self.parse_where_clause()?;
             self.expect(&token::Eq)?;
             let typ = self.parse_ty_sum()?;
             self.expect(&token::Semi)?;
-            (name, ast::ImplItemKind::Type(typ))
+            (name, ast::ImplItemKind::Type(typ, generics))
         } else if self.is_const_item() {
             self.expect_keyword(keywords::Const)?;
             let name = self.parse_ident()?;
@@ -4944,7 +5234,7 @@ impl<'a> Parser<'a> {
             Visibility::Inherited => (),
             _ => {
                 let is_macro_rules: bool = match self.token {
-                    token::Ident(sid) => sid.name == intern("macro_rules"),
+                    token::Ident(sid, _) => sid.name == intern("macro_rules"),
                     _ => false,
                 };
                 if is_macro_rules {
@@ -5013,12 +5303,25 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Parse trait Foo { ... }
+    /// Parse trait Foo { ... } or trait Foo = Bar + Quux;
     fn parse_item_trait(&mut self, unsafety: Unsafety) -> PResult<'a, ItemInfo> {
 
         let ident = self.parse_ident()?;
         let mut tps = self.parse_generics()?;
 
+        if self.eat(&token::Eq) {
+            // It's a trait alias.
+            let lo = self.last_span.lo;
+            let bounds = self.parse_ty_param_bounds(BoundParsingMode::Bare)?;
+            tps.where_clause = self.parse_where_clause()?;
+            self.expect(&token::Semi)?;
+            if unsafety == Unsafety::Unsafe {
+                self.span_err(mk_sp(lo, self.last_span.hi),
+                              "trait aliases cannot be declared `unsafe`");
+            }
+            return Ok((ident, ItemKind::TraitAlias(tps, bounds), None));
+        }
+
         // Parse supertrait bounds.
         let bounds = self.parse_colon_then_ty_param_bounds(BoundParsingMode::Bare)?;
 
@@ -5032,7 +5335,11 @@ impl<'a> Parser<'a> {
     ///    impl<T> Foo { ... }
     ///    impl<T> ToString for &'static T { ... }
     ///    impl Send for .. {}
-    fn parse_item_impl(&mut self, unsafety: ast::Unsafety) -> PResult<'a, ItemInfo> {
+    ///    default impl<T> ToString for T { ... }
+    fn parse_item_impl(&mut self,
+                       unsafety: ast::Unsafety,
+                       defaultness: Defaultness)
+                       -> PResult<'a, ItemInfo> {
         let impl_span = self.span;
 
         // First, parse type parameters if necessary.
@@ -5084,6 +5391,10 @@ impl<'a> Parser<'a> {
                 self.span_err(impl_span, "default trait implementations are not \
                                           allowed to have generics");
             }
+            if defaultness == Defaultness::Default {
+                self.span_err(impl_span, "default trait implementations cannot \
+                                          be declared `default`");
+            }
 
             self.expect(&token::OpenDelim(token::Brace))?;
             self.expect(&token::CloseDelim(token::Brace))?;
@@ -5104,7 +5415,7 @@ impl<'a> Parser<'a> {
             }
 
             Ok((keywords::Invalid.ident(),
-             ItemKind::Impl(unsafety, polarity, generics, opt_trait, ty, impl_items),
+             ItemKind::Impl(unsafety, polarity, defaultness, generics, opt_trait, ty, impl_items),
              Some(attrs)))
         }
     }
@@ -5189,6 +5500,25 @@ impl<'a> Parser<'a> {
         Ok((class_name, ItemKind::Struct(vdata, generics), None))
     }
 
+    /// Parse union Foo { ... }
+    fn parse_item_union(&mut self) -> PResult<'a, ItemInfo> {
+        let union_name = self.parse_ident()?;
+        let mut generics = self.parse_generics()?;
+
+        let vdata = if self.token.is_keyword(keywords::Where) {
+            generics.where_clause = self.parse_where_clause()?;
+            VariantData::Struct(self.parse_record_struct_body()?, ast::DUMMY_NODE_ID)
+        } else if self.token == token::OpenDelim(token::Brace) {
+            VariantData::Struct(self.parse_record_struct_body()?, ast::DUMMY_NODE_ID)
+        } else {
+            let token_str = self.this_token_to_string();
+            return Err(self.fatal(&format!("expected `where` or `{{` after union \
+                                            name, found `{}`", token_str)))
+        };
+
+        Ok((union_name, ItemKind::Union(vdata, generics), None))
+    }
+
     pub fn parse_record_struct_body(&mut self) -> PResult<'a, Vec<StructField>> {
         let mut fields = Vec::new();
         if self.eat(&token::OpenDelim(token::Brace)) {
@@ -5321,13 +5651,34 @@ impl<'a> Parser<'a> {
     /// Given a termination token, parse all of the items in a module
     fn parse_mod_items(&mut self, term: &token::Token, inner_lo: BytePos) -> PResult<'a, Mod> {
         let mut items = vec![];
-        while let Some(item) = self.parse_item()? {
-            items.push(item);
-        }
+        loop {
+            let consumed = self.tokens_consumed;
+            match self.parse_item() {
+                Ok(Some(item)) => {
+                    items.push(item);
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    if !self.recovery {
+                        return Err(e);
+                    }
+                    self.recovered_error(e);
+                    self.recover_item(consumed);
+                    continue;
+                }
+            }
 
-        if !self.eat(term) {
+            if self.eat(term) {
+                break;
+            }
             let token_str = self.this_token_to_string();
-            return Err(self.fatal(&format!("expected item, found `{}`", token_str)));
+            let err = self.fatal(&format!("expected item, found `{}`", token_str));
+            if !self.recovery {
+                return Err(err);
+            }
+            self.recovered_error(err);
+            self.recover_item(consumed);
         }
 
         let hi = if self.span == codemap::DUMMY_SP {
@@ -5342,6 +5693,36 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Skip tokens until the start of what looks like the next item, or the
+    /// end of the enclosing module. Used in error-recovery mode; always makes
+    /// progress past the token at which `consumed` tokens had been consumed.
+    fn recover_item(&mut self, consumed: usize) {
+        if self.tokens_consumed == consumed && self.token != token::Eof {
+            self.bump();
+        }
+        let mut brace_depth = 0;
+        loop {
+            match self.token {
+                token::Eof => return,
+                token::OpenDelim(token::Brace) => brace_depth += 1,
+                token::CloseDelim(token::Brace) => {
+                    if brace_depth == 0 {
+                        return;
+                    }
+                    brace_depth -= 1;
+                }
+                token::Pound if brace_depth == 0 => return,
+                _ if brace_depth == 0 && self.token_starts_item() => return,
+                _ => {}
+            }
+            self.bump();
+        }
+    }
+
+    fn token_starts_item(&self) -> bool {
+        ITEM_KEYWORDS.iter().any(|&kw| self.token.is_keyword(kw))
+    }
+
     fn parse_item_const(&mut self, m: Option<Mutability>) -> PResult<'a, ItemInfo> {
         let id = self.parse_ident()?;
         self.expect(&token::Colon)?;
@@ -5527,9 +5908,16 @@ impl<'a> Parser<'a> {
                                               owns_directory,
                                               Some(name),
                                               id_sp);
+        p0.recovery = self.recovery;
         let mod_inner_lo = p0.span.lo;
-        let mod_attrs = p0.parse_inner_attributes()?;
-        let m0 = p0.parse_mod_items(&token::Eof, mod_inner_lo)?;
+        let result = p0.parse_inner_attributes().and_then(|mod_attrs| {
+            let m0 = p0.parse_mod_items(&token::Eof, mod_inner_lo)?;
+            Ok((mod_attrs, m0))
+        });
+        for e in p0.take_recovered_errors() {
+            self.recovered_error(e);
+        }
+        let (mod_attrs, m0) = result?;
         self.sess.included_mod_stack.borrow_mut().pop();
         Ok((ast::ItemKind::Mod(m0), mod_attrs))
     }
@@ -5892,7 +6280,8 @@ impl<'a> Parser<'a> {
             // IMPL ITEM
             self.expect_keyword(keywords::Unsafe)?;
             self.expect_keyword(keywords::Impl)?;
-            let (ident, item_, extra_attrs) = self.parse_item_impl(ast::Unsafety::Unsafe)?;
+            let (ident, item_, extra_attrs) =
+                self.parse_item_impl(ast::Unsafety::Unsafe, Defaultness::Final)?;
             let last_span = self.last_span;
             let item = self.mk_item(lo,
                                     last_span.hi,
@@ -5987,9 +6376,30 @@ impl<'a> Parser<'a> {
                                     maybe_append(attrs, extra_attrs));
             return Ok(Some(item));
         }
+        if self.check_contextual_keyword(keywords::Default.ident()) &&
+            (self.look_ahead(1, |t| t.is_keyword(keywords::Impl)) ||
+             (self.look_ahead(1, |t| t.is_keyword(keywords::Unsafe)) &&
+              self.look_ahead(2, |t| t.is_keyword(keywords::Impl))))
+        {
+            // DEFAULT IMPL ITEM
+            self.bump();
+            let unsafety = self.parse_unsafety()?;
+            self.expect_keyword(keywords::Impl)?;
+            let (ident, item_, extra_attrs) =
+                self.parse_item_impl(unsafety, Defaultness::Default)?;
+            let last_span = self.last_span;
+            let item = self.mk_item(lo,
+                                    last_span.hi,
+                                    ident,
+                                    item_,
+                                    visibility,
+                                    maybe_append(attrs, extra_attrs));
+            return Ok(Some(item));
+        }
         if self.eat_keyword(keywords::Impl) {
             // IMPL ITEM
-            let (ident, item_, extra_attrs) = self.parse_item_impl(ast::Unsafety::Normal)?;
+            let (ident, item_, extra_attrs) =
+                self.parse_item_impl(ast::Unsafety::Normal, Defaultness::Final)?;
             let last_span = self.last_span;
             let item = self.mk_item(lo,
                                     last_span.hi,
@@ -6011,6 +6421,22 @@ impl<'a> Parser<'a> {
                                     maybe_append(attrs, extra_attrs));
             return Ok(Some(item));
         }
+        // `union` is a weak keyword: it only starts an item when
+        // followed by the name of the union.
+        if self.token.is_keyword(keywords::Union) &&
+                self.look_ahead(1, |t| t.is_ident() && !t.is_any_keyword()) {
+            // UNION ITEM
+            self.bump();
+            let (ident, item_, extra_attrs) = self.parse_item_union()?;
+            let last_span = self.last_span;
+            let item = self.mk_item(lo,
+                                    last_span.hi,
+                                    ident,
+                                    item_,
+                                    visibility,
+                                    maybe_append(attrs, extra_attrs));
+            return Ok(Some(item));
+        }
         self.parse_macro_use_or_failure(attrs,macros_allowed,attributes_allowed,lo,visibility)
     }
 
@@ -6207,6 +6633,31 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses a source module as a crate in error-recovery mode. A crate is
+    /// always produced; it contains `ExprKind::Err` placeholders wherever
+    /// an expression was missing, and skips items that could not be parsed.
+    /// The errors recovered from are returned alongside it, unemitted.
+    pub fn parse_crate_mod_with_recovery(&mut self) -> (Crate, Vec<DiagnosticBuilder<'a>>) {
+        self.recovery = true;
+        let lo = self.span.lo;
+        let attrs = self.parse_inner_attributes().unwrap_or_else(|e| {
+            self.recovered_error(e);
+            Vec::new()
+        });
+        let module = self.parse_mod_items(&token::Eof, lo).unwrap_or_else(|e| {
+            self.recovered_error(e);
+            ast::Mod { inner: mk_sp(lo, lo), items: Vec::new() }
+        });
+        let krate = ast::Crate {
+            attrs: attrs,
+            module: module,
+            config: self.cfg.clone(),
+            span: mk_sp(lo, self.span.lo),
+            exported_macros: Vec::new(),
+        };
+        (krate, self.take_recovered_errors())
+    }
+
     pub fn parse_optional_str(&mut self)
                               -> Option<(InternedString,
                                          ast::StrStyle,
@@ -131,7 +131,9 @@ pub enum Token {
     Literal(Lit, Option<ast::Name>),
 
     /* Name components */
-    Ident(ast::Ident),
+    /// An identifier. The flag is set for raw identifiers such as `r#match`,
+    /// which are never treated as keywords.
+    Ident(ast::Ident, bool),
     Underscore,
     Lifetime(ast::Ident),
 
@@ -273,17 +275,25 @@ impl Token {
         }
     }
 
+    /// Returns `true` if the token is a raw identifier.
+    pub fn is_raw_ident(&self) -> bool {
+        match *self {
+            Ident(_, is_raw) => is_raw,
+            _ => false,
+        }
+    }
+
     /// Returns `true` if the token is a given keyword, `kw`.
     pub fn is_keyword(&self, kw: keywords::Keyword) -> bool {
         match *self {
-            Ident(id) => id.name == kw.name(),
+            Ident(id, false) => id.name == kw.name(),
             _ => false,
         }
     }
 
     pub fn is_path_segment_keyword(&self) -> bool {
         match *self {
-            Ident(id) => id.name == keywords::Super.name() ||
+            Ident(id, false) => id.name == keywords::Super.name() ||
                          id.name == keywords::SelfValue.name() ||
                          id.name == keywords::SelfType.name(),
             _ => false,
@@ -298,7 +308,7 @@ impl Token {
     /// Returns `true` if the token is a strict keyword.
     pub fn is_strict_keyword(&self) -> bool {
         match *self {
-            Ident(id) => id.name >= keywords::As.name() &&
+            Ident(id, false) => id.name >= keywords::As.name() &&
                          id.name <= keywords::While.name(),
             _ => false,
         }
@@ -307,7 +317,7 @@ impl Token {
     /// Returns `true` if the token is a keyword reserved for possible future use.
     pub fn is_reserved_keyword(&self) -> bool {
         match *self {
-            Ident(id) => id.name >= keywords::Abstract.name() &&
+            Ident(id, false) => id.name >= keywords::Abstract.name() &&
                          id.name <= keywords::Yield.name(),
             _ => false,
         }
@@ -318,7 +328,9 @@ impl Token {
     /// See `styntax::ext::mtwt`.
     pub fn mtwt_eq(&self, other : &Token) -> bool {
         match (self, other) {
-            (&Ident(id1), &Ident(id2)) | (&Lifetime(id1), &Lifetime(id2)) =>
+            (&Ident(id1, is_raw1), &Ident(id2, is_raw2)) =>
+                is_raw1 == is_raw2 && mtwt::resolve(id1) == mtwt::resolve(id2),
+            (&Lifetime(id1), &Lifetime(id2)) =>
                 mtwt::resolve(id1) == mtwt::resolve(id2),
             _ => *self == *other
         }
@@ -334,7 +346,8 @@ pub enum Nonterminal {
     NtPat(P<ast::Pat>),
     NtExpr(P<ast::Expr>),
     NtTy(P<ast::Ty>),
-    NtIdent(Box<ast::SpannedIdent>),
+    /// An identifier, and whether it was written as a raw identifier.
+    NtIdent(Box<ast::SpannedIdent>, bool),
     /// Stuff inside brackets for attributes
     NtMeta(P<ast::MetaItem>),
     NtPath(Box<ast::Path>),
@@ -469,6 +482,7 @@ declare_keywords! {
     (53, Default,        "default")
     (54, StaticLifetime, "'static")
     (55, Union,          "union")
+    (56, Catch,          "catch")
 }
 
 // looks like we can get rid of this completely...
@@ -663,6 +677,6 @@ mod tests {
         assert!(Gt.mtwt_eq(&Gt));
         let a = str_to_ident("bac");
         let a1 = mark_ident(a,92);
-        assert!(Ident(a).mtwt_eq(&Ident(a1)));
+        assert!(Ident(a, false).mtwt_eq(&Ident(a1, false)));
     }
 }
@@ -67,6 +67,7 @@ pub struct State<'a> {
     cur_cmnt_and_lit: CurrentCommentAndLiteral,
     boxes: Vec<pp::Breaks>,
     ann: &'a (PpAnn+'a),
+    config: PrintConfig,
 }
 
 pub fn rust_printer<'a>(writer: Box<Write+'a>) -> State<'a> {
@@ -87,6 +88,7 @@ pub fn rust_printer_annotated<'a>(writer: Box<Write+'a>,
         },
         boxes: Vec::new(),
         ann: ann,
+        config: PrintConfig::default(),
     }
 }
 
@@ -94,6 +96,40 @@ pub const INDENT_UNIT: usize = 4;
 
 pub const DEFAULT_COLUMNS: usize = 78;
 
+/// Where the brace opening the body of an item goes.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BraceStyle {
+    /// At the end of the item's head, as in `fn f() {`.
+    SameLine,
+    /// On a line of its own, at the indentation of the item.
+    NextLine,
+}
+
+/// The layout the pretty printer produces.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct PrintConfig {
+    /// The column lines are broken at.
+    pub max_width: usize,
+    /// The number of columns each level of nesting is indented by. Must be
+    /// at least 1.
+    pub indent: usize,
+    pub brace_style: BraceStyle,
+    /// Whether a comment trailing an item, field or associated item stays on
+    /// its line. By default it's moved to the start of the next line.
+    pub trailing_comments: bool,
+}
+
+impl Default for PrintConfig {
+    fn default() -> PrintConfig {
+        PrintConfig {
+            max_width: DEFAULT_COLUMNS,
+            indent: INDENT_UNIT,
+            brace_style: BraceStyle::SameLine,
+            trailing_comments: false,
+        }
+    }
+}
+
 /// Requires you to pass an input filename and reader so that
 /// it can scan the input text for comments and literals to
 /// copy forward.
@@ -104,14 +140,16 @@ pub fn print_crate<'a>(cm: &'a CodeMap,
                        input: &mut Read,
                        out: Box<Write+'a>,
                        ann: &'a PpAnn,
-                       is_expanded: bool) -> io::Result<()> {
+                       is_expanded: bool,
+                       config: PrintConfig) -> io::Result<()> {
     let mut s = State::new_from_input(cm,
                                       span_diagnostic,
                                       filename,
                                       input,
                                       out,
                                       ann,
-                                      is_expanded);
+                                      is_expanded,
+                                      config);
     if is_expanded && !std_inject::no_std(krate) {
         // We need to print `#![no_std]` (and its feature gate) so that
         // compiling pretty-printed source won't inject libstd again.
@@ -119,7 +157,7 @@ pub fn print_crate<'a>(cm: &'a CodeMap,
         // of the feature gate, so we fake them up here.
 
         // #![feature(prelude_import)]
-        let prelude_import_meta = attr::mk_word_item(InternedString::new("prelude_import"));
+        let prelude_import_meta = attr::mk_list_word_item(InternedString::new("prelude_import"));
         let list = attr::mk_list_item(InternedString::new("feature"),
                                       vec![prelude_import_meta]);
         let fake_attr = attr::mk_attr_inner(attr::mk_attr_id(), list);
@@ -143,7 +181,8 @@ impl<'a> State<'a> {
                           input: &mut Read,
                           out: Box<Write+'a>,
                           ann: &'a PpAnn,
-                          is_expanded: bool) -> State<'a> {
+                          is_expanded: bool,
+                          config: PrintConfig) -> State<'a> {
         let (cmnts, lits) = comments::gather_comments_and_literals(
             span_diagnostic,
             filename,
@@ -157,16 +196,18 @@ impl<'a> State<'a> {
             // If the code is post expansion, don't use the table of
             // literals, since it doesn't correspond with the literals
             // in the AST anymore.
-            if is_expanded { None } else { Some(lits) })
+            if is_expanded { None } else { Some(lits) },
+            config)
     }
 
     pub fn new(cm: &'a CodeMap,
                out: Box<Write+'a>,
                ann: &'a PpAnn,
                comments: Option<Vec<comments::Comment>>,
-               literals: Option<Vec<comments::Literal>>) -> State<'a> {
+               literals: Option<Vec<comments::Literal>>,
+               config: PrintConfig) -> State<'a> {
         State {
-            s: pp::mk_printer(out, DEFAULT_COLUMNS),
+            s: pp::mk_printer(out, config.max_width),
             cm: Some(cm),
             comments: comments,
             literals: literals,
@@ -176,6 +217,7 @@ impl<'a> State<'a> {
             },
             boxes: Vec::new(),
             ann: ann,
+            config: config,
         }
     }
 }
@@ -270,7 +312,8 @@ pub fn token_to_string(tok: &Token) -> String {
         }
 
         /* Name components */
-        token::Ident(s)             => s.to_string(),
+        token::Ident(s, false)      => s.to_string(),
+        token::Ident(s, true)       => format!("r#{}", s),
         token::Lifetime(s)          => s.to_string(),
         token::Underscore           => "_".to_string(),
 
@@ -294,7 +337,8 @@ pub fn token_to_string(tok: &Token) -> String {
             token::NtBlock(ref e)       => block_to_string(&e),
             token::NtStmt(ref e)        => stmt_to_string(&e),
             token::NtPat(ref e)         => pat_to_string(&e),
-            token::NtIdent(ref e)       => ident_to_string(e.node),
+            token::NtIdent(ref e, false) => e.node.to_string(),
+            token::NtIdent(ref e, true) => format!("r#{}", e.node),
             token::NtTT(ref e)          => tt_to_string(&e),
             token::NtArm(ref e)         => arm_to_string(&e),
             token::NtImplItem(ref e)    => impl_item_to_string(&e),
@@ -397,7 +441,8 @@ pub fn fun_to_string(decl: &ast::FnDecl,
 pub fn block_to_string(blk: &ast::Block) -> String {
     to_string(|s| {
         // containing cbox, will be closed by print-block at }
-        s.cbox(INDENT_UNIT)?;
+        let indent = s.config.indent;
+        s.cbox(indent)?;
         // head-ibox, will be closed by print-block after {
         s.ibox(0)?;
         s.print_block(blk)
@@ -408,6 +453,10 @@ pub fn meta_item_to_string(mi: &ast::MetaItem) -> String {
     to_string(|s| s.print_meta_item(mi))
 }
 
+pub fn meta_list_item_to_string(li: &ast::NestedMetaItem) -> String {
+    to_string(|s| s.print_meta_list_item(li))
+}
+
 pub fn attribute_to_string(attr: &ast::Attribute) -> String {
     to_string(|s| s.print_attribute(attr))
 }
@@ -457,6 +506,7 @@ pub trait PrintState<'a> {
     fn comments(&mut self) -> &mut Option<Vec<comments::Comment>>;
     fn cur_cmnt_and_lit(&mut self) -> &mut CurrentCommentAndLiteral;
     fn literals(&self) -> &Option<Vec<comments::Literal>>;
+    fn config(&self) -> &PrintConfig;
 
     fn word_space(&mut self, w: &str) -> io::Result<()> {
         word(self.writer(), w)?;
@@ -522,25 +572,22 @@ pub trait PrintState<'a> {
     }
 
     fn next_lit(&mut self, pos: BytePos) -> Option<comments::Literal> {
-        let mut cur_lit = self.cur_cmnt_and_lit().cur_lit;
-
-        let mut result = None;
-
-        if let &Some(ref lits) = self.literals()
-        {
-            while cur_lit < lits.len() {
-                let ltrl = (*lits)[cur_lit].clone();
-                if ltrl.pos > pos { break; }
-                cur_lit += 1;
-                if ltrl.pos == pos {
-                    result = Some(ltrl);
-                    break;
-                }
-            }
-        }
+        // Look the literal up by its position rather than stepping through
+        // the table, so that printing a literal out of source order doesn't
+        // lose the source text of the ones it skipped over.
+        let found = match *self.literals() {
+            Some(ref lits) => {
+                lits.binary_search_by(|ltrl| ltrl.pos.cmp(&pos))
+                    .ok()
+                    .map(|i| (i, lits[i].clone()))
+            }
+            None => None,
+        };
 
-        self.cur_cmnt_and_lit().cur_lit = cur_lit;
-        result
+        found.map(|(i, ltrl)| {
+            self.cur_cmnt_and_lit().cur_lit = i + 1;
+            ltrl
+        })
     }
 
     fn maybe_print_comment(&mut self, pos: BytePos) -> io::Result<()> {
@@ -766,8 +813,20 @@ pub trait PrintState<'a> {
         }
     }
 
+    fn print_meta_list_item(&mut self, item: &ast::NestedMetaItem) -> io::Result<()> {
+        match item.node {
+            ast::NestedMetaItemKind::MetaItem(ref mi) => {
+                self.print_meta_item(mi)
+            },
+            ast::NestedMetaItemKind::Literal(ref lit) => {
+                self.print_literal(lit)
+            }
+        }
+    }
+
     fn print_meta_item(&mut self, item: &ast::MetaItem) -> io::Result<()> {
-        self.ibox(INDENT_UNIT)?;
+        let indent = self.config().indent;
+        self.ibox(indent)?;
         match item.node {
             ast::MetaItemKind::Word(ref name) => {
                 word(self.writer(), &name)?;
@@ -782,7 +841,7 @@ pub trait PrintState<'a> {
                 self.popen()?;
                 self.commasep(Consistent,
                               &items[..],
-                              |s, i| s.print_meta_item(&i))?;
+                              |s, i| s.print_meta_list_item(&i))?;
                 self.pclose()?;
             }
         }
@@ -817,6 +876,10 @@ impl<'a> PrintState<'a> for State<'a> {
     fn literals(&self) -> &Option<Vec<comments::Literal>> {
         &self.literals
     }
+
+    fn config(&self) -> &PrintConfig {
+        &self.config
+    }
 }
 
 impl<'a> State<'a> {
@@ -832,7 +895,8 @@ impl<'a> State<'a> {
 
     pub fn head(&mut self, w: &str) -> io::Result<()> {
         // outer-box is consistent
-        self.cbox(INDENT_UNIT)?;
+        let indent = self.config.indent;
+        self.cbox(indent)?;
         // head-box is inconsistent
         self.ibox(w.len() + 1)?;
         // keyword that starts the head
@@ -847,6 +911,31 @@ impl<'a> State<'a> {
         self.end() // close the head-box
     }
 
+    /// Separate the head of an item from the brace opening its body. This
+    /// prints nothing if the brace goes on a line of its own.
+    pub fn item_brace_space(&mut self, breakable: bool) -> io::Result<()> {
+        match self.config.brace_style {
+            BraceStyle::SameLine if breakable => space(&mut self.s),
+            BraceStyle::SameLine => word(&mut self.s, " "),
+            BraceStyle::NextLine => Ok(()),
+        }
+    }
+
+    /// Like `bopen`, for the body of an item, placing the brace according to
+    /// the brace style.
+    pub fn bopen_item(&mut self) -> io::Result<()> {
+        match self.config.brace_style {
+            BraceStyle::SameLine => self.bopen(),
+            BraceStyle::NextLine => {
+                self.end()?; // close the head-box
+                // Break back out to the indentation of the outer-box's start.
+                let indent = self.config.indent;
+                self.s.pretty_print(pp::hardbreak_tok_offset(-(indent as isize)))?;
+                word(&mut self.s, "{")
+            }
+        }
+    }
+
     pub fn bclose_(&mut self, span: codemap::Span,
                    indented: usize) -> io::Result<()> {
         self.bclose_maybe_open(span, indented, true)
@@ -862,7 +951,8 @@ impl<'a> State<'a> {
         Ok(())
     }
     pub fn bclose(&mut self, span: codemap::Span) -> io::Result<()> {
-        self.bclose_(span, INDENT_UNIT)
+        let indent = self.config.indent;
+        self.bclose_(span, indent)
     }
 
     pub fn in_cbox(&self) -> bool {
@@ -978,6 +1068,9 @@ impl<'a> State<'a> {
                 self.print_opt_lifetime(lifetime)?;
                 self.print_mt(mt)?;
             }
+            ast::TyKind::Never => {
+                word(&mut self.s, "!")?;
+            }
             ast::TyKind::Tup(ref elts) => {
                 self.popen()?;
                 self.commasep(Inconsistent, &elts[..],
@@ -996,6 +1089,7 @@ impl<'a> State<'a> {
                 let generics = ast::Generics {
                     lifetimes: f.lifetimes.clone(),
                     ty_params: P::new(),
+                    const_params: P::new(),
                     where_clause: ast::WhereClause {
                         id: ast::DUMMY_NODE_ID,
                         predicates: Vec::new(),
@@ -1021,6 +1115,9 @@ impl<'a> State<'a> {
             ast::TyKind::PolyTraitRef(ref bounds) => {
                 self.print_bounds("", &bounds[..])?;
             }
+            ast::TyKind::ImplTrait(ref bounds) => {
+                self.print_bounds("impl ", &bounds[..])?;
+            }
             ast::TyKind::FixedLengthVec(ref ty, ref v) => {
                 word(&mut self.s, "[")?;
                 self.print_type(&ty)?;
@@ -1096,14 +1193,17 @@ impl<'a> State<'a> {
 
     fn print_associated_type(&mut self,
                              ident: ast::Ident,
+                             generics: &ast::Generics,
                              bounds: Option<&ast::TyParamBounds>,
                              ty: Option<&ast::Ty>)
                              -> io::Result<()> {
         self.word_space("type")?;
         self.print_ident(ident)?;
+        self.print_generics(generics)?;
         if let Some(bounds) = bounds {
             self.print_bounds(":", bounds)?;
         }
+        self.print_where_clause(&generics.where_clause)?;
         if let Some(ty) = ty {
             space(&mut self.s)?;
             self.word_space("=")?;
@@ -1114,6 +1214,7 @@ impl<'a> State<'a> {
 
     /// Pretty-print an item
     pub fn print_item(&mut self, item: &ast::Item) -> io::Result<()> {
+        let indent = self.config.indent;
         self.hardbreak_if_not_bol()?;
         self.maybe_print_comment(item.span.lo)?;
         self.print_outer_attributes(&item.attrs)?;
@@ -1185,26 +1286,27 @@ impl<'a> State<'a> {
                     None,
                     &item.vis
                 )?;
-                word(&mut self.s, " ")?;
-                self.print_block_with_attrs(&body, &item.attrs)?;
+                self.item_brace_space(false)?;
+                self.print_item_body(&body, &item.attrs)?;
             }
             ast::ItemKind::Mod(ref _mod) => {
                 self.head(&visibility_qualified(&item.vis, "mod"))?;
                 self.print_ident(item.ident)?;
-                self.nbsp()?;
-                self.bopen()?;
+                self.item_brace_space(false)?;
+                self.bopen_item()?;
                 self.print_mod(_mod, &item.attrs)?;
                 self.bclose(item.span)?;
             }
             ast::ItemKind::ForeignMod(ref nmod) => {
                 self.head("extern")?;
-                self.word_nbsp(&nmod.abi.to_string())?;
-                self.bopen()?;
+                word(&mut self.s, &nmod.abi.to_string())?;
+                self.item_brace_space(false)?;
+                self.bopen_item()?;
                 self.print_foreign_mod(nmod, &item.attrs)?;
                 self.bclose(item.span)?;
             }
             ast::ItemKind::Ty(ref ty, ref params) => {
-                self.ibox(INDENT_UNIT)?;
+                self.ibox(indent)?;
                 self.ibox(0)?;
                 self.word_nbsp(&visibility_qualified(&item.vis, "type"))?;
                 self.print_ident(item.ident)?;
@@ -1231,6 +1333,10 @@ impl<'a> State<'a> {
                 self.head(&visibility_qualified(&item.vis, "struct"))?;
                 self.print_struct(&struct_def, generics, item.ident, item.span, true)?;
             }
+            ast::ItemKind::Union(ref struct_def, ref generics) => {
+                self.head(&visibility_qualified(&item.vis, "union"))?;
+                self.print_struct(&struct_def, generics, item.ident, item.span, true)?;
+            }
 
             ast::ItemKind::DefaultImpl(unsafety, ref trait_ref) => {
                 self.head("")?;
@@ -1240,18 +1346,23 @@ impl<'a> State<'a> {
                 self.print_trait_ref(trait_ref)?;
                 space(&mut self.s)?;
                 self.word_space("for")?;
-                self.word_space("..")?;
-                self.bopen()?;
+                word(&mut self.s, "..")?;
+                self.item_brace_space(true)?;
+                self.bopen_item()?;
                 self.bclose(item.span)?;
             }
             ast::ItemKind::Impl(unsafety,
                           polarity,
+                          defaultness,
                           ref generics,
                           ref opt_trait,
                           ref ty,
                           ref impl_items) => {
                 self.head("")?;
                 self.print_visibility(&item.vis)?;
+                if let ast::Defaultness::Default = defaultness {
+                    self.word_nbsp("default")?;
+                }
                 self.print_unsafety(unsafety)?;
                 self.word_nbsp("impl")?;
 
@@ -1279,8 +1390,8 @@ impl<'a> State<'a> {
                 self.print_type(&ty)?;
                 self.print_where_clause(&generics.where_clause)?;
 
-                space(&mut self.s)?;
-                self.bopen()?;
+                self.item_brace_space(true)?;
+                self.bopen_item()?;
                 self.print_inner_attributes(&item.attrs)?;
                 for impl_item in impl_items {
                     self.print_impl_item(impl_item)?;
@@ -1306,19 +1417,31 @@ impl<'a> State<'a> {
                 }
                 self.print_bounds(":", &real_bounds[..])?;
                 self.print_where_clause(&generics.where_clause)?;
-                word(&mut self.s, " ")?;
-                self.bopen()?;
+                self.item_brace_space(false)?;
+                self.bopen_item()?;
                 for trait_item in trait_items {
                     self.print_trait_item(trait_item)?;
                 }
                 self.bclose(item.span)?;
             }
+            ast::ItemKind::TraitAlias(ref generics, ref bounds) => {
+                self.head("")?;
+                self.print_visibility(&item.vis)?;
+                self.word_nbsp("trait")?;
+                self.print_ident(item.ident)?;
+                self.print_generics(generics)?;
+                self.print_bounds(" =", &bounds[..])?;
+                self.print_where_clause(&generics.where_clause)?;
+                word(&mut self.s, ";")?;
+                self.end()?; // end inner head-block
+                self.end()?; // end outer head-block
+            }
             ast::ItemKind::Mac(codemap::Spanned { ref node, .. }) => {
                 self.print_visibility(&item.vis)?;
                 self.print_path(&node.path, false, 0)?;
                 word(&mut self.s, "! ")?;
                 self.print_ident(item.ident)?;
-                self.cbox(INDENT_UNIT)?;
+                self.cbox(indent)?;
                 self.popen()?;
                 self.print_tts(&node.tts[..])?;
                 self.pclose()?;
@@ -1326,7 +1449,11 @@ impl<'a> State<'a> {
                 self.end()?;
             }
         }
-        self.ann.post(self, NodeItem(item))
+        self.ann.post(self, NodeItem(item))?;
+        if self.config.trailing_comments {
+            self.maybe_print_trailing_comment(item.span, None)?;
+        }
+        Ok(())
     }
 
     fn print_trait_ref(&mut self, t: &ast::TraitRef) -> io::Result<()> {
@@ -1362,19 +1489,20 @@ impl<'a> State<'a> {
         self.print_ident(ident)?;
         self.print_generics(generics)?;
         self.print_where_clause(&generics.where_clause)?;
-        space(&mut self.s)?;
+        self.item_brace_space(true)?;
         self.print_variants(&enum_definition.variants, span)
     }
 
     pub fn print_variants(&mut self,
                           variants: &[ast::Variant],
                           span: codemap::Span) -> io::Result<()> {
-        self.bopen()?;
+        let indent = self.config.indent;
+        self.bopen_item()?;
         for v in variants {
             self.space_if_not_bol()?;
             self.maybe_print_comment(v.span.lo)?;
             self.print_outer_attributes(&v.node.attrs)?;
-            self.ibox(INDENT_UNIT)?;
+            self.ibox(indent)?;
             self.print_variant(v)?;
             word(&mut self.s, ",")?;
             self.end()?;
@@ -1422,8 +1550,15 @@ impl<'a> State<'a> {
             self.end() // close the outer-box
         } else {
             self.print_where_clause(&generics.where_clause)?;
-            self.nbsp()?;
-            self.bopen()?;
+            // Struct variants keep their brace on the same line whatever
+            // the brace style; only struct items print a finalizer.
+            if print_finalizer {
+                self.item_brace_space(false)?;
+                self.bopen_item()?;
+            } else {
+                self.nbsp()?;
+                self.bopen()?;
+            }
             self.hardbreak_if_not_bol()?;
 
             for field in struct_def.fields() {
@@ -1435,6 +1570,9 @@ impl<'a> State<'a> {
                 self.word_nbsp(":")?;
                 self.print_type(&field.ty)?;
                 word(&mut self.s, ",")?;
+                if self.config.trailing_comments {
+                    self.maybe_print_trailing_comment(field.span, None)?;
+                }
             }
 
             self.bclose(span)
@@ -1492,6 +1630,9 @@ impl<'a> State<'a> {
             if i != 0 {
                 space(&mut self.s)?;
             }
+            // Keep comments inside macro invocations next to the tokens
+            // they precede rather than after the whole invocation.
+            self.maybe_print_comment(tt.get_span().lo)?;
             self.print_tt(tt)?;
         }
         self.end()
@@ -1544,21 +1685,26 @@ impl<'a> State<'a> {
                 }
                 self.print_method_sig(ti.ident, sig, &ast::Visibility::Inherited)?;
                 if let Some(ref body) = *body {
-                    self.nbsp()?;
-                    self.print_block_with_attrs(body, &ti.attrs)?;
+                    self.item_brace_space(false)?;
+                    self.print_item_body(body, &ti.attrs)?;
                 } else {
                     word(&mut self.s, ";")?;
                 }
             }
-            ast::TraitItemKind::Type(ref bounds, ref default) => {
-                self.print_associated_type(ti.ident, Some(bounds),
+            ast::TraitItemKind::Type(ref bounds, ref default, ref generics) => {
+                self.print_associated_type(ti.ident, generics, Some(bounds),
                                            default.as_ref().map(|ty| &**ty))?;
             }
         }
-        self.ann.post(self, NodeSubItem(ti.id))
+        self.ann.post(self, NodeSubItem(ti.id))?;
+        if self.config.trailing_comments {
+            self.maybe_print_trailing_comment(ti.span, None)?;
+        }
+        Ok(())
     }
 
     pub fn print_impl_item(&mut self, ii: &ast::ImplItem) -> io::Result<()> {
+        let indent = self.config.indent;
         self.ann.pre(self, NodeSubItem(ii.id))?;
         self.hardbreak_if_not_bol()?;
         self.maybe_print_comment(ii.span.lo)?;
@@ -1573,17 +1719,17 @@ impl<'a> State<'a> {
             ast::ImplItemKind::Method(ref sig, ref body) => {
                 self.head("")?;
                 self.print_method_sig(ii.ident, sig, &ii.vis)?;
-                self.nbsp()?;
-                self.print_block_with_attrs(body, &ii.attrs)?;
+                self.item_brace_space(false)?;
+                self.print_item_body(body, &ii.attrs)?;
             }
-            ast::ImplItemKind::Type(ref ty) => {
-                self.print_associated_type(ii.ident, None, Some(ty))?;
+            ast::ImplItemKind::Type(ref ty, ref generics) => {
+                self.print_associated_type(ii.ident, generics, None, Some(ty))?;
             }
             ast::ImplItemKind::Macro(codemap::Spanned { ref node, .. }) => {
                 // code copied from ItemKind::Mac:
                 self.print_path(&node.path, false, 0)?;
                 word(&mut self.s, "! ")?;
-                self.cbox(INDENT_UNIT)?;
+                self.cbox(indent)?;
                 self.popen()?;
                 self.print_tts(&node.tts[..])?;
                 self.pclose()?;
@@ -1591,7 +1737,11 @@ impl<'a> State<'a> {
                 self.end()?
             }
         }
-        self.ann.post(self, NodeSubItem(ii.id))
+        self.ann.post(self, NodeSubItem(ii.id))?;
+        if self.config.trailing_comments {
+            self.maybe_print_trailing_comment(ii.span, None)?;
+        }
+        Ok(())
     }
 
     pub fn print_stmt(&mut self, st: &ast::Stmt) -> io::Result<()> {
@@ -1634,13 +1784,15 @@ impl<'a> State<'a> {
     }
 
     pub fn print_block_unclosed(&mut self, blk: &ast::Block) -> io::Result<()> {
-        self.print_block_unclosed_indent(blk, INDENT_UNIT)
+        let indent = self.config.indent;
+        self.print_block_unclosed_indent(blk, indent)
     }
 
     pub fn print_block_unclosed_with_attrs(&mut self, blk: &ast::Block,
                                             attrs: &[ast::Attribute])
                                            -> io::Result<()> {
-        self.print_block_maybe_unclosed(blk, INDENT_UNIT, attrs, false)
+        let indent = self.config.indent;
+        self.print_block_maybe_unclosed(blk, indent, attrs, false)
     }
 
     pub fn print_block_unclosed_indent(&mut self, blk: &ast::Block,
@@ -1651,7 +1803,16 @@ impl<'a> State<'a> {
     pub fn print_block_with_attrs(&mut self,
                                   blk: &ast::Block,
                                   attrs: &[ast::Attribute]) -> io::Result<()> {
-        self.print_block_maybe_unclosed(blk, INDENT_UNIT, attrs, true)
+        let indent = self.config.indent;
+        self.print_block_maybe_unclosed(blk, indent, attrs, true)
+    }
+
+    /// Print the body of a function or method, opening it with `bopen_item`.
+    pub fn print_item_body(&mut self,
+                           blk: &ast::Block,
+                           attrs: &[ast::Attribute]) -> io::Result<()> {
+        let indent = self.config.indent;
+        self.print_block_inner(blk, indent, attrs, true, true)
     }
 
     pub fn print_block_maybe_unclosed(&mut self,
@@ -1659,13 +1820,26 @@ impl<'a> State<'a> {
                                       indented: usize,
                                       attrs: &[ast::Attribute],
                                       close_box: bool) -> io::Result<()> {
+        self.print_block_inner(blk, indented, attrs, close_box, false)
+    }
+
+    fn print_block_inner(&mut self,
+                         blk: &ast::Block,
+                         indented: usize,
+                         attrs: &[ast::Attribute],
+                         close_box: bool,
+                         item_body: bool) -> io::Result<()> {
         match blk.rules {
             BlockCheckMode::Unsafe(..) => self.word_space("unsafe")?,
             BlockCheckMode::Default => ()
         }
         self.maybe_print_comment(blk.span.lo)?;
         self.ann.pre(self, NodeBlock(blk))?;
-        self.bopen()?;
+        if item_body {
+            self.bopen_item()?;
+        } else {
+            self.bopen()?;
+        }
 
         self.print_inner_attributes(attrs)?;
 
@@ -1685,12 +1859,14 @@ impl<'a> State<'a> {
     }
 
     fn print_else(&mut self, els: Option<&ast::Expr>) -> io::Result<()> {
+        // The `else` hangs one column to the left of the indentation.
+        let indent = self.config.indent.saturating_sub(1);
         match els {
             Some(_else) => {
                 match _else.node {
                     // "another else-if"
                     ast::ExprKind::If(ref i, ref then, ref e) => {
-                        self.cbox(INDENT_UNIT - 1)?;
+                        self.cbox(indent)?;
                         self.ibox(0)?;
                         word(&mut self.s, " else if ")?;
                         self.print_expr(&i)?;
@@ -1700,7 +1876,7 @@ impl<'a> State<'a> {
                     }
                     // "another else-if-let"
                     ast::ExprKind::IfLet(ref pat, ref expr, ref then, ref e) => {
-                        self.cbox(INDENT_UNIT - 1)?;
+                        self.cbox(indent)?;
                         self.ibox(0)?;
                         word(&mut self.s, " else if let ")?;
                         self.print_pat(&pat)?;
@@ -1713,7 +1889,7 @@ impl<'a> State<'a> {
                     }
                     // "final else"
                     ast::ExprKind::Block(ref b) => {
-                        self.cbox(INDENT_UNIT - 1)?;
+                        self.cbox(indent)?;
                         self.ibox(0)?;
                         word(&mut self.s, " else ")?;
                         self.print_block(&b)
@@ -1814,7 +1990,8 @@ impl<'a> State<'a> {
 
     fn print_expr_vec(&mut self, exprs: &[P<ast::Expr>],
                       attrs: &[Attribute]) -> io::Result<()> {
-        self.ibox(INDENT_UNIT)?;
+        let indent = self.config.indent;
+        self.ibox(indent)?;
         word(&mut self.s, "[")?;
         self.print_inner_attributes_inline(attrs)?;
         self.commasep_exprs(Inconsistent, &exprs[..])?;
@@ -1826,7 +2003,8 @@ impl<'a> State<'a> {
                          element: &ast::Expr,
                          count: &ast::Expr,
                          attrs: &[Attribute]) -> io::Result<()> {
-        self.ibox(INDENT_UNIT)?;
+        let indent = self.config.indent;
+        self.ibox(indent)?;
         word(&mut self.s, "[")?;
         self.print_inner_attributes_inline(attrs)?;
         self.print_expr(element)?;
@@ -1841,6 +2019,7 @@ impl<'a> State<'a> {
                          fields: &[ast::Field],
                          wth: &Option<P<ast::Expr>>,
                          attrs: &[Attribute]) -> io::Result<()> {
+        let indent = self.config.indent;
         self.print_path(path, true, 0)?;
         word(&mut self.s, "{")?;
         self.print_inner_attributes_inline(attrs)?;
@@ -1848,16 +2027,18 @@ impl<'a> State<'a> {
             Consistent,
             &fields[..],
             |s, field| {
-                s.ibox(INDENT_UNIT)?;
+                s.ibox(indent)?;
                 s.print_ident(field.ident.node)?;
-                s.word_space(":")?;
-                s.print_expr(&field.expr)?;
+                if !field.is_shorthand {
+                    s.word_space(":")?;
+                    s.print_expr(&field.expr)?;
+                }
                 s.end()
             },
             |f| f.span)?;
         match *wth {
             Some(ref expr) => {
-                self.ibox(INDENT_UNIT)?;
+                self.ibox(indent)?;
                 if !fields.is_empty() {
                     word(&mut self.s, ",")?;
                     space(&mut self.s)?;
@@ -1949,6 +2130,7 @@ impl<'a> State<'a> {
     fn print_expr_outer_attr_style(&mut self,
                                   expr: &ast::Expr,
                                   is_inline: bool) -> io::Result<()> {
+        let indent = self.config.indent;
         self.maybe_print_comment(expr.span.lo)?;
 
         let attrs = expr.attrs.as_attr_slice();
@@ -1958,7 +2140,7 @@ impl<'a> State<'a> {
             self.print_outer_attributes(attrs)?;
         }
 
-        self.ibox(INDENT_UNIT)?;
+        self.ibox(indent)?;
         self.ann.pre(self, NodeExpr(expr))?;
         match expr.node {
             ast::ExprKind::Box(ref expr) => {
@@ -2065,8 +2247,8 @@ impl<'a> State<'a> {
                 self.print_block_with_attrs(&blk, attrs)?;
             }
             ast::ExprKind::Match(ref expr, ref arms) => {
-                self.cbox(INDENT_UNIT)?;
-                self.ibox(4)?;
+                self.cbox(indent)?;
+                self.ibox(indent)?;
                 self.word_nbsp("match")?;
                 self.print_expr(&expr)?;
                 space(&mut self.s)?;
@@ -2075,7 +2257,7 @@ impl<'a> State<'a> {
                 for arm in arms {
                     self.print_arm(arm)?;
                 }
-                self.bclose_(expr.span, INDENT_UNIT)?;
+                self.bclose_(expr.span, indent)?;
             }
             ast::ExprKind::Closure(capture_clause, ref decl, ref body, _) => {
                 self.print_capture_clause(capture_clause)?;
@@ -2113,7 +2295,7 @@ impl<'a> State<'a> {
             }
             ast::ExprKind::Block(ref blk) => {
                 // containing cbox, will be closed by print-block at }
-                self.cbox(INDENT_UNIT)?;
+                self.cbox(indent)?;
                 // head-box, will be closed by print-block after {
                 self.ibox(0)?;
                 self.print_block_with_attrs(&blk, attrs)?;
@@ -2166,13 +2348,17 @@ impl<'a> State<'a> {
             ast::ExprKind::Path(Some(ref qself), ref path) => {
                 self.print_qpath(path, qself, true)?
             }
-            ast::ExprKind::Break(opt_ident) => {
+            ast::ExprKind::Break(opt_ident, ref opt_expr) => {
                 word(&mut self.s, "break")?;
                 space(&mut self.s)?;
                 if let Some(ident) = opt_ident {
                     self.print_ident(ident.node)?;
                     space(&mut self.s)?;
                 }
+                if let Some(ref expr) = *opt_expr {
+                    self.print_expr(expr)?;
+                    space(&mut self.s)?;
+                }
             }
             ast::ExprKind::Again(opt_ident) => {
                 word(&mut self.s, "continue")?;
@@ -2268,6 +2454,16 @@ impl<'a> State<'a> {
                 self.print_expr(e)?;
                 word(&mut self.s, "?")?
             }
+            ast::ExprKind::Catch(ref blk) => {
+                self.head("do catch")?;
+                space(&mut self.s)?;
+                self.print_block_with_attrs(&blk, attrs)?;
+            }
+            ast::ExprKind::Err => {
+                self.popen()?;
+                word(&mut self.s, "/*ERROR*/")?;
+                self.pclose()?
+            }
         }
         self.ann.post(self, NodeExpr(expr))?;
         self.end()
@@ -2283,15 +2479,16 @@ impl<'a> State<'a> {
     }
 
     pub fn print_decl(&mut self, decl: &ast::Decl) -> io::Result<()> {
+        let indent = self.config.indent;
         self.maybe_print_comment(decl.span.lo)?;
         match decl.node {
             ast::DeclKind::Local(ref loc) => {
                 self.print_outer_attributes(loc.attrs.as_attr_slice())?;
                 self.space_if_not_bol()?;
-                self.ibox(INDENT_UNIT)?;
+                self.ibox(indent)?;
                 self.word_nbsp("let")?;
 
-                self.ibox(INDENT_UNIT)?;
+                self.ibox(indent)?;
                 self.print_local_decl(&loc)?;
                 self.end()?;
                 if let Some(ref init) = loc.init {
@@ -2306,7 +2503,14 @@ impl<'a> State<'a> {
     }
 
     pub fn print_ident(&mut self, ident: ast::Ident) -> io::Result<()> {
-        word(&mut self.s, &ident.name.as_str())?;
+        // A keyword used as an identifier must have been written as a raw
+        // identifier.
+        let tok = token::Ident(ident, false);
+        if tok.is_any_keyword() && !tok.is_path_segment_keyword() {
+            word(&mut self.s, &format!("r#{}", ident.name))?;
+        } else {
+            word(&mut self.s, &ident.name.as_str())?;
+        }
         self.ann.post(self, NodeIdent(&ident))
     }
 
@@ -2409,6 +2613,17 @@ impl<'a> State<'a> {
                         comma = true;
                 }
 
+                if !data.consts.is_empty() {
+                    if comma {
+                        self.word_space(",")?
+                    }
+                    self.commasep(
+                        Inconsistent,
+                        &data.consts,
+                        |s, c| s.print_expr(&c))?;
+                    comma = true;
+                }
+
                 for binding in data.bindings.iter() {
                     if comma {
                         self.word_space(",")?
@@ -2446,6 +2661,7 @@ impl<'a> State<'a> {
     }
 
     pub fn print_pat(&mut self, pat: &ast::Pat) -> io::Result<()> {
+        let indent = self.config.indent;
         self.maybe_print_comment(pat.span.lo)?;
         self.ann.pre(self, NodePat(pat))?;
         /* Pat isn't normalized, but the beauty of it
@@ -2497,7 +2713,7 @@ impl<'a> State<'a> {
                 self.commasep_cmnt(
                     Consistent, &fields[..],
                     |s, f| {
-                        s.cbox(INDENT_UNIT)?;
+                        s.cbox(indent)?;
                         if !f.node.is_shorthand {
                             s.print_ident(f.node.ident)?;
                             s.word_nbsp(":")?;
@@ -2565,12 +2781,13 @@ impl<'a> State<'a> {
     }
 
     fn print_arm(&mut self, arm: &ast::Arm) -> io::Result<()> {
+        let indent = self.config.indent;
         // I have no idea why this check is necessary, but here it
         // is :(
         if arm.attrs.is_empty() {
             space(&mut self.s)?;
         }
-        self.cbox(INDENT_UNIT)?;
+        self.cbox(indent)?;
         self.ibox(0)?;
         self.print_outer_attributes(&arm.attrs)?;
         let mut first = true;
@@ -2594,7 +2811,7 @@ impl<'a> State<'a> {
         match arm.body.node {
             ast::ExprKind::Block(ref blk) => {
                 // the block will close the pattern's ibox
-                self.print_block_unclosed_indent(&blk, INDENT_UNIT)?;
+                self.print_block_unclosed_indent(&blk, indent)?;
 
                 // If it is a user-provided unsafe block, print a comma after it
                 if let BlockCheckMode::Unsafe(ast::UserProvided) = blk.rules {
@@ -2795,7 +3012,8 @@ impl<'a> State<'a> {
                           generics: &ast::Generics)
                           -> io::Result<()>
     {
-        let total = generics.lifetimes.len() + generics.ty_params.len();
+        let total = generics.lifetimes.len() + generics.ty_params.len() +
+                    generics.const_params.len();
         if total == 0 {
             return Ok(());
         }
@@ -2811,10 +3029,14 @@ impl<'a> State<'a> {
             if idx < generics.lifetimes.len() {
                 let lifetime = &generics.lifetimes[idx];
                 s.print_lifetime_def(lifetime)
-            } else {
+            } else if idx < generics.lifetimes.len() + generics.ty_params.len() {
                 let idx = idx - generics.lifetimes.len();
                 let param = &generics.ty_params[idx];
                 s.print_ty_param(param)
+            } else {
+                let idx = idx - generics.lifetimes.len() - generics.ty_params.len();
+                let param = &generics.const_params[idx];
+                s.print_const_param(param)
             }
         })?;
 
@@ -2835,6 +3057,13 @@ impl<'a> State<'a> {
         }
     }
 
+    pub fn print_const_param(&mut self, param: &ast::ConstParam) -> io::Result<()> {
+        self.word_nbsp("const")?;
+        self.print_ident(param.ident)?;
+        self.word_space(":")?;
+        self.print_type(&param.ty)
+    }
+
     pub fn print_where_clause(&mut self, where_clause: &ast::WhereClause)
                               -> io::Result<()> {
         if where_clause.predicates.is_empty() {
@@ -2952,7 +3181,8 @@ impl<'a> State<'a> {
     }
 
     pub fn print_arg(&mut self, input: &ast::Arg, is_closure: bool) -> io::Result<()> {
-        self.ibox(INDENT_UNIT)?;
+        let indent = self.config.indent;
+        self.ibox(indent)?;
         match input.ty.node {
             ast::TyKind::Infer if is_closure => self.print_pat(&input.pat)?,
             _ => {
@@ -2974,12 +3204,13 @@ impl<'a> State<'a> {
     }
 
     pub fn print_fn_output(&mut self, decl: &ast::FnDecl) -> io::Result<()> {
+        let indent = self.config.indent;
         if let ast::FunctionRetTy::Default(..) = decl.output {
             return Ok(());
         }
 
         self.space_if_not_bol()?;
-        self.ibox(INDENT_UNIT)?;
+        self.ibox(indent)?;
         self.word_space("->")?;
         match decl.output {
             ast::FunctionRetTy::None(_) =>
@@ -3004,7 +3235,8 @@ impl<'a> State<'a> {
                        generics: &ast::Generics,
                        opt_explicit_self: Option<&ast::SelfKind>)
                        -> io::Result<()> {
-        self.ibox(INDENT_UNIT)?;
+        let indent = self.config.indent;
+        self.ibox(indent)?;
         if !generics.lifetimes.is_empty() || !generics.ty_params.is_empty() {
             word(&mut self.s, "for")?;
             self.print_generics(generics)?;
@@ -3012,6 +3244,7 @@ impl<'a> State<'a> {
         let generics = ast::Generics {
             lifetimes: Vec::new(),
             ty_params: P::new(),
+            const_params: P::new(),
             where_clause: ast::WhereClause {
                 id: ast::DUMMY_NODE_ID,
                 predicates: Vec::new(),
@@ -18,7 +18,7 @@ use std::iter;
 use std::slice;
 use std::mem;
 use std::vec;
-use attr::AttrMetaMethods;
+use attr::{AttrMetaMethods, AttrNestedMetaItemMethods};
 use attr;
 use codemap::{DUMMY_SP, Span, ExpnInfo, NameAndSpan, MacroAttribute};
 use codemap;
@@ -212,7 +212,7 @@ impl fold::Folder for EntryPointCleaner {
                     let dead_code_str = InternedString::new("dead_code");
                     let allow_dead_code_item =
                         attr::mk_list_item(allow_str,
-                                           vec![attr::mk_word_item(dead_code_str)]);
+                                           vec![attr::mk_list_word_item(dead_code_str)]);
                     let allow_dead_code = attr::mk_attr_outer(attr::mk_attr_id(),
                                                               allow_dead_code_item);
 
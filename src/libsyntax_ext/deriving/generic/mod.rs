@@ -481,7 +481,7 @@ impl<'a> TraitDef<'a> {
                     self.span,
                     type_ident,
                     generics
-                )),
+                ), ast::Generics::default()),
             }
         });
 
@@ -588,6 +588,7 @@ impl<'a> TraitDef<'a> {
         let trait_generics = Generics {
             lifetimes: lifetimes,
             ty_params: P::from_vec(ty_params),
+            const_params: generics.const_params.clone(),
             where_clause: where_clause
         };
 
@@ -605,10 +606,20 @@ impl<'a> TraitDef<'a> {
             .map(|ld| ld.lifetime)
             .collect();
 
+        // Create the const parameters on the `self` path, as `{N}`.
+        let self_const_params: Vec<P<ast::Expr>> = generics.const_params.iter().map(|param| {
+            cx.expr_block(cx.block_expr(cx.expr_ident(self.span, param.ident)))
+        }).collect();
+
         // Create the type of `self`.
-        let self_type = cx.ty_path(
-            cx.path_all(self.span, false, vec!( type_ident ), self_lifetimes,
-                        self_ty_params, Vec::new()));
+        let mut self_path = cx.path_all(self.span, false, vec!( type_ident ), self_lifetimes,
+                                        self_ty_params, Vec::new());
+        if let Some(segment) = self_path.segments.last_mut() {
+            if let ast::PathParameters::AngleBracketed(ref mut data) = segment.parameters {
+                data.consts = P::from_vec(self_const_params);
+            }
+        }
+        let self_type = cx.ty_path(self_path);
 
         let attr = cx.attribute(
             self.span,
@@ -617,12 +628,12 @@ impl<'a> TraitDef<'a> {
         // Just mark it now since we know that it'll end up used downstream
         attr::mark_used(&attr);
         let opt_trait_ref = Some(trait_ref);
-        let unused_qual = cx.attribute(
-            self.span,
-            cx.meta_list(self.span,
-                         InternedString::new("allow"),
-                         vec![cx.meta_word(self.span,
-                                           InternedString::new("unused_qualifications"))]));
+        let unused_qual = {
+            let word = cx.meta_list_item_word(self.span,
+                                              InternedString::new("unused_qualifications"));
+            cx.attribute(self.span,
+                         cx.meta_list(self.span, InternedString::new("allow"), vec![word]))
+        };
         let mut a = vec![attr, unused_qual];
         a.extend(self.attributes.iter().cloned());
 
@@ -638,6 +649,7 @@ impl<'a> TraitDef<'a> {
             a,
             ast::ItemKind::Impl(unsafety,
                                 ast::ImplPolarity::Positive,
+                                ast::Defaultness::Final,
                                 trait_generics,
                                 opt_trait_ref,
                                 self_type,
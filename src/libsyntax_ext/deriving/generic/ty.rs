@@ -208,6 +208,7 @@ fn mk_generics(lifetimes: Vec<ast::LifetimeDef>, ty_params: Vec<ast::TyParam>)
     Generics {
         lifetimes: lifetimes,
         ty_params: P::from_vec(ty_params),
+        const_params: P::new(),
         where_clause: ast::WhereClause {
             id: ast::DUMMY_NODE_ID,
             predicates: Vec::new(),
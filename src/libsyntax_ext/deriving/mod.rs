@@ -10,8 +10,8 @@
 
 //! The compiler code necessary to implement the `#[derive]` extensions.
 
-use syntax::ast::{MetaItem, MetaItemKind, self};
-use syntax::attr::AttrMetaMethods;
+use syntax::ast::{MetaItem, self};
+use syntax::attr::{AttrMetaMethods, AttrNestedMetaItemMethods};
 use syntax::ext::base::{ExtCtxt, SyntaxEnv, Annotatable};
 use syntax::ext::base::{MultiDecorator, MultiItemDecorator, MultiModifier};
 use syntax::ext::build::AstBuilder;
@@ -127,15 +127,15 @@ fn expand_derive(cx: &mut ExtCtxt,
             assert!(cx.parse_sess.codemap().span_allows_unstable(span));
 
             for titem in traits.iter().rev() {
-                let tname = match titem.node {
-                    MetaItemKind::Word(ref tname) => tname,
-                    _ => {
+                let tname = match titem.word() {
+                    Some(word) => word.name(),
+                    None => {
                         cx.span_err(titem.span, "malformed `derive` entry");
                         continue;
                     }
                 };
 
-                if !(is_builtin_trait(tname) || cx.ecfg.enable_custom_derive()) {
+                if !(is_builtin_trait(&tname) || cx.ecfg.enable_custom_derive()) {
                     feature_gate::emit_feature_err(&cx.parse_sess.span_diagnostic,
                                                    "custom_derive",
                                                    titem.span,
@@ -37,6 +37,7 @@
 #![feature(box_syntax)]
 #![feature(fnbox)]
 #![feature(libc)]
+#![feature(linkage)]
 #![feature(rustc_private)]
 #![feature(set_stdio)]
 #![feature(staged_api)]
@@ -244,6 +245,7 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>) {
         Some(Err(msg)) => panic!("{:?}", msg),
         None => return,
     };
+    name_coverage_profile();
     match run_tests_console(&opts, tests) {
         Ok(true) => {}
         Ok(false) => std::process::exit(101),
@@ -251,6 +253,40 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn>) {
     }
 }
 
+// When the test binary is instrumented with `-Z instrument-coverage` the
+// profiler runtime writes its profile to `default.profraw` in the current
+// directory at exit. Test suites run many test binaries from the same
+// directory, so name the profile after the binary instead, that way each
+// suite ends up with a profile `llvm-cov` can match to its binary. An
+// explicit `LLVM_PROFILE_FILE` still takes precedence.
+#[cfg(target_os = "linux")]
+fn name_coverage_profile() {
+    use std::ffi::CString;
+    use std::mem;
+
+    extern {
+        #[linkage = "extern_weak"]
+        static __llvm_profile_override_default_filename: *const libc::c_void;
+    }
+
+    unsafe {
+        if __llvm_profile_override_default_filename.is_null() {
+            return
+        }
+        let name = match env::current_exe().ok().as_ref().and_then(|p| p.file_stem()) {
+            Some(stem) => format!("{}.profraw", stem.to_string_lossy()),
+            None => return,
+        };
+        // The runtime holds on to the name until the process exits.
+        let name = CString::new(name).unwrap().into_raw();
+        type F = unsafe extern fn(*const libc::c_char);
+        mem::transmute::<*const libc::c_void, F>(__llvm_profile_override_default_filename)(name);
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn name_coverage_profile() {}
+
 // A variant optimized for invocation with a static test vector.
 // This will panic (intentionally) when fed any dynamic tests, because
 // it is copying the static values out into a dynamic vector and cannot
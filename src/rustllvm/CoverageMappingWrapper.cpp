@@ -0,0 +1,127 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#include "rustllvm.h"
+
+#if LLVM_VERSION_MINOR >= 9
+#include "llvm/ProfileData/Coverage/CoverageMapping.h"
+#include "llvm/ProfileData/Coverage/CoverageMappingWriter.h"
+#include "llvm/ProfileData/InstrProf.h"
+
+using namespace llvm::coverage;
+#endif
+
+using namespace llvm;
+
+struct LLVMRustCounterMappingRegion {
+  uint32_t Counter;
+  uint32_t FileID;
+  uint32_t LineStart;
+  uint32_t ColumnStart;
+  uint32_t LineEnd;
+  uint32_t ColumnEnd;
+};
+
+// Source-based coverage relies on the coverage mapping format and the
+// profiling intrinsics as they are in LLVM 3.9, and rustc refuses to
+// instrument anything with an older LLVM, so the fallbacks below are never
+// reached.
+#if LLVM_VERSION_MINOR >= 9
+
+extern "C" void
+LLVMRustCoverageWriteFilenamesSectionToBuffer(const char* const Filenames[],
+                                              size_t FilenamesLen,
+                                              RustStringRef BufferOut) {
+  SmallVector<StringRef, 32> FilenameRefs;
+  for (size_t i = 0; i < FilenamesLen; i++) {
+    FilenameRefs.push_back(StringRef(Filenames[i]));
+  }
+  raw_rust_string_ostream OS(BufferOut);
+  CoverageFilenamesSectionWriter(FilenameRefs).write(OS);
+}
+
+extern "C" void
+LLVMRustCoverageWriteMappingToBuffer(const unsigned *VirtualFileMappingIDs,
+                                     unsigned NumVirtualFileMappingIDs,
+                                     const LLVMRustCounterMappingRegion *RustRegions,
+                                     unsigned NumRegions,
+                                     RustStringRef BufferOut) {
+  SmallVector<CounterMappingRegion, 32> Regions;
+  for (unsigned i = 0; i < NumRegions; i++) {
+    const LLVMRustCounterMappingRegion &R = RustRegions[i];
+    Regions.push_back(CounterMappingRegion::makeRegion(
+        Counter::getCounter(R.Counter), R.FileID,
+        R.LineStart, R.ColumnStart, R.LineEnd, R.ColumnEnd));
+  }
+  ArrayRef<unsigned> FileIDs(VirtualFileMappingIDs, NumVirtualFileMappingIDs);
+  raw_rust_string_ostream OS(BufferOut);
+  CoverageMappingWriter(FileIDs, None, Regions).write(OS);
+}
+
+extern "C" LLVMValueRef
+LLVMRustCoverageCreatePGOFuncNameVar(LLVMValueRef F, const char *FuncName) {
+  return wrap(createPGOFuncNameVar(*unwrap<Function>(F), StringRef(FuncName)));
+}
+
+extern "C" uint64_t
+LLVMRustCoverageComputeHash(const char *Name) {
+  return IndexedInstrProf::ComputeHash(StringRef(Name));
+}
+
+extern "C" void
+LLVMRustCoverageWriteSectionNameToString(LLVMModuleRef M, RustStringRef Str) {
+  raw_rust_string_ostream OS(Str);
+  OS << getInstrProfCoverageSectionName(unwrap(M));
+}
+
+extern "C" uint32_t
+LLVMRustCoverageMappingVersion() {
+  return coverage::CovMapVersion::CurrentVersion;
+}
+
+#else
+
+extern "C" void
+LLVMRustCoverageWriteFilenamesSectionToBuffer(const char* const Filenames[],
+                                              size_t FilenamesLen,
+                                              RustStringRef BufferOut) {
+  report_fatal_error("coverage instrumentation requires LLVM 3.9 or later");
+}
+
+extern "C" void
+LLVMRustCoverageWriteMappingToBuffer(const unsigned *VirtualFileMappingIDs,
+                                     unsigned NumVirtualFileMappingIDs,
+                                     const LLVMRustCounterMappingRegion *RustRegions,
+                                     unsigned NumRegions,
+                                     RustStringRef BufferOut) {
+  report_fatal_error("coverage instrumentation requires LLVM 3.9 or later");
+}
+
+extern "C" LLVMValueRef
+LLVMRustCoverageCreatePGOFuncNameVar(LLVMValueRef F, const char *FuncName) {
+  report_fatal_error("coverage instrumentation requires LLVM 3.9 or later");
+}
+
+extern "C" uint64_t
+LLVMRustCoverageComputeHash(const char *Name) {
+  report_fatal_error("coverage instrumentation requires LLVM 3.9 or later");
+}
+
+extern "C" void
+LLVMRustCoverageWriteSectionNameToString(LLVMModuleRef M, RustStringRef Str) {
+  report_fatal_error("coverage instrumentation requires LLVM 3.9 or later");
+}
+
+extern "C" uint32_t
+LLVMRustCoverageMappingVersion() {
+  report_fatal_error("coverage instrumentation requires LLVM 3.9 or later");
+}
+
+#endif
@@ -176,7 +176,8 @@ LLVMRustCreateTargetMachine(const char *triple,
                             bool UseSoftFloat,
                             bool PositionIndependentExecutable,
                             bool FunctionSections,
-                            bool DataSections) {
+                            bool DataSections,
+                            bool CompressDebugSections) {
     std::string Error;
     Triple Trip(Triple::normalize(triple));
     const llvm::Target *TheTarget = TargetRegistry::lookupTarget(Trip.getTriple(),
@@ -199,6 +200,7 @@ LLVMRustCreateTargetMachine(const char *triple,
     }
     Options.DataSections = DataSections;
     Options.FunctionSections = FunctionSections;
+    Options.CompressDebugSections = CompressDebugSections;
 
     TargetMachine *TM = TheTarget->createTargetMachine(Trip.getTriple(),
                                                        real_cpu,
@@ -241,7 +243,9 @@ LLVMRustConfigurePassManagerBuilder(LLVMPassManagerBuilderRef PMB,
                                     CodeGenOpt::Level OptLevel,
                                     bool MergeFunctions,
                                     bool SLPVectorize,
-                                    bool LoopVectorize) {
+                                    bool LoopVectorize,
+                                    const char *PGOGenPath,
+                                    const char *PGOUsePath) {
 #if LLVM_VERSION_MINOR >= 6
     // Ignore mergefunc for now as enabling it causes crashes.
     //unwrap(PMB)->MergeFunctions = MergeFunctions;
@@ -249,6 +253,22 @@ LLVMRustConfigurePassManagerBuilder(LLVMPassManagerBuilderRef PMB,
     unwrap(PMB)->SLPVectorize = SLPVectorize;
     unwrap(PMB)->OptLevel = OptLevel;
     unwrap(PMB)->LoopVectorize = LoopVectorize;
+
+#if LLVM_VERSION_MINOR >= 9
+    if (PGOGenPath) {
+        assert(!PGOUsePath);
+        unwrap(PMB)->EnablePGOInstrGen = true;
+        unwrap(PMB)->PGOInstrGen = PGOGenPath;
+    }
+    if (PGOUsePath) {
+        assert(!PGOGenPath);
+        unwrap(PMB)->PGOInstrUse = PGOUsePath;
+    }
+#else
+    // IR-level profile instrumentation only exists since LLVM 3.9, the
+    // compiler refuses `-C profile-*` before getting here on older versions.
+    assert(!PGOGenPath && !PGOUsePath);
+#endif
 }
 
 // Unfortunately, the LLVM C API doesn't provide a way to set the `LibraryInfo`
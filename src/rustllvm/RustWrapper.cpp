@@ -316,7 +316,17 @@ extern "C" LLVMMetadataRef LLVMDIBuilderCreateCompileUnit(
     bool isOptimized,
     const char* Flags,
     unsigned RuntimeVer,
-    const char* SplitName) {
+    const char* SplitName,
+    bool LineTablesOnly) {
+#if LLVM_VERSION_MINOR >= 9
+    DICompileUnit::DebugEmissionKind Kind = LineTablesOnly
+        ? DICompileUnit::DebugEmissionKind::LineTablesOnly
+        : DICompileUnit::DebugEmissionKind::FullDebug;
+#else
+    DIBuilder::DebugEmissionKind Kind = LineTablesOnly
+        ? DIBuilder::LineTablesOnly
+        : DIBuilder::FullDebug;
+#endif
     return wrap(Builder->createCompileUnit(Lang,
                                            File,
                                            Dir,
@@ -324,7 +334,8 @@ extern "C" LLVMMetadataRef LLVMDIBuilderCreateCompileUnit(
                                            isOptimized,
                                            Flags,
                                            RuntimeVer,
-                                           SplitName));
+                                           SplitName,
+                                           Kind));
 }
 
 extern "C" LLVMMetadataRef LLVMDIBuilderCreateFile(
@@ -879,6 +890,24 @@ LLVMRustLinkInExternalBitcode(LLVMModuleRef dst, char *bc, size_t len) {
     return true;
 }
 
+// Writes out the producer recorded in a bitcode file, e.g. `LLVM3.9.0`, which
+// older versions of LLVM didn't record at all.
+extern "C" bool
+LLVMRustGetBitcodeProducer(char *bc, size_t len, RustStringRef str) {
+#if LLVM_VERSION_MINOR >= 8
+    MemoryBufferRef Buf(StringRef(bc, len), "");
+    LLVMContext Ctx;
+    ErrorOr<std::string> Producer = llvm::getBitcodeProducerString(Buf, Ctx);
+    if (!Producer || Producer->empty())
+        return false;
+    raw_rust_string_ostream os(str);
+    os << *Producer;
+    return true;
+#else
+    return false;
+#endif
+}
+
 extern "C" void
 LLVMRustSetDLLStorageClass(LLVMValueRef Value,
                            GlobalValue::DLLStorageClassTypes Class) {
@@ -0,0 +1,57 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// force-host
+
+#![feature(plugin_registrar, rustc_private)]
+#![feature(box_syntax)]
+
+#[macro_use] extern crate rustc;
+extern crate rustc_plugin;
+extern crate syntax;
+
+use rustc::hir;
+use rustc::hir::def::Def;
+use rustc::lint::{LateContext, LintContext, LintPass, LateLintPass, LateLintPassObject, LintArray};
+use rustc_plugin::Registry;
+use syntax::print::pprust;
+
+declare_lint!(EXTERN_ATTRS, Warn, "report the attributes of functions from other crates");
+
+struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXTERN_ATTRS)
+    }
+}
+
+impl LateLintPass for Pass {
+    fn check_expr(&mut self, cx: &LateContext, e: &hir::Expr) {
+        if let hir::ExprPath(..) = e.node {
+            let def = cx.tcx.def_map.borrow().get(&e.id).map(|d| d.full_def());
+            if let Some(Def::Fn(did)) = def {
+                if did.is_local() {
+                    return;
+                }
+                for attr in cx.tcx.get_attrs(did).iter() {
+                    cx.span_lint(EXTERN_ATTRS, e.span,
+                                 &format!("extern fn has #[{}]",
+                                          pprust::meta_item_to_string(&attr.node.value)));
+                }
+            }
+        }
+    }
+}
+
+#[plugin_registrar]
+pub fn plugin_registrar(reg: &mut Registry) {
+    reg.register_late_lint_pass(box Pass as LateLintPassObject);
+}
@@ -24,11 +24,10 @@ use syntax::ext::build::AstBuilder;
 use syntax::ext::base::{TTMacroExpander, ExtCtxt, MacResult, MacEager, NormalTT};
 use syntax::parse::token;
 use syntax::print::pprust;
-use syntax::ptr::P;
 use rustc_plugin::Registry;
 
 struct Expander {
-    args: Vec<P<ast::MetaItem>>,
+    args: Vec<ast::NestedMetaItem>,
 }
 
 impl TTMacroExpander for Expander {
@@ -36,7 +35,7 @@ impl TTMacroExpander for Expander {
                    ecx: &'cx mut ExtCtxt,
                    sp: Span,
                    _: &[ast::TokenTree]) -> Box<MacResult+'cx> {
-        let args = self.args.iter().map(|i| pprust::meta_item_to_string(&*i))
+        let args = self.args.iter().map(|i| pprust::meta_list_item_to_string(i))
             .collect::<Vec<_>>().join(", ");
         let interned = token::intern_and_get_ident(&args[..]);
         MacEager::expr(ecx.expr_str(sp, interned))
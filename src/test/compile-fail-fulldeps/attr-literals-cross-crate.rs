@@ -0,0 +1,26 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that literals in attributes, and their order in the list, survive
+// being written to and read back from crate metadata.
+
+// aux-build:lint_extern_attrs.rs
+// aux-build:attr_literals_extern.rs
+// ignore-stage1
+// compile-flags: -D extern-attrs
+
+#![feature(plugin)]
+#![plugin(lint_extern_attrs)]
+
+extern crate attr_literals_extern;
+
+fn main() {
+    attr_literals_extern::f(); //~ ERROR extern fn has #[literals("lit", 1, key = 2, nested(true))]
+}
@@ -0,0 +1,27 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(catch_expr)]
+
+fn main() {
+    loop {
+        let _: Option<()> = do catch {
+            break; //~ ERROR `break` inside of a `do catch` block must be labeled
+        };
+        let _: Option<()> = do catch {
+            continue; //~ ERROR `continue` inside of a `do catch` block must be labeled
+        };
+        let _: Option<()> = do catch {
+            while false {
+                break;
+            }
+        };
+    }
+}
@@ -0,0 +1,21 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(const_generics)]
+
+struct Buf<const N: usize> {
+    data: [u8; N],
+}
+
+fn main() {
+    let _: Buf<1, 2>; //~ ERROR wrong number of const arguments: expected 1, found 2
+    let _: Buf<-1>; //~ ERROR E0569
+    let _: Buf<{1}> = Buf { data: [0; 2] }; //~ ERROR mismatched types
+}
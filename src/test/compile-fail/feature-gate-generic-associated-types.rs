@@ -0,0 +1,27 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+trait StreamingIterator {
+    type Item<'a>; //~ ERROR generic associated types are unstable
+    fn next<'a>(&'a mut self) -> Option<Self::Item<'a>>;
+}
+
+struct Windows<T> {
+    data: Vec<T>,
+}
+
+impl<T> StreamingIterator for Windows<T> {
+    type Item<'a> = &'a [T]; //~ ERROR generic associated types are unstable
+    fn next<'a>(&'a mut self) -> Option<&'a [T]> {
+        Some(&self.data)
+    }
+}
+
+fn main() {}
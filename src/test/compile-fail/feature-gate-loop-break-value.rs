@@ -0,0 +1,22 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that `break` with a value is rejected without feature(loop_break_value)
+
+fn main() {
+    let x = loop {
+        break 4; //~ ERROR `break` with a value is experimental
+    };
+    let _ = 'outer: loop {
+        loop {
+            break 'outer x; //~ ERROR `break` with a value is experimental
+        }
+    };
+}
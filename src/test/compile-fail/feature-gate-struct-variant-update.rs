@@ -0,0 +1,19 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+enum Shape {
+    Rect { w: u32, h: u32 },
+}
+
+fn main() {
+    let a = Shape::Rect { w: 1, h: 2 };
+    let _ = Shape::Rect { w: 3, ..a };
+    //~^ ERROR functional record update of an enum variant is unstable
+}
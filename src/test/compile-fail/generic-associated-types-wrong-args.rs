@@ -0,0 +1,44 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(generic_associated_types, const_generics)]
+
+trait Family {
+    type Member<T>;
+}
+
+trait Lending {
+    type Item<'a>;
+}
+
+struct Foo;
+
+impl Family for Foo {
+    type Member = u8; //~ ERROR E0573
+}
+
+impl Lending for Foo {
+    type Item<'a, 'b> = &'a &'b u8; //~ ERROR E0573
+}
+
+fn two_types<F: Family>(_: F::Member<u8, u16>) {} //~ ERROR wrong number of type arguments
+
+fn no_types<F: Family>(_: F::Member) {} //~ ERROR wrong number of type arguments
+
+fn too_many_lifetimes<'a, L: Lending>(_: L::Item<'a, 'a>) {}
+//~^ ERROR wrong number of lifetime parameters
+
+fn no_object(_: &Lending) {} //~ ERROR E0038
+
+trait Konst {
+    type Arr<const N: usize>; //~ ERROR E0572
+}
+
+fn main() {}
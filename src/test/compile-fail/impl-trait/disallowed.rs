@@ -0,0 +1,40 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(conservative_impl_trait)]
+
+fn arguments(_: impl Fn()) {}
+//~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+
+fn into_fn_ptr() -> fn() -> impl Clone { loop {} }
+//~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+
+fn in_fn_trait() -> Box<Fn() -> impl Clone> { loop {} }
+//~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+
+type Alias = impl Clone;
+//~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+
+trait Trait {
+    fn in_trait(&self) -> impl Clone;
+    //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+}
+
+impl Trait for () {
+    fn in_trait(&self) -> impl Clone { 0 }
+    //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+}
+
+extern {
+    fn foreign() -> impl Clone;
+    //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(conservative_impl_trait)]
+
+use std::fmt::Debug;
+
+fn hide<T: Debug>(x: T) -> impl Debug {
+    x
+}
+
+fn main() {
+    // Only the methods of the bounds are visible on an `impl Trait`.
+    let v = hide(vec![1, 2, 3]);
+    v.len(); //~ ERROR no method named `len` found
+
+    let x: Vec<i32> = hide(vec![1]);
+    //~^ ERROR mismatched types
+}
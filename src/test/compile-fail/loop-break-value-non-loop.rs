@@ -0,0 +1,33 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(loop_break_value)]
+
+fn main() {
+    let mut i = 0;
+    while i < 10 {
+        i += 1;
+        break 1; //~ ERROR `break` with value from a `while` loop
+    }
+
+    while let Some(_) = Some(0) {
+        break 2; //~ ERROR `break` with value from a `while let` loop
+    }
+
+    for _ in 0..10 {
+        break 3; //~ ERROR `break` with value from a `for` loop
+    }
+
+    'outer: while i < 20 {
+        loop {
+            break 'outer 4; //~ ERROR `break` with value from a `while` loop
+        }
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// error-pattern:is not compiled with this crate's panic strategy `unwind`
+// no-prefer-dynamic
+// aux-build:panic-runtime-abort.rs
+
+// The panic runtime linked here was compiled with `-C panic=abort`, which
+// can't be used to unwind out of this crate.
+
+extern crate panic_runtime_abort;
+
+fn main() {}
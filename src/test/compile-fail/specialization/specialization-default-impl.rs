@@ -0,0 +1,37 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(specialization)]
+
+// Check that a `default impl` never implements the trait on its own, and
+// that only trait impls may be declared `default`.
+
+trait Foo {
+    fn foo(&self);
+    fn bar(&self);
+}
+
+// A `default impl` need not provide every item.
+default impl<T: Clone> Foo for T {
+    fn foo(&self) {}
+}
+
+// An impl that specializes it must still be complete.
+impl Foo for u8 {} //~ ERROR E0046
+
+fn needs_foo<T: Foo>(_: T) {}
+
+struct S;
+
+default impl S {} //~ ERROR E0574
+
+fn main() {
+    needs_foo(0u16); //~ ERROR the trait bound `u16: Foo` is not satisfied
+}
@@ -0,0 +1,23 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(trait_alias)]
+
+use std::cell::Cell;
+
+trait SendSync = Send + Sync;
+
+fn assert_send_sync<T: SendSync>(_: T) {}
+
+fn main() {
+    assert_send_sync(1u32);
+    assert_send_sync(Cell::new(1u32));
+    //~^ ERROR the trait bound `std::cell::Cell<u32>: std::marker::Sync` is not satisfied
+}
@@ -0,0 +1,32 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that without feature(never_type) matches must still cover variants
+// holding an uninhabited type, and only empty enums may be matched with no
+// arms.
+
+enum Void {}
+
+struct Wrapper(Void);
+
+fn unwrap(r: Result<u32, Void>) -> u32 {
+    match r { //~ ERROR non-exhaustive patterns: `Err(_)` not covered
+        Ok(t) => t,
+    }
+}
+
+fn absurd(w: Wrapper) -> u32 {
+    match w {} //~ ERROR non-exhaustive patterns: type Wrapper is non-empty
+}
+
+fn main() {
+    let _ = unwrap(Ok(1));
+    let _ = absurd;
+}
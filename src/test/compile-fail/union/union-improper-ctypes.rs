@@ -0,0 +1,37 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(untagged_unions)]
+#![deny(improper_ctypes)]
+#![allow(dead_code)]
+
+union U {
+    a: u8,
+}
+
+#[repr(C)]
+union C {
+    a: u8,
+    b: u32,
+}
+
+#[repr(C)]
+union B {
+    a: u8,
+    b: String,
+}
+
+extern {
+    fn uf(x: U); //~ ERROR found union without foreign-function-safe
+    fn cf(x: C); // OK
+    fn bf(x: B); //~ ERROR found non-foreign-function-safe member in union marked #[repr(C)]
+}
+
+fn main() {}
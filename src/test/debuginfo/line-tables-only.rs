@@ -0,0 +1,44 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-lldb
+
+// compile-flags:-C debuginfo=line-tables-only
+
+// Make sure functions still have names and breakpoints can be set by line
+// gdb-command:info functions
+// gdb-check:[...]void[...]main([...]);
+// gdb-check:[...]void[...]zzz([...]);
+
+// gdb-command:run
+
+// Make sure there is no information about locals or types
+// gdb-command:info locals
+// gdb-check:No locals.
+// gdb-command:ptype Point
+// gdb-check:No symbol "Point" in current context.
+// gdb-command:continue
+
+#![allow(unused_variables)]
+#![feature(omit_gdb_pretty_printer_section)]
+#![omit_gdb_pretty_printer_section]
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let point = Point { x: 7, y: -8 };
+    let total = point.x + point.y;
+    zzz(); // #break
+}
+
+fn zzz() {()}
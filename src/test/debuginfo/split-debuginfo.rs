@@ -0,0 +1,46 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-lldb
+// ignore-windows
+// ignore-macos
+// min-gdb-version 7.8
+
+// compile-flags:-g -C split-debuginfo=packed
+
+// The `.dwo` files are removed once they've been packaged, so gdb has to find
+// the variables and types in the `.dwp` file next to the executable. Where the
+// split debuginfo ends up in each mode is checked by run-make/split-debuginfo.
+
+// gdb-command:run
+// gdb-command:print point
+// gdb-check:$1 = {x = 7, y = -8}
+// gdb-command:print total
+// gdb-check:$2 = -1
+// gdb-command:whatis point.x
+// gdb-check:type = i32
+// gdb-command:continue
+
+#![allow(unused_variables)]
+#![feature(omit_gdb_pretty_printer_section)]
+#![omit_gdb_pretty_printer_section]
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let point = Point { x: 7, y: -8 };
+    let total = point.x + point.y;
+    zzz(); // #break
+}
+
+fn zzz() {()}
@@ -0,0 +1,38 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z parse-only -Z continue-parse-after-error
+
+fn main() {
+    0b102; //~ ERROR invalid digit for a base 2 literal
+    //~^ HELP base 2 literals may only contain the digits `0` and `1`
+    0o18; //~ ERROR invalid digit for a base 8 literal
+    //~^ HELP base 8 literals may only contain the digits `0` to `7`
+
+    1ul; //~ ERROR invalid suffix `ul` for numeric literal
+    //~^ HELP try using a valid suffix
+    1LL; //~ ERROR invalid suffix `LL` for numeric literal
+    //~^ HELP try using a valid suffix
+    1u33; //~ ERROR invalid width `33` for integer literal
+    //~^ HELP try using a valid suffix
+    1i1024; //~ ERROR invalid width `1024` for integer literal
+    //~^ HELP valid widths are 8, 16, 32 and 64
+    1usise; //~ ERROR invalid suffix `usise` for numeric literal
+    //~^ HELP try using a valid suffix
+    1suffix; //~ ERROR invalid suffix `suffix` for numeric literal
+    //~^ HELP the suffix must be one of the integral types
+
+    1.0f; //~ ERROR invalid suffix `f` for float literal
+    //~^ HELP try using a valid suffix
+    1.0f63; //~ ERROR invalid width `63` for float literal
+    //~^ HELP try using a valid suffix
+    1.0suffix; //~ ERROR invalid suffix `suffix` for float literal
+    //~^ HELP valid suffixes are `f32` and `f64`
+}
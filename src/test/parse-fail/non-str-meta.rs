@@ -10,8 +10,8 @@
 
 // compile-flags: -Z parse-only
 
-// Issue #623 - non-string meta items are not serialized correctly;
-// for now just forbid them
+// Literals in attributes are parsed, but suffixed ones are rejected since
+// the suffix carries no meaning there.
 
-#[foo = 1] //~ ERROR: non-string literals are not allowed in meta-items
+#[foo = 1usize] //~ ERROR: suffixed literals are not allowed in attributes
 fn main() { }
@@ -0,0 +1,40 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z parse-only -Z parse-recovery
+
+// Check that in error-recovery mode every syntax error in the file is
+// reported, rather than just the first one.
+
+fn missing_exprs(x: u32) {
+    let a = ; //~ ERROR expected expression, found `;`
+    foo(1, , 2); //~ ERROR expected expression, found `,`
+    match x {
+        0 => , //~ ERROR expected expression, found `,`
+        _ => {}
+    }
+}
+
+fn missing_semi() {
+    let a = 1
+    let b = 2; //~ ERROR found `let`
+}
+
+garbage here; //~ ERROR expected item, found `garbage`
+
+fn unclosed() { //~ ERROR un-closed delimiter
+    if true { //~ ERROR un-closed delimiter
+        bar();
+
+fn after_unclosed() {
+    let c = ; //~ ERROR expected expression, found `;`
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// pp-exact
+
+// Without `-Z pretty-style`, comments around items and fields are printed
+// as they always were.
+
+// The high byte.
+const MASK: u32 = 0xff_00;
+
+/// A point.
+struct Point {
+    // Horizontal.
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let point = Point{x: 0x1, y: MASK as i32,}; // trailing
+    sign(point.x);
+}
+
+fn sign(x: i32) -> i32 {
+    if x < 1 {
+        0
+    } else {
+        1
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// pp-exact
+// compile-flags: -Z pretty-style=indent=2,trailing-comments
+
+// Comments, including those trailing an item or a field, and the source
+// text of literals survive printing in a non-default style.
+
+const MASK: u32 = 0xff_00; // the high byte
+
+/// A point.
+struct Point {
+  x: i32, // horizontal
+  y: i32,
+}
+
+fn main() {
+  // The literals keep their spelling.
+  let _bytes = b"\x00";
+  let _big = 1_000_000u64;
+  let point = Point{x: 0x1, y: MASK as i32,}; // trailing
+  match point.x {
+    0x1 => (),
+    _ => (),
+  }
+}
@@ -0,0 +1,49 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// pp-exact
+// compile-flags: -Z pretty-style=indent=2,brace-style=next-line
+
+// The bodies of items open on a line of their own, those of expressions
+// don't.
+
+struct Point
+{
+  x: i32,
+  y: i32,
+}
+
+enum Shape
+{
+  Dot(Point),
+  Line(Point, Point),
+}
+
+trait Area
+{
+  fn area(&self) -> i32;
+}
+
+impl Area for Shape
+{
+  fn area(&self) -> i32
+  {
+    match *self {
+      Shape::Dot(..) => 0,
+      Shape::Line(ref a, ref b) => (b.x - a.x) * (b.y - a.y),
+    }
+  }
+}
+
+fn main()
+{
+  let shape = Shape::Dot(Point{x: 1, y: 2,});
+  let _area = shape.area();
+}
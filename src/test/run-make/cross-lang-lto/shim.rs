@@ -0,0 +1,23 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![crate_type = "rlib"]
+
+// The bundled library's only member is `shim_foreign.o`, which starts with
+// this crate's name without being one of rustc's own objects.
+#[link(name = "shim_foreign", kind = "static")]
+extern {
+    fn foreign_add_one(x: u32) -> u32;
+}
+
+pub fn add_one(x: u32) -> u32 {
+    unsafe { foreign_add_one(x) }
+}
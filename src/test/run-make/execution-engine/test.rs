@@ -238,6 +238,7 @@ fn compile_program(input: &str, sysroot: PathBuf)
 
         let krate = driver::phase_2_configure_and_expand(&sess, &cstore, krate, &id, None)
             .expect("phase_2 returned `None`");
+        let outputs = driver::build_output_filenames(&input, &None, &None, &krate.attrs, &sess);
 
         let dep_graph = DepGraph::new(sess.opts.build_dep_graph());
         let krate = driver::assign_node_ids(&sess, krate);
@@ -252,7 +253,8 @@ fn compile_program(input: &str, sysroot: PathBuf)
             &sess, ast_map, &arenas, &id,
             MakeGlobMap::No, |tcx, mir_map, analysis, _| {
 
-            let trans = driver::phase_4_translate_to_llvm(tcx, mir_map.unwrap(), analysis);
+            let trans = driver::phase_4_translate_to_llvm(tcx, mir_map.unwrap(), analysis,
+                                                          &outputs);
 
             let crates = tcx.sess.cstore.used_crates(LinkagePreference::RequireDynamic);
 
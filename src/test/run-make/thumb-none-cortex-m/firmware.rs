@@ -0,0 +1,24 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_type = "rlib"]
+#![no_std]
+
+pub fn checked_area(width: u64, height: u64) -> Option<u64> {
+    width.checked_mul(height)
+}
+
+pub fn checksum(data: &[u8]) -> u32 {
+    data.iter().fold(0u32, |sum, &b| sum.rotate_left(5) ^ b as u32)
+}
+
+pub fn scale(sample: f32) -> f32 {
+    sample * 2.5
+}
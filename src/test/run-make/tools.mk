@@ -126,3 +126,22 @@ endif
 
 $(TMPDIR)/lib%.o: %.c
 	$(call COMPILE_OBJ,$@,$<)
+
+# A test that needs a compiler-rt runtime sets NEEDS_SANITIZER_SUPPORT or
+# NEEDS_PROFILER_SUPPORT before including this file. The runtimes are only
+# built when the build was configured with them, the sanitizers only for
+# x86_64 Linux, and without them the test does nothing.
+ifdef NEEDS_SANITIZER_SUPPORT
+ifneq ($(SANITIZER_SUPPORT)-$(TARGET),1-x86_64-unknown-linux-gnu)
+SKIP_TEST = 1
+endif
+endif
+ifdef NEEDS_PROFILER_SUPPORT
+ifndef PROFILER_SUPPORT
+SKIP_TEST = 1
+endif
+endif
+ifdef SKIP_TEST
+.DEFAULT_GOAL := skip
+skip:
+endif
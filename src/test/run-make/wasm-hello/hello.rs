@@ -0,0 +1,22 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+fn main() {
+    // There's no randomness to seed it with, so this uses `RandomState`'s
+    // fixed keys rather than failing
+    let mut map = HashMap::new();
+    map.insert("answer", 42);
+    println!("hello from wasm");
+    println!("answer = {}", map["answer"]);
+    writeln!(io::stderr(), "bye from wasm").unwrap();
+}
@@ -0,0 +1,22 @@
+// Runs a module produced for wasm32-unknown-unknown, forwarding whatever it
+// writes to standard output and error to the process' own stdout.
+
+var fs = require('fs');
+
+var bytes = fs.readFileSync(process.argv[2]);
+var memory;
+var imports = {
+    env: {
+        rust_wasm_write: function(fd, ptr, len) {
+            var data = new Uint8Array(memory.buffer, ptr, len);
+            process.stdout.write(new Buffer(data));
+            return len;
+        }
+    }
+};
+
+WebAssembly.instantiate(bytes, imports).then(function(result) {
+    var exports = result.instance.exports;
+    memory = exports.memory;
+    exports.main(0, 0);
+});
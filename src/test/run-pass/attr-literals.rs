@@ -0,0 +1,34 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that literals in attributes parse just fine.
+
+#![feature(custom_attribute, attr_literals)]
+#![allow(dead_code)]
+#![allow(unused_variables)]
+
+#[fake_attr] // ok
+#[fake_attr(100)] // ok
+#[fake_attr(1, 2, 3)] // ok
+#[fake_attr("hello")] // ok
+#[fake_attr(name = "hello")] // ok
+#[fake_attr(1, "hi", key = 12, true, false)] // ok
+#[fake_attr(key = "hello", val = 10)] // ok
+#[fake_attr(key("hello"), val(10))] // ok
+#[fake_attr(enabled = true, disabled = false)] // ok
+#[fake_attr(true)] // ok
+#[fake_attr(pi = 3.14159)] // ok
+#[fake_attr(b"hi")] // ok
+#[fake_doc(r"doc")] // ok
+struct Q {  }
+
+#[limit(32)]
+#[flag(true)]
+fn main() { }
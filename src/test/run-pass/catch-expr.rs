@@ -0,0 +1,80 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(catch_expr, question_mark)]
+
+fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+    s.parse()
+}
+
+fn main() {
+    // `?` propagates to the `do catch` block, not out of `main`.
+    let sum: Result<i32, std::num::ParseIntError> = do catch {
+        parse("1")? + parse("2")?
+    };
+    assert_eq!(sum, Ok(3));
+
+    let sum: Result<i32, std::num::ParseIntError> = do catch {
+        parse("1")? + parse("x")?
+    };
+    assert!(sum.is_err());
+
+    // `?` on an `Option` works the same way, and the block can be left
+    // early from inside a loop.
+    let v = vec![1, 2, 3];
+    let mut seen = 0;
+    let last: Option<i32> = do catch {
+        let mut i = 0;
+        loop {
+            let x = *v.get(i)?;
+            seen += 1;
+            i += 1;
+            if x > 5 {
+                break;
+            }
+        }
+        0
+    };
+    assert_eq!(last, None);
+    assert_eq!(seen, 3);
+
+    // Nested blocks only catch their own errors.
+    let outer: Option<Option<i32>> = do catch {
+        let inner: Option<i32> = do catch {
+            None::<i32>?
+        };
+        let x = Some(inner.unwrap_or(7))?;
+        Some(x)
+    };
+    assert_eq!(outer, Some(Some(7)));
+
+    // A closure inside the block returns its own errors.
+    let r: Option<i32> = do catch {
+        let f = |x: Option<i32>| -> Option<i32> { Some(x? * 2) };
+        assert_eq!(f(None), None);
+        f(Some(21))?
+    };
+    assert_eq!(r, Some(42));
+
+    // Labeled jumps out of the block still work.
+    let mut count = 0;
+    'outer: for i in 0..10 {
+        let _: Option<()> = do catch {
+            if i == 3 {
+                break 'outer;
+            }
+            if i % 2 == 0 {
+                continue 'outer;
+            }
+            count += 1;
+        };
+    }
+    assert_eq!(count, 1);
+}
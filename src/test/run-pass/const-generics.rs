@@ -0,0 +1,50 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(const_generics)]
+
+struct ArrayVec<T, const N: usize> {
+    data: [T; N],
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> ArrayVec<T, {N}> {
+    fn new(init: T) -> ArrayVec<T, {N}> {
+        ArrayVec { data: [init; N], len: 0 }
+    }
+
+    fn push(&mut self, value: T) {
+        self.data[self.len] = value;
+        self.len += 1;
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+fn sum<const N: usize>(xs: [u32; N]) -> u32 {
+    let mut total = 0;
+    for i in 0..N {
+        total += xs[i];
+    }
+    total
+}
+
+fn main() {
+    let mut v: ArrayVec<u8, 4> = ArrayVec::new(0);
+    v.push(1);
+    v.push(2);
+    assert_eq!(v.capacity(), 4);
+    assert_eq!(v.data, [1, 2, 0, 0]);
+
+    assert_eq!(sum([1, 2, 3]), 6);
+    assert_eq!(sum::<2>([5, 7]), 12);
+}
@@ -0,0 +1,69 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(generic_associated_types)]
+
+trait StreamingIterator {
+    type Item<'a> where Self: 'a;
+    fn next<'a>(&'a mut self) -> Option<Self::Item<'a>>;
+}
+
+struct Windows<T> {
+    data: Vec<T>,
+    size: usize,
+    pos: usize,
+}
+
+impl<T> StreamingIterator for Windows<T> {
+    type Item<'a> where T: 'a = &'a mut [T];
+
+    fn next<'a>(&'a mut self) -> Option<&'a mut [T]> {
+        let start = self.pos;
+        if start + self.size > self.data.len() {
+            return None;
+        }
+        self.pos += 1;
+        Some(&mut self.data[start..start + self.size])
+    }
+}
+
+trait PointerFamily {
+    type Pointer<T>;
+    fn new<T>(value: T) -> Self::Pointer<T>;
+}
+
+struct BoxFamily;
+
+impl PointerFamily for BoxFamily {
+    type Pointer<T> = Box<T>;
+    fn new<T>(value: T) -> Box<T> {
+        Box::new(value)
+    }
+}
+
+fn make_pair<P: PointerFamily>(a: u8, b: &'static str)
+                               -> (<P as PointerFamily>::Pointer<u8>, P::Pointer<&'static str>) {
+    (P::new(a), P::new(b))
+}
+
+fn main() {
+    let mut windows = Windows { data: vec![1, 2, 3], size: 2, pos: 0 };
+    {
+        let first = windows.next().unwrap();
+        assert_eq!(first, &mut [1, 2]);
+    }
+    let second = windows.next().unwrap();
+    assert_eq!(second, &mut [2, 3]);
+    assert!(windows.next().is_none());
+
+    let (a, b) = make_pair::<BoxFamily>(7, "seven");
+    assert_eq!(*a, 7);
+    assert_eq!(*b, "seven");
+}
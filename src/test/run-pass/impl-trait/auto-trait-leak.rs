@@ -0,0 +1,34 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(conservative_impl_trait)]
+
+// Auto traits such as Send are implemented by an anonymized type
+// whenever they are implemented by its concrete type.
+
+fn send<T: Send>(_: T) {}
+
+fn before() -> impl Fn(i32) {
+    let p = Box::new(0);
+    move |x| assert_eq!(x, *p)
+}
+
+fn main() {
+    send(before());
+
+    // `after` has not been type-checked yet, so its concrete type is
+    // not known and this `Send` obligation is checked at the end.
+    send(after());
+}
+
+fn after() -> impl Fn(i32) {
+    let p = Box::new(0);
+    move |x| assert_eq!(x, *p)
+}
@@ -0,0 +1,63 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(conservative_impl_trait)]
+
+use std::fmt::Debug;
+
+// Free functions returning iterators and closures.
+fn evens(n: u32) -> impl Iterator<Item=u32> {
+    (0..n).filter(|x| x % 2 == 0)
+}
+
+fn adder(n: u32) -> impl Fn(u32) -> u32 {
+    move |x| x + n
+}
+
+// Each `impl Trait` in a return type is inferred independently.
+fn pair() -> (impl Debug, impl Clone) {
+    (1u8, "two")
+}
+
+// The concrete type may depend on the type parameters.
+fn wrap<T: Clone>(x: T) -> impl Iterator<Item=T> {
+    Some(x).into_iter()
+}
+
+// Lifetimes that appear in the bounds may be captured.
+fn chars<'a>(s: &'a str) -> impl Iterator<Item=char> + 'a {
+    s.chars().rev()
+}
+
+struct Counter {
+    max: u32,
+}
+
+impl Counter {
+    // `impl Trait` is allowed in inherent methods.
+    fn iter(&self) -> impl Iterator<Item=u32> {
+        1..self.max + 1
+    }
+}
+
+fn main() {
+    assert_eq!(evens(10).collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+    assert_eq!(adder(3)(4), 7);
+
+    let (a, b) = pair();
+    assert_eq!(format!("{:?}", a), "1");
+    let _b2 = b.clone();
+
+    assert_eq!(wrap(5).collect::<Vec<_>>(), [5]);
+    assert_eq!(chars("abc").collect::<String>(), "cba");
+
+    let c = Counter { max: 3 };
+    assert_eq!(c.iter().fold(0, |a, b| a + b), 6);
+}
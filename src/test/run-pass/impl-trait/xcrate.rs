@@ -0,0 +1,22 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:impl_trait_xcrate.rs
+
+#![feature(conservative_impl_trait)]
+
+extern crate impl_trait_xcrate;
+
+use impl_trait_xcrate::{adder, numbers};
+
+fn main() {
+    assert_eq!(adder(1)(2), 3);
+    assert_eq!(numbers().collect::<Vec<_>>(), [1, 2, 3]);
+}
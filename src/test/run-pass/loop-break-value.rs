@@ -0,0 +1,66 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(loop_break_value)]
+
+fn find_first_even(xs: &[i32]) -> Option<i32> {
+    let mut iter = xs.iter();
+    loop {
+        match iter.next() {
+            Some(&x) if x % 2 == 0 => break Some(x),
+            Some(_) => {}
+            None => break None,
+        }
+    }
+}
+
+fn main() {
+    let mut count = 0;
+    let x = loop {
+        count += 1;
+        if count == 3 {
+            break count * 10;
+        }
+    };
+    assert_eq!(x, 30);
+
+    let s: String = loop {
+        break format!("{}-{}", x, count);
+    };
+    assert_eq!(s, "30-3");
+
+    let pair = 'outer: loop {
+        let mut i = 0;
+        loop {
+            i += 1;
+            if i == 5 {
+                break 'outer (i, "five");
+            }
+        }
+    };
+    assert_eq!(pair, (5, "five"));
+
+    let nested = loop {
+        let inner = loop {
+            break 2;
+        };
+        break inner + 1;
+    };
+    assert_eq!(nested, 3);
+
+    // Breaking without a value gives `()`, as before.
+    let unit: () = loop {
+        break;
+    };
+    assert_eq!(unit, ());
+
+    assert_eq!(find_first_even(&[1, 3, 4, 5]), Some(4));
+    assert_eq!(find_first_even(&[1, 3]), None);
+}
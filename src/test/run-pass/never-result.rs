@@ -0,0 +1,35 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that we can extract a `!` through pattern matching and use it as
+// several different types, and that matching on `Result<T, !>` only has to
+// cover `Ok`.
+
+#![feature(never_type)]
+
+fn unwrap<T>(r: Result<T, !>) -> T {
+    match r {
+        Ok(t) => t,
+    }
+}
+
+fn main() {
+    let x: Result<u32, !> = Ok(123);
+    match x {
+        Ok(z) => assert_eq!(z, 123),
+        Err(y) => {
+            let _q: u32 = y;
+            let _w: i32 = y;
+            let _e: String = y;
+            y
+        }
+    }
+    assert_eq!(unwrap(Ok::<u32, !>(456)), 456);
+}
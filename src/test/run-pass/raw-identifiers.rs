@@ -0,0 +1,56 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(raw_identifiers)]
+
+struct r#struct {
+    r#match: u32,
+    r#type: &'static str,
+}
+
+fn r#fn(r#in: u32) -> u32 {
+    r#in + 1
+}
+
+macro_rules! ident {
+    ($i:ident) => { $i }
+}
+
+macro_rules! is_foo {
+    (foo) => { true };
+    ($i:ident) => { false };
+}
+
+macro_rules! is_match {
+    (match) => { true };
+    ($i:ident) => { false };
+}
+
+fn main() {
+    let r#let = r#fn(1);
+    assert_eq!(r#let, 2);
+    assert_eq!(ident!(r#let), 2);
+
+    // A raw identifier that isn't a keyword names the same thing as the plain
+    // one, but macro matchers still tell the two tokens apart.
+    let r#foo = 3;
+    assert_eq!(foo, 3);
+    assert!(is_foo!(foo));
+    assert!(!is_foo!(r#foo));
+    assert!(!is_foo!(r#bar));
+
+    // `r#match` is an identifier, not the `match` keyword.
+    assert!(is_match!(match));
+    assert!(!is_match!(r#match));
+
+    let s = r#struct { r#match: r#let, r#type: "type" };
+    assert_eq!(s.r#match, 2);
+    assert_eq!(s.r#type, "type");
+}
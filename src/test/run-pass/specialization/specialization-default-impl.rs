@@ -0,0 +1,46 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(specialization)]
+
+// Test that a `default impl` supplies items to the impls that specialize it
+
+trait Foo {
+    fn foo(&self) -> &'static str;
+    fn bar(&self) -> &'static str;
+}
+
+// Specialization tree for Foo:
+//
+//     default T
+//        /  \
+//      u8   Vec<T>
+
+default impl<T: Clone> Foo for T {
+    fn foo(&self) -> &'static str { "generic foo" }
+    fn bar(&self) -> &'static str { "generic bar" }
+}
+
+impl Foo for u8 {
+    fn bar(&self) -> &'static str { "u8 bar" }
+}
+
+impl<T: Clone> Foo for Vec<T> {
+    fn foo(&self) -> &'static str { "Vec foo" }
+}
+
+fn foo_bar<T: Foo>(t: &T) -> (&'static str, &'static str) {
+    (t.foo(), t.bar())
+}
+
+fn main() {
+    assert_eq!(foo_bar(&0u8), ("generic foo", "u8 bar"));
+    assert_eq!(foo_bar(&vec![0u8]), ("Vec foo", "generic bar"));
+}
@@ -0,0 +1,42 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(specialization)]
+
+// Test that two overlapping impls are accepted when a third impl specializes
+// both of them and covers their intersection
+
+trait Foo {
+    fn foo(&self) -> &'static str;
+}
+
+// Specialization lattice for Foo:
+//
+//    (T, u8)   (u8, T)
+//          \   /
+//         (u8, u8)
+
+impl<T> Foo for (T, u8) {
+    default fn foo(&self) -> &'static str { "(T, u8)" }
+}
+
+impl<T> Foo for (u8, T) {
+    default fn foo(&self) -> &'static str { "(u8, T)" }
+}
+
+impl Foo for (u8, u8) {
+    fn foo(&self) -> &'static str { "(u8, u8)" }
+}
+
+fn main() {
+    assert_eq!((0u16, 0u8).foo(), "(T, u8)");
+    assert_eq!((0u8, 0u16).foo(), "(u8, T)");
+    assert_eq!((0u8, 0u8).foo(), "(u8, u8)");
+}
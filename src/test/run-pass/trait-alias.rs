@@ -0,0 +1,57 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(trait_alias)]
+
+use std::fmt::Display;
+
+trait Printable = Display + Clone;
+trait IntIter = Iterator<Item=i32>;
+trait Handler = Fn(u32) -> u32 + Send + Sync + 'static;
+trait Pair<T> = PartialEq<T> + PartialOrd<T>;
+
+fn show_twice<T: Printable>(x: T) -> String {
+    let y = x.clone();
+    format!("{}{}", x, y)
+}
+
+fn sum<I>(iter: I) -> i32 where I: IntIter {
+    iter.fold(0, |a, b| a + b)
+}
+
+fn at_most<T: Pair<u8>>(x: T, y: u8) -> bool {
+    x <= y
+}
+
+fn boxed_sum(iter: Box<IntIter>) -> i32 {
+    let mut total = 0;
+    for x in iter {
+        total += x;
+    }
+    total
+}
+
+fn call(handler: &Handler, x: u32) -> u32 {
+    handler(x)
+}
+
+fn assert_send<T: Send + ?Sized>(_: &T) {}
+
+fn main() {
+    assert_eq!(show_twice(12), "1212");
+    assert_eq!(sum(vec![1, 2, 3].into_iter()), 6);
+    assert!(at_most(3u8, 4));
+    assert!(!at_most(5u8, 4));
+    assert_eq!(boxed_sum(Box::new(vec![4, 5].into_iter())), 9);
+
+    let handler: Box<Handler> = Box::new(|x| x * 2);
+    assert_send(&*handler);
+    assert_eq!(call(&*handler, 21), 42);
+}
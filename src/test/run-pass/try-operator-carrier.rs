@@ -0,0 +1,81 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(question_mark, question_mark_carrier)]
+
+use std::ops::Carrier;
+use std::option::NoneError;
+
+// A result-like type of our own, with `?` converting to and from it.
+#[derive(Debug, PartialEq)]
+enum Outcome<T> {
+    Done(T),
+    Failed(String),
+}
+
+impl<T> Carrier for Outcome<T> {
+    type Success = T;
+    type Error = String;
+
+    fn into_result(self) -> Result<T, String> {
+        match self {
+            Outcome::Done(v) => Ok(v),
+            Outcome::Failed(e) => Err(e),
+        }
+    }
+
+    fn from_success(v: T) -> Self {
+        Outcome::Done(v)
+    }
+
+    fn from_error(e: String) -> Self {
+        Outcome::Failed(e)
+    }
+}
+
+fn half(x: u32) -> Outcome<u32> {
+    if x % 2 == 0 {
+        Outcome::Done(x / 2)
+    } else {
+        Outcome::Failed(format!("{} is odd", x))
+    }
+}
+
+fn quarter(x: u32) -> Outcome<u32> {
+    let y = half(x)?;
+    half(y)
+}
+
+fn quarter_result(x: u32) -> Result<u32, String> {
+    Ok(half(half(x)?)?)
+}
+
+fn first_even(v: &[u32]) -> Option<u32> {
+    let first = *v.first()?;
+    if first % 2 == 0 { Some(first) } else { None }
+}
+
+fn first_or_error(v: &[u32]) -> Result<u32, NoneError> {
+    Ok(*v.first()?)
+}
+
+fn main() {
+    assert_eq!(quarter(8), Outcome::Done(2));
+    assert_eq!(quarter(6), Outcome::Failed("3 is odd".to_string()));
+    assert_eq!(quarter_result(4), Ok(1));
+    assert_eq!(quarter_result(5), Err("5 is odd".to_string()));
+
+    assert_eq!(first_even(&[2, 3]), Some(2));
+    assert_eq!(first_even(&[3]), None);
+    assert_eq!(first_even(&[]), None);
+
+    assert_eq!(first_or_error(&[1]), Ok(1));
+    assert_eq!(first_or_error(&[]), Err(NoneError));
+}